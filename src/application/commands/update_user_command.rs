@@ -8,8 +8,7 @@ use crate::domain::entity::user::User;
 use crate::domain::repository::user_command_repository::UserCommandRepositoryInterface;
 use crate::domain::repository::user_query_repository::UserQueryRepositoryInterface;
 use crate::domain::value_object::{
-    birth_date::BirthDate, email::Email, password::Password, phone::Phone, user_id::UserId,
-    user_name::UserName,
+    birth_date::BirthDate, phone::Phone, user_id::UserId, user_name::UserName,
 };
 use crate::shared::error::application_error::{ApplicationError, ApplicationResult};
 use async_trait::async_trait;
@@ -99,19 +98,20 @@ impl UpdateUserCommandInterface for UpdateUserCommand {
             existing_user.birth_date().cloned()
         };
 
-        // 4. ドメインエンティティ再生成（メール・パスワードは変更不可と仮定）
+        // 4. ドメインエンティティ再生成（メール・パスワードハッシュ・ロールは変更不可と仮定）
         let user = User::new(
             user_id_vo,
             existing_user.email().clone(),
             name,
-            existing_user.password().clone(),
+            existing_user.password_hash().clone(),
             phone,
             birth_date,
         )
         .map_err(|e| ApplicationError::InvalidInput {
             input: "user".to_string(),
             reason: format!("{}", e),
-        })?;
+        })?
+        .with_role(existing_user.role());
 
         // 5. 保存（永続化）
         self.command_repository.update(&user).await.map_err(|e| {
@@ -0,0 +1,63 @@
+//application/dto/api_key_dto.rs
+// APIキー管理 DTO
+// 2025/7/8
+
+use crate::domain::entity::api_key::ApiKey;
+use serde::{Deserialize, Serialize};
+
+/// APIキー作成の入力（`owner_id`省略時は呼び出したユーザー自身）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateApiKeyDto {
+    pub owner_id: Option<String>,
+    pub name: String,
+    pub scopes: Vec<String>,
+    pub expires_in_days: Option<i64>,
+}
+
+/// APIキー更新の入力（指定した項目のみ変更）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateApiKeyDto {
+    pub name: Option<String>,
+    pub scopes: Option<Vec<String>>,
+}
+
+/// APIキーの情報（キー本体は含まない）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiKeyDto {
+    pub id: String,
+    pub owner_id: String,
+    pub name: String,
+    pub prefix: String,
+    pub scopes: Vec<String>,
+    pub expires_at: Option<String>,
+    pub last_used_at: Option<String>,
+    pub created_at: String,
+    pub created_by: String,
+}
+
+impl From<ApiKey> for ApiKeyDto {
+    fn from(api_key: ApiKey) -> Self {
+        Self {
+            id: api_key.id,
+            owner_id: api_key.owner_id.0,
+            name: api_key.name,
+            prefix: api_key.prefix,
+            scopes: api_key
+                .scopes
+                .iter()
+                .map(|scope| scope.as_str().to_string())
+                .collect(),
+            expires_at: api_key.expires_at.map(|t| t.to_rfc3339()),
+            last_used_at: api_key.last_used_at.map(|t| t.to_rfc3339()),
+            created_at: api_key.created_at.to_rfc3339(),
+            created_by: api_key.created_by.0,
+        }
+    }
+}
+
+/// 作成したAPIキー（`key`はこの応答でのみ返す）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreatedApiKeyDto {
+    pub key: String,
+    pub api_key: ApiKeyDto,
+}
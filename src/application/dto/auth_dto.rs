@@ -0,0 +1,140 @@
+//application/dto/auth_dto.rs
+// 認証関連DTO
+// 2025/7/8
+
+use serde::{Deserialize, Serialize};
+
+/// ログインリクエストDTO
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoginRequestDto {
+    pub email: String,
+    pub password: String,
+    /// 接続元IPアドレス（取得できない場合はIP単位の制限を行わない）
+    pub client_ip: Option<String>,
+}
+
+/// リフレッシュリクエストDTO
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefreshTokenRequestDto {
+    pub refresh_token: String,
+    /// OAuthの`refresh_token`グラントで認証したクライアント（`/auth/refresh`ではNone）
+    pub client_id: Option<String>,
+}
+
+/// パスワードリセット要求DTO
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PasswordResetRequestDto {
+    pub email: String,
+    /// 接続元IPアドレス（取得できない場合はIP単位の制限を行わない）
+    pub client_ip: Option<String>,
+}
+
+/// パスワードリセット確定DTO
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PasswordResetConfirmDto {
+    pub token: String,
+    pub new_password: String,
+}
+
+/// メールアドレス確認DTO
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmailVerificationConfirmDto {
+    pub token: String,
+}
+
+/// 確認メール再送DTO
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmailVerificationResendDto {
+    pub email: String,
+}
+
+/// 認証済みリクエストのセッション情報（アクセストークンのクレームから生成）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthenticatedSessionDto {
+    pub user_id: String,
+    pub jti: String,
+    pub session_id: Option<String>,
+    pub expires_at: i64,
+}
+
+/// 操作を行うユーザー（認可判定用、アクセストークンのクレームから生成）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActorDto {
+    pub user_id: String,
+    pub role: String,
+    /// 権限の絞り込み（APIキーのスコープなど、空白区切り）
+    pub scope: Option<String>,
+}
+
+/// トークンに埋め込まれるユーザー情報
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthUserDto {
+    pub id: String,
+    pub email: String,
+    pub name: String,
+    pub role: String,
+}
+
+/// 発行済みトークンペアDTO
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenPairDto {
+    pub access_token: String,
+    pub refresh_token: String,
+    pub token_type: String,
+    pub expires_in: i64,
+    pub user: AuthUserDto,
+}
+
+/// なりすましトークン（リフレッシュトークンなし）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImpersonationTokenDto {
+    pub access_token: String,
+    pub token_type: String,
+    pub expires_in: i64,
+    /// なりすまし対象のユーザー
+    pub user: AuthUserDto,
+    /// 実際に操作するユーザーID（トークンの`act.sub`）
+    pub impersonator_id: String,
+}
+
+/// 二要素認証待ちのログイン（パスワード確認済み、`mfa_token`で2段階目を行う）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MfaChallengeDto {
+    pub mfa_token: String,
+    pub expires_in: i64,
+    /// 未登録のため、2段階目の前に登録が必要
+    pub enrollment_required: bool,
+}
+
+/// ログイン結果
+#[derive(Debug, Clone)]
+pub enum LoginResultDto {
+    Authenticated(TokenPairDto),
+    MfaRequired(MfaChallengeDto),
+}
+
+/// 二要素認証APIの呼び出し元（トークンのクレームから生成）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MfaCallerDto {
+    pub user_id: String,
+    pub jti: String,
+    pub expires_at: i64,
+    /// `mfa_pending`トークンでの呼び出し（ログイン途中）
+    pub pending: bool,
+    pub client_ip: Option<String>,
+}
+
+/// 二要素認証の登録開始結果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MfaEnrollmentDto {
+    pub secret: String,
+    pub provisioning_uri: String,
+}
+
+/// 二要素認証の登録確認結果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MfaConfirmResultDto {
+    pub recovery_codes: Vec<String>,
+    /// ログイン途中で登録した場合に開始したセッション
+    pub session: Option<TokenPairDto>,
+}
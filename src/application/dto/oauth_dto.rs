@@ -0,0 +1,129 @@
+//application/dto/oauth_dto.rs
+// OAuth認可サーバー DTO
+// 2025/7/8
+
+use crate::domain::entity::oauth_client::OAuthClient;
+use serde::{Deserialize, Serialize};
+
+/// クライアント認証の入力（Basic認証またはフォームの`client_id`・`client_secret`）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClientAuthDto {
+    pub client_id: String,
+    /// 公開クライアントの場合はNone
+    pub client_secret: Option<String>,
+}
+
+/// 認可リクエスト（`/oauth/authorize`のクエリ）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthorizeRequestDto {
+    pub response_type: String,
+    pub client_id: String,
+    pub redirect_uri: String,
+    pub scope: Option<String>,
+    pub code_challenge: Option<String>,
+    pub code_challenge_method: Option<String>,
+    /// OpenID Connectの`nonce`（IDトークンに含める）
+    pub nonce: Option<String>,
+}
+
+/// 発行した認可コード（リダイレクト先は登録済みのURIと一致したもの）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthorizationCodeDto {
+    pub code: String,
+    pub redirect_uri: String,
+}
+
+/// トークンリクエスト（`/oauth/token`のフォーム、グラントごとに使う項目が異なる）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TokenRequestDto {
+    pub grant_type: String,
+    pub code: Option<String>,
+    pub redirect_uri: Option<String>,
+    pub code_verifier: Option<String>,
+    pub refresh_token: Option<String>,
+    pub scope: Option<String>,
+}
+
+/// トークンレスポンス（RFC 6749 5.1）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OAuthTokenDto {
+    pub access_token: String,
+    pub token_type: String,
+    pub expires_in: i64,
+    pub refresh_token: Option<String>,
+    pub scope: String,
+    /// `openid`スコープを含む認可コードの交換時のみ
+    pub id_token: Option<String>,
+}
+
+/// トークンイントロスペクションの結果（RFC 7662、無効なトークンは`active`のみ）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TokenIntrospectionDto {
+    pub active: bool,
+    pub scope: Option<String>,
+    pub client_id: Option<String>,
+    pub username: Option<String>,
+    pub token_type: Option<String>,
+    pub exp: Option<i64>,
+    pub iat: Option<i64>,
+    pub sub: Option<String>,
+    pub jti: Option<String>,
+}
+
+/// クライアント登録の入力
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegisterOAuthClientDto {
+    pub name: String,
+    pub redirect_uris: Vec<String>,
+    pub grant_types: Vec<String>,
+    pub scopes: Vec<String>,
+    /// falseの場合はシークレットを発行しない公開クライアント
+    pub confidential: bool,
+    /// client_credentialsグラントで使うユーザー
+    pub service_account_id: Option<String>,
+}
+
+/// 登録済みクライアントの情報（シークレットは含まない）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OAuthClientDto {
+    pub client_id: String,
+    pub name: String,
+    pub confidential: bool,
+    pub redirect_uris: Vec<String>,
+    pub grant_types: Vec<String>,
+    pub scopes: Vec<String>,
+    pub service_account_id: Option<String>,
+    pub created_at: String,
+    pub created_by: String,
+}
+
+impl From<OAuthClient> for OAuthClientDto {
+    fn from(client: OAuthClient) -> Self {
+        Self {
+            confidential: client.is_confidential(),
+            client_id: client.client_id,
+            name: client.name,
+            redirect_uris: client.redirect_uris,
+            grant_types: client
+                .grant_types
+                .iter()
+                .map(|grant_type| grant_type.as_str().to_string())
+                .collect(),
+            scopes: client
+                .scopes
+                .iter()
+                .map(|scope| scope.as_str().to_string())
+                .collect(),
+            service_account_id: client.service_account_id.map(|id| id.0),
+            created_at: client.created_at.to_rfc3339(),
+            created_by: client.created_by.0,
+        }
+    }
+}
+
+/// 登録したクライアント（`client_secret`はこの応答でのみ返す）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegisteredOAuthClientDto {
+    pub client_secret: Option<String>,
+    pub client: OAuthClientDto,
+}
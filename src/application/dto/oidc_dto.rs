@@ -0,0 +1,61 @@
+//application/dto/oidc_dto.rs
+// OpenID Connect DTO
+// 2025/7/8
+
+use crate::domain::entity::user::User;
+use crate::domain::value_object::oidc_scope::OidcScope;
+use serde::{Deserialize, Serialize};
+
+/// UserInfoのクレーム（`scope`で許可されたもののみ）
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UserInfoDto {
+    pub sub: String,
+    pub email: Option<String>,
+    pub email_verified: Option<bool>,
+    pub name: Option<String>,
+    pub birthdate: Option<String>,
+    pub phone_number: Option<String>,
+}
+
+impl UserInfoDto {
+    /// ユーザーからクレームを組み立てる（`scope`がNoneの場合はすべて含める）
+    pub fn from_user(user: &User, scope: Option<&str>) -> Self {
+        let allows = |oidc_scope: OidcScope| scope.is_none_or(|scope| oidc_scope.is_in(scope));
+        let email = allows(OidcScope::Email);
+        let profile = allows(OidcScope::Profile);
+        Self {
+            sub: user.id().0.clone(),
+            email: email.then(|| user.email().0.clone()),
+            email_verified: email.then(|| user.is_email_verified()),
+            name: profile.then(|| user.name().0.clone()),
+            birthdate: user
+                .birth_date()
+                .filter(|_| profile)
+                .map(|birth_date| birth_date.0.clone()),
+            phone_number: user
+                .phone()
+                .filter(|_| allows(OidcScope::Phone))
+                .map(|phone| phone.0.clone()),
+        }
+    }
+}
+
+/// OpenID Providerのメタデータ（`/.well-known/openid-configuration`）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenIdConfigurationDto {
+    pub issuer: String,
+    pub authorization_endpoint: String,
+    pub token_endpoint: String,
+    pub userinfo_endpoint: String,
+    pub jwks_uri: String,
+    pub introspection_endpoint: String,
+    pub revocation_endpoint: String,
+    pub scopes_supported: Vec<String>,
+    pub response_types_supported: Vec<String>,
+    pub grant_types_supported: Vec<String>,
+    pub subject_types_supported: Vec<String>,
+    pub id_token_signing_alg_values_supported: Vec<String>,
+    pub token_endpoint_auth_methods_supported: Vec<String>,
+    pub code_challenge_methods_supported: Vec<String>,
+    pub claims_supported: Vec<String>,
+}
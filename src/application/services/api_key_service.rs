@@ -0,0 +1,127 @@
+//application/services/api_key_service.rs
+// APIキーの発行・認証サービス
+// 2025/7/8
+
+use crate::domain::entity::api_key::ApiKey;
+use crate::domain::repository::api_key_repository::ApiKeyRepositoryInterface;
+use crate::domain::repository::user_query_repository::UserQueryRepositoryInterface;
+use crate::shared::middleware::auth_middleware::{
+    ApiKeyAuthenticatorInterface, AuthError, JwtClaims, TokenType,
+};
+use crate::shared::utils::secure_token::{generate_token, hash_token};
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+
+/// キー本体の接頭辞（ログやシークレットスキャンで識別しやすくする）
+pub const API_KEY_PREFIX: &str = "rca_";
+
+/// 一覧表示用に保存するキー先頭部分の長さ
+const DISPLAY_PREFIX_LEN: usize = 12;
+
+/// 最終使用日時を更新する最短間隔（リクエストごとの書き込みを避ける）
+const LAST_USED_RESOLUTION_SECS: i64 = 60;
+
+/// 発行したキー本体と保存用の値
+pub struct GeneratedApiKey {
+    pub key: String,
+    pub prefix: String,
+    pub key_hash: String,
+}
+
+/// APIキーサービス
+///
+/// 責務:
+/// 1. キー本体の生成（保存するのはハッシュと表示用の先頭部分のみ）
+/// 2. `X-API-Key`ヘッダーの認証（期限・所有者の確認と最終使用日時の記録）
+pub struct ApiKeyService {
+    api_key_repository: Arc<dyn ApiKeyRepositoryInterface>,
+    query_repository: Arc<dyn UserQueryRepositoryInterface + Send + Sync>,
+}
+
+impl ApiKeyService {
+    pub fn new(
+        api_key_repository: Arc<dyn ApiKeyRepositoryInterface>,
+        query_repository: Arc<dyn UserQueryRepositoryInterface + Send + Sync>,
+    ) -> Self {
+        Self {
+            api_key_repository,
+            query_repository,
+        }
+    }
+
+    /// 新しいキー本体を生成する
+    pub fn generate() -> GeneratedApiKey {
+        let key = format!("{}{}", API_KEY_PREFIX, generate_token());
+        GeneratedApiKey {
+            prefix: key[..DISPLAY_PREFIX_LEN].to_string(),
+            key_hash: hash_token(&key),
+            key,
+        }
+    }
+
+    async fn record_use(&self, api_key: &ApiKey) {
+        let now = Utc::now();
+        let recent = api_key
+            .last_used_at
+            .is_some_and(|used_at| now - used_at < Duration::seconds(LAST_USED_RESOLUTION_SECS));
+        if recent {
+            return;
+        }
+        if let Err(e) = self
+            .api_key_repository
+            .touch_last_used(&api_key.id, now)
+            .await
+        {
+            println!("ApiKeyService: Failed to record last use: {}", e);
+        }
+    }
+}
+
+#[async_trait]
+impl ApiKeyAuthenticatorInterface for ApiKeyService {
+    async fn authenticate(&self, api_key: &str) -> Result<JwtClaims, AuthError> {
+        if !api_key.starts_with(API_KEY_PREFIX) {
+            return Err(AuthError::InvalidToken);
+        }
+        let stored = self
+            .api_key_repository
+            .find_by_hash(&hash_token(api_key))
+            .await
+            .map_err(|e| {
+                println!("ApiKeyService: {}", e);
+                AuthError::Internal
+            })?
+            .ok_or(AuthError::InvalidToken)?;
+        if stored.is_expired(Utc::now()) {
+            return Err(AuthError::TokenExpired);
+        }
+        // 所有者が削除されたキーは使えない
+        let owner = self
+            .query_repository
+            .find_by_id(&stored.owner_id)
+            .await
+            .map_err(|e| {
+                println!("ApiKeyService: {}", e);
+                AuthError::Internal
+            })?
+            .ok_or(AuthError::InvalidToken)?;
+        self.record_use(&stored).await;
+
+        let mut claims = JwtClaims::new(
+            owner.id().0.clone(),
+            owner.email().0.clone(),
+            owner.name().0.clone(),
+            owner.role().as_str().to_string(),
+        );
+        claims.jti = stored.id.clone();
+        claims.iat = stored.created_at.timestamp();
+        claims.exp = stored
+            .expires_at
+            .map(|expires_at| expires_at.timestamp())
+            .unwrap_or(i64::MAX);
+        claims.token_type = TokenType::ApiKey;
+        claims.scope = Some(stored.scope_string());
+        Ok(claims)
+    }
+}
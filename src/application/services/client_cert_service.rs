@@ -0,0 +1,66 @@
+//application/services/client_cert_service.rs
+// クライアント証明書（mTLS）によるユーザー認証サービス
+// 2025/7/8
+
+use crate::domain::repository::user_query_repository::UserQueryRepositoryInterface;
+use crate::domain::value_object::email::Email;
+use crate::shared::middleware::auth_middleware::{
+    AuthError, ClientCertAuthenticatorInterface, JwtClaims, TokenType,
+};
+use crate::shared::middleware::client_cert_middleware::ClientCertificate;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// クライアント証明書認証サービス
+///
+/// 責務:
+/// 1. 証明書の主体（CN、ない場合はサブジェクト全体）から対応するユーザーを引く
+/// 2. ユーザーのロールでクレームを組み立てる（対応表にない主体・削除されたユーザーは拒否）
+pub struct ClientCertService {
+    /// 主体 → ユーザーのメールアドレス
+    users: HashMap<String, String>,
+    query_repository: Arc<dyn UserQueryRepositoryInterface + Send + Sync>,
+}
+
+impl ClientCertService {
+    pub fn new(
+        users: Vec<(String, String)>,
+        query_repository: Arc<dyn UserQueryRepositoryInterface + Send + Sync>,
+    ) -> Self {
+        Self {
+            users: users.into_iter().collect(),
+            query_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl ClientCertAuthenticatorInterface for ClientCertService {
+    async fn authenticate(&self, certificate: &ClientCertificate) -> Result<JwtClaims, AuthError> {
+        let email = self
+            .users
+            .get(certificate.principal())
+            .ok_or(AuthError::InvalidToken)?;
+        let email = Email::new(email.clone()).map_err(|_| AuthError::InvalidToken)?;
+        let user = self
+            .query_repository
+            .find_by_email(&email)
+            .await
+            .map_err(|e| {
+                println!("ClientCertService: {}", e);
+                AuthError::Internal
+            })?
+            .ok_or(AuthError::InvalidToken)?;
+
+        let mut claims = JwtClaims::new(
+            user.id().0.clone(),
+            user.email().0.clone(),
+            user.name().0.clone(),
+            user.role().as_str().to_string(),
+        );
+        claims.jti = format!("cert:{}", certificate.fingerprint_sha256);
+        claims.token_type = TokenType::ClientCert;
+        Ok(claims)
+    }
+}
@@ -0,0 +1,124 @@
+//application/services/email_verification_service.rs
+// メールアドレス確認トークンの発行・送信サービス
+// 2025/7/8
+
+use crate::domain::entity::email_verification_token::EmailVerificationToken;
+use crate::domain::entity::user::User;
+use crate::domain::repository::email_verification_token_repository::EmailVerificationTokenRepositoryInterface;
+use crate::domain::service::mailer::{EmailMessage, Mailer};
+use crate::domain::value_object::user_id::UserId;
+use crate::shared::error::application_error::{ApplicationError, ApplicationResult};
+use crate::shared::error::infrastructure_error::InfrastructureError;
+use crate::shared::utils::secure_token::{generate_token, hash_token};
+use chrono::{DateTime, Duration, Utc};
+use std::sync::Arc;
+
+/// メールアドレス確認サービス
+///
+/// 責務:
+/// 1. 確認トークンの発行（保存はハッシュのみ、以前のトークンは無効化）と確認メールの送信
+/// 2. 再送の回数制限（最短間隔・1時間あたりの上限）
+///
+/// ユーザー作成時と再送要求の両方から使う
+pub struct EmailVerificationService {
+    token_repository: Arc<dyn EmailVerificationTokenRepositoryInterface>,
+    mailer: Arc<dyn Mailer>,
+    token_ttl: Duration,
+    verify_url: String,
+    resend_cooldown: Duration,
+    max_sends_per_hour: usize,
+}
+
+impl EmailVerificationService {
+    pub fn new(
+        token_repository: Arc<dyn EmailVerificationTokenRepositoryInterface>,
+        mailer: Arc<dyn Mailer>,
+        token_ttl: std::time::Duration,
+        verify_url: String,
+        resend_cooldown: std::time::Duration,
+        max_sends_per_hour: usize,
+    ) -> Self {
+        let to_chrono = |d: std::time::Duration| Duration::seconds(d.as_secs() as i64);
+        Self {
+            token_repository,
+            mailer,
+            token_ttl: to_chrono(token_ttl),
+            verify_url,
+            resend_cooldown: to_chrono(resend_cooldown),
+            max_sends_per_hour,
+        }
+    }
+
+    fn infrastructure_error(
+        resource: &str,
+        e: Box<dyn std::error::Error + Send + Sync>,
+    ) -> ApplicationError {
+        ApplicationError::Infrastructure(InfrastructureError::ResourceUnavailable {
+            resource: resource.to_string(),
+            message: format!("{}", e),
+        })
+    }
+
+    fn verify_link(&self, token: &str) -> String {
+        let separator = if self.verify_url.contains('?') {
+            '&'
+        } else {
+            '?'
+        };
+        format!("{}{}token={}", self.verify_url, separator, token)
+    }
+
+    /// 新しい確認トークンを発行して確認メールを送る
+    pub async fn send_verification(&self, user: &User) -> ApplicationResult<()> {
+        self.token_repository
+            .invalidate_user_tokens(user.id())
+            .await
+            .map_err(|e| Self::infrastructure_error("email_verification_token", e))?;
+        let token = generate_token();
+        let now = Utc::now();
+        self.token_repository
+            .save(&EmailVerificationToken::new(
+                hash_token(&token),
+                user.id().clone(),
+                user.email().0.clone(),
+                now,
+                now + self.token_ttl,
+            ))
+            .await
+            .map_err(|e| Self::infrastructure_error("email_verification_token", e))?;
+
+        let message = EmailMessage {
+            to: user.email().0.clone(),
+            subject: "メールアドレス確認のお願い".to_string(),
+            body: format!(
+                "{} 様\n\n以下のリンクからメールアドレスを確認してください（有効期限: {}時間）。\n{}\n\nお心当たりがない場合はこのメールを破棄してください。",
+                user.name().0,
+                self.token_ttl.num_hours(),
+                self.verify_link(&token)
+            ),
+        };
+        self.mailer
+            .send(&message)
+            .await
+            .map_err(|e| Self::infrastructure_error("mailer", e))
+    }
+
+    /// 再送してよいか（直近の送信から最短間隔が経過し、1時間あたりの上限未満）
+    pub async fn can_resend(
+        &self,
+        user_id: &UserId,
+        now: DateTime<Utc>,
+    ) -> ApplicationResult<bool> {
+        let issued = self
+            .token_repository
+            .issued_since(user_id, now - Duration::hours(1))
+            .await
+            .map_err(|e| Self::infrastructure_error("email_verification_token", e))?;
+        if issued.len() >= self.max_sends_per_hour {
+            return Ok(false);
+        }
+        Ok(issued
+            .last()
+            .is_none_or(|last| now - *last >= self.resend_cooldown))
+    }
+}
@@ -0,0 +1,197 @@
+//application/services/login_throttle_service.rs
+// ログイン試行の制限（アカウント・IPごとのロックアウト）
+// 2025/7/8
+
+use crate::domain::entity::audit_event::AuditEvent;
+use crate::domain::entity::login_attempt::{LoginAttempt, LoginAttemptScope};
+use crate::domain::repository::audit_log_repository::AuditLogRepositoryInterface;
+use crate::domain::repository::login_attempt_repository::LoginAttemptRepositoryInterface;
+use crate::domain::service::lockout_policy::LockoutPolicy;
+use crate::shared::error::application_error::{ApplicationError, ApplicationResult};
+use crate::shared::error::infrastructure_error::InfrastructureError;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+
+/// ログイン試行制限サービス
+///
+/// 責務:
+/// 1. アカウント・接続元IPごとの失敗回数の記録とロック判定
+/// 2. ロック・ロック解除の監査ログ記録
+///
+/// アカウントは正規化したメールアドレスで数えるため、未登録のメールアドレスも
+/// 登録済みと同じようにロックされ、応答からアカウントの存在有無は分からない。
+pub struct LoginThrottleService {
+    attempt_repository: Arc<dyn LoginAttemptRepositoryInterface>,
+    audit_log_repository: Arc<dyn AuditLogRepositoryInterface>,
+    account_policy: LockoutPolicy,
+    ip_policy: LockoutPolicy,
+}
+
+impl LoginThrottleService {
+    pub fn new(
+        attempt_repository: Arc<dyn LoginAttemptRepositoryInterface>,
+        audit_log_repository: Arc<dyn AuditLogRepositoryInterface>,
+        account_policy: LockoutPolicy,
+        ip_policy: LockoutPolicy,
+    ) -> Self {
+        Self {
+            attempt_repository,
+            audit_log_repository,
+            account_policy,
+            ip_policy,
+        }
+    }
+
+    /// 監査ログ・カウンターで使うアカウントのキー
+    pub fn account_key(email: &str) -> String {
+        email.trim().to_lowercase()
+    }
+
+    /// ロック中であればTooManyAttemptsを返す
+    pub async fn check(&self, email: &str, client_ip: Option<&str>) -> ApplicationResult<()> {
+        let now = Utc::now();
+        let mut retry_after = None;
+        for (scope, key) in Self::keys(email, client_ip) {
+            if let Some(attempt) = self.find(scope, &key).await?
+                && let Some(remaining) = attempt.retry_after(now)
+            {
+                retry_after = retry_after.max(Some(remaining));
+            }
+        }
+        match retry_after {
+            // 端数は切り上げ（0秒で再試行させない）
+            Some(remaining) => Err(ApplicationError::TooManyAttempts {
+                retry_after_secs: (remaining.num_milliseconds().max(0) as u64).div_ceil(1000),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// ログイン失敗を記録し、閾値に達した場合はロックする
+    pub async fn record_failure(
+        &self,
+        email: &str,
+        client_ip: Option<&str>,
+    ) -> ApplicationResult<()> {
+        let now = Utc::now();
+        for (scope, key) in Self::keys(email, client_ip) {
+            let policy = match scope {
+                LoginAttemptScope::Account => &self.account_policy,
+                LoginAttemptScope::Ip => &self.ip_policy,
+            };
+            let (attempt, locked) = self
+                .attempt_repository
+                .register_failure(scope, &key, now, policy)
+                .await
+                .map_err(Self::infrastructure_error)?;
+            if locked && let Some(until) = attempt.locked_until {
+                self.audit(
+                    "login.locked",
+                    None,
+                    &attempt,
+                    format!(
+                        "failed_count={} locked_until={}",
+                        attempt.failed_count,
+                        until.to_rfc3339()
+                    ),
+                )
+                .await;
+            }
+        }
+        Ok(())
+    }
+
+    /// ログイン成功時にアカウントの失敗回数をリセットする（IPのカウンターは維持）
+    pub async fn record_success(&self, email: &str) -> ApplicationResult<()> {
+        self.attempt_repository
+            .delete(LoginAttemptScope::Account, &Self::account_key(email))
+            .await
+            .map_err(Self::infrastructure_error)?;
+        Ok(())
+    }
+
+    /// 管理者によるアカウントのロック解除。解除対象があった場合はtrue
+    pub async fn unlock_account(&self, email: &str, actor: &str) -> ApplicationResult<bool> {
+        let key = Self::account_key(email);
+        let attempt = self.find(LoginAttemptScope::Account, &key).await?;
+        let cleared = self
+            .attempt_repository
+            .delete(LoginAttemptScope::Account, &key)
+            .await
+            .map_err(Self::infrastructure_error)?;
+        if let Some(attempt) = attempt {
+            self.audit(
+                "login.unlocked",
+                Some(actor.to_string()),
+                &attempt,
+                format!("failed_count={}", attempt.failed_count),
+            )
+            .await;
+        }
+        Ok(cleared)
+    }
+
+    /// 失敗から十分時間が経ったカウンターを削除し、削除件数を返す
+    pub async fn purge_stale(&self) -> ApplicationResult<usize> {
+        let window = self
+            .account_policy
+            .failure_window
+            .max(self.ip_policy.failure_window);
+        let before: DateTime<Utc> = Utc::now() - window;
+        self.attempt_repository
+            .purge_stale(before)
+            .await
+            .map_err(Self::infrastructure_error)
+    }
+
+    /// 監査ログの対象識別子（`account:<email>` / `ip:<addr>`）
+    pub fn audit_subject(scope: LoginAttemptScope, key: &str) -> String {
+        format!("{}:{}", scope.as_str(), key)
+    }
+
+    fn keys(email: &str, client_ip: Option<&str>) -> Vec<(LoginAttemptScope, String)> {
+        let mut keys = vec![(LoginAttemptScope::Account, Self::account_key(email))];
+        if let Some(ip) = client_ip {
+            keys.push((LoginAttemptScope::Ip, ip.to_string()));
+        }
+        keys
+    }
+
+    async fn find(
+        &self,
+        scope: LoginAttemptScope,
+        key: &str,
+    ) -> ApplicationResult<Option<LoginAttempt>> {
+        self.attempt_repository
+            .find(scope, key)
+            .await
+            .map_err(Self::infrastructure_error)
+    }
+
+    /// 監査ログの記録（失敗してもログイン処理は止めない）
+    async fn audit(
+        &self,
+        action: &str,
+        actor: Option<String>,
+        attempt: &LoginAttempt,
+        detail: String,
+    ) {
+        let event = AuditEvent::new(
+            action,
+            actor,
+            Self::audit_subject(attempt.scope, &attempt.key),
+            Some(detail),
+        );
+        println!("🔒 audit: {} {}", event.action, event.subject);
+        if let Err(e) = self.audit_log_repository.record(&event).await {
+            println!("LoginThrottleService: Failed to write audit log: {}", e);
+        }
+    }
+
+    fn infrastructure_error(e: Box<dyn std::error::Error + Send + Sync>) -> ApplicationError {
+        ApplicationError::Infrastructure(InfrastructureError::ResourceUnavailable {
+            resource: "login_attempt".to_string(),
+            message: format!("{}", e),
+        })
+    }
+}
@@ -0,0 +1,261 @@
+//application/services/mfa_service.rs
+// 二要素認証（TOTP・リカバリーコード）サービス
+// 2025/7/8
+
+use crate::application::dto::auth_dto::MfaEnrollmentDto;
+use crate::domain::entity::mfa_credential::MfaCredential;
+use crate::domain::entity::user::User;
+use crate::domain::repository::mfa_credential_repository::MfaCredentialRepositoryInterface;
+use crate::domain::value_object::{role::Role, user_id::UserId};
+use crate::shared::error::application_error::{ApplicationError, ApplicationResult};
+use crate::shared::error::infrastructure_error::InfrastructureError;
+use crate::shared::utils::secure_token::hash_token;
+use crate::shared::utils::totp;
+use chrono::Utc;
+use rand::Rng;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// 照合時に許容する前後の時間ステップ数（時計のずれ対策）
+const ALLOWED_SKEW_STEPS: i64 = 1;
+
+/// リカバリーコードに使う文字（紛らわしい0/o/1/l/iを除く）
+const RECOVERY_CODE_ALPHABET: &[u8] = b"abcdefghjkmnpqrstuvwxyz23456789";
+
+/// 二要素認証コードの照合結果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MfaVerification {
+    Totp,
+    /// リカバリーコードを使用（`remaining`は残数）
+    RecoveryCode {
+        remaining: usize,
+    },
+    Invalid,
+}
+
+/// 二要素認証サービス
+///
+/// 責務:
+/// 1. TOTPシークレットの発行と登録の確認（確認時にリカバリーコードを発行）
+/// 2. TOTPコード・リカバリーコードの照合（同じコードの再利用は拒否）
+/// 3. 登録必須ロールの判定
+pub struct MfaService {
+    repository: Arc<dyn MfaCredentialRepositoryInterface>,
+    issuer: String,
+    required_roles: Vec<Role>,
+    pending_token_ttl: Duration,
+    recovery_code_count: usize,
+}
+
+impl MfaService {
+    pub fn new(
+        repository: Arc<dyn MfaCredentialRepositoryInterface>,
+        issuer: String,
+        required_roles: Vec<Role>,
+        pending_token_ttl: Duration,
+        recovery_code_count: usize,
+    ) -> Self {
+        Self {
+            repository,
+            issuer,
+            required_roles,
+            pending_token_ttl,
+            recovery_code_count,
+        }
+    }
+
+    fn infrastructure_error(e: Box<dyn std::error::Error + Send + Sync>) -> ApplicationError {
+        ApplicationError::Infrastructure(InfrastructureError::ResourceUnavailable {
+            resource: "mfa_credential".to_string(),
+            message: format!("{}", e),
+        })
+    }
+
+    fn not_permitted(operation: &str, reason: &str) -> ApplicationError {
+        ApplicationError::OperationNotPermitted {
+            operation: operation.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+
+    /// ログイン途中トークンの有効期間
+    pub fn pending_token_ttl(&self) -> Duration {
+        self.pending_token_ttl
+    }
+
+    /// ロールに登録が必須か
+    pub fn is_required_for(&self, role: Role) -> bool {
+        self.required_roles.contains(&role)
+    }
+
+    /// 確認済みの登録があるか
+    pub async fn is_enabled(&self, user_id: &UserId) -> ApplicationResult<bool> {
+        Ok(self
+            .repository
+            .find(user_id)
+            .await
+            .map_err(Self::infrastructure_error)?
+            .is_some_and(|credential| credential.is_confirmed()))
+    }
+
+    /// 新しいシークレットを発行して登録を開始する（確認済みの場合は不可）
+    pub async fn begin_enrollment(&self, user: &User) -> ApplicationResult<MfaEnrollmentDto> {
+        if self.is_enabled(user.id()).await? {
+            return Err(Self::not_permitted("mfa.enroll", "already enabled"));
+        }
+        let secret = totp::generate_secret();
+        self.repository
+            .save_pending(&MfaCredential::pending(user.id().clone(), secret.clone()))
+            .await
+            .map_err(Self::infrastructure_error)?;
+        Ok(MfaEnrollmentDto {
+            provisioning_uri: totp::provisioning_uri(&self.issuer, &user.email().0, &secret),
+            secret,
+        })
+    }
+
+    /// 認証アプリのコードで登録を確定し、リカバリーコードを発行する
+    pub async fn confirm_enrollment(
+        &self,
+        user_id: &UserId,
+        code: &str,
+    ) -> ApplicationResult<Vec<String>> {
+        let credential = self
+            .repository
+            .find(user_id)
+            .await
+            .map_err(Self::infrastructure_error)?
+            .filter(|credential| !credential.is_confirmed())
+            .ok_or_else(|| Self::not_permitted("mfa.confirm", "no pending enrollment"))?;
+        let now = Utc::now();
+        let step = totp::verify_code(
+            &credential.secret,
+            code,
+            now.timestamp(),
+            ALLOWED_SKEW_STEPS,
+        )
+        .ok_or(ApplicationError::InvalidCredentials)?;
+        if !self
+            .repository
+            .confirm(user_id, now, step)
+            .await
+            .map_err(Self::infrastructure_error)?
+        {
+            return Err(Self::not_permitted("mfa.confirm", "no pending enrollment"));
+        }
+        self.issue_recovery_codes(user_id).await
+    }
+
+    /// TOTPコードまたはリカバリーコードを照合する
+    pub async fn verify(&self, user_id: &UserId, code: &str) -> ApplicationResult<MfaVerification> {
+        let Some(credential) = self
+            .repository
+            .find(user_id)
+            .await
+            .map_err(Self::infrastructure_error)?
+            .filter(|credential| credential.is_confirmed())
+        else {
+            return Ok(MfaVerification::Invalid);
+        };
+
+        if totp::looks_like_code(code) {
+            let Some(step) = totp::verify_code(
+                &credential.secret,
+                code,
+                Utc::now().timestamp(),
+                ALLOWED_SKEW_STEPS,
+            ) else {
+                return Ok(MfaVerification::Invalid);
+            };
+            // 受け付け済みのステップ以前のコードは再利用とみなす
+            let accepted = self
+                .repository
+                .record_used_step(user_id, step)
+                .await
+                .map_err(Self::infrastructure_error)?;
+            return Ok(if accepted {
+                MfaVerification::Totp
+            } else {
+                MfaVerification::Invalid
+            });
+        }
+
+        let consumed = self
+            .repository
+            .consume_recovery_code(user_id, &hash_token(&Self::normalize_recovery_code(code)))
+            .await
+            .map_err(Self::infrastructure_error)?;
+        if !consumed {
+            return Ok(MfaVerification::Invalid);
+        }
+        let remaining = self
+            .repository
+            .remaining_recovery_codes(user_id)
+            .await
+            .map_err(Self::infrastructure_error)?;
+        Ok(MfaVerification::RecoveryCode { remaining })
+    }
+
+    /// コードを確認して登録を解除する（登録必須ロールは不可）
+    pub async fn disable(&self, user: &User, code: &str) -> ApplicationResult<()> {
+        if self.is_required_for(user.role()) {
+            return Err(Self::not_permitted(
+                "mfa.disable",
+                "two-factor authentication is required for this role",
+            ));
+        }
+        if self.verify(user.id(), code).await? == MfaVerification::Invalid {
+            return Err(ApplicationError::InvalidCredentials);
+        }
+        self.repository
+            .delete(user.id())
+            .await
+            .map_err(Self::infrastructure_error)?;
+        Ok(())
+    }
+
+    /// コードを確認してリカバリーコードを再発行する（以前のコードは無効）
+    pub async fn regenerate_recovery_codes(
+        &self,
+        user_id: &UserId,
+        code: &str,
+    ) -> ApplicationResult<Vec<String>> {
+        if self.verify(user_id, code).await? == MfaVerification::Invalid {
+            return Err(ApplicationError::InvalidCredentials);
+        }
+        self.issue_recovery_codes(user_id).await
+    }
+
+    async fn issue_recovery_codes(&self, user_id: &UserId) -> ApplicationResult<Vec<String>> {
+        let codes: Vec<String> = (0..self.recovery_code_count)
+            .map(|_| Self::generate_recovery_code())
+            .collect();
+        let hashes: Vec<String> = codes
+            .iter()
+            .map(|code| hash_token(&Self::normalize_recovery_code(code)))
+            .collect();
+        self.repository
+            .replace_recovery_codes(user_id, &hashes)
+            .await
+            .map_err(Self::infrastructure_error)?;
+        Ok(codes)
+    }
+
+    /// `xxxxx-xxxxx`形式のリカバリーコード
+    fn generate_recovery_code() -> String {
+        let mut rng = rand::thread_rng();
+        let mut chars: Vec<char> = (0..10)
+            .map(|_| RECOVERY_CODE_ALPHABET[rng.gen_range(0..RECOVERY_CODE_ALPHABET.len())] as char)
+            .collect();
+        chars.insert(5, '-');
+        chars.into_iter().collect()
+    }
+
+    /// 区切り・空白・大文字小文字の違いを無視して照合する
+    fn normalize_recovery_code(code: &str) -> String {
+        code.chars()
+            .filter(|c| c.is_ascii_alphanumeric())
+            .map(|c| c.to_ascii_lowercase())
+            .collect()
+    }
+}
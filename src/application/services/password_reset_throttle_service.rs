@@ -0,0 +1,79 @@
+//application/services/password_reset_throttle_service.rs
+// パスワードリセット要求の回数制限（メールアドレス・IPごと）
+// 2025/7/8
+
+use crate::domain::repository::password_reset_request_repository::{
+    PasswordResetRequestRepositoryInterface, PasswordResetRequestScope,
+};
+use crate::domain::service::request_rate_policy::RequestRatePolicy;
+use crate::shared::error::application_error::{ApplicationError, ApplicationResult};
+use crate::shared::error::infrastructure_error::InfrastructureError;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+
+/// パスワードリセット要求の回数制限サービス
+///
+/// 責務:
+/// 1. 接続元IP・メールアドレスごとの要求の記録と制限判定
+///
+/// メールアドレスは正規化した値で数えるため、未登録のメールアドレスも
+/// 登録済みと同じように制限され、アカウントの存在有無は分からない。
+pub struct PasswordResetThrottleService {
+    request_repository: Arc<dyn PasswordResetRequestRepositoryInterface>,
+    email_policy: RequestRatePolicy,
+    ip_policy: RequestRatePolicy,
+}
+
+impl PasswordResetThrottleService {
+    pub fn new(
+        request_repository: Arc<dyn PasswordResetRequestRepositoryInterface>,
+        email_policy: RequestRatePolicy,
+        ip_policy: RequestRatePolicy,
+    ) -> Self {
+        Self {
+            request_repository,
+            email_policy,
+            ip_policy,
+        }
+    }
+
+    /// 要求を記録し、制限内であればtrueを返す
+    ///
+    /// IPで制限された要求はメールアドレスの回数に数えない
+    pub async fn try_acquire(
+        &self,
+        email: &str,
+        client_ip: Option<&str>,
+        now: DateTime<Utc>,
+    ) -> ApplicationResult<bool> {
+        let mut keys = Vec::new();
+        if let Some(ip) = client_ip {
+            keys.push((
+                PasswordResetRequestScope::Ip,
+                ip.to_string(),
+                &self.ip_policy,
+            ));
+        }
+        keys.push((
+            PasswordResetRequestScope::Email,
+            email.trim().to_lowercase(),
+            &self.email_policy,
+        ));
+        for (scope, key, policy) in keys {
+            let allowed = self
+                .request_repository
+                .record_if_allowed(scope, &key, now, policy)
+                .await
+                .map_err(|e| {
+                    ApplicationError::Infrastructure(InfrastructureError::ResourceUnavailable {
+                        resource: "password_reset_request".to_string(),
+                        message: format!("{}", e),
+                    })
+                })?;
+            if !allowed {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+}
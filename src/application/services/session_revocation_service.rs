@@ -0,0 +1,121 @@
+//application/services/session_revocation_service.rs
+// セッション失効チェック・失効リストのガベージコレクション
+// 2025/7/8
+
+use crate::domain::repository::refresh_token_repository::RefreshTokenRepositoryInterface;
+use crate::domain::repository::token_revocation_repository::TokenRevocationRepositoryInterface;
+use crate::domain::value_object::user_id::UserId;
+use crate::shared::error::application_error::{ApplicationError, ApplicationResult};
+use crate::shared::error::infrastructure_error::InfrastructureError;
+use crate::shared::middleware::auth_middleware::{AuthError, JwtClaims, SessionValidatorInterface};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// セッション失効サービス
+///
+/// 責務:
+/// 1. アクセストークンの失効チェック（jti単位の失効リスト + セッションファミリー + ユーザー単位の一括失効）
+/// 2. 有効期限切れの失効エントリ・リフレッシュトークンの削除
+pub struct SessionRevocationService {
+    refresh_token_repository: Arc<dyn RefreshTokenRepositoryInterface>,
+    token_revocation_repository: Arc<dyn TokenRevocationRepositoryInterface>,
+}
+
+impl SessionRevocationService {
+    pub fn new(
+        refresh_token_repository: Arc<dyn RefreshTokenRepositoryInterface>,
+        token_revocation_repository: Arc<dyn TokenRevocationRepositoryInterface>,
+    ) -> Self {
+        Self {
+            refresh_token_repository,
+            token_revocation_repository,
+        }
+    }
+
+    /// 有効期限切れのエントリを削除し、削除件数を返す
+    pub async fn purge_expired(&self) -> ApplicationResult<usize> {
+        let now = chrono::Utc::now();
+        let revoked = self
+            .token_revocation_repository
+            .purge_expired(now)
+            .await
+            .map_err(Self::infrastructure_error)?;
+        let refresh = self
+            .refresh_token_repository
+            .purge_expired(now)
+            .await
+            .map_err(Self::infrastructure_error)?;
+        Ok(revoked + refresh)
+    }
+
+    /// ユーザーのトークンを一括失効した後に発行されたか
+    ///
+    /// `iat`は秒単位のため、失効と同じ秒に発行されたトークンも無効とする
+    async fn issued_after_invalidation(
+        &self,
+        user_id: &str,
+        issued_at: i64,
+    ) -> Result<bool, AuthError> {
+        let invalidated_at = self
+            .token_revocation_repository
+            .sessions_invalidated_at(&UserId::new(user_id.to_string()))
+            .await
+            .map_err(|e| {
+                println!("SessionRevocationService: {}", e);
+                AuthError::Internal
+            })?;
+        Ok(invalidated_at.is_none_or(|at| issued_at > at.timestamp()))
+    }
+
+    fn infrastructure_error(e: Box<dyn std::error::Error + Send + Sync>) -> ApplicationError {
+        ApplicationError::Infrastructure(InfrastructureError::ResourceUnavailable {
+            resource: "token_revocation".to_string(),
+            message: format!("{}", e),
+        })
+    }
+}
+
+#[async_trait]
+impl SessionValidatorInterface for SessionRevocationService {
+    async fn is_active(&self, claims: &JwtClaims) -> Result<bool, AuthError> {
+        let revoked = self
+            .token_revocation_repository
+            .is_revoked(&claims.jti)
+            .await
+            .map_err(|e| {
+                println!("SessionRevocationService: {}", e);
+                AuthError::Internal
+            })?;
+        if revoked {
+            return Ok(false);
+        }
+        match &claims.sid {
+            Some(session_id) => self
+                .refresh_token_repository
+                .is_family_active(session_id)
+                .await
+                .map_err(|e| {
+                    println!("SessionRevocationService: {}", e);
+                    AuthError::Internal
+                }),
+            // セッションに紐づかないトークン（なりすまし・client_credentials・二要素認証待ち）は
+            // ユーザー単位の一括失効で判定する（セッションのトークンはファミリーの失効で無効になる）
+            None => {
+                if !self
+                    .issued_after_invalidation(&claims.sub, claims.iat)
+                    .await?
+                {
+                    return Ok(false);
+                }
+                // なりすましトークンは、なりすましている側が全セッションを終了した場合も無効にする
+                match claims.impersonator() {
+                    Some(impersonator) => {
+                        self.issued_after_invalidation(impersonator, claims.iat)
+                            .await
+                    }
+                    None => Ok(true),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,250 @@
+//application/services/session_token_service.rs
+// セッショントークン発行サービス
+// 2025/7/8
+
+use crate::application::dto::auth_dto::{
+    AuthUserDto, ImpersonationTokenDto, MfaChallengeDto, TokenPairDto,
+};
+use crate::domain::entity::refresh_token::RefreshToken;
+use crate::domain::entity::user::User;
+use crate::domain::repository::refresh_token_repository::RefreshTokenRepositoryInterface;
+use crate::domain::value_object::oidc_scope::OidcScope;
+use crate::shared::error::application_error::{ApplicationError, ApplicationResult};
+use crate::shared::error::infrastructure_error::InfrastructureError;
+use crate::shared::middleware::auth_middleware::{
+    ClientGrant, IdTokenClaims, JWT_CONFIG, JwtClaims, JwtService,
+};
+use chrono::{TimeZone, Utc};
+use std::sync::Arc;
+
+/// セッショントークン発行サービス
+///
+/// 責務:
+/// 1. ログイン時に新しいリフレッシュトークンファミリーを作成
+/// 2. アクセストークン・リフレッシュトークンの発行
+/// 3. 発行したリフレッシュトークンの永続化
+/// 4. 二要素認証待ちトークンの発行
+/// 5. OAuthクライアント向けトークンの発行（`client_id`・`scope`付き）
+/// 6. OpenID ConnectのIDトークンの発行
+/// 7. なりすましトークンの発行
+pub struct SessionTokenService {
+    refresh_token_repository: Arc<dyn RefreshTokenRepositoryInterface>,
+    jwt_service: JwtService,
+}
+
+impl SessionTokenService {
+    pub fn new(refresh_token_repository: Arc<dyn RefreshTokenRepositoryInterface>) -> Self {
+        Self {
+            refresh_token_repository,
+            jwt_service: JwtService::new(),
+        }
+    }
+
+    /// 新しいセッション（ファミリー）を開始してトークンペアを発行する
+    pub async fn start_session(&self, user: &User) -> ApplicationResult<TokenPairDto> {
+        self.start(user, None).await
+    }
+
+    /// OAuthクライアントへの委任としてセッションを開始する
+    pub async fn start_client_session(
+        &self,
+        user: &User,
+        grant: &ClientGrant,
+    ) -> ApplicationResult<TokenPairDto> {
+        self.start(user, Some(grant)).await
+    }
+
+    async fn start(
+        &self,
+        user: &User,
+        grant: Option<&ClientGrant>,
+    ) -> ApplicationResult<TokenPairDto> {
+        let family_id = uuid::Uuid::new_v4().to_string();
+        self.refresh_token_repository
+            .create_family(&family_id, user.id())
+            .await
+            .map_err(Self::infrastructure_error)?;
+        self.issue(user, &family_id, grant).await
+    }
+
+    /// 既存セッションのトークンをローテーションする（委任内容は引き継ぐ）
+    pub async fn rotate(
+        &self,
+        user: &User,
+        family_id: &str,
+        grant: Option<&ClientGrant>,
+    ) -> ApplicationResult<TokenPairDto> {
+        self.issue(user, family_id, grant).await
+    }
+
+    /// OAuthクライアント向けのアクセストークンのみを発行する（client_credentials）
+    pub fn issue_client_access_token(
+        &self,
+        user: &User,
+        grant: &ClientGrant,
+    ) -> ApplicationResult<(String, i64)> {
+        let (access_token, _) = self
+            .jwt_service
+            .issue_client_access_token(
+                user.id().0.clone(),
+                user.email().0.clone(),
+                user.name().0.clone(),
+                user.role().to_string(),
+                grant,
+            )
+            .map_err(|e| ApplicationError::PostconditionFailed {
+                condition: format!("token issuance: {}", e),
+            })?;
+        Ok((access_token, JWT_CONFIG.expiration_hours * 3600))
+    }
+
+    /// OAuthクライアント向けのアクセストークンに対応するIDトークンを発行する
+    ///
+    /// 主体・発行先・期限と`email`・`name`はアクセストークンのクレームから、
+    /// `email_verified`・`birthdate`・`phone_number`はユーザーから、`scope`に応じて含める。
+    pub fn issue_id_token(
+        &self,
+        user: &User,
+        access_token: &str,
+        issuer: &str,
+        nonce: Option<String>,
+    ) -> ApplicationResult<String> {
+        let token_error = |message: String| ApplicationError::PostconditionFailed {
+            condition: format!("id token issuance: {}", message),
+        };
+        let access_claims =
+            JwtClaims::from_token(access_token).map_err(|e| token_error(e.to_string()))?;
+        let mut claims = IdTokenClaims::from_access_claims(&access_claims, issuer, nonce)
+            .ok_or_else(|| token_error("access token has no client_id".to_string()))?;
+        let scope = access_claims.scope.as_deref().unwrap_or_default();
+        if OidcScope::Email.is_in(scope) {
+            claims.email_verified = Some(user.is_email_verified());
+        }
+        if OidcScope::Profile.is_in(scope) {
+            claims.birthdate = user.birth_date().map(|birth_date| birth_date.0.clone());
+        }
+        if OidcScope::Phone.is_in(scope) {
+            claims.phone_number = user.phone().map(|phone| phone.0.clone());
+        }
+        claims.to_token().map_err(|e| token_error(e.to_string()))
+    }
+
+    /// 対象ユーザーとしてのなりすましトークンを発行する（`act`に実際の操作者を入れる）
+    pub fn issue_impersonation_token(
+        &self,
+        user: &User,
+        impersonator_id: &str,
+        ttl: std::time::Duration,
+    ) -> ApplicationResult<ImpersonationTokenDto> {
+        let (access_token, _) = self
+            .jwt_service
+            .issue_impersonation_token(
+                user.id().0.clone(),
+                user.email().0.clone(),
+                user.name().0.clone(),
+                user.role().to_string(),
+                impersonator_id.to_string(),
+                chrono::Duration::seconds(ttl.as_secs() as i64),
+            )
+            .map_err(|e| ApplicationError::PostconditionFailed {
+                condition: format!("token issuance: {}", e),
+            })?;
+        Ok(ImpersonationTokenDto {
+            access_token,
+            token_type: "Bearer".to_string(),
+            expires_in: ttl.as_secs() as i64,
+            user: AuthUserDto {
+                id: user.id().0.clone(),
+                email: user.email().0.clone(),
+                name: user.name().0.clone(),
+                role: user.role().to_string(),
+            },
+            impersonator_id: impersonator_id.to_string(),
+        })
+    }
+
+    /// 二要素認証待ちトークンを発行する（セッションはまだ開始しない）
+    pub fn issue_mfa_challenge(
+        &self,
+        user: &User,
+        ttl: std::time::Duration,
+        enrollment_required: bool,
+    ) -> ApplicationResult<MfaChallengeDto> {
+        let (mfa_token, _) = self
+            .jwt_service
+            .issue_mfa_pending_token(
+                user.id().0.clone(),
+                user.email().0.clone(),
+                user.name().0.clone(),
+                user.role().to_string(),
+                chrono::Duration::seconds(ttl.as_secs() as i64),
+            )
+            .map_err(|e| ApplicationError::PostconditionFailed {
+                condition: format!("token issuance: {}", e),
+            })?;
+        Ok(MfaChallengeDto {
+            mfa_token,
+            expires_in: ttl.as_secs() as i64,
+            enrollment_required,
+        })
+    }
+
+    /// ユーザーの現在のロールでトークンを発行する
+    async fn issue(
+        &self,
+        user: &User,
+        family_id: &str,
+        grant: Option<&ClientGrant>,
+    ) -> ApplicationResult<TokenPairDto> {
+        let issued = self
+            .jwt_service
+            .issue_session_tokens(
+                user.id().0.clone(),
+                user.email().0.clone(),
+                user.name().0.clone(),
+                user.role().to_string(),
+                family_id,
+                grant,
+            )
+            .map_err(|e| ApplicationError::PostconditionFailed {
+                condition: format!("token issuance: {}", e),
+            })?;
+
+        let expires_at = Utc
+            .timestamp_opt(issued.refresh_claims.exp, 0)
+            .single()
+            .ok_or_else(|| ApplicationError::PostconditionFailed {
+                condition: "refresh token expiry out of range".to_string(),
+            })?;
+        let refresh_token = RefreshToken::new(
+            issued.refresh_claims.jti.clone(),
+            family_id.to_string(),
+            user.id().clone(),
+            expires_at,
+        );
+        self.refresh_token_repository
+            .save(&refresh_token)
+            .await
+            .map_err(Self::infrastructure_error)?;
+
+        Ok(TokenPairDto {
+            access_token: issued.access_token,
+            refresh_token: issued.refresh_token,
+            token_type: "Bearer".to_string(),
+            expires_in: JWT_CONFIG.expiration_hours * 3600,
+            user: AuthUserDto {
+                id: user.id().0.clone(),
+                email: user.email().0.clone(),
+                name: user.name().0.clone(),
+                role: user.role().to_string(),
+            },
+        })
+    }
+
+    fn infrastructure_error(e: Box<dyn std::error::Error + Send + Sync>) -> ApplicationError {
+        ApplicationError::Infrastructure(InfrastructureError::ResourceUnavailable {
+            resource: "refresh_token".to_string(),
+            message: format!("{}", e),
+        })
+    }
+}
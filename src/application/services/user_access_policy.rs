@@ -0,0 +1,54 @@
+//application/services/user_access_policy.rs
+// ユーザーリソースの認可（本人またはロール権限）
+// 2025/7/8
+
+use crate::application::dto::auth_dto::ActorDto;
+use crate::domain::service::permission_policy::PermissionPolicy;
+use crate::domain::value_object::{permission::Permission, role::Role, user_id::UserId};
+use crate::shared::error::application_error::{ApplicationError, ApplicationResult};
+use std::sync::Arc;
+
+/// ユーザーリソースへの操作可否を判定する
+///
+/// 本人のレコードは常に操作でき、他人のレコードはロールが該当権限を持つ場合のみ操作できる。
+/// スコープ付きの呼び出し（APIキー）は、本人のレコードでもスコープに含まれる権限が必要。
+pub struct UserAccessPolicy {
+    permission_policy: Arc<PermissionPolicy>,
+}
+
+impl UserAccessPolicy {
+    pub fn new(permission_policy: Arc<PermissionPolicy>) -> Self {
+        Self { permission_policy }
+    }
+
+    pub fn authorize(
+        &self,
+        actor: &ActorDto,
+        target: &UserId,
+        permission: Permission,
+    ) -> ApplicationResult<()> {
+        if let Some(scope) = &actor.scope
+            && !scope.split_whitespace().any(|s| s == permission.as_str())
+        {
+            return Err(ApplicationError::AuthorizationFailed {
+                message: format!("scope does not include {}", permission),
+            });
+        }
+        if actor.user_id == target.0 {
+            return Ok(());
+        }
+        let allowed = Role::new(&actor.role)
+            .map(|role| self.permission_policy.allows(role, permission))
+            .unwrap_or(false);
+        if allowed {
+            Ok(())
+        } else {
+            Err(ApplicationError::AuthorizationFailed {
+                message: format!(
+                    "'{}' cannot modify user '{}' without {}",
+                    actor.user_id, target.0, permission
+                ),
+            })
+        }
+    }
+}
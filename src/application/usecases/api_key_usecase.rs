@@ -0,0 +1,317 @@
+//application/usecases/api_key_usecase.rs
+// APIキー管理ユースケース（作成・一覧・取得・更新・削除）
+// 2025/7/8
+
+use crate::application::dto::api_key_dto::{
+    ApiKeyDto, CreateApiKeyDto, CreatedApiKeyDto, UpdateApiKeyDto,
+};
+use crate::application::dto::auth_dto::ActorDto;
+use crate::application::services::api_key_service::ApiKeyService;
+use crate::domain::entity::api_key::ApiKey;
+use crate::domain::entity::audit_event::AuditEvent;
+use crate::domain::entity::user::User;
+use crate::domain::repository::api_key_repository::ApiKeyRepositoryInterface;
+use crate::domain::repository::audit_log_repository::AuditLogRepositoryInterface;
+use crate::domain::repository::user_query_repository::UserQueryRepositoryInterface;
+use crate::domain::service::permission_policy::PermissionPolicy;
+use crate::domain::value_object::{permission::Permission, role::Role, user_id::UserId};
+use crate::shared::error::application_error::{ApplicationError, ApplicationResult};
+use crate::shared::error::infrastructure_error::InfrastructureError;
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+
+/// APIキー名の最大文字数
+const MAX_NAME_LENGTH: usize = 100;
+
+#[async_trait]
+pub trait ApiKeyUsecaseInterface: Send + Sync {
+    /// APIキーを作成する（キー本体は戻り値でのみ返す）
+    async fn create(
+        &self,
+        actor: ActorDto,
+        request: CreateApiKeyDto,
+    ) -> ApplicationResult<CreatedApiKeyDto>;
+    /// 所有者のAPIキー一覧（`owner_id`省略時は自分）
+    async fn list(
+        &self,
+        actor: ActorDto,
+        owner_id: Option<String>,
+    ) -> ApplicationResult<Vec<ApiKeyDto>>;
+    /// APIキーを取得する
+    async fn get(&self, actor: ActorDto, id: String) -> ApplicationResult<ApiKeyDto>;
+    /// 名前・スコープを更新する
+    async fn update(
+        &self,
+        actor: ActorDto,
+        id: String,
+        request: UpdateApiKeyDto,
+    ) -> ApplicationResult<ApiKeyDto>;
+    /// APIキーを削除する（以降の認証は失敗する）
+    async fn delete(&self, actor: ActorDto, id: String) -> ApplicationResult<()>;
+}
+
+/// APIキー管理ユースケース
+///
+/// 責務:
+/// 1. 所有者の確認（本人または`api_keys:manage`権限を持つロールのみ操作可）
+/// 2. スコープ・有効期間の検証（スコープは所有者のロールの権限内に限る）
+/// 3. 作成・更新・削除の監査ログ記録
+pub struct ApiKeyUseCase {
+    api_key_repository: Arc<dyn ApiKeyRepositoryInterface>,
+    query_repository: Arc<dyn UserQueryRepositoryInterface + Send + Sync>,
+    audit_log_repository: Arc<dyn AuditLogRepositoryInterface>,
+    permission_policy: Arc<PermissionPolicy>,
+    default_ttl_days: i64,
+    max_ttl_days: i64,
+}
+
+impl ApiKeyUseCase {
+    pub fn new(
+        api_key_repository: Arc<dyn ApiKeyRepositoryInterface>,
+        query_repository: Arc<dyn UserQueryRepositoryInterface + Send + Sync>,
+        audit_log_repository: Arc<dyn AuditLogRepositoryInterface>,
+        permission_policy: Arc<PermissionPolicy>,
+        default_ttl_days: i64,
+        max_ttl_days: i64,
+    ) -> Self {
+        Self {
+            api_key_repository,
+            query_repository,
+            audit_log_repository,
+            permission_policy,
+            default_ttl_days,
+            max_ttl_days,
+        }
+    }
+
+    fn infrastructure_error(
+        resource: &str,
+        e: Box<dyn std::error::Error + Send + Sync>,
+    ) -> ApplicationError {
+        ApplicationError::Infrastructure(InfrastructureError::ResourceUnavailable {
+            resource: resource.to_string(),
+            message: format!("{}", e),
+        })
+    }
+
+    fn validation_error(field: &str, message: impl Into<String>) -> ApplicationError {
+        ApplicationError::ValidationFailed {
+            field: field.to_string(),
+            message: message.into(),
+        }
+    }
+
+    fn not_found(id: &str) -> ApplicationError {
+        ApplicationError::ResourceNotFound {
+            resource: "API key".to_string(),
+            id: id.to_string(),
+        }
+    }
+
+    /// 本人、または他人のキーを管理する権限を持つか
+    fn can_manage(&self, actor: &ActorDto, owner_id: &UserId) -> bool {
+        actor.user_id == owner_id.0
+            || Role::new(&actor.role).is_ok_and(|role| {
+                self.permission_policy
+                    .allows(role, Permission::ApiKeysManage)
+            })
+    }
+
+    /// 所有者を取得する（他人のキーを管理する権限がない場合は拒否）
+    async fn authorized_owner(
+        &self,
+        actor: &ActorDto,
+        owner_id: Option<String>,
+    ) -> ApplicationResult<User> {
+        let owner_id = UserId::new(owner_id.unwrap_or_else(|| actor.user_id.clone()));
+        if !self.can_manage(actor, &owner_id) {
+            return Err(ApplicationError::AuthorizationFailed {
+                message: format!(
+                    "'{}' cannot manage API keys of '{}' without {}",
+                    actor.user_id,
+                    owner_id.0,
+                    Permission::ApiKeysManage
+                ),
+            });
+        }
+        self.query_repository
+            .find_by_id(&owner_id)
+            .await
+            .map_err(|e| Self::infrastructure_error("user", e))?
+            .ok_or(ApplicationError::UserNotFound { id: owner_id.0 })
+    }
+
+    /// 操作対象のキーを取得する（管理できないキーは存在しないものとして扱う）
+    async fn authorized_key(&self, actor: &ActorDto, id: &str) -> ApplicationResult<ApiKey> {
+        self.api_key_repository
+            .find_by_id(id)
+            .await
+            .map_err(|e| Self::infrastructure_error("api_key", e))?
+            .filter(|api_key| self.can_manage(actor, &api_key.owner_id))
+            .ok_or_else(|| Self::not_found(id))
+    }
+
+    fn validate_name(name: &str) -> ApplicationResult<String> {
+        let name = name.trim();
+        if name.is_empty() || name.chars().count() > MAX_NAME_LENGTH {
+            return Err(Self::validation_error(
+                "name",
+                format!("must be 1 to {} characters", MAX_NAME_LENGTH),
+            ));
+        }
+        Ok(name.to_string())
+    }
+
+    /// スコープを解釈し、所有者のロールが持つ権限に含まれることを確認する
+    fn validate_scopes(
+        &self,
+        scopes: &[String],
+        owner: &User,
+    ) -> ApplicationResult<Vec<Permission>> {
+        if scopes.is_empty() {
+            return Err(Self::validation_error(
+                "scopes",
+                "at least one scope is required",
+            ));
+        }
+        let mut permissions = Vec::new();
+        for scope in scopes {
+            let permission = Permission::new(scope).map_err(|_| {
+                Self::validation_error("scopes", format!("unknown scope '{}'", scope))
+            })?;
+            if !self.permission_policy.allows(owner.role(), permission) {
+                return Err(Self::validation_error(
+                    "scopes",
+                    format!("owner's role '{}' does not grant '{}'", owner.role(), scope),
+                ));
+            }
+            if !permissions.contains(&permission) {
+                permissions.push(permission);
+            }
+        }
+        permissions.sort();
+        Ok(permissions)
+    }
+
+    async fn audit(&self, action: &str, actor: &ActorDto, api_key: &ApiKey) {
+        let event = AuditEvent::new(
+            action,
+            Some(actor.user_id.clone()),
+            format!("user:{}", api_key.owner_id.0),
+            Some(format!("api_key={} prefix={}", api_key.id, api_key.prefix)),
+        );
+        if let Err(e) = self.audit_log_repository.record(&event).await {
+            println!("ApiKeyUseCase: Failed to write audit log: {}", e);
+        }
+    }
+}
+
+#[async_trait]
+impl ApiKeyUsecaseInterface for ApiKeyUseCase {
+    async fn create(
+        &self,
+        actor: ActorDto,
+        request: CreateApiKeyDto,
+    ) -> ApplicationResult<CreatedApiKeyDto> {
+        let owner = self.authorized_owner(&actor, request.owner_id).await?;
+        let name = Self::validate_name(&request.name)?;
+        let scopes = self.validate_scopes(&request.scopes, &owner)?;
+        let ttl_days = request.expires_in_days.unwrap_or(self.default_ttl_days);
+        if !(1..=self.max_ttl_days).contains(&ttl_days) {
+            return Err(Self::validation_error(
+                "expires_in_days",
+                format!("must be between 1 and {}", self.max_ttl_days),
+            ));
+        }
+
+        let generated = ApiKeyService::generate();
+        let now = Utc::now();
+        let api_key = ApiKey {
+            id: uuid::Uuid::new_v4().to_string(),
+            owner_id: owner.id().clone(),
+            name,
+            prefix: generated.prefix,
+            key_hash: generated.key_hash,
+            scopes,
+            expires_at: Some(now + Duration::days(ttl_days)),
+            last_used_at: None,
+            created_at: now,
+            created_by: UserId::new(actor.user_id.clone()),
+        };
+        self.api_key_repository
+            .save(&api_key)
+            .await
+            .map_err(|e| Self::infrastructure_error("api_key", e))?;
+        self.audit("api_key.created", &actor, &api_key).await;
+        Ok(CreatedApiKeyDto {
+            key: generated.key,
+            api_key: api_key.into(),
+        })
+    }
+
+    async fn list(
+        &self,
+        actor: ActorDto,
+        owner_id: Option<String>,
+    ) -> ApplicationResult<Vec<ApiKeyDto>> {
+        let owner = self.authorized_owner(&actor, owner_id).await?;
+        let api_keys = self
+            .api_key_repository
+            .find_by_owner(owner.id())
+            .await
+            .map_err(|e| Self::infrastructure_error("api_key", e))?;
+        Ok(api_keys.into_iter().map(ApiKeyDto::from).collect())
+    }
+
+    async fn get(&self, actor: ActorDto, id: String) -> ApplicationResult<ApiKeyDto> {
+        Ok(self.authorized_key(&actor, &id).await?.into())
+    }
+
+    async fn update(
+        &self,
+        actor: ActorDto,
+        id: String,
+        request: UpdateApiKeyDto,
+    ) -> ApplicationResult<ApiKeyDto> {
+        let mut api_key = self.authorized_key(&actor, &id).await?;
+        if let Some(name) = &request.name {
+            api_key.name = Self::validate_name(name)?;
+        }
+        if let Some(scopes) = &request.scopes {
+            let owner = self
+                .query_repository
+                .find_by_id(&api_key.owner_id)
+                .await
+                .map_err(|e| Self::infrastructure_error("user", e))?
+                .ok_or_else(|| ApplicationError::UserNotFound {
+                    id: api_key.owner_id.0.clone(),
+                })?;
+            api_key.scopes = self.validate_scopes(scopes, &owner)?;
+        }
+        let updated = self
+            .api_key_repository
+            .update(&api_key)
+            .await
+            .map_err(|e| Self::infrastructure_error("api_key", e))?;
+        if !updated {
+            return Err(Self::not_found(&id));
+        }
+        self.audit("api_key.updated", &actor, &api_key).await;
+        Ok(api_key.into())
+    }
+
+    async fn delete(&self, actor: ActorDto, id: String) -> ApplicationResult<()> {
+        let api_key = self.authorized_key(&actor, &id).await?;
+        let deleted = self
+            .api_key_repository
+            .delete(&api_key.id)
+            .await
+            .map_err(|e| Self::infrastructure_error("api_key", e))?;
+        if !deleted {
+            return Err(Self::not_found(&id));
+        }
+        self.audit("api_key.deleted", &actor, &api_key).await;
+        Ok(())
+    }
+}
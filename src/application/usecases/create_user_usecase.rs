@@ -4,13 +4,13 @@
 
 use crate::application::dto::user_request_dto::CreateUserRequestDto;
 use crate::application::dto::user_response_dto::UserResponseDto;
-use crate::domain::entity::user::User;
+use crate::application::services::email_verification_service::EmailVerificationService;
 use crate::domain::repository::user_command_repository::UserCommandRepositoryInterface;
-use crate::domain::value_object::{
-    birth_date::BirthDate, email::Email, password::Password, phone::Phone, user_id::UserId,
-    user_name::UserName,
-};
+use crate::domain::service::password_policy::{PasswordContext, PasswordPolicy};
+use crate::domain::value_object::password::Password;
+use crate::domain::value_object::user_id::UserId;
 use crate::shared::error::application_error::{ApplicationError, ApplicationResult};
+use crate::shared::utils::password_hasher::PasswordHasher;
 use async_trait::async_trait;
 use std::sync::Arc;
 
@@ -28,6 +28,9 @@ where
 {
     command_repository: std::sync::Arc<dyn UserCommandRepositoryInterface + Send + Sync>,
     id_generator: U,
+    password_hasher: Arc<PasswordHasher>,
+    email_verification: Option<Arc<EmailVerificationService>>,
+    password_policy: Option<Arc<PasswordPolicy>>,
 }
 
 impl<U> CreateUserUseCase<U>
@@ -37,12 +40,28 @@ where
     pub fn new(
         command_repository: std::sync::Arc<dyn UserCommandRepositoryInterface + Send + Sync>,
         id_generator: U,
+        password_hasher: Arc<PasswordHasher>,
     ) -> Self {
         Self {
             command_repository,
             id_generator,
+            password_hasher,
+            email_verification: None,
+            password_policy: None,
         }
     }
+
+    /// パスワードを設定したポリシーで検証する（未設定の場合は既定の長さのみ）
+    pub fn with_password_policy(mut self, policy: Arc<PasswordPolicy>) -> Self {
+        self.password_policy = Some(policy);
+        self
+    }
+
+    /// 作成したユーザーに確認メールを送る（初期ユーザーの登録では使わない）
+    pub fn with_email_verification(mut self, service: Arc<EmailVerificationService>) -> Self {
+        self.email_verification = Some(service);
+        self
+    }
 }
 
 #[async_trait]
@@ -66,12 +85,19 @@ where
                 field: "name".to_string(),
                 message: e.to_string(),
             })?;
-        let password =
-            crate::domain::value_object::password::Password::new(request_dto.password.clone())
-                .map_err(|e| ApplicationError::ValidationFailed {
-                    field: "password".to_string(),
-                    message: e.to_string(),
-                })?;
+        // パスワードポリシーの違反はすべてまとめて返す
+        let password = match &self.password_policy {
+            Some(policy) => Password::with_policy(
+                request_dto.password.clone(),
+                policy,
+                &PasswordContext {
+                    email: Some(&request_dto.email),
+                    name: Some(&request_dto.name),
+                },
+            ),
+            None => Password::new(request_dto.password.clone()),
+        }
+        .map_err(ApplicationError::Domain)?;
         let phone = request_dto
             .phone
             .as_ref()
@@ -91,12 +117,21 @@ where
                 message: e.to_string(),
             })?;
 
-        let user =
-            crate::domain::entity::user::User::new(id, email, name, password, phone, birth_date)
-                .map_err(|e| ApplicationError::InvalidInput {
-                    input: "user".to_string(),
-                    reason: format!("{}", e),
-                })?;
+        // 平文パスワードはここでハッシュ化し、以降Userには保持しない
+        let password_hash = self.password_hasher.hash(&password).await?;
+
+        let user = crate::domain::entity::user::User::new(
+            id,
+            email,
+            name,
+            password_hash,
+            phone,
+            birth_date,
+        )
+        .map_err(|e| ApplicationError::InvalidInput {
+            input: "user".to_string(),
+            reason: format!("{}", e),
+        })?;
 
         // 2. 保存
         self.command_repository.save(&user).await.map_err(|e| {
@@ -108,7 +143,17 @@ where
         )
         })?;
 
-        // 3. レスポンスDTO生成
+        // 3. 確認メール送信（失敗しても作成済みのユーザーは残し、再送で回復できる）
+        if let Some(service) = &self.email_verification
+            && let Err(e) = service.send_verification(&user).await
+        {
+            println!(
+                "CreateUserUseCase: Failed to send verification email: {}",
+                e
+            );
+        }
+
+        // 4. レスポンスDTO生成
         Ok(UserResponseDto {
             id: user.id.0,
             email: user.email.0,
@@ -2,10 +2,13 @@
 // ユーザー削除ユースケース
 // 2025/7/8
 
+use crate::application::dto::auth_dto::ActorDto;
 use crate::application::dto::user_request_dto::DeleteUserRequestDto;
 use crate::application::dto::user_response_dto::UserResponseDto;
+use crate::application::services::user_access_policy::UserAccessPolicy;
 use crate::domain::repository::user_command_repository::UserCommandRepositoryInterface;
 use crate::domain::repository::user_query_repository::UserQueryRepositoryInterface;
+use crate::domain::value_object::permission::Permission;
 use crate::domain::value_object::user_id::UserId;
 use crate::shared::error::application_error::{ApplicationError, ApplicationResult};
 use async_trait::async_trait;
@@ -16,6 +19,7 @@ use uuid::Uuid;
 pub trait DeleteUserUsecaseInterface: Send + Sync {
     async fn execute(
         &self,
+        actor: ActorDto,
         request_dto: DeleteUserRequestDto,
     ) -> ApplicationResult<UserResponseDto>;
 }
@@ -23,16 +27,19 @@ pub trait DeleteUserUsecaseInterface: Send + Sync {
 pub struct DeleteUserUseCase {
     command_repository: Arc<dyn UserCommandRepositoryInterface + Send + Sync>,
     query_repository: Arc<dyn UserQueryRepositoryInterface + Send + Sync>,
+    access_policy: Arc<UserAccessPolicy>,
 }
 
 impl DeleteUserUseCase {
     pub fn new(
         command_repository: Arc<dyn UserCommandRepositoryInterface + Send + Sync>,
         query_repository: Arc<dyn UserQueryRepositoryInterface + Send + Sync>,
+        access_policy: Arc<UserAccessPolicy>,
     ) -> Self {
         Self {
             command_repository,
             query_repository,
+            access_policy,
         }
     }
 }
@@ -41,6 +48,7 @@ impl DeleteUserUseCase {
 impl DeleteUserUsecaseInterface for DeleteUserUseCase {
     async fn execute(
         &self,
+        actor: ActorDto,
         request_dto: DeleteUserRequestDto,
     ) -> ApplicationResult<UserResponseDto> {
         println!(
@@ -57,6 +65,10 @@ impl DeleteUserUsecaseInterface for DeleteUserUseCase {
         let user_id_vo = UserId::new(user_id.to_string());
         println!("DeleteUserUseCase: User ID validated: {}", user_id_vo.0);
 
+        // 認可（本人以外はusers:deleteが必要。存在有無を漏らさないよう取得前に判定）
+        self.access_policy
+            .authorize(&actor, &user_id_vo, Permission::UsersDelete)?;
+
         // 2. 既存ユーザーの存在確認
         println!("DeleteUserUseCase: Checking if user exists...");
         let existing_user = self.query_repository
@@ -0,0 +1,150 @@
+//application/usecases/email_verification_usecase.rs
+// メールアドレス確認ユースケース
+// 2025/7/8
+
+use crate::application::dto::auth_dto::{EmailVerificationConfirmDto, EmailVerificationResendDto};
+use crate::application::services::email_verification_service::EmailVerificationService;
+use crate::domain::entity::audit_event::AuditEvent;
+use crate::domain::repository::audit_log_repository::AuditLogRepositoryInterface;
+use crate::domain::repository::email_verification_token_repository::EmailVerificationTokenRepositoryInterface;
+use crate::domain::repository::user_command_repository::UserCommandRepositoryInterface;
+use crate::domain::repository::user_query_repository::UserQueryRepositoryInterface;
+use crate::domain::value_object::email::Email;
+use crate::shared::error::application_error::{ApplicationError, ApplicationResult};
+use crate::shared::error::infrastructure_error::InfrastructureError;
+use crate::shared::utils::secure_token::hash_token;
+use async_trait::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait EmailVerificationUsecaseInterface: Send + Sync {
+    /// トークンを検証してメールアドレスを確認済みにする
+    async fn verify(&self, request_dto: EmailVerificationConfirmDto) -> ApplicationResult<()>;
+    /// 確認メールを再送する（未登録・確認済み・回数制限中でも成功を返す）
+    async fn resend(&self, request_dto: EmailVerificationResendDto) -> ApplicationResult<()>;
+}
+
+/// メールアドレス確認ユースケース
+///
+/// 責務:
+/// 1. 確認トークンの消費とユーザーの確認日時の記録
+/// 2. 確認メールの再送（回数制限はEmailVerificationServiceが判定）
+///
+/// 再送はアカウントの有無や制限状況に関わらず同じ応答を返す
+pub struct EmailVerificationUseCase {
+    query_repository: Arc<dyn UserQueryRepositoryInterface + Send + Sync>,
+    command_repository: Arc<dyn UserCommandRepositoryInterface + Send + Sync>,
+    token_repository: Arc<dyn EmailVerificationTokenRepositoryInterface>,
+    audit_log_repository: Arc<dyn AuditLogRepositoryInterface>,
+    verification_service: Arc<EmailVerificationService>,
+}
+
+impl EmailVerificationUseCase {
+    pub fn new(
+        query_repository: Arc<dyn UserQueryRepositoryInterface + Send + Sync>,
+        command_repository: Arc<dyn UserCommandRepositoryInterface + Send + Sync>,
+        token_repository: Arc<dyn EmailVerificationTokenRepositoryInterface>,
+        audit_log_repository: Arc<dyn AuditLogRepositoryInterface>,
+        verification_service: Arc<EmailVerificationService>,
+    ) -> Self {
+        Self {
+            query_repository,
+            command_repository,
+            token_repository,
+            audit_log_repository,
+            verification_service,
+        }
+    }
+
+    fn infrastructure_error(
+        resource: &str,
+        e: Box<dyn std::error::Error + Send + Sync>,
+    ) -> ApplicationError {
+        ApplicationError::Infrastructure(InfrastructureError::ResourceUnavailable {
+            resource: resource.to_string(),
+            message: format!("{}", e),
+        })
+    }
+}
+
+#[async_trait]
+impl EmailVerificationUsecaseInterface for EmailVerificationUseCase {
+    async fn verify(&self, request_dto: EmailVerificationConfirmDto) -> ApplicationResult<()> {
+        // 1. トークンの消費（未登録・期限切れ・使用済みは同じエラー）
+        let now = Utc::now();
+        let token = self
+            .token_repository
+            .consume(&hash_token(request_dto.token.trim()), now)
+            .await
+            .map_err(|e| Self::infrastructure_error("email_verification_token", e))?
+            .ok_or(ApplicationError::InvalidToken)?;
+        let user = self
+            .query_repository
+            .find_by_id(&token.user_id)
+            .await
+            .map_err(|e| Self::infrastructure_error("user", e))?
+            .ok_or(ApplicationError::InvalidToken)?;
+
+        // 2. 発行後にメールアドレスが変わっていれば無効
+        if user.email().0 != token.email {
+            return Err(ApplicationError::InvalidToken);
+        }
+
+        // 3. 確認日時の記録と残りのトークンの無効化
+        if !user.is_email_verified() {
+            self.command_repository
+                .mark_email_verified(user.id(), now)
+                .await
+                .map_err(|e| Self::infrastructure_error("user", e))?;
+        }
+        self.token_repository
+            .invalidate_user_tokens(user.id())
+            .await
+            .map_err(|e| Self::infrastructure_error("email_verification_token", e))?;
+
+        let event = AuditEvent::new(
+            "email.verified",
+            Some(user.id().0.clone()),
+            format!("user:{}", user.id().0),
+            Some(format!("email={}", token.email)),
+        );
+        if let Err(e) = self.audit_log_repository.record(&event).await {
+            println!("EmailVerificationUseCase: Failed to write audit log: {}", e);
+        }
+        Ok(())
+    }
+
+    async fn resend(&self, request_dto: EmailVerificationResendDto) -> ApplicationResult<()> {
+        // 1. ユーザー検索（未登録・形式不正でも同じ応答にする）
+        let Ok(email) = Email::new(request_dto.email.trim().to_string()) else {
+            return Ok(());
+        };
+        let Some(user) = self
+            .query_repository
+            .find_by_email(&email)
+            .await
+            .map_err(|e| Self::infrastructure_error("user", e))?
+        else {
+            println!("EmailVerificationUseCase: resend requested for unknown email");
+            return Ok(());
+        };
+        if user.is_email_verified() {
+            return Ok(());
+        }
+
+        // 2. 回数制限を超えていれば送らない
+        if !self
+            .verification_service
+            .can_resend(user.id(), Utc::now())
+            .await?
+        {
+            println!(
+                "EmailVerificationUseCase: resend throttled for user {}",
+                user.id().0
+            );
+            return Ok(());
+        }
+        self.verification_service.send_verification(&user).await
+    }
+}
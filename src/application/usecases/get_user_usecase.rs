@@ -16,14 +16,14 @@ pub trait GetUserQueryUsecaseInterface: Send + Sync {
 
 pub struct GetUserUseCase<T>
 where
-    T: UserQueryRepositoryInterface + Send + Sync,
+    T: UserQueryRepositoryInterface + Send + Sync + ?Sized,
 {
     query_repository: Arc<T>,
 }
 
 impl<T> GetUserUseCase<T>
 where
-    T: UserQueryRepositoryInterface + Send + Sync,
+    T: UserQueryRepositoryInterface + Send + Sync + ?Sized,
 {
     pub fn new(query_repository: Arc<T>) -> Self {
         Self { query_repository }
@@ -33,7 +33,7 @@ where
 #[async_trait]
 impl<T> GetUserQueryUsecaseInterface for GetUserUseCase<T>
 where
-    T: UserQueryRepositoryInterface + Send + Sync,
+    T: UserQueryRepositoryInterface + Send + Sync + ?Sized,
 {
     async fn execute(&self, user_id: String) -> ApplicationResult<UserResponseDto> {
         // 1. UserIdの構築
@@ -0,0 +1,107 @@
+//application/usecases/impersonation_usecase.rs
+// なりすましユースケース（サポート担当による不具合の再現用）
+// 2025/7/8
+
+use crate::application::dto::auth_dto::ImpersonationTokenDto;
+use crate::application::services::session_token_service::SessionTokenService;
+use crate::domain::entity::audit_event::AuditEvent;
+use crate::domain::repository::audit_log_repository::AuditLogRepositoryInterface;
+use crate::domain::repository::user_query_repository::UserQueryRepositoryInterface;
+use crate::domain::value_object::{role::Role, user_id::UserId};
+use crate::shared::error::application_error::{ApplicationError, ApplicationResult};
+use crate::shared::error::infrastructure_error::InfrastructureError;
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::time::Duration;
+
+#[async_trait]
+pub trait ImpersonationUsecaseInterface: Send + Sync {
+    /// スーパー管理者（impersonator_id）が対象ユーザーとして操作するトークンを発行する
+    async fn impersonate(
+        &self,
+        impersonator_id: String,
+        user_id: String,
+    ) -> ApplicationResult<ImpersonationTokenDto>;
+}
+
+/// なりすましユースケース
+///
+/// 責務:
+/// 1. 対象ユーザーの確認（自分自身・スーパー管理者は対象にできない）
+/// 2. `act`付きの短期間のトークンの発行（リフレッシュトークンなし）
+/// 3. 開始の監査ログ記録
+///
+/// 呼び出し元がスーパー管理者であることはルーターの`SuperAdminUser`で確認する。
+/// なりすまし中の各リクエストは認証Extractorが監査ログに記録する。
+pub struct ImpersonationUseCase {
+    query_repository: Arc<dyn UserQueryRepositoryInterface + Send + Sync>,
+    session_token_service: Arc<SessionTokenService>,
+    audit_log_repository: Arc<dyn AuditLogRepositoryInterface>,
+    token_ttl: Duration,
+}
+
+impl ImpersonationUseCase {
+    pub fn new(
+        query_repository: Arc<dyn UserQueryRepositoryInterface + Send + Sync>,
+        session_token_service: Arc<SessionTokenService>,
+        audit_log_repository: Arc<dyn AuditLogRepositoryInterface>,
+        token_ttl: Duration,
+    ) -> Self {
+        Self {
+            query_repository,
+            session_token_service,
+            audit_log_repository,
+            token_ttl,
+        }
+    }
+
+    fn not_permitted(reason: &str) -> ApplicationError {
+        ApplicationError::OperationNotPermitted {
+            operation: "impersonate".to_string(),
+            reason: reason.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl ImpersonationUsecaseInterface for ImpersonationUseCase {
+    async fn impersonate(
+        &self,
+        impersonator_id: String,
+        user_id: String,
+    ) -> ApplicationResult<ImpersonationTokenDto> {
+        if impersonator_id == user_id {
+            return Err(Self::not_permitted("cannot impersonate yourself"));
+        }
+        let user = self
+            .query_repository
+            .find_by_id(&UserId::new(user_id.clone()))
+            .await
+            .map_err(|e| {
+                ApplicationError::Infrastructure(InfrastructureError::ResourceUnavailable {
+                    resource: "user".to_string(),
+                    message: format!("{}", e),
+                })
+            })?
+            .ok_or(ApplicationError::UserNotFound { id: user_id })?;
+        if user.role() == Role::SuperAdmin {
+            return Err(Self::not_permitted("cannot impersonate a superadmin"));
+        }
+
+        let token = self.session_token_service.issue_impersonation_token(
+            &user,
+            &impersonator_id,
+            self.token_ttl,
+        )?;
+        let event = AuditEvent::new(
+            "impersonation.started",
+            Some(impersonator_id),
+            format!("user:{}", user.id().0),
+            Some(format!("expires_in={}", token.expires_in)),
+        );
+        if let Err(e) = self.audit_log_repository.record(&event).await {
+            println!("ImpersonationUseCase: Failed to write audit log: {}", e);
+        }
+        Ok(token)
+    }
+}
@@ -1,3 +1,185 @@
 //application/usecases/login_usecase.rs
 // ログインユースケース
 // 2025/7/8
+
+use crate::application::dto::auth_dto::{LoginRequestDto, LoginResultDto};
+use crate::application::services::login_throttle_service::LoginThrottleService;
+use crate::application::services::mfa_service::MfaService;
+use crate::application::services::session_token_service::SessionTokenService;
+use crate::domain::repository::user_command_repository::UserCommandRepositoryInterface;
+use crate::domain::repository::user_query_repository::UserQueryRepositoryInterface;
+use crate::domain::value_object::{email::Email, password::Password, password_hash::PasswordHash};
+use crate::shared::error::application_error::{ApplicationError, ApplicationResult};
+use crate::shared::error::infrastructure_error::InfrastructureError;
+use crate::shared::utils::password_hasher::{PasswordHasher, PasswordVerification};
+use async_trait::async_trait;
+use std::sync::Arc;
+use tokio::sync::OnceCell;
+
+#[async_trait]
+pub trait LoginUsecaseInterface: Send + Sync {
+    async fn execute(&self, request_dto: LoginRequestDto) -> ApplicationResult<LoginResultDto>;
+}
+
+/// ログインユースケース
+///
+/// 責務:
+/// 1. アカウント・接続元IPのロック確認
+/// 2. メールアドレスでユーザーを検索
+/// 3. パスワードハッシュの照合（コスト変更時は再ハッシュして保存）、失敗回数の記録
+/// 4. メールアドレス確認の要否の判定（設定で有効な場合のみ）
+/// 5. 二要素認証が有効・必須の場合は`mfa_pending`トークンを返す（2段階目はMfaUseCase）
+/// 6. 最終ログイン日時の更新
+/// 7. 新しいセッションを開始し、実ユーザー情報からトークンペアを発行
+///
+/// 未登録メールアドレスとパスワード不一致は同じエラーを返し、
+/// 未登録の場合もダミーハッシュで照合して処理時間を揃える。
+pub struct LoginUseCase {
+    query_repository: Arc<dyn UserQueryRepositoryInterface + Send + Sync>,
+    command_repository: Arc<dyn UserCommandRepositoryInterface + Send + Sync>,
+    password_hasher: Arc<PasswordHasher>,
+    session_token_service: Arc<SessionTokenService>,
+    login_throttle_service: Arc<LoginThrottleService>,
+    require_verified_email: bool,
+    mfa_service: Option<Arc<MfaService>>,
+    dummy_hash: OnceCell<PasswordHash>,
+}
+
+impl LoginUseCase {
+    pub fn new(
+        query_repository: Arc<dyn UserQueryRepositoryInterface + Send + Sync>,
+        command_repository: Arc<dyn UserCommandRepositoryInterface + Send + Sync>,
+        password_hasher: Arc<PasswordHasher>,
+        session_token_service: Arc<SessionTokenService>,
+        login_throttle_service: Arc<LoginThrottleService>,
+    ) -> Self {
+        Self {
+            query_repository,
+            command_repository,
+            password_hasher,
+            session_token_service,
+            login_throttle_service,
+            require_verified_email: false,
+            mfa_service: None,
+            dummy_hash: OnceCell::new(),
+        }
+    }
+
+    /// メールアドレス確認済みのユーザーだけログインを許可する
+    pub fn with_email_verification_required(mut self, required: bool) -> Self {
+        self.require_verified_email = required;
+        self
+    }
+
+    /// 二要素認証を有効にする（登録済みユーザー・登録必須ロールはログインが2段階になる）
+    pub fn with_mfa(mut self, mfa_service: Arc<MfaService>) -> Self {
+        self.mfa_service = Some(mfa_service);
+        self
+    }
+
+    /// 未登録ユーザー照合用のダミーハッシュ（現在のコスト設定で一度だけ生成）
+    async fn dummy_hash(&self) -> ApplicationResult<&PasswordHash> {
+        self.dummy_hash
+            .get_or_try_init(|| async {
+                let dummy = Password(uuid::Uuid::new_v4().to_string());
+                self.password_hasher.hash(&dummy).await
+            })
+            .await
+            .map_err(ApplicationError::from)
+    }
+
+    fn infrastructure_error(e: Box<dyn std::error::Error + Send + Sync>) -> ApplicationError {
+        ApplicationError::Infrastructure(InfrastructureError::ResourceUnavailable {
+            resource: "user".to_string(),
+            message: format!("{}", e),
+        })
+    }
+}
+
+#[async_trait]
+impl LoginUsecaseInterface for LoginUseCase {
+    async fn execute(&self, request_dto: LoginRequestDto) -> ApplicationResult<LoginResultDto> {
+        // 1. ロック中は照合せずに拒否（パスワードが正しくても同じ）
+        let client_ip = request_dto.client_ip.as_deref();
+        self.login_throttle_service
+            .check(&request_dto.email, client_ip)
+            .await?;
+
+        // 2. ユーザー検索（形式不正なメールアドレスも未登録と同じ扱い）
+        let user = match Email::new(request_dto.email.clone()) {
+            Ok(email) => self
+                .query_repository
+                .find_by_email(&email)
+                .await
+                .map_err(Self::infrastructure_error)?,
+            Err(_) => None,
+        };
+
+        // 3. パスワード照合（未登録の場合もダミーハッシュで同じ処理を行う）
+        let verification = match &user {
+            Some(user) => {
+                self.password_hasher
+                    .verify(&request_dto.password, user.password_hash())
+                    .await?
+            }
+            None => {
+                let dummy_hash = self.dummy_hash().await?;
+                self.password_hasher
+                    .verify(&request_dto.password, dummy_hash)
+                    .await?;
+                PasswordVerification::Mismatch
+            }
+        };
+        let user = match user {
+            Some(user) if verification.is_match() => user,
+            _ => {
+                self.login_throttle_service
+                    .record_failure(&request_dto.email, client_ip)
+                    .await?;
+                return Err(ApplicationError::InvalidCredentials);
+            }
+        };
+        self.login_throttle_service
+            .record_success(&request_dto.email)
+            .await?;
+
+        // 4. 未確認のメールアドレスはパスワード照合後に拒否（存在の有無は漏らさない）
+        if self.require_verified_email && !user.is_email_verified() {
+            return Err(ApplicationError::EmailNotVerified);
+        }
+
+        // 5. コストパラメータ変更時はハッシュを透過的に更新
+        if let PasswordVerification::MatchRehashed(new_hash) = &verification
+            && let Err(e) = self
+                .command_repository
+                .update_password_hash(user.id(), new_hash)
+                .await
+        {
+            // ハッシュ更新の失敗はログインを妨げない（次回ログイン時に再試行）
+            println!("LoginUseCase: Failed to upgrade password hash: {}", e);
+        }
+
+        // 6. 二要素認証（登録済み、または登録必須ロールで未登録）
+        if let Some(mfa_service) = &self.mfa_service {
+            let enabled = mfa_service.is_enabled(user.id()).await?;
+            if enabled || mfa_service.is_required_for(user.role()) {
+                return self
+                    .session_token_service
+                    .issue_mfa_challenge(&user, mfa_service.pending_token_ttl(), !enabled)
+                    .map(LoginResultDto::MfaRequired);
+            }
+        }
+
+        // 7. 最終ログイン日時の更新
+        self.command_repository
+            .update_last_login(user.id(), chrono::Utc::now())
+            .await
+            .map_err(Self::infrastructure_error)?;
+
+        // 8. セッション開始・トークンペア発行
+        self.session_token_service
+            .start_session(&user)
+            .await
+            .map(LoginResultDto::Authenticated)
+    }
+}
@@ -0,0 +1,126 @@
+//application/usecases/logout_usecase.rs
+// ログアウト・セッション失効ユースケース
+// 2025/7/8
+
+use crate::application::dto::auth_dto::AuthenticatedSessionDto;
+use crate::domain::repository::refresh_token_repository::RefreshTokenRepositoryInterface;
+use crate::domain::repository::token_revocation_repository::TokenRevocationRepositoryInterface;
+use crate::domain::repository::user_query_repository::UserQueryRepositoryInterface;
+use crate::domain::value_object::user_id::UserId;
+use crate::shared::error::application_error::{ApplicationError, ApplicationResult};
+use crate::shared::error::infrastructure_error::InfrastructureError;
+use async_trait::async_trait;
+use chrono::{TimeZone, Utc};
+use std::sync::Arc;
+
+#[async_trait]
+pub trait LogoutUsecaseInterface: Send + Sync {
+    /// 現在のセッションを終了する
+    async fn logout(&self, session: AuthenticatedSessionDto) -> ApplicationResult<()>;
+    /// 自分の全セッションを終了し、終了したセッション数を返す
+    async fn logout_all(&self, session: AuthenticatedSessionDto) -> ApplicationResult<usize>;
+    /// 管理者による対象ユーザーの全セッション終了
+    async fn revoke_user_sessions(&self, user_id: String) -> ApplicationResult<usize>;
+}
+
+/// ログアウトユースケース
+///
+/// 責務:
+/// 1. 使用中アクセストークン（jti）を失効リストへ登録
+/// 2. リフレッシュトークンファミリーの失効（同じセッションのアクセストークンも無効になる）
+/// 3. 対象ユーザーの全ファミリー失効と、セッションに紐づかないトークンの一括失効
+pub struct LogoutUseCase {
+    query_repository: Arc<dyn UserQueryRepositoryInterface + Send + Sync>,
+    refresh_token_repository: Arc<dyn RefreshTokenRepositoryInterface>,
+    token_revocation_repository: Arc<dyn TokenRevocationRepositoryInterface>,
+}
+
+impl LogoutUseCase {
+    pub fn new(
+        query_repository: Arc<dyn UserQueryRepositoryInterface + Send + Sync>,
+        refresh_token_repository: Arc<dyn RefreshTokenRepositoryInterface>,
+        token_revocation_repository: Arc<dyn TokenRevocationRepositoryInterface>,
+    ) -> Self {
+        Self {
+            query_repository,
+            refresh_token_repository,
+            token_revocation_repository,
+        }
+    }
+
+    async fn revoke_access_token(
+        &self,
+        session: &AuthenticatedSessionDto,
+    ) -> ApplicationResult<()> {
+        let expires_at = Utc
+            .timestamp_opt(session.expires_at, 0)
+            .single()
+            .unwrap_or_else(Utc::now);
+        self.token_revocation_repository
+            .revoke(
+                &session.jti,
+                &UserId::new(session.user_id.clone()),
+                expires_at,
+            )
+            .await
+            .map_err(Self::infrastructure_error)
+    }
+
+    /// ユーザーの全セッションを終了する（セッションに紐づかないトークンも無効にする）
+    async fn revoke_all_sessions(&self, user_id: &UserId) -> ApplicationResult<usize> {
+        let now = Utc::now();
+        self.token_revocation_repository
+            .invalidate_user_sessions(user_id, now)
+            .await
+            .map_err(Self::infrastructure_error)?;
+        self.refresh_token_repository
+            .revoke_user_families(user_id, now)
+            .await
+            .map_err(Self::infrastructure_error)
+    }
+
+    fn infrastructure_error(e: Box<dyn std::error::Error + Send + Sync>) -> ApplicationError {
+        ApplicationError::Infrastructure(InfrastructureError::ResourceUnavailable {
+            resource: "session".to_string(),
+            message: format!("{}", e),
+        })
+    }
+}
+
+#[async_trait]
+impl LogoutUsecaseInterface for LogoutUseCase {
+    async fn logout(&self, session: AuthenticatedSessionDto) -> ApplicationResult<()> {
+        self.revoke_access_token(&session).await?;
+        if let Some(session_id) = &session.session_id {
+            self.refresh_token_repository
+                .revoke_family(session_id, Utc::now())
+                .await
+                .map_err(Self::infrastructure_error)?;
+        }
+        Ok(())
+    }
+
+    async fn logout_all(&self, session: AuthenticatedSessionDto) -> ApplicationResult<usize> {
+        self.revoke_access_token(&session).await?;
+        self.revoke_all_sessions(&UserId::new(session.user_id))
+            .await
+    }
+
+    async fn revoke_user_sessions(&self, user_id: String) -> ApplicationResult<usize> {
+        let user_id = UserId::new(user_id);
+        let user = self
+            .query_repository
+            .find_by_id(&user_id)
+            .await
+            .map_err(Self::infrastructure_error)?;
+        if user.is_none() {
+            return Err(ApplicationError::UserNotFound { id: user_id.0 });
+        }
+        let revoked = self.revoke_all_sessions(&user_id).await?;
+        println!(
+            "LogoutUseCase: Revoked {} session(s) of user {}",
+            revoked, user_id.0
+        );
+        Ok(revoked)
+    }
+}
@@ -0,0 +1,251 @@
+//application/usecases/mfa_usecase.rs
+// 二要素認証ユースケース（登録・ログイン2段階目・解除）
+// 2025/7/8
+
+use crate::application::dto::auth_dto::{
+    MfaCallerDto, MfaConfirmResultDto, MfaEnrollmentDto, TokenPairDto,
+};
+use crate::application::services::login_throttle_service::LoginThrottleService;
+use crate::application::services::mfa_service::{MfaService, MfaVerification};
+use crate::application::services::session_token_service::SessionTokenService;
+use crate::domain::entity::audit_event::AuditEvent;
+use crate::domain::entity::user::User;
+use crate::domain::repository::audit_log_repository::AuditLogRepositoryInterface;
+use crate::domain::repository::token_revocation_repository::TokenRevocationRepositoryInterface;
+use crate::domain::repository::user_command_repository::UserCommandRepositoryInterface;
+use crate::domain::repository::user_query_repository::UserQueryRepositoryInterface;
+use crate::domain::value_object::user_id::UserId;
+use crate::shared::error::application_error::{ApplicationError, ApplicationResult};
+use crate::shared::error::infrastructure_error::InfrastructureError;
+use async_trait::async_trait;
+use chrono::{TimeZone, Utc};
+use std::sync::Arc;
+
+#[async_trait]
+pub trait MfaUsecaseInterface: Send + Sync {
+    /// 登録を開始し、シークレットと`otpauth://` URIを返す
+    async fn enroll(&self, caller: MfaCallerDto) -> ApplicationResult<MfaEnrollmentDto>;
+    /// 登録を確定してリカバリーコードを返す（ログイン途中の場合はセッションも開始）
+    async fn confirm_enrollment(
+        &self,
+        caller: MfaCallerDto,
+        code: String,
+    ) -> ApplicationResult<MfaConfirmResultDto>;
+    /// ログインの2段階目（TOTPコードまたはリカバリーコード）
+    async fn verify_login(
+        &self,
+        caller: MfaCallerDto,
+        code: String,
+    ) -> ApplicationResult<TokenPairDto>;
+    /// 登録を解除する
+    async fn disable(&self, caller: MfaCallerDto, code: String) -> ApplicationResult<()>;
+    /// リカバリーコードを再発行する
+    async fn regenerate_recovery_codes(
+        &self,
+        caller: MfaCallerDto,
+        code: String,
+    ) -> ApplicationResult<Vec<String>>;
+}
+
+/// 二要素認証ユースケース
+///
+/// 責務:
+/// 1. 登録・確認・解除・リカバリーコード再発行
+/// 2. ログイン2段階目のコード照合（失敗回数はログインと同じ制限を適用）
+/// 3. 2段階目完了時の`mfa_pending`トークンの失効とセッション開始
+pub struct MfaUseCase {
+    query_repository: Arc<dyn UserQueryRepositoryInterface + Send + Sync>,
+    command_repository: Arc<dyn UserCommandRepositoryInterface + Send + Sync>,
+    token_revocation_repository: Arc<dyn TokenRevocationRepositoryInterface>,
+    audit_log_repository: Arc<dyn AuditLogRepositoryInterface>,
+    mfa_service: Arc<MfaService>,
+    session_token_service: Arc<SessionTokenService>,
+    login_throttle_service: Arc<LoginThrottleService>,
+}
+
+impl MfaUseCase {
+    pub fn new(
+        query_repository: Arc<dyn UserQueryRepositoryInterface + Send + Sync>,
+        command_repository: Arc<dyn UserCommandRepositoryInterface + Send + Sync>,
+        token_revocation_repository: Arc<dyn TokenRevocationRepositoryInterface>,
+        audit_log_repository: Arc<dyn AuditLogRepositoryInterface>,
+        mfa_service: Arc<MfaService>,
+        session_token_service: Arc<SessionTokenService>,
+        login_throttle_service: Arc<LoginThrottleService>,
+    ) -> Self {
+        Self {
+            query_repository,
+            command_repository,
+            token_revocation_repository,
+            audit_log_repository,
+            mfa_service,
+            session_token_service,
+            login_throttle_service,
+        }
+    }
+
+    fn infrastructure_error(
+        resource: &str,
+        e: Box<dyn std::error::Error + Send + Sync>,
+    ) -> ApplicationError {
+        ApplicationError::Infrastructure(InfrastructureError::ResourceUnavailable {
+            resource: resource.to_string(),
+            message: format!("{}", e),
+        })
+    }
+
+    async fn find_user(&self, caller: &MfaCallerDto) -> ApplicationResult<User> {
+        self.query_repository
+            .find_by_id(&UserId::new(caller.user_id.clone()))
+            .await
+            .map_err(|e| Self::infrastructure_error("user", e))?
+            .ok_or(ApplicationError::InvalidToken)
+    }
+
+    /// ログイン途中のコード照合失敗を記録する（ロック判定はログインと共通）
+    async fn record_pending_failure(
+        &self,
+        user: &User,
+        caller: &MfaCallerDto,
+    ) -> ApplicationResult<()> {
+        if caller.pending {
+            self.login_throttle_service
+                .record_failure(&user.email().0, caller.client_ip.as_deref())
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// `mfa_pending`トークンを失効させてセッションを開始する
+    async fn complete_login(
+        &self,
+        user: &User,
+        caller: &MfaCallerDto,
+    ) -> ApplicationResult<TokenPairDto> {
+        let expires_at = Utc
+            .timestamp_opt(caller.expires_at, 0)
+            .single()
+            .unwrap_or_else(Utc::now);
+        self.token_revocation_repository
+            .revoke(&caller.jti, user.id(), expires_at)
+            .await
+            .map_err(|e| Self::infrastructure_error("token_revocation", e))?;
+        self.login_throttle_service
+            .record_success(&user.email().0)
+            .await?;
+        self.command_repository
+            .update_last_login(user.id(), Utc::now())
+            .await
+            .map_err(|e| Self::infrastructure_error("user", e))?;
+        self.session_token_service.start_session(user).await
+    }
+
+    async fn audit(&self, action: &str, user: &User, detail: Option<String>) {
+        let event = AuditEvent::new(
+            action,
+            Some(user.id().0.clone()),
+            format!("user:{}", user.id().0),
+            detail,
+        );
+        if let Err(e) = self.audit_log_repository.record(&event).await {
+            println!("MfaUseCase: Failed to write audit log: {}", e);
+        }
+    }
+}
+
+#[async_trait]
+impl MfaUsecaseInterface for MfaUseCase {
+    async fn enroll(&self, caller: MfaCallerDto) -> ApplicationResult<MfaEnrollmentDto> {
+        let user = self.find_user(&caller).await?;
+        self.mfa_service.begin_enrollment(&user).await
+    }
+
+    async fn confirm_enrollment(
+        &self,
+        caller: MfaCallerDto,
+        code: String,
+    ) -> ApplicationResult<MfaConfirmResultDto> {
+        let user = self.find_user(&caller).await?;
+        if caller.pending {
+            self.login_throttle_service
+                .check(&user.email().0, caller.client_ip.as_deref())
+                .await?;
+        }
+        let recovery_codes = match self.mfa_service.confirm_enrollment(user.id(), &code).await {
+            Err(ApplicationError::InvalidCredentials) => {
+                self.record_pending_failure(&user, &caller).await?;
+                return Err(ApplicationError::InvalidCredentials);
+            }
+            other => other?,
+        };
+        self.audit("mfa.enabled", &user, None).await;
+
+        let session = if caller.pending {
+            Some(self.complete_login(&user, &caller).await?)
+        } else {
+            None
+        };
+        Ok(MfaConfirmResultDto {
+            recovery_codes,
+            session,
+        })
+    }
+
+    async fn verify_login(
+        &self,
+        caller: MfaCallerDto,
+        code: String,
+    ) -> ApplicationResult<TokenPairDto> {
+        if !caller.pending {
+            return Err(ApplicationError::InvalidToken);
+        }
+        let user = self.find_user(&caller).await?;
+        self.login_throttle_service
+            .check(&user.email().0, caller.client_ip.as_deref())
+            .await?;
+        match self.mfa_service.verify(user.id(), &code).await? {
+            MfaVerification::Invalid => {
+                self.record_pending_failure(&user, &caller).await?;
+                return Err(ApplicationError::InvalidCredentials);
+            }
+            MfaVerification::RecoveryCode { remaining } => {
+                self.audit(
+                    "mfa.recovery_code_used",
+                    &user,
+                    Some(format!("remaining={}", remaining)),
+                )
+                .await;
+            }
+            MfaVerification::Totp => {}
+        }
+        self.complete_login(&user, &caller).await
+    }
+
+    async fn disable(&self, caller: MfaCallerDto, code: String) -> ApplicationResult<()> {
+        if caller.pending {
+            return Err(ApplicationError::InvalidToken);
+        }
+        let user = self.find_user(&caller).await?;
+        self.mfa_service.disable(&user, &code).await?;
+        self.audit("mfa.disabled", &user, None).await;
+        Ok(())
+    }
+
+    async fn regenerate_recovery_codes(
+        &self,
+        caller: MfaCallerDto,
+        code: String,
+    ) -> ApplicationResult<Vec<String>> {
+        if caller.pending {
+            return Err(ApplicationError::InvalidToken);
+        }
+        let user = self.find_user(&caller).await?;
+        let codes = self
+            .mfa_service
+            .regenerate_recovery_codes(user.id(), &code)
+            .await?;
+        self.audit("mfa.recovery_codes_regenerated", &user, None)
+            .await;
+        Ok(codes)
+    }
+}
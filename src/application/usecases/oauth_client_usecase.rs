@@ -0,0 +1,290 @@
+//application/usecases/oauth_client_usecase.rs
+// OAuthクライアント管理ユースケース（登録・一覧・削除）
+// 2025/7/8
+
+use crate::application::dto::auth_dto::ActorDto;
+use crate::application::dto::oauth_dto::{
+    OAuthClientDto, RegisterOAuthClientDto, RegisteredOAuthClientDto,
+};
+use crate::domain::entity::audit_event::AuditEvent;
+use crate::domain::entity::oauth_client::OAuthClient;
+use crate::domain::repository::audit_log_repository::AuditLogRepositoryInterface;
+use crate::domain::repository::oauth_client_repository::OAuthClientRepositoryInterface;
+use crate::domain::repository::user_query_repository::UserQueryRepositoryInterface;
+use crate::domain::value_object::{
+    oauth_grant_type::OAuthGrantType, permission::Permission, user_id::UserId,
+};
+use crate::shared::error::application_error::{ApplicationError, ApplicationResult};
+use crate::shared::error::infrastructure_error::InfrastructureError;
+use crate::shared::utils::secure_token::{generate_token, hash_token};
+use async_trait::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+
+/// クライアント名の最大文字数
+const MAX_NAME_LENGTH: usize = 100;
+
+/// クライアントシークレットの接頭辞
+pub const CLIENT_SECRET_PREFIX: &str = "rcs_";
+
+#[async_trait]
+pub trait OAuthClientUsecaseInterface: Send + Sync {
+    /// クライアントを登録する（シークレットは戻り値でのみ返す）
+    async fn register(
+        &self,
+        actor: ActorDto,
+        request: RegisterOAuthClientDto,
+    ) -> ApplicationResult<RegisteredOAuthClientDto>;
+    /// 登録済みクライアントの一覧
+    async fn list(&self) -> ApplicationResult<Vec<OAuthClientDto>>;
+    /// クライアントを削除する（発行済みのアクセストークンは期限まで有効）
+    async fn delete(&self, actor: ActorDto, client_id: String) -> ApplicationResult<()>;
+}
+
+/// OAuthクライアント管理ユースケース
+///
+/// 責務:
+/// 1. 登録内容の検証（グラント種別・リダイレクトURI・スコープ・サービスアカウント）
+/// 2. クライアントID・シークレットの発行（シークレットはハッシュのみ保存）
+/// 3. 登録・削除の監査ログ記録
+///
+/// 権限（`oauth_clients:manage`）の確認はルーターの`RequirePermission`で行う。
+pub struct OAuthClientUseCase {
+    oauth_client_repository: Arc<dyn OAuthClientRepositoryInterface>,
+    query_repository: Arc<dyn UserQueryRepositoryInterface + Send + Sync>,
+    audit_log_repository: Arc<dyn AuditLogRepositoryInterface>,
+}
+
+impl OAuthClientUseCase {
+    pub fn new(
+        oauth_client_repository: Arc<dyn OAuthClientRepositoryInterface>,
+        query_repository: Arc<dyn UserQueryRepositoryInterface + Send + Sync>,
+        audit_log_repository: Arc<dyn AuditLogRepositoryInterface>,
+    ) -> Self {
+        Self {
+            oauth_client_repository,
+            query_repository,
+            audit_log_repository,
+        }
+    }
+
+    fn infrastructure_error(
+        resource: &str,
+        e: Box<dyn std::error::Error + Send + Sync>,
+    ) -> ApplicationError {
+        ApplicationError::Infrastructure(InfrastructureError::ResourceUnavailable {
+            resource: resource.to_string(),
+            message: format!("{}", e),
+        })
+    }
+
+    fn validation_error(field: &str, message: impl Into<String>) -> ApplicationError {
+        ApplicationError::ValidationFailed {
+            field: field.to_string(),
+            message: message.into(),
+        }
+    }
+
+    fn validate_name(name: &str) -> ApplicationResult<String> {
+        let name = name.trim();
+        if name.is_empty() || name.chars().count() > MAX_NAME_LENGTH {
+            return Err(Self::validation_error(
+                "name",
+                format!("must be 1 to {} characters", MAX_NAME_LENGTH),
+            ));
+        }
+        Ok(name.to_string())
+    }
+
+    fn parse_grant_types(
+        grant_types: &[String],
+        confidential: bool,
+    ) -> ApplicationResult<Vec<OAuthGrantType>> {
+        if grant_types.is_empty() {
+            return Err(Self::validation_error(
+                "grant_types",
+                "at least one grant type is required",
+            ));
+        }
+        let mut parsed = Vec::new();
+        for grant_type in grant_types {
+            let grant_type = OAuthGrantType::new(grant_type).map_err(|_| {
+                Self::validation_error(
+                    "grant_types",
+                    format!("unknown grant type '{}'", grant_type),
+                )
+            })?;
+            if !parsed.contains(&grant_type) {
+                parsed.push(grant_type);
+            }
+        }
+        // 公開クライアントはシークレットを持たないため、クライアント自身としての認証はできない
+        if !confidential && parsed.contains(&OAuthGrantType::ClientCredentials) {
+            return Err(Self::validation_error(
+                "grant_types",
+                "client_credentials requires a confidential client",
+            ));
+        }
+        parsed.sort();
+        Ok(parsed)
+    }
+
+    /// リダイレクトURIは絶対URL（http/https、フラグメントなし）で、完全一致で照合する
+    fn validate_redirect_uris(
+        redirect_uris: &[String],
+        grant_types: &[OAuthGrantType],
+    ) -> ApplicationResult<Vec<String>> {
+        if grant_types.contains(&OAuthGrantType::AuthorizationCode) && redirect_uris.is_empty() {
+            return Err(Self::validation_error(
+                "redirect_uris",
+                "at least one redirect URI is required for authorization_code",
+            ));
+        }
+        for uri in redirect_uris {
+            let valid = (uri.starts_with("https://") || uri.starts_with("http://"))
+                && !uri.contains('#')
+                && !uri.chars().any(char::is_whitespace);
+            if !valid {
+                return Err(Self::validation_error(
+                    "redirect_uris",
+                    format!(
+                        "'{}' must be an absolute http(s) URI without a fragment",
+                        uri
+                    ),
+                ));
+            }
+        }
+        Ok(redirect_uris.to_vec())
+    }
+
+    fn parse_scopes(scopes: &[String]) -> ApplicationResult<Vec<Permission>> {
+        if scopes.is_empty() {
+            return Err(Self::validation_error(
+                "scopes",
+                "at least one scope is required",
+            ));
+        }
+        let mut permissions = Vec::new();
+        for scope in scopes {
+            let permission = Permission::new(scope).map_err(|_| {
+                Self::validation_error("scopes", format!("unknown scope '{}'", scope))
+            })?;
+            if !permissions.contains(&permission) {
+                permissions.push(permission);
+            }
+        }
+        permissions.sort();
+        Ok(permissions)
+    }
+
+    /// client_credentialsグラントにはトークンの主体となるサービスアカウントが必要
+    async fn validate_service_account(
+        &self,
+        service_account_id: Option<String>,
+        grant_types: &[OAuthGrantType],
+    ) -> ApplicationResult<Option<UserId>> {
+        let Some(service_account_id) = service_account_id else {
+            if grant_types.contains(&OAuthGrantType::ClientCredentials) {
+                return Err(Self::validation_error(
+                    "service_account_id",
+                    "required for client_credentials",
+                ));
+            }
+            return Ok(None);
+        };
+        let user_id = UserId::new(service_account_id);
+        self.query_repository
+            .find_by_id(&user_id)
+            .await
+            .map_err(|e| Self::infrastructure_error("user", e))?
+            .ok_or_else(|| ApplicationError::UserNotFound {
+                id: user_id.0.clone(),
+            })?;
+        Ok(Some(user_id))
+    }
+
+    async fn audit(&self, action: &str, actor: &ActorDto, client: &OAuthClient) {
+        let event = AuditEvent::new(
+            action,
+            Some(actor.user_id.clone()),
+            format!("oauth_client:{}", client.client_id),
+            Some(format!("name={}", client.name)),
+        );
+        if let Err(e) = self.audit_log_repository.record(&event).await {
+            println!("OAuthClientUseCase: Failed to write audit log: {}", e);
+        }
+    }
+}
+
+#[async_trait]
+impl OAuthClientUsecaseInterface for OAuthClientUseCase {
+    async fn register(
+        &self,
+        actor: ActorDto,
+        request: RegisterOAuthClientDto,
+    ) -> ApplicationResult<RegisteredOAuthClientDto> {
+        let name = Self::validate_name(&request.name)?;
+        let grant_types = Self::parse_grant_types(&request.grant_types, request.confidential)?;
+        let redirect_uris = Self::validate_redirect_uris(&request.redirect_uris, &grant_types)?;
+        let scopes = Self::parse_scopes(&request.scopes)?;
+        let service_account_id = self
+            .validate_service_account(request.service_account_id, &grant_types)
+            .await?;
+
+        let client_secret = request
+            .confidential
+            .then(|| format!("{}{}", CLIENT_SECRET_PREFIX, generate_token()));
+        let client = OAuthClient {
+            client_id: uuid::Uuid::new_v4().to_string(),
+            client_secret_hash: client_secret.as_deref().map(hash_token),
+            name,
+            redirect_uris,
+            grant_types,
+            scopes,
+            service_account_id,
+            created_at: Utc::now(),
+            created_by: UserId::new(actor.user_id.clone()),
+        };
+        self.oauth_client_repository
+            .save(&client)
+            .await
+            .map_err(|e| Self::infrastructure_error("oauth_client", e))?;
+        self.audit("oauth_client.registered", &actor, &client).await;
+        Ok(RegisteredOAuthClientDto {
+            client_secret,
+            client: client.into(),
+        })
+    }
+
+    async fn list(&self) -> ApplicationResult<Vec<OAuthClientDto>> {
+        let clients = self
+            .oauth_client_repository
+            .find_all()
+            .await
+            .map_err(|e| Self::infrastructure_error("oauth_client", e))?;
+        Ok(clients.into_iter().map(OAuthClientDto::from).collect())
+    }
+
+    async fn delete(&self, actor: ActorDto, client_id: String) -> ApplicationResult<()> {
+        let not_found = || ApplicationError::ResourceNotFound {
+            resource: "OAuth client".to_string(),
+            id: client_id.clone(),
+        };
+        let client = self
+            .oauth_client_repository
+            .find_by_id(&client_id)
+            .await
+            .map_err(|e| Self::infrastructure_error("oauth_client", e))?
+            .ok_or_else(not_found)?;
+        let deleted = self
+            .oauth_client_repository
+            .delete(&client.client_id)
+            .await
+            .map_err(|e| Self::infrastructure_error("oauth_client", e))?;
+        if !deleted {
+            return Err(not_found());
+        }
+        self.audit("oauth_client.deleted", &actor, &client).await;
+        Ok(())
+    }
+}
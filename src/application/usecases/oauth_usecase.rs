@@ -0,0 +1,579 @@
+//application/usecases/oauth_usecase.rs
+// OAuth認可サーバーユースケース（認可・トークン発行・イントロスペクション・失効）
+// 2025/7/8
+
+use crate::application::dto::auth_dto::{ActorDto, RefreshTokenRequestDto};
+use crate::application::dto::oauth_dto::{
+    AuthorizationCodeDto, AuthorizeRequestDto, ClientAuthDto, OAuthTokenDto, TokenIntrospectionDto,
+    TokenRequestDto,
+};
+use crate::application::services::session_token_service::SessionTokenService;
+use crate::application::usecases::refresh_token_usecase::RefreshTokenUsecaseInterface;
+use crate::domain::entity::oauth_authorization_code::OAuthAuthorizationCode;
+use crate::domain::entity::oauth_client::OAuthClient;
+use crate::domain::entity::user::User;
+use crate::domain::repository::oauth_authorization_code_repository::OAuthAuthorizationCodeRepositoryInterface;
+use crate::domain::repository::oauth_client_repository::OAuthClientRepositoryInterface;
+use crate::domain::repository::refresh_token_repository::RefreshTokenRepositoryInterface;
+use crate::domain::repository::token_revocation_repository::TokenRevocationRepositoryInterface;
+use crate::domain::repository::user_query_repository::UserQueryRepositoryInterface;
+use crate::domain::service::permission_policy::PermissionPolicy;
+use crate::domain::value_object::{
+    oauth_grant_type::OAuthGrantType, oidc_scope::OidcScope, permission::Permission, role::Role,
+    user_id::UserId,
+};
+use crate::shared::error::application_error::{ApplicationError, ApplicationResult};
+use crate::shared::error::infrastructure_error::InfrastructureError;
+use crate::shared::middleware::auth_middleware::{
+    ClientGrant, JwtClaims, SessionValidatorInterface, TokenType,
+};
+use crate::shared::utils::pkce::{self, CODE_CHALLENGE_METHOD_S256};
+use crate::shared::utils::secure_token::{generate_token, hash_token};
+use async_trait::async_trait;
+use chrono::{TimeZone, Utc};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// `nonce`の最大長（認可コードと一緒に保存するため制限する）
+const MAX_NONCE_LENGTH: usize = 255;
+
+#[async_trait]
+pub trait OAuthUsecaseInterface: Send + Sync {
+    /// ログイン中のユーザーとしてクライアントに認可コードを発行する
+    async fn authorize(
+        &self,
+        actor: ActorDto,
+        request: AuthorizeRequestDto,
+    ) -> ApplicationResult<AuthorizationCodeDto>;
+    /// グラントに応じてトークンを発行する
+    async fn token(
+        &self,
+        client: ClientAuthDto,
+        request: TokenRequestDto,
+    ) -> ApplicationResult<OAuthTokenDto>;
+    /// トークンの有効性と内容を返す（RFC 7662）
+    async fn introspect(
+        &self,
+        client: ClientAuthDto,
+        token: String,
+    ) -> ApplicationResult<TokenIntrospectionDto>;
+    /// クライアントに発行したトークンを失効させる（RFC 7009）
+    async fn revoke(&self, client: ClientAuthDto, token: String) -> ApplicationResult<()>;
+}
+
+/// OAuth認可サーバーユースケース
+///
+/// 責務:
+/// 1. クライアント認証（機密クライアントはシークレット、公開クライアントはPKCE必須）
+/// 2. 認可コードの発行と交換（S256のPKCE、一度だけ交換可能）
+/// 3. client_credentials・refresh_tokenグラントのトークン発行
+/// 4. トークンのイントロスペクションと失効
+/// 5. `openid`スコープを含む認可コードの交換時のIDトークンの発行
+///
+/// トークンは既存の`JwtClaims`に`client_id`・`scope`を付けて発行し、
+/// スコープはクライアントの登録スコープとユーザーのロールの権限の共通部分に限る
+/// （認可コードグラントではOpenID Connectのスコープも要求できる）。
+/// `authorize`のクライアント・リダイレクトURIの誤りは`InvalidInput`、
+/// それ以外のエラーは`OAuthProtocol`（RFC 6749のエラーコード）で返す。
+pub struct OAuthUseCase {
+    oauth_client_repository: Arc<dyn OAuthClientRepositoryInterface>,
+    authorization_code_repository: Arc<dyn OAuthAuthorizationCodeRepositoryInterface>,
+    query_repository: Arc<dyn UserQueryRepositoryInterface + Send + Sync>,
+    refresh_token_repository: Arc<dyn RefreshTokenRepositoryInterface>,
+    token_revocation_repository: Arc<dyn TokenRevocationRepositoryInterface>,
+    session_validator: Arc<dyn SessionValidatorInterface>,
+    session_token_service: Arc<SessionTokenService>,
+    refresh_token_usecase: Arc<dyn RefreshTokenUsecaseInterface>,
+    permission_policy: Arc<PermissionPolicy>,
+    authorization_code_ttl: Duration,
+    issuer: String,
+}
+
+impl OAuthUseCase {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        oauth_client_repository: Arc<dyn OAuthClientRepositoryInterface>,
+        authorization_code_repository: Arc<dyn OAuthAuthorizationCodeRepositoryInterface>,
+        query_repository: Arc<dyn UserQueryRepositoryInterface + Send + Sync>,
+        refresh_token_repository: Arc<dyn RefreshTokenRepositoryInterface>,
+        token_revocation_repository: Arc<dyn TokenRevocationRepositoryInterface>,
+        session_validator: Arc<dyn SessionValidatorInterface>,
+        session_token_service: Arc<SessionTokenService>,
+        refresh_token_usecase: Arc<dyn RefreshTokenUsecaseInterface>,
+        permission_policy: Arc<PermissionPolicy>,
+        authorization_code_ttl: Duration,
+        issuer: String,
+    ) -> Self {
+        Self {
+            oauth_client_repository,
+            authorization_code_repository,
+            query_repository,
+            refresh_token_repository,
+            token_revocation_repository,
+            session_validator,
+            session_token_service,
+            refresh_token_usecase,
+            permission_policy,
+            authorization_code_ttl,
+            issuer,
+        }
+    }
+
+    fn infrastructure_error(
+        resource: &str,
+        e: Box<dyn std::error::Error + Send + Sync>,
+    ) -> ApplicationError {
+        ApplicationError::Infrastructure(InfrastructureError::ResourceUnavailable {
+            resource: resource.to_string(),
+            message: format!("{}", e),
+        })
+    }
+
+    fn oauth_error(error: &str, description: impl Into<String>) -> ApplicationError {
+        ApplicationError::OAuthProtocol {
+            error: error.to_string(),
+            description: description.into(),
+        }
+    }
+
+    async fn find_client(&self, client_id: &str) -> ApplicationResult<Option<OAuthClient>> {
+        self.oauth_client_repository
+            .find_by_id(client_id)
+            .await
+            .map_err(|e| Self::infrastructure_error("oauth_client", e))
+    }
+
+    async fn find_user(&self, user_id: &UserId) -> ApplicationResult<Option<User>> {
+        self.query_repository
+            .find_by_id(user_id)
+            .await
+            .map_err(|e| Self::infrastructure_error("user", e))
+    }
+
+    /// クライアント認証（未登録・シークレット不一致は区別せず`invalid_client`）
+    async fn authenticate_client(&self, auth: &ClientAuthDto) -> ApplicationResult<OAuthClient> {
+        let invalid_client = || Self::oauth_error("invalid_client", "client authentication failed");
+        let client = self
+            .find_client(&auth.client_id)
+            .await?
+            .ok_or_else(invalid_client)?;
+        let authenticated = match (&client.client_secret_hash, &auth.client_secret) {
+            (Some(secret_hash), Some(secret)) => hash_token(secret) == *secret_hash,
+            (None, None) => true,
+            _ => false,
+        };
+        if !authenticated {
+            return Err(invalid_client());
+        }
+        Ok(client)
+    }
+
+    fn require_grant(client: &OAuthClient, grant_type: OAuthGrantType) -> ApplicationResult<()> {
+        if !client.allows_grant(grant_type) {
+            return Err(Self::oauth_error(
+                "unauthorized_client",
+                format!("client is not allowed to use {}", grant_type),
+            ));
+        }
+        Ok(())
+    }
+
+    /// クライアントの登録スコープとロールの権限の共通部分
+    fn grantable_scopes(&self, client: &OAuthClient, role: Role) -> Vec<Permission> {
+        client
+            .scopes
+            .iter()
+            .copied()
+            .filter(|permission| self.permission_policy.allows(role, *permission))
+            .collect()
+    }
+
+    /// 要求スコープを解釈する（省略時は付与できる権限すべて、範囲外を含む場合は`invalid_scope`）
+    ///
+    /// `allow_oidc`の場合はOpenID Connectのスコープ（`openid`など）も受け付ける。
+    fn resolve_scope(
+        requested: Option<&str>,
+        grantable: &[Permission],
+        allow_oidc: bool,
+    ) -> ApplicationResult<String> {
+        let invalid_scope = |message: String| Self::oauth_error("invalid_scope", message);
+        let scopes: Vec<&'static str> = match requested.filter(|s| !s.trim().is_empty()) {
+            None => grantable
+                .iter()
+                .map(|permission| permission.as_str())
+                .collect(),
+            Some(requested) => {
+                let mut scopes = Vec::new();
+                for scope in requested.split_whitespace() {
+                    let resolved = Permission::new(scope)
+                        .ok()
+                        .filter(|permission| grantable.contains(permission))
+                        .map(|permission| permission.as_str())
+                        .or_else(|| {
+                            OidcScope::new(scope)
+                                .ok()
+                                .filter(|_| allow_oidc)
+                                .map(|oidc_scope| oidc_scope.as_str())
+                        })
+                        .ok_or_else(|| {
+                            invalid_scope(format!("scope '{}' is not allowed", scope))
+                        })?;
+                    if !scopes.contains(&resolved) {
+                        scopes.push(resolved);
+                    }
+                }
+                scopes
+            }
+        };
+        if scopes.is_empty() {
+            return Err(invalid_scope("no grantable scope".to_string()));
+        }
+        Ok(scopes.join(" "))
+    }
+
+    async fn authorization_code_grant(
+        &self,
+        client: &OAuthClient,
+        request: TokenRequestDto,
+    ) -> ApplicationResult<OAuthTokenDto> {
+        Self::require_grant(client, OAuthGrantType::AuthorizationCode)?;
+        let missing =
+            |field: &str| Self::oauth_error("invalid_request", format!("{} is required", field));
+        let code = request.code.ok_or_else(|| missing("code"))?;
+        let redirect_uri = request
+            .redirect_uri
+            .ok_or_else(|| missing("redirect_uri"))?;
+        let code_verifier = request
+            .code_verifier
+            .ok_or_else(|| missing("code_verifier"))?;
+
+        // 1. コードの消費（再提示は失敗する）
+        let invalid_grant = |message: &str| Self::oauth_error("invalid_grant", message);
+        let now = Utc::now();
+        let authorization_code = self
+            .authorization_code_repository
+            .consume(&hash_token(&code), now)
+            .await
+            .map_err(|e| Self::infrastructure_error("oauth_authorization_code", e))?
+            .ok_or_else(|| invalid_grant("invalid or already used authorization code"))?;
+
+        // 2. 発行時のクライアント・リダイレクトURI・PKCEとの照合
+        if authorization_code.is_expired(now)
+            || authorization_code.client_id != client.client_id
+            || authorization_code.redirect_uri != redirect_uri
+        {
+            return Err(invalid_grant("invalid or expired authorization code"));
+        }
+        if !pkce::verify_s256(&code_verifier, &authorization_code.code_challenge) {
+            return Err(invalid_grant(
+                "code_verifier does not match the code_challenge",
+            ));
+        }
+
+        // 3. 現在のロールで付与できる範囲に絞ってセッションを開始
+        let user = self
+            .find_user(&authorization_code.user_id)
+            .await?
+            .ok_or_else(|| invalid_grant("resource owner no longer exists"))?;
+        let grantable = self.grantable_scopes(client, user.role());
+        let scope = Self::resolve_scope(Some(&authorization_code.scope), &grantable, true)
+            .map_err(|_| invalid_grant("granted scope is no longer available"))?;
+        let grant = ClientGrant {
+            client_id: client.client_id.clone(),
+            scope: scope.clone(),
+        };
+        let tokens = self
+            .session_token_service
+            .start_client_session(&user, &grant)
+            .await?;
+        let id_token = if OidcScope::OpenId.is_in(&scope) {
+            Some(self.session_token_service.issue_id_token(
+                &user,
+                &tokens.access_token,
+                &self.issuer,
+                authorization_code.nonce,
+            )?)
+        } else {
+            None
+        };
+        Ok(OAuthTokenDto {
+            access_token: tokens.access_token,
+            token_type: tokens.token_type,
+            expires_in: tokens.expires_in,
+            refresh_token: client
+                .allows_grant(OAuthGrantType::RefreshToken)
+                .then_some(tokens.refresh_token),
+            scope,
+            id_token,
+        })
+    }
+
+    async fn client_credentials_grant(
+        &self,
+        client: &OAuthClient,
+        request: TokenRequestDto,
+    ) -> ApplicationResult<OAuthTokenDto> {
+        Self::require_grant(client, OAuthGrantType::ClientCredentials)?;
+        if !client.is_confidential() {
+            return Err(Self::oauth_error(
+                "unauthorized_client",
+                "client_credentials requires a confidential client",
+            ));
+        }
+        let service_account = match &client.service_account_id {
+            Some(user_id) => self.find_user(user_id).await?,
+            None => None,
+        }
+        .ok_or_else(|| Self::oauth_error("unauthorized_client", "client has no service account"))?;
+        let grantable = self.grantable_scopes(client, service_account.role());
+        let scope = Self::resolve_scope(request.scope.as_deref(), &grantable, false)?;
+        let grant = ClientGrant {
+            client_id: client.client_id.clone(),
+            scope: scope.clone(),
+        };
+        let (access_token, expires_in) = self
+            .session_token_service
+            .issue_client_access_token(&service_account, &grant)?;
+        Ok(OAuthTokenDto {
+            access_token,
+            token_type: "Bearer".to_string(),
+            expires_in,
+            refresh_token: None,
+            scope,
+            id_token: None,
+        })
+    }
+
+    /// 委任内容は元のトークンを引き継ぐ（`scope`の指定は無視する）
+    async fn refresh_token_grant(
+        &self,
+        client: &OAuthClient,
+        request: TokenRequestDto,
+    ) -> ApplicationResult<OAuthTokenDto> {
+        Self::require_grant(client, OAuthGrantType::RefreshToken)?;
+        let refresh_token = request
+            .refresh_token
+            .ok_or_else(|| Self::oauth_error("invalid_request", "refresh_token is required"))?;
+        let tokens = self
+            .refresh_token_usecase
+            .execute(RefreshTokenRequestDto {
+                refresh_token,
+                client_id: Some(client.client_id.clone()),
+            })
+            .await
+            .map_err(|e| match e {
+                ApplicationError::InvalidToken => {
+                    Self::oauth_error("invalid_grant", "invalid or revoked refresh token")
+                }
+                other => other,
+            })?;
+        let scope = JwtClaims::from_token(&tokens.access_token)
+            .ok()
+            .and_then(|claims| claims.scope)
+            .unwrap_or_default();
+        Ok(OAuthTokenDto {
+            access_token: tokens.access_token,
+            token_type: tokens.token_type,
+            expires_in: tokens.expires_in,
+            refresh_token: Some(tokens.refresh_token),
+            scope,
+            id_token: None,
+        })
+    }
+
+    /// 署名・期限・失効を確認したクレーム（無効な場合はNone）
+    async fn active_claims(&self, token: &str) -> ApplicationResult<Option<JwtClaims>> {
+        let Ok(claims) = JwtClaims::from_token(token) else {
+            return Ok(None);
+        };
+        if claims.is_expired() {
+            return Ok(None);
+        }
+        let active = match claims.token_type {
+            TokenType::Access => self
+                .session_validator
+                .is_active(&claims)
+                .await
+                .map_err(|e| {
+                    ApplicationError::Infrastructure(InfrastructureError::ResourceUnavailable {
+                        resource: "token_revocation".to_string(),
+                        message: format!("{}", e),
+                    })
+                })?,
+            TokenType::Refresh => self
+                .refresh_token_repository
+                .is_token_active(&claims.jti, Utc::now())
+                .await
+                .map_err(|e| Self::infrastructure_error("refresh_token", e))?,
+            TokenType::MfaPending | TokenType::ApiKey | TokenType::ClientCert => false,
+        };
+        Ok(active.then_some(claims))
+    }
+}
+
+#[async_trait]
+impl OAuthUsecaseInterface for OAuthUseCase {
+    async fn authorize(
+        &self,
+        actor: ActorDto,
+        request: AuthorizeRequestDto,
+    ) -> ApplicationResult<AuthorizationCodeDto> {
+        // 1. クライアントとリダイレクトURI（不正な場合はリダイレクトしない）
+        let client = self.find_client(&request.client_id).await?.ok_or_else(|| {
+            ApplicationError::InvalidInput {
+                input: "client_id".to_string(),
+                reason: "unknown client".to_string(),
+            }
+        })?;
+        if !client.allows_redirect_uri(&request.redirect_uri) {
+            return Err(ApplicationError::InvalidInput {
+                input: "redirect_uri".to_string(),
+                reason: "not registered for this client".to_string(),
+            });
+        }
+
+        // 2. リクエスト内容（以降のエラーはリダイレクト先に返す）
+        if request.response_type != "code" {
+            return Err(Self::oauth_error(
+                "unsupported_response_type",
+                "only response_type=code is supported",
+            ));
+        }
+        Self::require_grant(&client, OAuthGrantType::AuthorizationCode)?;
+        let code_challenge = request
+            .code_challenge
+            .filter(|challenge| pkce::is_valid_challenge(challenge))
+            .ok_or_else(|| {
+                Self::oauth_error("invalid_request", "a valid S256 code_challenge is required")
+            })?;
+        if request.code_challenge_method.as_deref() != Some(CODE_CHALLENGE_METHOD_S256) {
+            return Err(Self::oauth_error(
+                "invalid_request",
+                "code_challenge_method must be S256",
+            ));
+        }
+        if request
+            .nonce
+            .as_ref()
+            .is_some_and(|nonce| nonce.len() > MAX_NONCE_LENGTH)
+        {
+            return Err(Self::oauth_error(
+                "invalid_request",
+                format!("nonce must be at most {} bytes", MAX_NONCE_LENGTH),
+            ));
+        }
+        let role = Role::new(&actor.role)
+            .map_err(|_| Self::oauth_error("access_denied", "unknown role"))?;
+        let grantable = self.grantable_scopes(&client, role);
+        let scope = Self::resolve_scope(request.scope.as_deref(), &grantable, true)?;
+
+        // 3. 認可コードの発行（ハッシュのみ保存）
+        let code = generate_token();
+        let authorization_code = OAuthAuthorizationCode {
+            code_hash: hash_token(&code),
+            client_id: client.client_id.clone(),
+            user_id: UserId::new(actor.user_id),
+            redirect_uri: request.redirect_uri.clone(),
+            scope,
+            code_challenge,
+            nonce: request.nonce,
+            expires_at: Utc::now()
+                + chrono::Duration::seconds(self.authorization_code_ttl.as_secs() as i64),
+        };
+        self.authorization_code_repository
+            .save(&authorization_code)
+            .await
+            .map_err(|e| Self::infrastructure_error("oauth_authorization_code", e))?;
+        Ok(AuthorizationCodeDto {
+            code,
+            redirect_uri: request.redirect_uri,
+        })
+    }
+
+    async fn token(
+        &self,
+        client: ClientAuthDto,
+        request: TokenRequestDto,
+    ) -> ApplicationResult<OAuthTokenDto> {
+        let client = self.authenticate_client(&client).await?;
+        let grant_type = OAuthGrantType::new(&request.grant_type).map_err(|_| {
+            Self::oauth_error(
+                "unsupported_grant_type",
+                format!("unsupported grant_type '{}'", request.grant_type),
+            )
+        })?;
+        match grant_type {
+            OAuthGrantType::AuthorizationCode => {
+                self.authorization_code_grant(&client, request).await
+            }
+            OAuthGrantType::ClientCredentials => {
+                self.client_credentials_grant(&client, request).await
+            }
+            OAuthGrantType::RefreshToken => self.refresh_token_grant(&client, request).await,
+        }
+    }
+
+    async fn introspect(
+        &self,
+        client: ClientAuthDto,
+        token: String,
+    ) -> ApplicationResult<TokenIntrospectionDto> {
+        // リソースサーバーとして使うため、機密クライアントに限る
+        let client = self.authenticate_client(&client).await?;
+        if !client.is_confidential() {
+            return Err(Self::oauth_error(
+                "unauthorized_client",
+                "introspection requires a confidential client",
+            ));
+        }
+        let Some(claims) = self.active_claims(&token).await? else {
+            return Ok(TokenIntrospectionDto::default());
+        };
+        Ok(TokenIntrospectionDto {
+            active: true,
+            scope: claims.scope,
+            client_id: claims.client_id,
+            username: Some(claims.email),
+            token_type: Some(match claims.token_type {
+                TokenType::Refresh => "refresh_token".to_string(),
+                _ => "Bearer".to_string(),
+            }),
+            exp: Some(claims.exp),
+            iat: Some(claims.iat),
+            sub: Some(claims.sub),
+            jti: Some(claims.jti),
+        })
+    }
+
+    async fn revoke(&self, client: ClientAuthDto, token: String) -> ApplicationResult<()> {
+        let client = self.authenticate_client(&client).await?;
+        // 無効・失効済みのトークンは成功として扱う（RFC 7009 2.2）
+        let Some(claims) = self.active_claims(&token).await? else {
+            return Ok(());
+        };
+        if claims.client_id.as_deref() != Some(client.client_id.as_str()) {
+            return Err(Self::oauth_error(
+                "unauthorized_client",
+                "token was not issued to this client",
+            ));
+        }
+        match (claims.token_type, &claims.sid) {
+            // リフレッシュトークンはセッション（ファミリー）ごと失効させる
+            (TokenType::Refresh, Some(family_id)) => self
+                .refresh_token_repository
+                .revoke_family(family_id, Utc::now())
+                .await
+                .map_err(|e| Self::infrastructure_error("refresh_token", e)),
+            _ => {
+                let expires_at = Utc
+                    .timestamp_opt(claims.exp, 0)
+                    .single()
+                    .unwrap_or_else(Utc::now);
+                self.token_revocation_repository
+                    .revoke(&claims.jti, &UserId::new(claims.sub), expires_at)
+                    .await
+                    .map_err(|e| Self::infrastructure_error("token_revocation", e))
+            }
+        }
+    }
+}
@@ -0,0 +1,129 @@
+//application/usecases/oidc_usecase.rs
+// OpenID Connectユースケース（UserInfo・ディスカバリー）
+// 2025/7/8
+
+use crate::application::dto::auth_dto::ActorDto;
+use crate::application::dto::oidc_dto::{OpenIdConfigurationDto, UserInfoDto};
+use crate::domain::repository::user_query_repository::UserQueryRepositoryInterface;
+use crate::domain::value_object::{
+    oauth_grant_type::OAuthGrantType, oidc_scope::OidcScope, permission::Permission,
+    user_id::UserId,
+};
+use crate::shared::error::application_error::{ApplicationError, ApplicationResult};
+use crate::shared::error::infrastructure_error::InfrastructureError;
+use crate::shared::middleware::auth_middleware::JWT_CONFIG;
+use crate::shared::utils::pkce::CODE_CHALLENGE_METHOD_S256;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait OidcUsecaseInterface: Send + Sync {
+    /// トークンの主体のクレームを返す（`scope`で許可されたもののみ）
+    async fn userinfo(&self, actor: ActorDto) -> ApplicationResult<UserInfoDto>;
+    /// OpenID Providerのメタデータ
+    fn configuration(&self) -> OpenIdConfigurationDto;
+}
+
+/// OpenID Connectユースケース
+///
+/// 責務:
+/// 1. UserInfo（`User`エンティティから`scope`に応じたクレームを返す）
+/// 2. ディスカバリー用メタデータの組み立て
+///
+/// OAuthクライアント向けのトークンは`openid`スコープが必要。
+/// `scope`のない自前のセッションのトークンはすべてのクレームを返す。
+pub struct OidcUseCase {
+    query_repository: Arc<dyn UserQueryRepositoryInterface + Send + Sync>,
+    issuer: String,
+}
+
+impl OidcUseCase {
+    pub fn new(
+        query_repository: Arc<dyn UserQueryRepositoryInterface + Send + Sync>,
+        issuer: String,
+    ) -> Self {
+        Self {
+            query_repository,
+            issuer,
+        }
+    }
+
+    fn endpoint(&self, path: &str) -> String {
+        format!("{}{}", self.issuer, path)
+    }
+}
+
+#[async_trait]
+impl OidcUsecaseInterface for OidcUseCase {
+    async fn userinfo(&self, actor: ActorDto) -> ApplicationResult<UserInfoDto> {
+        if actor
+            .scope
+            .as_deref()
+            .is_some_and(|scope| !OidcScope::OpenId.is_in(scope))
+        {
+            return Err(ApplicationError::AuthorizationFailed {
+                message: "the access token was not granted the openid scope".to_string(),
+            });
+        }
+        let user_id = UserId::new(actor.user_id);
+        let user = self
+            .query_repository
+            .find_by_id(&user_id)
+            .await
+            .map_err(|e| {
+                ApplicationError::Infrastructure(InfrastructureError::ResourceUnavailable {
+                    resource: "user".to_string(),
+                    message: format!("{}", e),
+                })
+            })?
+            .ok_or_else(|| ApplicationError::UserNotFound {
+                id: user_id.0.clone(),
+            })?;
+        Ok(UserInfoDto::from_user(&user, actor.scope.as_deref()))
+    }
+
+    fn configuration(&self) -> OpenIdConfigurationDto {
+        let strings = |values: &[&str]| values.iter().map(|v| v.to_string()).collect();
+        OpenIdConfigurationDto {
+            issuer: self.issuer.clone(),
+            authorization_endpoint: self.endpoint("/oauth/authorize"),
+            token_endpoint: self.endpoint("/oauth/token"),
+            userinfo_endpoint: self.endpoint("/userinfo"),
+            jwks_uri: self.endpoint("/.well-known/jwks.json"),
+            introspection_endpoint: self.endpoint("/oauth/introspect"),
+            revocation_endpoint: self.endpoint("/oauth/revoke"),
+            scopes_supported: OidcScope::ALL
+                .iter()
+                .map(|scope| scope.as_str())
+                .chain(Permission::ALL.iter().map(|permission| permission.as_str()))
+                .map(str::to_string)
+                .collect(),
+            response_types_supported: strings(&["code"]),
+            grant_types_supported: OAuthGrantType::ALL
+                .iter()
+                .map(|grant_type| grant_type.as_str().to_string())
+                .collect(),
+            subject_types_supported: strings(&["public"]),
+            id_token_signing_alg_values_supported: vec![format!("{:?}", JWT_CONFIG.algorithm)],
+            token_endpoint_auth_methods_supported: strings(&[
+                "client_secret_basic",
+                "client_secret_post",
+                "none",
+            ]),
+            code_challenge_methods_supported: strings(&[CODE_CHALLENGE_METHOD_S256]),
+            claims_supported: strings(&[
+                "iss",
+                "sub",
+                "aud",
+                "iat",
+                "exp",
+                "nonce",
+                "email",
+                "email_verified",
+                "name",
+                "birthdate",
+                "phone_number",
+            ]),
+        }
+    }
+}
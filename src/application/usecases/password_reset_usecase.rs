@@ -0,0 +1,254 @@
+//application/usecases/password_reset_usecase.rs
+// パスワードリセットユースケース
+// 2025/7/8
+
+use crate::application::dto::auth_dto::{PasswordResetConfirmDto, PasswordResetRequestDto};
+use crate::application::services::password_reset_throttle_service::PasswordResetThrottleService;
+use crate::domain::entity::audit_event::AuditEvent;
+use crate::domain::entity::password_reset_token::PasswordResetToken;
+use crate::domain::repository::audit_log_repository::AuditLogRepositoryInterface;
+use crate::domain::repository::password_reset_token_repository::PasswordResetTokenRepositoryInterface;
+use crate::domain::repository::refresh_token_repository::RefreshTokenRepositoryInterface;
+use crate::domain::repository::token_revocation_repository::TokenRevocationRepositoryInterface;
+use crate::domain::repository::user_command_repository::UserCommandRepositoryInterface;
+use crate::domain::repository::user_query_repository::UserQueryRepositoryInterface;
+use crate::domain::service::mailer::{EmailMessage, Mailer};
+use crate::domain::service::password_policy::{PasswordContext, PasswordPolicy};
+use crate::domain::value_object::{email::Email, password::Password};
+use crate::shared::error::application_error::{ApplicationError, ApplicationResult};
+use crate::shared::error::infrastructure_error::InfrastructureError;
+use crate::shared::utils::password_hasher::PasswordHasher;
+use crate::shared::utils::secure_token::{generate_token, hash_token};
+use async_trait::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+use std::time::Duration;
+
+#[async_trait]
+pub trait PasswordResetUsecaseInterface: Send + Sync {
+    /// リセットメールを送る（未登録・回数制限中・送信失敗でも成功を返す）
+    async fn request_reset(&self, request_dto: PasswordResetRequestDto) -> ApplicationResult<()>;
+    /// トークンを検証して新しいパスワードを設定し、全セッションを終了する
+    async fn confirm_reset(&self, request_dto: PasswordResetConfirmDto) -> ApplicationResult<()>;
+}
+
+/// パスワードリセットユースケース
+///
+/// 責務:
+/// 1. 一度だけ使えるリセットトークンの発行（保存はハッシュのみ）とメール送信
+/// 2. トークン検証・新パスワードの保存
+/// 3. 既存セッション（リフレッシュトークンファミリー）とセッションに紐づかないトークンの失効
+///
+/// リセット要求はアカウントの有無・回数制限・メール送信の成否に関わらず同じ応答を返す
+/// （回数制限はPasswordResetThrottleServiceが判定）
+pub struct PasswordResetUseCase {
+    query_repository: Arc<dyn UserQueryRepositoryInterface + Send + Sync>,
+    command_repository: Arc<dyn UserCommandRepositoryInterface + Send + Sync>,
+    reset_token_repository: Arc<dyn PasswordResetTokenRepositoryInterface>,
+    refresh_token_repository: Arc<dyn RefreshTokenRepositoryInterface>,
+    token_revocation_repository: Arc<dyn TokenRevocationRepositoryInterface>,
+    audit_log_repository: Arc<dyn AuditLogRepositoryInterface>,
+    password_hasher: Arc<PasswordHasher>,
+    mailer: Arc<dyn Mailer>,
+    throttle_service: Arc<PasswordResetThrottleService>,
+    token_ttl: Duration,
+    reset_url: String,
+    password_policy: Option<Arc<PasswordPolicy>>,
+}
+
+impl PasswordResetUseCase {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        query_repository: Arc<dyn UserQueryRepositoryInterface + Send + Sync>,
+        command_repository: Arc<dyn UserCommandRepositoryInterface + Send + Sync>,
+        reset_token_repository: Arc<dyn PasswordResetTokenRepositoryInterface>,
+        refresh_token_repository: Arc<dyn RefreshTokenRepositoryInterface>,
+        token_revocation_repository: Arc<dyn TokenRevocationRepositoryInterface>,
+        audit_log_repository: Arc<dyn AuditLogRepositoryInterface>,
+        password_hasher: Arc<PasswordHasher>,
+        mailer: Arc<dyn Mailer>,
+        throttle_service: Arc<PasswordResetThrottleService>,
+        token_ttl: Duration,
+        reset_url: String,
+    ) -> Self {
+        Self {
+            query_repository,
+            command_repository,
+            reset_token_repository,
+            refresh_token_repository,
+            token_revocation_repository,
+            audit_log_repository,
+            password_hasher,
+            mailer,
+            throttle_service,
+            token_ttl,
+            reset_url,
+            password_policy: None,
+        }
+    }
+
+    /// 新しいパスワードを設定したポリシーで検証する（未設定の場合は既定の長さのみ）
+    pub fn with_password_policy(mut self, policy: Arc<PasswordPolicy>) -> Self {
+        self.password_policy = Some(policy);
+        self
+    }
+
+    fn infrastructure_error(
+        resource: &str,
+        e: Box<dyn std::error::Error + Send + Sync>,
+    ) -> ApplicationError {
+        ApplicationError::Infrastructure(InfrastructureError::ResourceUnavailable {
+            resource: resource.to_string(),
+            message: format!("{}", e),
+        })
+    }
+
+    fn reset_link(&self, token: &str) -> String {
+        let separator = if self.reset_url.contains('?') {
+            '&'
+        } else {
+            '?'
+        };
+        format!("{}{}token={}", self.reset_url, separator, token)
+    }
+}
+
+#[async_trait]
+impl PasswordResetUsecaseInterface for PasswordResetUseCase {
+    async fn request_reset(&self, request_dto: PasswordResetRequestDto) -> ApplicationResult<()> {
+        // 1. 回数制限（未登録のメールアドレスも同じように数える）
+        let Ok(email) = Email::new(request_dto.email.trim().to_string()) else {
+            return Ok(());
+        };
+        if !self
+            .throttle_service
+            .try_acquire(&email.0, request_dto.client_ip.as_deref(), Utc::now())
+            .await?
+        {
+            println!("PasswordResetUseCase: reset request throttled");
+            return Ok(());
+        }
+
+        // 2. ユーザー検索（未登録でも同じ応答にする）
+        let Some(user) = self
+            .query_repository
+            .find_by_email(&email)
+            .await
+            .map_err(|e| Self::infrastructure_error("user", e))?
+        else {
+            println!("PasswordResetUseCase: reset requested for unknown email");
+            return Ok(());
+        };
+
+        // 3. 以前のトークンを無効にして新しいトークンを発行
+        self.reset_token_repository
+            .invalidate_user_tokens(user.id())
+            .await
+            .map_err(|e| Self::infrastructure_error("password_reset_token", e))?;
+        let token = generate_token();
+        let ttl = chrono::Duration::seconds(self.token_ttl.as_secs() as i64);
+        self.reset_token_repository
+            .save(&PasswordResetToken::new(
+                hash_token(&token),
+                user.id().clone(),
+                Utc::now() + ttl,
+            ))
+            .await
+            .map_err(|e| Self::infrastructure_error("password_reset_token", e))?;
+
+        // 4. リセットリンクをメールで送信（失敗は記録のみ、応答からアカウントの有無が分からないようにする）
+        let message = EmailMessage {
+            to: user.email().0.clone(),
+            subject: "パスワード再設定のご案内".to_string(),
+            body: format!(
+                "{} 様\n\n以下のリンクからパスワードを再設定してください（有効期限: {}分）。\n{}\n\nお心当たりがない場合はこのメールを破棄してください。",
+                user.name().0,
+                ttl.num_minutes(),
+                self.reset_link(&token)
+            ),
+        };
+        if let Err(e) = self.mailer.send(&message).await {
+            println!(
+                "PasswordResetUseCase: Failed to send reset mail to user {}: {}",
+                user.id().0,
+                e
+            );
+        }
+        Ok(())
+    }
+
+    async fn confirm_reset(&self, request_dto: PasswordResetConfirmDto) -> ApplicationResult<()> {
+        // 1. トークンの確認（未登録・期限切れ・使用済みは同じエラー）
+        let token_hash = hash_token(request_dto.token.trim());
+        let user_id = self
+            .reset_token_repository
+            .find_user_id(&token_hash, Utc::now())
+            .await
+            .map_err(|e| Self::infrastructure_error("password_reset_token", e))?
+            .ok_or(ApplicationError::InvalidToken)?;
+        let user = self
+            .query_repository
+            .find_by_id(&user_id)
+            .await
+            .map_err(|e| Self::infrastructure_error("user", e))?
+            .ok_or(ApplicationError::InvalidToken)?;
+
+        // 2. 新パスワードのバリデーション（トークンを消費する前に行い、違反はすべて返す）
+        let password = match &self.password_policy {
+            Some(policy) => Password::with_policy(
+                request_dto.new_password,
+                policy,
+                &PasswordContext {
+                    email: Some(&user.email().0),
+                    name: Some(&user.name().0),
+                },
+            ),
+            None => Password::new(request_dto.new_password),
+        }
+        .map_err(ApplicationError::Domain)?;
+
+        // 3. トークンの消費（同時に使われた場合は1リクエストだけが成功する）
+        let consumed = self
+            .reset_token_repository
+            .consume(&token_hash, Utc::now())
+            .await
+            .map_err(|e| Self::infrastructure_error("password_reset_token", e))?;
+        if consumed.as_ref() != Some(user.id()) {
+            return Err(ApplicationError::InvalidToken);
+        }
+
+        // 4. 新しいパスワードハッシュの保存
+        let password_hash = self.password_hasher.hash(&password).await?;
+        self.command_repository
+            .update_password_hash(user.id(), &password_hash)
+            .await
+            .map_err(|e| Self::infrastructure_error("user", e))?;
+
+        // 5. 残りのトークンと既存セッションをすべて失効
+        self.reset_token_repository
+            .invalidate_user_tokens(user.id())
+            .await
+            .map_err(|e| Self::infrastructure_error("password_reset_token", e))?;
+        let now = Utc::now();
+        self.token_revocation_repository
+            .invalidate_user_sessions(user.id(), now)
+            .await
+            .map_err(|e| Self::infrastructure_error("session", e))?;
+        let revoked = self
+            .refresh_token_repository
+            .revoke_user_families(user.id(), now)
+            .await
+            .map_err(|e| Self::infrastructure_error("session", e))?;
+
+        let event = AuditEvent::new(
+            "password.reset",
+            Some(user.id().0.clone()),
+            format!("user:{}", user.id().0),
+            Some(format!("revoked_sessions={}", revoked)),
+        );
+        if let Err(e) = self.audit_log_repository.record(&event).await {
+            println!("PasswordResetUseCase: Failed to write audit log: {}", e);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,126 @@
+//application/usecases/refresh_token_usecase.rs
+// リフレッシュトークンユースケース（ローテーション + 再利用検知）
+// 2025/7/8
+
+use crate::application::dto::auth_dto::{RefreshTokenRequestDto, TokenPairDto};
+use crate::application::services::session_token_service::SessionTokenService;
+use crate::domain::repository::refresh_token_repository::{
+    RefreshTokenConsumption, RefreshTokenRepositoryInterface,
+};
+use crate::domain::repository::user_query_repository::UserQueryRepositoryInterface;
+use crate::shared::error::application_error::{ApplicationError, ApplicationResult};
+use crate::shared::error::infrastructure_error::InfrastructureError;
+use crate::shared::middleware::auth_middleware::{JwtClaims, TokenType};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait RefreshTokenUsecaseInterface: Send + Sync {
+    async fn execute(&self, request_dto: RefreshTokenRequestDto)
+    -> ApplicationResult<TokenPairDto>;
+}
+
+/// リフレッシュトークンユースケース
+///
+/// 責務:
+/// 1. リフレッシュトークン（JWT）の検証
+/// 2. 保存済みトークンを使用済みにしてローテーション
+/// 3. 使用済みトークンの再提示を検知した場合はファミリー全体を失効
+/// 4. OAuthクライアント経由のトークンは委任内容（`client_id`・`scope`）を引き継ぐ
+///
+/// 失敗理由（未登録・失効済み・再利用）はクライアントに区別して返さない。
+pub struct RefreshTokenUseCase {
+    query_repository: Arc<dyn UserQueryRepositoryInterface + Send + Sync>,
+    refresh_token_repository: Arc<dyn RefreshTokenRepositoryInterface>,
+    session_token_service: Arc<SessionTokenService>,
+}
+
+impl RefreshTokenUseCase {
+    pub fn new(
+        query_repository: Arc<dyn UserQueryRepositoryInterface + Send + Sync>,
+        refresh_token_repository: Arc<dyn RefreshTokenRepositoryInterface>,
+        session_token_service: Arc<SessionTokenService>,
+    ) -> Self {
+        Self {
+            query_repository,
+            refresh_token_repository,
+            session_token_service,
+        }
+    }
+
+    async fn revoke_family(&self, family_id: &str) -> ApplicationResult<()> {
+        self.refresh_token_repository
+            .revoke_family(family_id, chrono::Utc::now())
+            .await
+            .map_err(Self::infrastructure_error)
+    }
+
+    fn infrastructure_error(e: Box<dyn std::error::Error + Send + Sync>) -> ApplicationError {
+        ApplicationError::Infrastructure(InfrastructureError::ResourceUnavailable {
+            resource: "refresh_token".to_string(),
+            message: format!("{}", e),
+        })
+    }
+}
+
+#[async_trait]
+impl RefreshTokenUsecaseInterface for RefreshTokenUseCase {
+    async fn execute(
+        &self,
+        request_dto: RefreshTokenRequestDto,
+    ) -> ApplicationResult<TokenPairDto> {
+        // 1. JWTとしての検証（署名・期限・種別）
+        let claims = JwtClaims::from_token(&request_dto.refresh_token)
+            .map_err(|_| ApplicationError::InvalidToken)?;
+        if claims.token_type != TokenType::Refresh || claims.is_expired() || claims.sid.is_none() {
+            return Err(ApplicationError::InvalidToken);
+        }
+        // OAuthクライアントに発行したトークンは同じクライアントからのみ更新できる
+        if claims.client_id != request_dto.client_id {
+            return Err(ApplicationError::InvalidToken);
+        }
+
+        // 2. 保存済みトークンの消費
+        let now = chrono::Utc::now();
+        let token = match self
+            .refresh_token_repository
+            .consume(&claims.jti, now)
+            .await
+            .map_err(Self::infrastructure_error)?
+        {
+            RefreshTokenConsumption::Consumed(token) => token,
+            RefreshTokenConsumption::Reused(token) => {
+                // 3. 再利用検知: 盗用の可能性があるためファミリー全体を失効
+                println!(
+                    "RefreshTokenUseCase: Reuse detected for family {} (user {}), revoking",
+                    token.family_id, token.user_id.0
+                );
+                self.revoke_family(&token.family_id).await?;
+                return Err(ApplicationError::InvalidToken);
+            }
+            RefreshTokenConsumption::FamilyRevoked | RefreshTokenConsumption::NotFound => {
+                return Err(ApplicationError::InvalidToken);
+            }
+        };
+        if token.user_id.0 != claims.sub
+            || claims.sid.as_deref() != Some(token.family_id.as_str())
+            || token.is_expired(now)
+        {
+            return Err(ApplicationError::InvalidToken);
+        }
+
+        // 4. 最新のユーザー情報・ロールでローテーション（削除済みユーザーはセッション終了）
+        let user = self
+            .query_repository
+            .find_by_id(&token.user_id)
+            .await
+            .map_err(Self::infrastructure_error)?;
+        let Some(user) = user else {
+            self.revoke_family(&token.family_id).await?;
+            return Err(ApplicationError::InvalidToken);
+        };
+        self.session_token_service
+            .rotate(&user, &token.family_id, claims.client_grant().as_ref())
+            .await
+    }
+}
@@ -0,0 +1,59 @@
+//application/usecases/unlock_account_usecase.rs
+// アカウントロック解除ユースケース
+// 2025/7/8
+
+use crate::application::services::login_throttle_service::LoginThrottleService;
+use crate::domain::repository::user_query_repository::UserQueryRepositoryInterface;
+use crate::domain::value_object::user_id::UserId;
+use crate::shared::error::application_error::{ApplicationError, ApplicationResult};
+use crate::shared::error::infrastructure_error::InfrastructureError;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait UnlockAccountUsecaseInterface: Send + Sync {
+    /// 管理者（actor_id）による対象ユーザーのロック解除。解除対象があった場合はtrue
+    async fn execute(&self, actor_id: String, user_id: String) -> ApplicationResult<bool>;
+}
+
+/// アカウントロック解除ユースケース
+///
+/// 責務:
+/// 1. 対象ユーザーの存在確認
+/// 2. アカウント単位の失敗回数・ロックの削除（監査ログに記録）
+pub struct UnlockAccountUseCase {
+    query_repository: Arc<dyn UserQueryRepositoryInterface + Send + Sync>,
+    login_throttle_service: Arc<LoginThrottleService>,
+}
+
+impl UnlockAccountUseCase {
+    pub fn new(
+        query_repository: Arc<dyn UserQueryRepositoryInterface + Send + Sync>,
+        login_throttle_service: Arc<LoginThrottleService>,
+    ) -> Self {
+        Self {
+            query_repository,
+            login_throttle_service,
+        }
+    }
+}
+
+#[async_trait]
+impl UnlockAccountUsecaseInterface for UnlockAccountUseCase {
+    async fn execute(&self, actor_id: String, user_id: String) -> ApplicationResult<bool> {
+        let user = self
+            .query_repository
+            .find_by_id(&UserId::new(user_id.clone()))
+            .await
+            .map_err(|e| {
+                ApplicationError::Infrastructure(InfrastructureError::ResourceUnavailable {
+                    resource: "user".to_string(),
+                    message: format!("{}", e),
+                })
+            })?
+            .ok_or(ApplicationError::UserNotFound { id: user_id })?;
+        self.login_throttle_service
+            .unlock_account(&user.email().0, &actor_id)
+            .await
+    }
+}
@@ -2,14 +2,16 @@
 // ユーザー更新ユースケース
 // 2025/7/8
 
+use crate::application::dto::auth_dto::ActorDto;
 use crate::application::dto::user_request_dto::UpdateUserRequestDto;
 use crate::application::dto::user_response_dto::UserResponseDto;
+use crate::application::services::user_access_policy::UserAccessPolicy;
 use crate::domain::entity::user::User;
 use crate::domain::repository::user_command_repository::UserCommandRepositoryInterface;
 use crate::domain::repository::user_query_repository::UserQueryRepositoryInterface;
+use crate::domain::value_object::permission::Permission;
 use crate::domain::value_object::{
-    birth_date::BirthDate, email::Email, password::Password, phone::Phone, user_id::UserId,
-    user_name::UserName,
+    birth_date::BirthDate, phone::Phone, user_id::UserId, user_name::UserName,
 };
 use crate::shared::error::application_error::{ApplicationError, ApplicationResult};
 use async_trait::async_trait;
@@ -20,6 +22,7 @@ use uuid::Uuid;
 pub trait UpdateUserUsecaseInterface: Send + Sync {
     async fn execute(
         &self,
+        actor: ActorDto,
         request_dto: UpdateUserRequestDto,
     ) -> ApplicationResult<UserResponseDto>;
 }
@@ -27,16 +30,19 @@ pub trait UpdateUserUsecaseInterface: Send + Sync {
 pub struct UpdateUserUseCase {
     command_repository: Arc<dyn UserCommandRepositoryInterface + Send + Sync>,
     query_repository: Arc<dyn UserQueryRepositoryInterface + Send + Sync>,
+    access_policy: Arc<UserAccessPolicy>,
 }
 
 impl UpdateUserUseCase {
     pub fn new(
         command_repository: Arc<dyn UserCommandRepositoryInterface + Send + Sync>,
         query_repository: Arc<dyn UserQueryRepositoryInterface + Send + Sync>,
+        access_policy: Arc<UserAccessPolicy>,
     ) -> Self {
         Self {
             command_repository,
             query_repository,
+            access_policy,
         }
     }
 }
@@ -45,6 +51,7 @@ impl UpdateUserUseCase {
 impl UpdateUserUsecaseInterface for UpdateUserUseCase {
     async fn execute(
         &self,
+        actor: ActorDto,
         request_dto: UpdateUserRequestDto,
     ) -> ApplicationResult<UserResponseDto> {
         println!(
@@ -61,6 +68,10 @@ impl UpdateUserUsecaseInterface for UpdateUserUseCase {
         let user_id_vo = UserId::new(user_id.to_string());
         println!("UpdateUserUseCase: User ID validated: {}", user_id_vo.0);
 
+        // 認可（本人以外はusers:writeが必要。存在有無を漏らさないよう取得前に判定）
+        self.access_policy
+            .authorize(&actor, &user_id_vo, Permission::UsersWrite)?;
+
         // 2. 既存ユーザーの取得
         println!("UpdateUserUseCase: Fetching existing user...");
         let existing_user = self.query_repository
@@ -116,19 +127,20 @@ impl UpdateUserUsecaseInterface for UpdateUserUseCase {
             existing_user.birth_date().cloned()
         };
 
-        // 4. ドメインエンティティ再生成（メール・パスワードは変更不可と仮定）
+        // 4. ドメインエンティティ再生成（メール・パスワードハッシュ・ロールは変更不可と仮定）
         let user = User::new(
             user_id_vo,
             existing_user.email().clone(),
             name,
-            existing_user.password().clone(),
+            existing_user.password_hash().clone(),
             phone,
             birth_date,
         )
         .map_err(|e| ApplicationError::InvalidInput {
             input: "user".to_string(),
             reason: format!("{}", e),
-        })?;
+        })?
+        .with_role(existing_user.role());
 
         // 5. 保存（永続化）
         println!("UpdateUserUseCase: Updating user...");
@@ -0,0 +1,39 @@
+//domain/entity/api_key.rs
+// APIキー エンティティ
+// 2025/7/8
+
+use crate::domain::value_object::{permission::Permission, user_id::UserId};
+use chrono::{DateTime, Utc};
+
+/// サービス間連携用のAPIキー
+///
+/// キー本体はハッシュのみ保存し、表示用に先頭部分（`prefix`）を残す。
+/// 所有者（ユーザーまたはサービスアカウント）の権限のうち`scopes`に含まれるものだけを使える。
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiKey {
+    pub id: String,
+    pub owner_id: UserId,
+    pub name: String,
+    pub prefix: String,
+    pub key_hash: String,
+    pub scopes: Vec<Permission>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub created_by: UserId,
+}
+
+impl ApiKey {
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+
+    /// `scope`クレーム用の空白区切り表現
+    pub fn scope_string(&self) -> String {
+        self.scopes
+            .iter()
+            .map(|permission| permission.as_str())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
@@ -0,0 +1,38 @@
+//domain/entity/audit_event.rs
+// 監査ログ エンティティ
+// 2025/7/8
+
+use chrono::{DateTime, Utc};
+
+/// 監査ログの1件
+///
+/// `action`は`login.locked`のようなドット区切りの識別子、
+/// `subject`は対象（`account:alice@example.com`や`user:<id>`）、
+/// `actor`は操作したユーザーID（システムによる自動処理の場合はNone）。
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditEvent {
+    pub id: String,
+    pub action: String,
+    pub actor: Option<String>,
+    pub subject: String,
+    pub detail: Option<String>,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl AuditEvent {
+    pub fn new(
+        action: &str,
+        actor: Option<String>,
+        subject: String,
+        detail: Option<String>,
+    ) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            action: action.to_string(),
+            actor,
+            subject,
+            detail,
+            occurred_at: Utc::now(),
+        }
+    }
+}
@@ -0,0 +1,37 @@
+//domain/entity/email_verification_token.rs
+// メールアドレス確認トークン エンティティ
+// 2025/7/8
+
+use crate::domain::value_object::user_id::UserId;
+use chrono::{DateTime, Utc};
+
+/// 発行済みメールアドレス確認トークン
+///
+/// 保存するのはSHA-256ハッシュ（`token_hash`）だけ。送信先の`email`も保持し、
+/// 発行後にメールアドレスが変更された場合はトークンを無効として扱う。
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmailVerificationToken {
+    pub token_hash: String,
+    pub user_id: UserId,
+    pub email: String,
+    pub issued_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl EmailVerificationToken {
+    pub fn new(
+        token_hash: String,
+        user_id: UserId,
+        email: String,
+        issued_at: DateTime<Utc>,
+        expires_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            token_hash,
+            user_id,
+            email,
+            issued_at,
+            expires_at,
+        }
+    }
+}
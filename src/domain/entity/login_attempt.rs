@@ -0,0 +1,85 @@
+//domain/entity/login_attempt.rs
+// ログイン失敗カウンター エンティティ
+// 2025/7/8
+
+use crate::domain::service::lockout_policy::LockoutPolicy;
+use chrono::{DateTime, Duration, Utc};
+
+/// 失敗回数を数える単位
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LoginAttemptScope {
+    /// アカウント（正規化したメールアドレス。未登録でも同じように数える）
+    Account,
+    /// 接続元IPアドレス
+    Ip,
+}
+
+impl LoginAttemptScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LoginAttemptScope::Account => "account",
+            LoginAttemptScope::Ip => "ip",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "account" => Some(LoginAttemptScope::Account),
+            "ip" => Some(LoginAttemptScope::Ip),
+            _ => None,
+        }
+    }
+}
+
+/// アカウント・IPごとのログイン失敗状況
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoginAttempt {
+    pub scope: LoginAttemptScope,
+    pub key: String,
+    pub failed_count: u32,
+    pub last_failed_at: DateTime<Utc>,
+    pub locked_until: Option<DateTime<Utc>>,
+}
+
+impl LoginAttempt {
+    pub fn new(scope: LoginAttemptScope, key: String, now: DateTime<Utc>) -> Self {
+        Self {
+            scope,
+            key,
+            failed_count: 0,
+            last_failed_at: now,
+            locked_until: None,
+        }
+    }
+
+    pub fn is_locked(&self, now: DateTime<Utc>) -> bool {
+        self.locked_until.is_some_and(|until| now < until)
+    }
+
+    /// ロック解除までの残り時間
+    pub fn retry_after(&self, now: DateTime<Utc>) -> Option<Duration> {
+        self.locked_until
+            .filter(|until| now < *until)
+            .map(|until| until - now)
+    }
+
+    /// 失敗を記録する。新たにロックされた場合はtrueを返す
+    pub fn register_failure(&mut self, now: DateTime<Utc>, policy: &LockoutPolicy) -> bool {
+        let last_activity = self
+            .locked_until
+            .map_or(self.last_failed_at, |until| until.max(self.last_failed_at));
+        if now - last_activity > policy.failure_window {
+            self.failed_count = 0;
+            self.locked_until = None;
+        }
+        self.failed_count = self.failed_count.saturating_add(1);
+        self.last_failed_at = now;
+        match policy.lockout_duration(self.failed_count) {
+            Some(duration) => {
+                self.locked_until = Some(now + duration);
+                true
+            }
+            None => false,
+        }
+    }
+}
@@ -0,0 +1,34 @@
+//domain/entity/mfa_credential.rs
+// 二要素認証（TOTP）の登録情報 エンティティ
+// 2025/7/8
+
+use crate::domain::value_object::user_id::UserId;
+use chrono::{DateTime, Utc};
+
+/// ユーザーのTOTP登録情報
+///
+/// 登録開始時は未確認（`confirmed_at`なし）で、認証アプリのコードで確認されて有効になる。
+/// `last_used_step`は最後に受け付けた時間ステップで、同じコードの再利用を防ぐ。
+#[derive(Debug, Clone, PartialEq)]
+pub struct MfaCredential {
+    pub user_id: UserId,
+    pub secret: String,
+    pub confirmed_at: Option<DateTime<Utc>>,
+    pub last_used_step: Option<i64>,
+}
+
+impl MfaCredential {
+    /// 確認前の登録情報
+    pub fn pending(user_id: UserId, secret: String) -> Self {
+        Self {
+            user_id,
+            secret,
+            confirmed_at: None,
+            last_used_step: None,
+        }
+    }
+
+    pub fn is_confirmed(&self) -> bool {
+        self.confirmed_at.is_some()
+    }
+}
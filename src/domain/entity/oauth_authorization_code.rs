@@ -0,0 +1,27 @@
+//domain/entity/oauth_authorization_code.rs
+// OAuth認可コード エンティティ
+// 2025/7/8
+
+use crate::domain::value_object::user_id::UserId;
+use chrono::{DateTime, Utc};
+
+/// 発行済みの認可コード（コード自体はハッシュのみ保存、一度だけ交換できる）
+#[derive(Debug, Clone, PartialEq)]
+pub struct OAuthAuthorizationCode {
+    pub code_hash: String,
+    pub client_id: String,
+    pub user_id: UserId,
+    pub redirect_uri: String,
+    pub scope: String,
+    /// PKCEのコードチャレンジ（S256）
+    pub code_challenge: String,
+    /// OpenID Connectの`nonce`（IDトークンにそのまま入れる）
+    pub nonce: Option<String>,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl OAuthAuthorizationCode {
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        now > self.expires_at
+    }
+}
@@ -0,0 +1,41 @@
+//domain/entity/oauth_client.rs
+// OAuthクライアント エンティティ
+// 2025/7/8
+
+use crate::domain::value_object::{
+    oauth_grant_type::OAuthGrantType, permission::Permission, user_id::UserId,
+};
+use chrono::{DateTime, Utc};
+
+/// 登録済みのOAuthクライアント
+///
+/// `client_secret_hash`がないクライアントは公開クライアント（SPA・ネイティブアプリ）で、
+/// PKCE付きの認可コードグラントとリフレッシュのみ使える。
+/// client_credentialsグラントのトークンは`service_account_id`のユーザーとして発行する。
+#[derive(Debug, Clone, PartialEq)]
+pub struct OAuthClient {
+    pub client_id: String,
+    pub client_secret_hash: Option<String>,
+    pub name: String,
+    pub redirect_uris: Vec<String>,
+    pub grant_types: Vec<OAuthGrantType>,
+    pub scopes: Vec<Permission>,
+    pub service_account_id: Option<UserId>,
+    pub created_at: DateTime<Utc>,
+    pub created_by: UserId,
+}
+
+impl OAuthClient {
+    pub fn is_confidential(&self) -> bool {
+        self.client_secret_hash.is_some()
+    }
+
+    pub fn allows_grant(&self, grant_type: OAuthGrantType) -> bool {
+        self.grant_types.contains(&grant_type)
+    }
+
+    /// 登録済みのリダイレクトURIと完全一致するか
+    pub fn allows_redirect_uri(&self, redirect_uri: &str) -> bool {
+        self.redirect_uris.iter().any(|uri| uri == redirect_uri)
+    }
+}
@@ -0,0 +1,26 @@
+//domain/entity/password_reset_token.rs
+// パスワードリセットトークン エンティティ
+// 2025/7/8
+
+use crate::domain::value_object::user_id::UserId;
+use chrono::{DateTime, Utc};
+
+/// 発行済みパスワードリセットトークン
+///
+/// トークン本体はメールでのみ送り、保存するのはSHA-256ハッシュ（`token_hash`）だけ。
+#[derive(Debug, Clone, PartialEq)]
+pub struct PasswordResetToken {
+    pub token_hash: String,
+    pub user_id: UserId,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl PasswordResetToken {
+    pub fn new(token_hash: String, user_id: UserId, expires_at: DateTime<Utc>) -> Self {
+        Self {
+            token_hash,
+            user_id,
+            expires_at,
+        }
+    }
+}
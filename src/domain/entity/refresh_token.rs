@@ -0,0 +1,33 @@
+//domain/entity/refresh_token.rs
+// リフレッシュトークン エンティティ
+// 2025/7/8
+
+use crate::domain::value_object::user_id::UserId;
+use chrono::{DateTime, Utc};
+
+/// 発行済みリフレッシュトークン
+///
+/// 同一ログインから発行されたトークンはファミリー（`family_id`）を共有し、
+/// ローテーションのたびに新しい`jti`のトークンがファミリーに追加される。
+#[derive(Debug, Clone, PartialEq)]
+pub struct RefreshToken {
+    pub jti: String,
+    pub family_id: String,
+    pub user_id: UserId,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl RefreshToken {
+    pub fn new(jti: String, family_id: String, user_id: UserId, expires_at: DateTime<Utc>) -> Self {
+        Self {
+            jti,
+            family_id,
+            user_id,
+            expires_at,
+        }
+    }
+
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        now > self.expires_at
+    }
+}
@@ -3,19 +3,22 @@
 // 2025/7/8
 
 use crate::domain::value_object::{
-    birth_date::BirthDate, email::Email, password::Password, phone::Phone, user_id::UserId,
-    user_name::UserName,
+    birth_date::BirthDate, email::Email, password_hash::PasswordHash, phone::Phone, role::Role,
+    user_id::UserId, user_name::UserName,
 };
 use crate::shared::error::domain_error::DomainResult;
+use chrono::{DateTime, Utc};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct User {
     pub id: UserId,
     pub email: Email,
     pub name: UserName,
-    pub password: Password,
+    pub password_hash: PasswordHash,
     pub phone: Option<Phone>,
     pub birth_date: Option<BirthDate>,
+    pub role: Role,
+    pub email_verified_at: Option<DateTime<Utc>>,
 }
 
 impl User {
@@ -23,7 +26,7 @@ impl User {
         id: UserId,
         email: Email,
         name: UserName,
-        password: Password,
+        password_hash: PasswordHash,
         phone: Option<Phone>,
         birth_date: Option<BirthDate>,
     ) -> DomainResult<Self> {
@@ -32,12 +35,26 @@ impl User {
             id,
             email,
             name,
-            password,
+            password_hash,
             phone,
             birth_date,
+            role: Role::User,
+            email_verified_at: None,
         })
     }
 
+    /// ロールを指定する（新規作成時は`Role::User`）
+    pub fn with_role(mut self, role: Role) -> Self {
+        self.role = role;
+        self
+    }
+
+    /// メールアドレス確認日時を指定する（新規作成時は未確認）
+    pub fn with_email_verified_at(mut self, verified_at: Option<DateTime<Utc>>) -> Self {
+        self.email_verified_at = verified_at;
+        self
+    }
+
     pub fn get_id(&self) -> &UserId {
         &self.id
     }
@@ -54,8 +71,8 @@ impl User {
         &self.name
     }
 
-    pub fn password(&self) -> &Password {
-        &self.password
+    pub fn password_hash(&self) -> &PasswordHash {
+        &self.password_hash
     }
 
     pub fn phone(&self) -> Option<&Phone> {
@@ -65,4 +82,16 @@ impl User {
     pub fn birth_date(&self) -> Option<&BirthDate> {
         self.birth_date.as_ref()
     }
+
+    pub fn role(&self) -> Role {
+        self.role
+    }
+
+    pub fn email_verified_at(&self) -> Option<DateTime<Utc>> {
+        self.email_verified_at
+    }
+
+    pub fn is_email_verified(&self) -> bool {
+        self.email_verified_at.is_some()
+    }
 }
@@ -0,0 +1,48 @@
+//domain/repository/api_key_repository.rs
+// APIキー Repository トレイト
+// 2025/7/8
+
+use crate::domain::entity::api_key::ApiKey;
+use crate::domain::value_object::user_id::UserId;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+#[async_trait]
+pub trait ApiKeyRepositoryInterface: Send + Sync {
+    // APIキーを保存する
+    async fn save(&self, api_key: &ApiKey) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    // IDで取得する
+    async fn find_by_id(
+        &self,
+        id: &str,
+    ) -> Result<Option<ApiKey>, Box<dyn std::error::Error + Send + Sync>>;
+
+    // キー本体のハッシュで取得する（認証用）
+    async fn find_by_hash(
+        &self,
+        key_hash: &str,
+    ) -> Result<Option<ApiKey>, Box<dyn std::error::Error + Send + Sync>>;
+
+    // 所有者のAPIキー一覧（作成日時順）
+    async fn find_by_owner(
+        &self,
+        owner_id: &UserId,
+    ) -> Result<Vec<ApiKey>, Box<dyn std::error::Error + Send + Sync>>;
+
+    // 名前・スコープを更新する
+    async fn update(
+        &self,
+        api_key: &ApiKey,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>>;
+
+    // 最終使用日時を記録する
+    async fn touch_last_used(
+        &self,
+        id: &str,
+        used_at: DateTime<Utc>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    // 削除する
+    async fn delete(&self, id: &str) -> Result<bool, Box<dyn std::error::Error + Send + Sync>>;
+}
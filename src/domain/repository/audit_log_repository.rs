@@ -0,0 +1,22 @@
+//domain/repository/audit_log_repository.rs
+// 監査ログ Repository トレイト
+// 2025/7/8
+
+use crate::domain::entity::audit_event::AuditEvent;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait AuditLogRepositoryInterface: Send + Sync {
+    // 監査ログの追記
+    async fn record(
+        &self,
+        event: &AuditEvent,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    // 対象ごとの監査ログ（新しい順）
+    async fn find_by_subject(
+        &self,
+        subject: &str,
+        limit: usize,
+    ) -> Result<Vec<AuditEvent>, Box<dyn std::error::Error + Send + Sync>>;
+}
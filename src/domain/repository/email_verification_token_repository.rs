@@ -0,0 +1,37 @@
+//domain/repository/email_verification_token_repository.rs
+// メールアドレス確認トークン Repository トレイト
+// 2025/7/8
+
+use crate::domain::entity::email_verification_token::EmailVerificationToken;
+use crate::domain::value_object::user_id::UserId;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+#[async_trait]
+pub trait EmailVerificationTokenRepositoryInterface: Send + Sync {
+    // トークンの保存
+    async fn save(
+        &self,
+        token: &EmailVerificationToken,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    // 未使用かつ有効期限内のトークンを使用済みにして返す（一度だけ成功する）
+    async fn consume(
+        &self,
+        token_hash: &str,
+        now: DateTime<Utc>,
+    ) -> Result<Option<EmailVerificationToken>, Box<dyn std::error::Error + Send + Sync>>;
+
+    // 対象ユーザーの未使用トークンをすべて無効にする（再送・確認完了時）
+    async fn invalidate_user_tokens(
+        &self,
+        user_id: &UserId,
+    ) -> Result<usize, Box<dyn std::error::Error + Send + Sync>>;
+
+    // 指定日時以降に発行したトークンの発行日時（古い順、再送の回数制限用）
+    async fn issued_since(
+        &self,
+        user_id: &UserId,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<DateTime<Utc>>, Box<dyn std::error::Error + Send + Sync>>;
+}
@@ -0,0 +1,41 @@
+//domain/repository/login_attempt_repository.rs
+// ログイン失敗カウンター Repository トレイト
+// 2025/7/8
+
+use crate::domain::entity::login_attempt::{LoginAttempt, LoginAttemptScope};
+use crate::domain::service::lockout_policy::LockoutPolicy;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+#[async_trait]
+pub trait LoginAttemptRepositoryInterface: Send + Sync {
+    // 失敗状況の取得
+    async fn find(
+        &self,
+        scope: LoginAttemptScope,
+        key: &str,
+    ) -> Result<Option<LoginAttempt>, Box<dyn std::error::Error + Send + Sync>>;
+
+    // 失敗を1回記録し、記録後の状況と新たにロックしたかを返す
+    // （並行して失敗しても数え漏れないよう、読み取りから保存までを1つのトランザクションで行う）
+    async fn register_failure(
+        &self,
+        scope: LoginAttemptScope,
+        key: &str,
+        now: DateTime<Utc>,
+        policy: &LockoutPolicy,
+    ) -> Result<(LoginAttempt, bool), Box<dyn std::error::Error + Send + Sync>>;
+
+    // 失敗状況の削除（ログイン成功・ロック解除）。削除した場合はtrue
+    async fn delete(
+        &self,
+        scope: LoginAttemptScope,
+        key: &str,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>>;
+
+    // 指定日時以前に最後の失敗・ロックが終わったエントリを削除し、削除件数を返す
+    async fn purge_stale(
+        &self,
+        before: DateTime<Utc>,
+    ) -> Result<usize, Box<dyn std::error::Error + Send + Sync>>;
+}
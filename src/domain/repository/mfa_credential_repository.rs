@@ -0,0 +1,64 @@
+//domain/repository/mfa_credential_repository.rs
+// 二要素認証 Repository トレイト
+// 2025/7/8
+
+use crate::domain::entity::mfa_credential::MfaCredential;
+use crate::domain::value_object::user_id::UserId;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+#[async_trait]
+pub trait MfaCredentialRepositoryInterface: Send + Sync {
+    // 登録情報の取得
+    async fn find(
+        &self,
+        user_id: &UserId,
+    ) -> Result<Option<MfaCredential>, Box<dyn std::error::Error + Send + Sync>>;
+
+    // 確認前の登録情報を保存する（既存の未確認の登録は置き換える）
+    async fn save_pending(
+        &self,
+        credential: &MfaCredential,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    // 未確認の登録を確認済みにする（確認に使ったステップも記録）
+    async fn confirm(
+        &self,
+        user_id: &UserId,
+        confirmed_at: DateTime<Utc>,
+        step: i64,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>>;
+
+    // 記録済みより新しいステップの場合のみ記録する（再利用されたコードはfalse）
+    async fn record_used_step(
+        &self,
+        user_id: &UserId,
+        step: i64,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>>;
+
+    // 登録情報とリカバリーコードを削除する
+    async fn delete(
+        &self,
+        user_id: &UserId,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>>;
+
+    // リカバリーコード（ハッシュ）をすべて置き換える
+    async fn replace_recovery_codes(
+        &self,
+        user_id: &UserId,
+        code_hashes: &[String],
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    // 未使用のリカバリーコードを使用済みにする（一度だけ成功する）
+    async fn consume_recovery_code(
+        &self,
+        user_id: &UserId,
+        code_hash: &str,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>>;
+
+    // 未使用のリカバリーコードの数
+    async fn remaining_recovery_codes(
+        &self,
+        user_id: &UserId,
+    ) -> Result<usize, Box<dyn std::error::Error + Send + Sync>>;
+}
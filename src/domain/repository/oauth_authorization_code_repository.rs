@@ -0,0 +1,23 @@
+//domain/repository/oauth_authorization_code_repository.rs
+// OAuth認可コード Repository トレイト
+// 2025/7/8
+
+use crate::domain::entity::oauth_authorization_code::OAuthAuthorizationCode;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+#[async_trait]
+pub trait OAuthAuthorizationCodeRepositoryInterface: Send + Sync {
+    // 認可コードを保存する（有効期限切れのコードはこの時に削除する）
+    async fn save(
+        &self,
+        code: &OAuthAuthorizationCode,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    // 認可コードを原子的に使用済みにして返す（未使用の場合のみ、期限は呼び出し側で確認）
+    async fn consume(
+        &self,
+        code_hash: &str,
+        used_at: DateTime<Utc>,
+    ) -> Result<Option<OAuthAuthorizationCode>, Box<dyn std::error::Error + Send + Sync>>;
+}
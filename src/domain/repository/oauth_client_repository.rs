@@ -0,0 +1,30 @@
+//domain/repository/oauth_client_repository.rs
+// OAuthクライアント Repository トレイト
+// 2025/7/8
+
+use crate::domain::entity::oauth_client::OAuthClient;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait OAuthClientRepositoryInterface: Send + Sync {
+    // クライアントを登録する
+    async fn save(
+        &self,
+        client: &OAuthClient,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    // クライアントIDで取得する
+    async fn find_by_id(
+        &self,
+        client_id: &str,
+    ) -> Result<Option<OAuthClient>, Box<dyn std::error::Error + Send + Sync>>;
+
+    // 登録済みクライアントの一覧（登録日時順）
+    async fn find_all(&self) -> Result<Vec<OAuthClient>, Box<dyn std::error::Error + Send + Sync>>;
+
+    // クライアントと未使用の認可コードを削除する
+    async fn delete(
+        &self,
+        client_id: &str,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>>;
+}
@@ -0,0 +1,38 @@
+//domain/repository/password_reset_request_repository.rs
+// パスワードリセット要求の記録 Repository トレイト
+// 2025/7/8
+
+use crate::domain::service::request_rate_policy::RequestRatePolicy;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+/// 要求を数える単位
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PasswordResetRequestScope {
+    /// 正規化したメールアドレス（未登録でも同じように数える）
+    Email,
+    /// 接続元IPアドレス
+    Ip,
+}
+
+impl PasswordResetRequestScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PasswordResetRequestScope::Email => "email",
+            PasswordResetRequestScope::Ip => "ip",
+        }
+    }
+}
+
+#[async_trait]
+pub trait PasswordResetRequestRepositoryInterface: Send + Sync {
+    // 方針の範囲内であれば要求を記録してtrueを返す（超えていれば記録せずfalse）
+    // （並行した要求で上限を超えないよう、確認から記録までを1つのトランザクションで行い、期間外の記録は削除する）
+    async fn record_if_allowed(
+        &self,
+        scope: PasswordResetRequestScope,
+        key: &str,
+        now: DateTime<Utc>,
+        policy: &RequestRatePolicy,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>>;
+}
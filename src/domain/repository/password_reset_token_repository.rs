@@ -0,0 +1,37 @@
+//domain/repository/password_reset_token_repository.rs
+// パスワードリセットトークン Repository トレイト
+// 2025/7/8
+
+use crate::domain::entity::password_reset_token::PasswordResetToken;
+use crate::domain::value_object::user_id::UserId;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+#[async_trait]
+pub trait PasswordResetTokenRepositoryInterface: Send + Sync {
+    // トークンの保存
+    async fn save(
+        &self,
+        token: &PasswordResetToken,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    // 未使用かつ有効期限内のトークンの対象ユーザーを返す（使用済みにはしない）
+    async fn find_user_id(
+        &self,
+        token_hash: &str,
+        now: DateTime<Utc>,
+    ) -> Result<Option<UserId>, Box<dyn std::error::Error + Send + Sync>>;
+
+    // 未使用かつ有効期限内のトークンを使用済みにし、対象ユーザーを返す（一度だけ成功する）
+    async fn consume(
+        &self,
+        token_hash: &str,
+        now: DateTime<Utc>,
+    ) -> Result<Option<UserId>, Box<dyn std::error::Error + Send + Sync>>;
+
+    // 対象ユーザーの未使用トークンをすべて無効にする（再発行・リセット完了時）
+    async fn invalidate_user_tokens(
+        &self,
+        user_id: &UserId,
+    ) -> Result<usize, Box<dyn std::error::Error + Send + Sync>>;
+}
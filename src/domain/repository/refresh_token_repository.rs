@@ -0,0 +1,77 @@
+//domain/repository/refresh_token_repository.rs
+// リフレッシュトークン Repository トレイト
+// 2025/7/8
+
+use crate::domain::entity::refresh_token::RefreshToken;
+use crate::domain::value_object::user_id::UserId;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+/// リフレッシュトークン使用（消費）の結果
+#[derive(Debug, Clone, PartialEq)]
+pub enum RefreshTokenConsumption {
+    /// 未使用のトークンを使用済みにした
+    Consumed(RefreshToken),
+    /// 使用済みトークンの再提示（盗用の疑い）
+    Reused(RefreshToken),
+    /// ファミリーが失効済み
+    FamilyRevoked,
+    /// 未登録のトークン
+    NotFound,
+}
+
+#[async_trait]
+pub trait RefreshTokenRepositoryInterface: Send + Sync {
+    // ファミリー（ログインセッション）の作成
+    async fn create_family(
+        &self,
+        family_id: &str,
+        user_id: &UserId,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    // 発行したトークンの登録
+    async fn save(
+        &self,
+        token: &RefreshToken,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    // トークンを原子的に使用済みにする
+    async fn consume(
+        &self,
+        jti: &str,
+        used_at: DateTime<Utc>,
+    ) -> Result<RefreshTokenConsumption, Box<dyn std::error::Error + Send + Sync>>;
+
+    // ファミリー全体の失効
+    async fn revoke_family(
+        &self,
+        family_id: &str,
+        revoked_at: DateTime<Utc>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    // ユーザーの全ファミリーを失効し、失効件数を返す
+    async fn revoke_user_families(
+        &self,
+        user_id: &UserId,
+        revoked_at: DateTime<Utc>,
+    ) -> Result<usize, Box<dyn std::error::Error + Send + Sync>>;
+
+    // ファミリーが存在し、失効していないか
+    async fn is_family_active(
+        &self,
+        family_id: &str,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>>;
+
+    // トークンが未使用・期限内で、ファミリーも失効していないか
+    async fn is_token_active(
+        &self,
+        jti: &str,
+        now: DateTime<Utc>,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>>;
+
+    // 有効期限切れのトークンと空になったファミリーを削除し、削除件数を返す
+    async fn purge_expired(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<usize, Box<dyn std::error::Error + Send + Sync>>;
+}
@@ -0,0 +1,41 @@
+//domain/repository/token_revocation_repository.rs
+// アクセストークン失効リスト Repository トレイト
+// 2025/7/8
+
+use crate::domain::value_object::user_id::UserId;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+#[async_trait]
+pub trait TokenRevocationRepositoryInterface: Send + Sync {
+    // トークン（jti）を有効期限まで失効扱いにする
+    async fn revoke(
+        &self,
+        jti: &str,
+        user_id: &UserId,
+        expires_at: DateTime<Utc>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    // 失効済みかどうか
+    async fn is_revoked(&self, jti: &str)
+    -> Result<bool, Box<dyn std::error::Error + Send + Sync>>;
+
+    // ユーザーのトークンを`at`までに発行した分すべて失効扱いにする（セッションに紐づかないトークンも含む）
+    async fn invalidate_user_sessions(
+        &self,
+        user_id: &UserId,
+        at: DateTime<Utc>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    // ユーザーのトークンを一括で失効させた日時（未実施の場合はNone）
+    async fn sessions_invalidated_at(
+        &self,
+        user_id: &UserId,
+    ) -> Result<Option<DateTime<Utc>>, Box<dyn std::error::Error + Send + Sync>>;
+
+    // 有効期限切れのエントリを削除し、削除件数を返す
+    async fn purge_expired(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<usize, Box<dyn std::error::Error + Send + Sync>>;
+}
@@ -3,7 +3,9 @@
 // 2025/7/8
 
 use crate::domain::entity::user::User;
-use crate::domain::value_object::{email::Email, user_id::UserId};
+use crate::domain::value_object::{
+    email::Email, password_hash::PasswordHash, role::Role, user_id::UserId,
+};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 
@@ -11,6 +13,7 @@ use chrono::{DateTime, Utc};
 pub trait UserCommandRepositoryInterface: Send + Sync {
     // 基本的なCRUD操作
     async fn save(&self, user: &User) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+    // メールアドレス確認日時は保存済みの値を保ち、メールアドレスが変わった場合は未確認に戻す
     async fn update(&self, user: &User) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
     async fn delete(
         &self,
@@ -28,6 +31,27 @@ pub trait UserCommandRepositoryInterface: Send + Sync {
         login_time: DateTime<Utc>,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
 
+    // パスワードハッシュの差し替え（コスト変更時の再ハッシュ等）
+    async fn update_password_hash(
+        &self,
+        user_id: &UserId,
+        password_hash: &PasswordHash,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    // ロールの変更
+    async fn update_role(
+        &self,
+        user_id: &UserId,
+        role: Role,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    // メールアドレス確認済みにする
+    async fn mark_email_verified(
+        &self,
+        user_id: &UserId,
+        verified_at: DateTime<Utc>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
     // 重複チェック用
     async fn exists_by_email(
         &self,
@@ -0,0 +1,34 @@
+//domain/service/lockout_policy.rs
+// ログイン失敗時のロックアウト方針（指数バックオフ）
+// 2025/7/8
+
+use chrono::Duration;
+
+/// ロックアウト方針
+///
+/// 失敗回数が`max_failures`に達するとロックし、以降は失敗のたびにロック時間を倍にする
+/// （`base_lockout * 2^(失敗回数 - max_failures)`、上限`max_lockout`）。
+/// 最後の失敗（またはロック解除）から`failure_window`経過すると失敗回数はリセットされる。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LockoutPolicy {
+    pub max_failures: u32,
+    pub base_lockout: Duration,
+    pub max_lockout: Duration,
+    pub failure_window: Duration,
+}
+
+impl LockoutPolicy {
+    /// 失敗回数に応じたロック時間（閾値未満の場合はNone）
+    pub fn lockout_duration(&self, failed_count: u32) -> Option<Duration> {
+        if self.max_failures == 0 || failed_count < self.max_failures {
+            return None;
+        }
+        let exponent = (failed_count - self.max_failures).min(30);
+        let seconds = self
+            .base_lockout
+            .num_seconds()
+            .saturating_mul(1_i64 << exponent)
+            .min(self.max_lockout.num_seconds());
+        Some(Duration::seconds(seconds))
+    }
+}
@@ -0,0 +1,24 @@
+//domain/service/mailer.rs
+// メール送信トレイト
+// 2025/7/8
+
+use async_trait::async_trait;
+
+/// 送信するメール（テキスト形式）
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmailMessage {
+    pub to: String,
+    pub subject: String,
+    pub body: String,
+}
+
+/// メール送信
+///
+/// 実装はinfrastructure/mail配下（SMTP・ファイル出力・メモリ保持）
+#[async_trait]
+pub trait Mailer: Send + Sync {
+    async fn send(
+        &self,
+        message: &EmailMessage,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+}
@@ -0,0 +1,187 @@
+//domain/service/password_policy.rs
+// パスワードポリシー（長さ・文字種・個人情報・漏洩済みパスワード）
+// 2025/7/8
+
+use std::collections::HashSet;
+use std::fmt;
+
+/// 個人情報とみなす部分文字列の最短文字数（短すぎる名前の一部での誤検知を避ける）
+const MIN_PERSONAL_INFO_CHARS: usize = 3;
+
+/// パスワードポリシー違反の理由
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PasswordViolation {
+    TooShort { min_length: usize },
+    TooLong { max_length: usize },
+    MissingLowercase,
+    MissingUppercase,
+    MissingDigit,
+    MissingSymbol,
+    ContainsEmail,
+    ContainsName,
+    Breached,
+}
+
+impl PasswordViolation {
+    /// APIの応答で使う識別子
+    pub fn code(&self) -> &'static str {
+        match self {
+            PasswordViolation::TooShort { .. } => "too_short",
+            PasswordViolation::TooLong { .. } => "too_long",
+            PasswordViolation::MissingLowercase => "missing_lowercase",
+            PasswordViolation::MissingUppercase => "missing_uppercase",
+            PasswordViolation::MissingDigit => "missing_digit",
+            PasswordViolation::MissingSymbol => "missing_symbol",
+            PasswordViolation::ContainsEmail => "contains_email",
+            PasswordViolation::ContainsName => "contains_name",
+            PasswordViolation::Breached => "breached",
+        }
+    }
+}
+
+impl fmt::Display for PasswordViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PasswordViolation::TooShort { min_length } => {
+                write!(f, "must be at least {} characters", min_length)
+            }
+            PasswordViolation::TooLong { max_length } => {
+                write!(f, "must be at most {} characters", max_length)
+            }
+            PasswordViolation::MissingLowercase => write!(f, "must contain a lowercase letter"),
+            PasswordViolation::MissingUppercase => write!(f, "must contain an uppercase letter"),
+            PasswordViolation::MissingDigit => write!(f, "must contain a digit"),
+            PasswordViolation::MissingSymbol => write!(f, "must contain a symbol"),
+            PasswordViolation::ContainsEmail => {
+                write!(f, "must not contain the email address")
+            }
+            PasswordViolation::ContainsName => write!(f, "must not contain the user name"),
+            PasswordViolation::Breached => {
+                write!(f, "appears in a list of breached passwords")
+            }
+        }
+    }
+}
+
+/// パスワードと照合する利用者の情報（不明なものはNone）
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PasswordContext<'a> {
+    pub email: Option<&'a str>,
+    pub name: Option<&'a str>,
+}
+
+/// パスワードポリシー
+///
+/// 長さはバイト数ではなく文字数で数える。文字種はUnicodeの分類で判定するため、
+/// 全角の数字も数字として扱う。漏洩済みパスワードとの照合は大文字・小文字を区別しない。
+#[derive(Debug, Clone)]
+pub struct PasswordPolicy {
+    pub min_length: usize,
+    pub max_length: usize,
+    pub require_lowercase: bool,
+    pub require_uppercase: bool,
+    pub require_digit: bool,
+    pub require_symbol: bool,
+    /// メールアドレス・名前（3文字以上の部分）を含むパスワードを拒否する
+    pub forbid_personal_info: bool,
+    breached_passwords: HashSet<String>,
+}
+
+impl Default for PasswordPolicy {
+    fn default() -> Self {
+        Self {
+            min_length: 8,
+            max_length: 128,
+            require_lowercase: false,
+            require_uppercase: false,
+            require_digit: false,
+            require_symbol: false,
+            forbid_personal_info: true,
+            breached_passwords: HashSet::new(),
+        }
+    }
+}
+
+impl PasswordPolicy {
+    /// 漏洩済みパスワードの一覧を追加する（空行は無視する）
+    pub fn with_breached_passwords(mut self, passwords: impl IntoIterator<Item = String>) -> Self {
+        self.breached_passwords.extend(
+            passwords
+                .into_iter()
+                .map(|password| password.trim().to_lowercase())
+                .filter(|password| !password.is_empty()),
+        );
+        self
+    }
+
+    /// 違反をすべて返す（空の場合は適合）
+    pub fn check(&self, password: &str, context: &PasswordContext) -> Vec<PasswordViolation> {
+        let mut violations = Vec::new();
+        let length = password.chars().count();
+        if length < self.min_length {
+            violations.push(PasswordViolation::TooShort {
+                min_length: self.min_length,
+            });
+        }
+        if length > self.max_length {
+            violations.push(PasswordViolation::TooLong {
+                max_length: self.max_length,
+            });
+        }
+        let character_classes = [
+            (
+                self.require_lowercase,
+                char::is_lowercase as fn(char) -> bool,
+                PasswordViolation::MissingLowercase,
+            ),
+            (
+                self.require_uppercase,
+                char::is_uppercase,
+                PasswordViolation::MissingUppercase,
+            ),
+            (
+                self.require_digit,
+                char::is_numeric,
+                PasswordViolation::MissingDigit,
+            ),
+            (
+                self.require_symbol,
+                |c: char| !c.is_alphanumeric() && !c.is_whitespace(),
+                PasswordViolation::MissingSymbol,
+            ),
+        ];
+        for (required, matches, violation) in character_classes {
+            if required && !password.chars().any(matches) {
+                violations.push(violation);
+            }
+        }
+
+        let lowered = password.to_lowercase();
+        if self.forbid_personal_info {
+            if let Some(email) = context.email {
+                let local_part = email.split('@').next().unwrap_or_default();
+                if Self::contains_any(&lowered, local_part) {
+                    violations.push(PasswordViolation::ContainsEmail);
+                }
+            }
+            if let Some(name) = context.name
+                && Self::contains_any(&lowered, name)
+            {
+                violations.push(PasswordViolation::ContainsName);
+            }
+        }
+        if self.breached_passwords.contains(&lowered) {
+            violations.push(PasswordViolation::Breached);
+        }
+        violations
+    }
+
+    /// 値全体、または記号・空白で区切った各部分のいずれかを含むか
+    fn contains_any(lowered_password: &str, value: &str) -> bool {
+        let value = value.to_lowercase();
+        std::iter::once(value.as_str())
+            .chain(value.split(|c: char| !c.is_alphanumeric()))
+            .filter(|part| part.chars().count() >= MIN_PERSONAL_INFO_CHARS)
+            .any(|part| lowered_password.contains(part))
+    }
+}
@@ -0,0 +1,62 @@
+//domain/service/permission_policy.rs
+// ロール→権限のポリシーテーブル
+// 2025/7/8
+
+use crate::domain::value_object::{permission::Permission, role::Role};
+use std::collections::{BTreeSet, HashMap};
+
+/// ロールごとに許可する権限の表
+///
+/// 既定値は `user < admin < superadmin` の包含関係だが、
+/// 設定ファイルでロール単位に差し替えられる。
+#[derive(Debug, Clone, PartialEq)]
+pub struct PermissionPolicy {
+    grants: HashMap<Role, BTreeSet<Permission>>,
+}
+
+impl Default for PermissionPolicy {
+    fn default() -> Self {
+        let user = BTreeSet::from([Permission::UsersRead]);
+        let admin = BTreeSet::from([
+            Permission::UsersRead,
+            Permission::UsersWrite,
+            Permission::UsersDelete,
+            Permission::SessionsRevoke,
+            Permission::ApiKeysManage,
+            Permission::OAuthClientsManage,
+        ]);
+        let superadmin = Permission::ALL.into_iter().collect();
+        Self {
+            grants: HashMap::from([
+                (Role::User, user),
+                (Role::Admin, admin),
+                (Role::SuperAdmin, superadmin),
+            ]),
+        }
+    }
+}
+
+impl PermissionPolicy {
+    /// 指定したロールの権限を差し替える（指定のないロールは既定値のまま）
+    pub fn with_grants(
+        mut self,
+        role: Role,
+        permissions: impl IntoIterator<Item = Permission>,
+    ) -> Self {
+        self.grants.insert(role, permissions.into_iter().collect());
+        self
+    }
+
+    pub fn allows(&self, role: Role, permission: Permission) -> bool {
+        self.grants
+            .get(&role)
+            .is_some_and(|permissions| permissions.contains(&permission))
+    }
+
+    pub fn permissions(&self, role: Role) -> Vec<Permission> {
+        self.grants
+            .get(&role)
+            .map(|permissions| permissions.iter().copied().collect())
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,31 @@
+//domain/service/request_rate_policy.rs
+// メール送信を伴う要求の回数制限方針（最短間隔・1時間あたりの上限）
+// 2025/7/8
+
+use chrono::{DateTime, Duration, Utc};
+
+/// 要求の回数制限方針
+///
+/// 直近1時間の要求が`max_per_hour`未満で、最後の要求から`cooldown`経過していれば受け付ける。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RequestRatePolicy {
+    pub cooldown: Duration,
+    pub max_per_hour: u32,
+}
+
+impl RequestRatePolicy {
+    /// 回数を数える期間
+    pub fn window() -> Duration {
+        Duration::hours(1)
+    }
+
+    /// 期間内の要求日時（古い順）から、今回の要求を受け付けるか
+    pub fn allows(&self, recent: &[DateTime<Utc>], now: DateTime<Utc>) -> bool {
+        if recent.len() >= self.max_per_hour as usize {
+            return false;
+        }
+        recent
+            .last()
+            .is_none_or(|last| now - *last >= self.cooldown)
+    }
+}
@@ -1,15 +1,25 @@
 pub mod birth_date;
 pub mod email;
+pub mod oauth_grant_type;
+pub mod oidc_scope;
 pub mod pagination;
 pub mod password;
+pub mod password_hash;
+pub mod permission;
 pub mod phone;
+pub mod role;
 pub mod user_id;
 pub mod user_name;
 
 pub use birth_date::BirthDate;
 pub use email::Email;
+pub use oauth_grant_type::OAuthGrantType;
+pub use oidc_scope::OidcScope;
 pub use pagination::*;
 pub use password::Password;
+pub use password_hash::PasswordHash;
+pub use permission::Permission;
 pub use phone::Phone;
+pub use role::Role;
 pub use user_id::UserId;
 pub use user_name::UserName;
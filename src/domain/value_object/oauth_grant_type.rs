@@ -0,0 +1,49 @@
+//domain/value_object/oauth_grant_type.rs
+// OAuthGrantType バリューオブジェクト
+// 2025/7/8
+
+use crate::shared::error::domain_error::{DomainError, DomainResult};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// OAuth 2.0のグラント種別（クライアントごとに許可する）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OAuthGrantType {
+    AuthorizationCode,
+    ClientCredentials,
+    RefreshToken,
+}
+
+impl OAuthGrantType {
+    pub const ALL: [OAuthGrantType; 3] = [
+        OAuthGrantType::AuthorizationCode,
+        OAuthGrantType::ClientCredentials,
+        OAuthGrantType::RefreshToken,
+    ];
+
+    pub fn new(value: &str) -> DomainResult<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|g| g.as_str() == value)
+            .ok_or_else(|| DomainError::EntityValidationFailed {
+                entity: "OAuthClient".to_string(),
+                field: "grant_types".to_string(),
+                message: format!("Unknown grant type '{}'", value),
+            })
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OAuthGrantType::AuthorizationCode => "authorization_code",
+            OAuthGrantType::ClientCredentials => "client_credentials",
+            OAuthGrantType::RefreshToken => "refresh_token",
+        }
+    }
+}
+
+impl fmt::Display for OAuthGrantType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -0,0 +1,62 @@
+//domain/value_object/oidc_scope.rs
+// OidcScope バリューオブジェクト
+// 2025/7/8
+
+use crate::shared::error::domain_error::{DomainError, DomainResult};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// OpenID Connectの標準スコープ（権限ではなく、IDトークン・UserInfoで返すクレームを選ぶ）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OidcScope {
+    /// IDトークンの発行を要求する
+    #[serde(rename = "openid")]
+    OpenId,
+    /// `name`・`birthdate`
+    Profile,
+    /// `email`・`email_verified`
+    Email,
+    /// `phone_number`
+    Phone,
+}
+
+impl OidcScope {
+    pub const ALL: [OidcScope; 4] = [
+        OidcScope::OpenId,
+        OidcScope::Profile,
+        OidcScope::Email,
+        OidcScope::Phone,
+    ];
+
+    pub fn new(value: &str) -> DomainResult<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|s| s.as_str() == value)
+            .ok_or_else(|| DomainError::EntityValidationFailed {
+                entity: "OAuthClient".to_string(),
+                field: "scope".to_string(),
+                message: format!("Unknown OpenID Connect scope '{}'", value),
+            })
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OidcScope::OpenId => "openid",
+            OidcScope::Profile => "profile",
+            OidcScope::Email => "email",
+            OidcScope::Phone => "phone",
+        }
+    }
+
+    /// 空白区切りのスコープ文字列に含まれるか
+    pub fn is_in(&self, scope: &str) -> bool {
+        scope.split_whitespace().any(|s| s == self.as_str())
+    }
+}
+
+impl fmt::Display for OidcScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
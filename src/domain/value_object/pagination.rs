@@ -34,18 +34,27 @@ pub struct SortParams {
     pub order: SortOrder,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl SortParams {
+    /// ユーザー検索で並び替えに使える項目
+    pub const USER_FIELDS: [&'static str; 5] =
+        ["created_at", "last_login_at", "email", "name", "role"];
+
+    /// ユーザー検索で並び替える項目（SQLに埋め込むため、使えない項目は作成日時にする）
+    pub fn user_field(&self) -> &'static str {
+        Self::USER_FIELDS
+            .into_iter()
+            .find(|f| *f == self.field)
+            .unwrap_or("created_at")
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub enum SortOrder {
     Asc,
+    #[default]
     Desc,
 }
 
-impl Default for SortOrder {
-    fn default() -> Self {
-        SortOrder::Desc
-    }
-}
-
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserSearchFilters {
     pub email_domain: Option<String>,
@@ -2,17 +2,31 @@
 // Password バリューオブジェクト
 // 2025/7/8
 
+use crate::domain::service::password_policy::{PasswordContext, PasswordPolicy};
 use crate::shared::error::domain_error::{DomainError, DomainResult};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Password(pub String);
 
 impl Password {
+    /// 既定のパスワードポリシーで検証する
     pub fn new(value: String) -> DomainResult<Self> {
-        if value.len() < 8 {
-            return Err(DomainError::InvalidPassword {
-                reason: "Password must be at least 8 characters".to_string(),
-            });
+        Self::with_policy(
+            value,
+            &PasswordPolicy::default(),
+            &PasswordContext::default(),
+        )
+    }
+
+    /// 指定したポリシーで検証する（違反はすべてまとめて返す）
+    pub fn with_policy(
+        value: String,
+        policy: &PasswordPolicy,
+        context: &PasswordContext,
+    ) -> DomainResult<Self> {
+        let violations = policy.check(&value, context);
+        if !violations.is_empty() {
+            return Err(DomainError::PasswordPolicyViolation { violations });
         }
         Ok(Self(value))
     }
@@ -0,0 +1,24 @@
+//domain/value_object/password_hash.rs
+// PasswordHash バリューオブジェクト（PHC文字列形式のハッシュ）
+// 平文パスワードはPasswordで検証後、直ちにハッシュ化してこの型で保持する
+
+use crate::shared::error::domain_error::{DomainError, DomainResult};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PasswordHash(pub String);
+
+impl PasswordHash {
+    pub fn new(value: String) -> DomainResult<Self> {
+        // PHC文字列形式（$argon2id$v=19$m=...,t=...,p=...$salt$hash）の簡易チェック
+        if !value.starts_with('$') || value.split('$').count() < 4 {
+            return Err(DomainError::InvalidPassword {
+                reason: "Password hash must be a PHC string".to_string(),
+            });
+        }
+        Ok(Self(value))
+    }
+
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
@@ -0,0 +1,65 @@
+//domain/value_object/permission.rs
+// Permission バリューオブジェクト
+// 2025/7/8
+
+use crate::shared::error::domain_error::{DomainError, DomainResult};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// 操作単位の権限（`リソース:操作` 形式）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Permission {
+    #[serde(rename = "users:read")]
+    UsersRead,
+    #[serde(rename = "users:write")]
+    UsersWrite,
+    #[serde(rename = "users:delete")]
+    UsersDelete,
+    #[serde(rename = "sessions:revoke")]
+    SessionsRevoke,
+    /// 他のユーザー（サービスアカウントを含む）のAPIキーの管理
+    #[serde(rename = "api_keys:manage")]
+    ApiKeysManage,
+    /// OAuthクライアントの登録・削除
+    #[serde(rename = "oauth_clients:manage")]
+    OAuthClientsManage,
+}
+
+impl Permission {
+    pub const ALL: [Permission; 6] = [
+        Permission::UsersRead,
+        Permission::UsersWrite,
+        Permission::UsersDelete,
+        Permission::SessionsRevoke,
+        Permission::ApiKeysManage,
+        Permission::OAuthClientsManage,
+    ];
+
+    pub fn new(value: &str) -> DomainResult<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|p| p.as_str() == value)
+            .ok_or_else(|| DomainError::EntityValidationFailed {
+                entity: "PermissionPolicy".to_string(),
+                field: "permission".to_string(),
+                message: format!("Unknown permission '{}'", value),
+            })
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Permission::UsersRead => "users:read",
+            Permission::UsersWrite => "users:write",
+            Permission::UsersDelete => "users:delete",
+            Permission::SessionsRevoke => "sessions:revoke",
+            Permission::ApiKeysManage => "api_keys:manage",
+            Permission::OAuthClientsManage => "oauth_clients:manage",
+        }
+    }
+}
+
+impl fmt::Display for Permission {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -0,0 +1,48 @@
+//domain/value_object/role.rs
+// Role バリューオブジェクト
+// 2025/7/8
+
+use crate::shared::error::domain_error::{DomainError, DomainResult};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// ユーザーのロール（権限はPermissionPolicyでロールごとに定義する）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    #[default]
+    User,
+    Admin,
+    SuperAdmin,
+}
+
+impl Role {
+    pub const ALL: [Role; 3] = [Role::User, Role::Admin, Role::SuperAdmin];
+
+    pub fn new(value: &str) -> DomainResult<Self> {
+        match value {
+            "user" => Ok(Role::User),
+            "admin" => Ok(Role::Admin),
+            "superadmin" => Ok(Role::SuperAdmin),
+            _ => Err(DomainError::EntityValidationFailed {
+                entity: "User".to_string(),
+                field: "role".to_string(),
+                message: format!("Unknown role '{}'", value),
+            }),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::User => "user",
+            Role::Admin => "admin",
+            Role::SuperAdmin => "superadmin",
+        }
+    }
+}
+
+impl fmt::Display for Role {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
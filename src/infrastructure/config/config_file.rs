@@ -0,0 +1,298 @@
+//infrastructure/config/config_file.rs
+// 設定ファイル（TOML）の読み込み
+// 2025/7/8
+
+use std::collections::BTreeMap;
+
+/// 設定ファイルの解析結果
+///
+/// テーブルとキーは環境変数と同じ名前に平坦化する（`[server] port = 3000` → `SERVER_PORT`）。
+/// `[profile.<名前>]`配下はプロファイルごとの上書きとして分けて保持する。
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConfigFile {
+    pub values: BTreeMap<String, String>,
+    pub profiles: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+/// 設定ファイルの構文エラー（行番号は1始まり）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigFileError {
+    pub line: usize,
+    pub message: String,
+}
+
+/// テーブル・キーを設定キーの名前に変換する（`server.port` → `SERVER_PORT`）
+pub fn flatten_key(path: &[&str]) -> String {
+    path.iter()
+        .map(|part| part.trim().replace('-', "_").to_ascii_uppercase())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+impl ConfigFile {
+    /// TOMLを解析する
+    ///
+    /// 設定に必要な範囲（テーブル、ドット区切りのキー、文字列・整数・浮動小数点数・真偽値、
+    /// それらの配列）のみに対応する。値は環境変数と同じ文字列表現にする（配列はカンマ区切り）。
+    pub fn parse(content: &str) -> Result<Self, Vec<ConfigFileError>> {
+        let mut file = ConfigFile::default();
+        let mut errors = Vec::new();
+        let mut table: Vec<String> = Vec::new();
+        let mut lines = content.lines().enumerate();
+        while let Some((index, line)) = lines.next() {
+            let line_no = index + 1;
+            let mut statement = strip_comment(line).trim().to_string();
+            if statement.is_empty() {
+                continue;
+            }
+            if statement.starts_with('[') {
+                if statement.starts_with("[[") {
+                    errors.push(error(line_no, "arrays of tables are not supported"));
+                    continue;
+                }
+                match statement
+                    .strip_prefix('[')
+                    .and_then(|s| s.strip_suffix(']'))
+                    .map(parse_key_path)
+                {
+                    Some(Ok(path)) => table = path,
+                    Some(Err(message)) => errors.push(error(line_no, &message)),
+                    None => errors.push(error(line_no, "unterminated table header")),
+                }
+                continue;
+            }
+            // 複数行の配列は閉じ括弧まで読み進める
+            while bracket_depth(&statement) > 0 {
+                match lines.next() {
+                    Some((_, next)) => {
+                        statement.push(' ');
+                        statement.push_str(strip_comment(next).trim());
+                    }
+                    None => break,
+                }
+            }
+            let Some((key, value)) = split_assignment(&statement) else {
+                errors.push(error(line_no, "expected `key = value`"));
+                continue;
+            };
+            let key_path = match parse_key_path(key) {
+                Ok(path) => path,
+                Err(message) => {
+                    errors.push(error(line_no, &message));
+                    continue;
+                }
+            };
+            let value = match parse_value(value.trim()) {
+                Ok(value) => value,
+                Err(message) => {
+                    errors.push(error(line_no, &message));
+                    continue;
+                }
+            };
+            let mut path: Vec<&str> = table.iter().map(String::as_str).collect();
+            path.extend(key_path.iter().map(String::as_str));
+            let (target, path) = match path.as_slice() {
+                ["profile", name, rest @ ..] if !rest.is_empty() => (
+                    file.profiles.entry(name.to_string()).or_default(),
+                    rest.to_vec(),
+                ),
+                ["profile", ..] => {
+                    errors.push(error(line_no, "profile values must be in [profile.<name>]"));
+                    continue;
+                }
+                _ => (&mut file.values, path),
+            };
+            let key = flatten_key(&path);
+            if target.insert(key.clone(), value).is_some() {
+                errors.push(error(line_no, &format!("duplicate key {}", key)));
+            }
+        }
+        if errors.is_empty() {
+            Ok(file)
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+fn error(line: usize, message: &str) -> ConfigFileError {
+    ConfigFileError {
+        line,
+        message: message.to_string(),
+    }
+}
+
+/// 文字列の外にある`#`以降を取り除く
+fn strip_comment(line: &str) -> &str {
+    let mut quote = None;
+    let mut escaped = false;
+    for (i, c) in line.char_indices() {
+        match (quote, c) {
+            (Some('"'), '\\') if !escaped => {
+                escaped = true;
+                continue;
+            }
+            (Some(q), c) if c == q && !escaped => quote = None,
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '#') => return &line[..i],
+            _ => {}
+        }
+        escaped = false;
+    }
+    line
+}
+
+/// 文字列の外にある`[`と`]`の差
+fn bracket_depth(value: &str) -> i32 {
+    let Some((_, value)) = split_assignment(value) else {
+        return 0;
+    };
+    let mut depth = 0;
+    let mut quote = None;
+    let mut escaped = false;
+    for c in value.chars() {
+        match (quote, c) {
+            (Some('"'), '\\') if !escaped => {
+                escaped = true;
+                continue;
+            }
+            (Some(q), c) if c == q && !escaped => quote = None,
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '[') => depth += 1,
+            (None, ']') => depth -= 1,
+            _ => {}
+        }
+        escaped = false;
+    }
+    depth
+}
+
+fn split_assignment(statement: &str) -> Option<(&str, &str)> {
+    statement.split_once('=')
+}
+
+/// `a.b-c.d`を`["a", "b-c", "d"]`に分ける（クォートしたキーは使えない）
+fn parse_key_path(key: &str) -> Result<Vec<String>, String> {
+    key.split('.')
+        .map(|part| {
+            let part = part.trim();
+            if !part.is_empty()
+                && part
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+            {
+                Ok(part.to_string())
+            } else {
+                Err(format!("invalid key `{}`", key.trim()))
+            }
+        })
+        .collect()
+}
+
+fn parse_value(value: &str) -> Result<String, String> {
+    if let Some(inner) = value.strip_prefix('[') {
+        let inner = inner
+            .strip_suffix(']')
+            .ok_or_else(|| "unterminated array".to_string())?;
+        return split_array(inner)?
+            .iter()
+            .map(|item| parse_scalar(item.trim()))
+            .collect::<Result<Vec<_>, _>>()
+            .map(|items| items.join(","));
+    }
+    parse_scalar(value)
+}
+
+/// 配列の要素を文字列の外のカンマで分ける（末尾のカンマは許可する）
+fn split_array(inner: &str) -> Result<Vec<String>, String> {
+    let mut items = Vec::new();
+    let mut current = String::new();
+    let mut quote = None;
+    let mut escaped = false;
+    for c in inner.chars() {
+        match (quote, c) {
+            (Some('"'), '\\') if !escaped => {
+                escaped = true;
+                current.push(c);
+                continue;
+            }
+            (Some(q), c) if c == q && !escaped => quote = None,
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '[') => return Err("nested arrays are not supported".to_string()),
+            (None, ',') => {
+                items.push(std::mem::take(&mut current));
+                continue;
+            }
+            _ => {}
+        }
+        escaped = false;
+        current.push(c);
+    }
+    items.push(current);
+    if items.last().is_some_and(|item| item.trim().is_empty()) {
+        items.pop();
+    }
+    if items.iter().any(|item| item.trim().is_empty()) {
+        return Err("empty array element".to_string());
+    }
+    Ok(items)
+}
+
+fn parse_scalar(value: &str) -> Result<String, String> {
+    if let Some(inner) = value.strip_prefix('"') {
+        let inner = inner
+            .strip_suffix('"')
+            .ok_or_else(|| "unterminated string".to_string())?;
+        return unescape(inner);
+    }
+    if let Some(inner) = value.strip_prefix('\'') {
+        return inner
+            .strip_suffix('\'')
+            .map(str::to_string)
+            .ok_or_else(|| "unterminated string".to_string());
+    }
+    if value == "true" || value == "false" {
+        return Ok(value.to_string());
+    }
+    let number = value.replace('_', "");
+    if !number.is_empty() && (number.parse::<i64>().is_ok() || number.parse::<f64>().is_ok()) {
+        return Ok(number);
+    }
+    Err(format!("unsupported value `{}` (quote strings)", value))
+}
+
+fn unescape(value: &str) -> Result<String, String> {
+    let mut out = String::new();
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c == '"' {
+            return Err("unescaped quote in string".to_string());
+        }
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('"') => out.push('"'),
+            Some('\\') => out.push('\\'),
+            Some('n') => out.push('\n'),
+            Some('t') => out.push('\t'),
+            Some('r') => out.push('\r'),
+            Some('u') => {
+                let hex: String = chars.by_ref().take(4).collect();
+                let c = u32::from_str_radix(&hex, 16)
+                    .ok()
+                    .and_then(char::from_u32)
+                    .ok_or_else(|| format!("invalid unicode escape \\u{}", hex))?;
+                out.push(c);
+            }
+            other => {
+                return Err(format!(
+                    "invalid escape \\{}",
+                    other.map(String::from).unwrap_or_default()
+                ));
+            }
+        }
+    }
+    Ok(out)
+}
@@ -0,0 +1,638 @@
+//infrastructure/config/config_source.rs
+// 設定値の取得元（既定値・設定ファイル・プロファイル・環境変数・コマンドライン引数）
+// 2025/7/8
+
+use crate::infrastructure::config::config_file::{ConfigFile, ConfigFileError, flatten_key};
+use crate::shared::error::infrastructure_error::InfrastructureError;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+
+/// 設定ファイルを指定しない場合に読み込むファイル（存在する場合のみ）
+pub const DEFAULT_CONFIG_FILE: &str = "config.toml";
+
+/// `--print-config`で秘密情報の代わりに表示する値
+pub const REDACTED: &str = "<redacted>";
+
+/// 実行プロファイル
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Profile {
+    #[default]
+    Dev,
+    Test,
+    Prod,
+}
+
+impl Profile {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Profile::Dev => "dev",
+            Profile::Test => "test",
+            Profile::Prod => "prod",
+        }
+    }
+}
+
+impl FromStr for Profile {
+    type Err = String;
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "dev" | "development" => Ok(Profile::Dev),
+            "test" => Ok(Profile::Test),
+            "prod" | "production" => Ok(Profile::Prod),
+            other => Err(format!("unknown profile `{}` (dev / test / prod)", other)),
+        }
+    }
+}
+
+/// 設定値の取得元（後のものほど優先する）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ConfigOrigin {
+    File,
+    Profile,
+    Env,
+    Cli,
+}
+
+impl ConfigOrigin {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ConfigOrigin::File => "file",
+            ConfigOrigin::Profile => "profile",
+            ConfigOrigin::Env => "env",
+            ConfigOrigin::Cli => "cli",
+        }
+    }
+}
+
+/// 設定エラーの一覧（起動時にまとめて表示する）
+#[derive(Debug, Default)]
+pub struct ConfigErrors(pub Vec<InfrastructureError>);
+
+impl ConfigErrors {
+    pub fn push(&mut self, key: &str, message: impl Into<String>) {
+        self.0.push(InfrastructureError::Configuration {
+            key: key.to_string(),
+            message: message.into(),
+        });
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// エラーがなければOk
+    pub fn into_result(self) -> Result<(), ConfigErrors> {
+        if self.is_empty() { Ok(()) } else { Err(self) }
+    }
+}
+
+impl fmt::Display for ConfigErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} configuration error(s):", self.0.len())?;
+        for error in &self.0 {
+            writeln!(f, "  - {}", error)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ConfigErrors {}
+
+/// サブコマンド（指定がなければサーバーを起動する）
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CliCommand {
+    #[default]
+    Serve,
+    Migrate(MigrateCommand),
+}
+
+/// `migrate`サブコマンド
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MigrateCommand {
+    /// 各バージョンの適用状態を表示する
+    Status,
+    /// 未適用のマイグレーションを適用する
+    Up { dry_run: bool },
+    /// `target`のバージョンまで取り消す
+    Down { target: u32, dry_run: bool },
+}
+
+/// コマンドライン引数
+///
+/// `--config <path>`・`--profile <name>`・`--print-config`のほか、
+/// `--set KEY=VALUE`または`--server-port 8080`（`SERVER_PORT`）の形で任意の設定値を上書きする。
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CliArgs {
+    pub config_path: Option<PathBuf>,
+    pub profile: Option<String>,
+    pub print_config: bool,
+    pub help: bool,
+    pub overrides: Vec<(String, String)>,
+    pub command: CliCommand,
+}
+
+impl CliArgs {
+    pub const USAGE: &'static str = "\
+usage: rusted-ca [--config <path>] [--profile dev|test|prod] [--print-config]
+                 [--set KEY=VALUE]... [--<key> <value>]...
+       rusted-ca [options] migrate status
+       rusted-ca [options] migrate up [--dry-run]
+       rusted-ca [options] migrate down <version> [--dry-run]
+
+  --config <path>     TOML config file (default: APP_CONFIG, or ./config.toml if present)
+  --profile <name>    profile overlay from [profile.<name>] (default: APP_PROFILE, or dev)
+  --print-config      print the effective configuration with secrets redacted and exit
+  --set KEY=VALUE     override a value (KEY as env var name or dotted, e.g. server.port)
+  --<key> <value>     same as --set, e.g. --server-port 8080 sets SERVER_PORT
+  --dry-run           with migrate up/down: print the migrations without applying them
+";
+
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, ConfigErrors> {
+        let mut cli = CliArgs::default();
+        let mut errors = ConfigErrors::default();
+        let mut positional = Vec::new();
+        let mut dry_run = false;
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let Some(flag) = arg.strip_prefix("--") else {
+                if arg == "-h" {
+                    cli.help = true;
+                } else {
+                    positional.push(arg);
+                }
+                continue;
+            };
+            let (name, inline_value) = match flag.split_once('=') {
+                Some((name, value)) => (name, Some(value.to_string())),
+                None => (flag, None),
+            };
+            match name {
+                "help" => cli.help = true,
+                "print-config" => cli.print_config = true,
+                "dry-run" => dry_run = true,
+                _ => {
+                    let Some(value) = inline_value.or_else(|| args.next()) else {
+                        errors.push("cli", format!("--{} requires a value", name));
+                        continue;
+                    };
+                    match name {
+                        "config" => cli.config_path = Some(PathBuf::from(value)),
+                        "profile" => cli.profile = Some(value),
+                        "set" => match value.split_once('=') {
+                            Some((key, value)) => cli
+                                .overrides
+                                .push((Self::override_key(key), value.to_string())),
+                            None => {
+                                errors.push("cli", format!("--set expects KEY=VALUE: {}", value))
+                            }
+                        },
+                        key => cli.overrides.push((Self::override_key(key), value)),
+                    }
+                }
+            }
+        }
+        match Self::parse_command(&positional, dry_run) {
+            Ok(command) => cli.command = command,
+            Err(message) => errors.push("cli", message),
+        }
+        errors.into_result().map(|_| cli)
+    }
+
+    fn parse_command(positional: &[String], dry_run: bool) -> Result<CliCommand, String> {
+        let args: Vec<&str> = positional.iter().map(String::as_str).collect();
+        let command = match args.as_slice() {
+            [] => CliCommand::Serve,
+            ["migrate", "status"] => CliCommand::Migrate(MigrateCommand::Status),
+            ["migrate", "up"] => CliCommand::Migrate(MigrateCommand::Up { dry_run }),
+            ["migrate", "down", target] => {
+                let target = target
+                    .parse()
+                    .map_err(|_| format!("migrate down expects a version number: {}", target))?;
+                CliCommand::Migrate(MigrateCommand::Down { target, dry_run })
+            }
+            ["migrate", "down"] => return Err("migrate down requires a version".to_string()),
+            ["migrate", ..] => {
+                return Err(format!(
+                    "unknown migrate command `{}` (expected status, up or down)",
+                    args[1..].join(" ")
+                ));
+            }
+            [arg, ..] => return Err(format!("unexpected argument `{}`", arg)),
+        };
+        if dry_run
+            && matches!(
+                command,
+                CliCommand::Serve | CliCommand::Migrate(MigrateCommand::Status)
+            )
+        {
+            return Err("--dry-run is only valid with migrate up/down".to_string());
+        }
+        Ok(command)
+    }
+
+    fn override_key(key: &str) -> String {
+        flatten_key(&key.split('.').collect::<Vec<_>>())
+    }
+}
+
+/// 設定値（取得元付き）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigValue {
+    pub value: String,
+    pub origin: ConfigOrigin,
+}
+
+/// 層を重ねた設定値
+///
+/// 優先順位は 既定値（各設定の型が持つ） < 設定ファイル < プロファイル < 環境変数 < コマンドライン引数
+#[derive(Debug, Clone, Default)]
+pub struct ConfigSource {
+    pub profile: Profile,
+    pub file: Option<PathBuf>,
+    values: BTreeMap<String, ConfigValue>,
+}
+
+impl ConfigSource {
+    /// 値のない状態から作る（テストなど）
+    pub fn new(profile: Profile) -> Self {
+        Self {
+            profile,
+            ..Default::default()
+        }
+    }
+
+    /// 環境変数のみ（設定ファイル・コマンドライン引数を使わない場合）
+    pub fn from_env() -> Self {
+        let profile = std::env::var("APP_PROFILE")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or_default();
+        let mut source = Self::new(profile);
+        source.extend(std::env::vars(), ConfigOrigin::Env);
+        source
+    }
+
+    /// コマンドライン引数・環境変数から設定ファイルとプロファイルを決めて読み込む
+    ///
+    /// 設定ファイルは`--config` > `APP_CONFIG` > `./config.toml`（存在する場合のみ）、
+    /// プロファイルは`--profile` > `APP_PROFILE` > `dev`の順に決める
+    pub fn load(cli: &CliArgs) -> Result<Self, ConfigErrors> {
+        let mut errors = ConfigErrors::default();
+        let profile = cli
+            .profile
+            .clone()
+            .or_else(|| std::env::var("APP_PROFILE").ok())
+            .map(|v| v.parse::<Profile>())
+            .transpose()
+            .unwrap_or_else(|message| {
+                errors.push("APP_PROFILE", message);
+                None
+            })
+            .unwrap_or_default();
+        let file = cli
+            .config_path
+            .clone()
+            .or_else(|| std::env::var("APP_CONFIG").ok().map(PathBuf::from))
+            .or_else(|| {
+                let default = PathBuf::from(DEFAULT_CONFIG_FILE);
+                default.exists().then_some(default)
+            });
+        let mut source = Self::new(profile);
+        if let Some(path) = file
+            && let Err(file_errors) = source.read_file(&path)
+        {
+            errors.0.extend(file_errors.0);
+        }
+        source.extend(std::env::vars(), ConfigOrigin::Env);
+        source.extend(cli.overrides.iter().cloned(), ConfigOrigin::Cli);
+        errors.into_result().map(|_| source)
+    }
+
+    /// 設定ファイルを読み込む（構文エラーは行番号付きで全て返す）
+    pub fn read_file(&mut self, path: &Path) -> Result<(), ConfigErrors> {
+        let mut errors = ConfigErrors::default();
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) => {
+                errors.push(
+                    "APP_CONFIG",
+                    format!("cannot read {}: {}", path.display(), e),
+                );
+                return Err(errors);
+            }
+        };
+        self.file = Some(path.to_path_buf());
+        self.apply_file(&content).map_err(|file_errors| {
+            for e in file_errors {
+                errors.push(
+                    "APP_CONFIG",
+                    format!("{}:{}: {}", path.display(), e.line, e.message),
+                );
+            }
+            errors
+        })
+    }
+
+    /// 設定ファイルの内容を重ねる（共通の値と、現在のプロファイルの上書き）
+    pub fn apply_file(&mut self, content: &str) -> Result<(), Vec<ConfigFileError>> {
+        let config_file = ConfigFile::parse(content)?;
+        let mut errors = Vec::new();
+        for name in config_file.profiles.keys() {
+            if let Err(message) = name.parse::<Profile>() {
+                errors.push(ConfigFileError {
+                    line: 0,
+                    message: format!("[profile.{}]: {}", name, message),
+                });
+            }
+        }
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+        self.extend(config_file.values, ConfigOrigin::File);
+        let profile = self.profile;
+        let overlay = config_file
+            .profiles
+            .into_iter()
+            .filter(|(name, _)| name.parse::<Profile>().ok() == Some(profile))
+            .flat_map(|(_, values)| values)
+            .collect::<Vec<_>>();
+        self.extend(overlay, ConfigOrigin::Profile);
+        Ok(())
+    }
+
+    /// 値を追加する（同じキーは上書きする）
+    pub fn extend(
+        &mut self,
+        values: impl IntoIterator<Item = (String, String)>,
+        origin: ConfigOrigin,
+    ) {
+        for (key, value) in values {
+            self.values.insert(key, ConfigValue { value, origin });
+        }
+    }
+
+    /// 値を1つ設定する（テストなど）
+    pub fn with_value(mut self, key: &str, value: &str, origin: ConfigOrigin) -> Self {
+        self.extend([(key.to_string(), value.to_string())], origin);
+        self
+    }
+
+    pub fn get(&self, key: &str) -> Option<&ConfigValue> {
+        self.values.get(key)
+    }
+}
+
+/// 読み取った設定値（`--print-config`の出力用）
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ConfigEntry {
+    /// 実際に使う値（未設定の場合は既定値、既定値もない場合はNone）
+    value: Option<String>,
+    /// Noneは既定値
+    origin: Option<ConfigOrigin>,
+    secret: bool,
+}
+
+/// 設定値の読み取り
+///
+/// 不正な値はエラーとして記録して既定値で続け、最後にまとめて返す。
+/// 読み取ったキーを記録し、設定ファイル・コマンドライン引数の未知のキーを検出する。
+pub struct ConfigReader<'a> {
+    source: &'a ConfigSource,
+    entries: RefCell<BTreeMap<String, ConfigEntry>>,
+    errors: RefCell<ConfigErrors>,
+}
+
+impl<'a> ConfigReader<'a> {
+    pub fn new(source: &'a ConfigSource) -> Self {
+        Self {
+            source,
+            entries: RefCell::new(BTreeMap::new()),
+            errors: RefCell::new(ConfigErrors::default()),
+        }
+    }
+
+    pub fn profile(&self) -> Profile {
+        self.source.profile
+    }
+
+    /// 前後の空白を除いた値（空文字は未設定として扱う）
+    fn lookup(&self, key: &str) -> Option<(String, ConfigOrigin)> {
+        self.source
+            .get(key)
+            .map(|v| (v.value.trim().to_string(), v.origin))
+            .filter(|(value, _)| !value.is_empty())
+    }
+
+    fn record(&self, key: &str, value: Option<String>, origin: Option<ConfigOrigin>, secret: bool) {
+        self.entries.borrow_mut().insert(
+            key.to_string(),
+            ConfigEntry {
+                value,
+                origin,
+                secret,
+            },
+        );
+    }
+
+    /// `--print-config`に表示する値を差し替える（接続文字列のパスワードを伏せる場合など）
+    pub fn mask(&self, key: &str, shown: String) {
+        if let Some(entry) = self.entries.borrow_mut().get_mut(key) {
+            entry.value = Some(shown);
+        }
+    }
+
+    pub fn error(&self, key: &str, message: impl Into<String>) {
+        self.errors.borrow_mut().push(key, message);
+    }
+
+    /// 任意の文字列
+    pub fn optional(&self, key: &str) -> Option<String> {
+        let found = self.lookup(key);
+        self.record(
+            key,
+            found.as_ref().map(|(v, _)| v.clone()),
+            found.as_ref().map(|(_, o)| *o),
+            false,
+        );
+        found.map(|(value, _)| value)
+    }
+
+    /// 秘密情報（`--print-config`では伏せる）
+    pub fn secret(&self, key: &str) -> Option<String> {
+        let found = self.lookup(key);
+        self.record(
+            key,
+            found.as_ref().map(|(v, _)| v.clone()),
+            found.as_ref().map(|(_, o)| *o),
+            true,
+        );
+        found.map(|(value, _)| value)
+    }
+
+    /// 文字列（未設定の場合は既定値）
+    pub fn string(&self, key: &str, default: &str) -> String {
+        self.parse(key, default, |v| Ok(v.to_string()))
+    }
+
+    /// 値を解釈する（未設定の場合は`default`を同じ規則で解釈する）
+    pub fn parse<T>(
+        &self,
+        key: &str,
+        default: &str,
+        parse: impl Fn(&str) -> Result<T, String>,
+    ) -> T {
+        let found = self.lookup(key);
+        let (raw, origin) = match &found {
+            Some((value, origin)) => (value.as_str(), Some(*origin)),
+            None => (default, None),
+        };
+        self.record(key, Some(raw.to_string()), origin, false);
+        parse(raw).unwrap_or_else(|message| {
+            self.error(key, message);
+            parse(default).expect("default configuration values are valid")
+        })
+    }
+
+    /// 任意の値を解釈する（未設定の場合はNone）
+    pub fn parse_optional<T>(
+        &self,
+        key: &str,
+        parse: impl Fn(&str) -> Result<T, String>,
+    ) -> Option<T> {
+        let value = self.optional(key)?;
+        parse(&value)
+            .map_err(|message| self.error(key, message))
+            .ok()
+    }
+
+    /// 数値
+    pub fn number<T>(&self, key: &str, default: T) -> T
+    where
+        T: FromStr + fmt::Display,
+        T::Err: fmt::Display,
+    {
+        self.parse(key, &default.to_string(), |v| {
+            v.parse::<T>()
+                .map_err(|e| format!("invalid number `{}`: {}", v, e))
+        })
+    }
+
+    /// 1以上の数値
+    pub fn positive<T>(&self, key: &str, default: T) -> T
+    where
+        T: FromStr + fmt::Display + PartialOrd + Default,
+        T::Err: fmt::Display,
+    {
+        self.parse(key, &default.to_string(), |v| {
+            let value = v
+                .parse::<T>()
+                .map_err(|e| format!("invalid number `{}`: {}", v, e))?;
+            if value > T::default() {
+                Ok(value)
+            } else {
+                Err(format!("must be greater than 0: {}", v))
+            }
+        })
+    }
+
+    /// 秒数（1以上）
+    pub fn seconds(&self, key: &str, default: Duration) -> Duration {
+        Duration::from_secs(self.positive(key, default.as_secs()))
+    }
+
+    /// 真偽値（true/false・1/0・yes/no・on/off）
+    pub fn flag(&self, key: &str, default: bool) -> bool {
+        self.parse(key, &default.to_string(), |v| {
+            match v.to_ascii_lowercase().as_str() {
+                "true" | "1" | "yes" | "on" => Ok(true),
+                "false" | "0" | "no" | "off" => Ok(false),
+                _ => Err(format!("expected true or false: {}", v)),
+            }
+        })
+    }
+
+    /// カンマ区切りの一覧（未設定の場合は既定値、空文字は空の一覧、空の要素は除く）
+    pub fn list(&self, key: &str, default: &[&str]) -> Vec<String> {
+        let (raw, origin) = match self.source.get(key) {
+            Some(value) => (value.value.trim().to_string(), Some(value.origin)),
+            None => (default.join(","), None),
+        };
+        self.record(key, Some(raw.clone()), origin, false);
+        raw.split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// 読み取りを終え、`--print-config`用の一覧とエラーを返す
+    ///
+    /// 設定ファイル・プロファイル・コマンドライン引数で指定したのに読み取られなかったキーは
+    /// 綴り間違いとみなしてエラーにする（環境変数は無関係なものを含むため対象外）
+    pub fn finish(self) -> (ConfigReport, ConfigErrors) {
+        let entries = self.entries.into_inner();
+        let mut errors = self.errors.into_inner();
+        for (key, value) in &self.source.values {
+            if value.origin != ConfigOrigin::Env && !entries.contains_key(key) {
+                errors.push(
+                    key,
+                    format!("unknown configuration key (from {})", value.origin.as_str()),
+                );
+            }
+        }
+        let report = ConfigReport {
+            profile: self.source.profile,
+            file: self.source.file.clone(),
+            entries,
+        };
+        (report, errors)
+    }
+}
+
+/// 実際に使う設定値の一覧（`--print-config`）
+#[derive(Debug, Clone)]
+pub struct ConfigReport {
+    pub profile: Profile,
+    pub file: Option<PathBuf>,
+    entries: BTreeMap<String, ConfigEntry>,
+}
+
+impl ConfigReport {
+    /// キーの値（秘密情報は伏せた値、未設定はNone）
+    pub fn value(&self, key: &str) -> Option<String> {
+        let entry = self.entries.get(key)?;
+        let value = entry.value.as_ref()?;
+        Some(if entry.secret {
+            REDACTED.to_string()
+        } else {
+            value.clone()
+        })
+    }
+}
+
+/// 設定ファイルとしてそのまま使えるTOML形式で出力する（取得元はコメント）
+impl fmt::Display for ConfigReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# profile: {}", self.profile.as_str())?;
+        match &self.file {
+            Some(file) => writeln!(f, "# config file: {}", file.display())?,
+            None => writeln!(f, "# config file: (none)")?,
+        }
+        for key in self.entries.keys() {
+            let origin = self.entries[key]
+                .origin
+                .map(|o| o.as_str())
+                .unwrap_or("default");
+            let name = key.to_ascii_lowercase();
+            match self.value(key) {
+                Some(value) => writeln!(f, "{} = {:?}  # {}", name, value, origin)?,
+                None => writeln!(f, "# {} = (unset)", name)?,
+            }
+        }
+        Ok(())
+    }
+}
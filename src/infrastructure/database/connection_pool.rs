@@ -0,0 +1,158 @@
+//infrastructure/database/connection_pool.rs
+// SQLite接続プール（取得待ちのタイムアウト・利用状況のメトリクス）
+// 2025/7/8
+
+use crate::shared::error::infrastructure_error::{InfrastructureError, InfrastructureResult};
+use crate::shared::metrics::types::PoolMetricsSnapshot;
+use rusqlite::Connection;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+use tokio::sync::Semaphore;
+use tokio::task;
+
+/// 同じ用途（書き込み・読み取り）の接続のプール
+///
+/// 接続は`spawn_blocking`のスレッドで使い、処理が終わると（パニックした場合も）プールに戻す。
+/// 空きがない場合は`acquire_timeout`まで待ち、超えた場合はエラーにする。
+#[derive(Debug)]
+pub struct ConnectionPool {
+    name: &'static str,
+    size: usize,
+    acquire_timeout: Duration,
+    idle: Mutex<Vec<Connection>>,
+    permits: Arc<Semaphore>,
+    waiting: AtomicU64,
+    acquired_total: AtomicU64,
+    timeouts_total: AtomicU64,
+    wait_micros_total: AtomicU64,
+}
+
+impl ConnectionPool {
+    pub fn new(
+        name: &'static str,
+        connections: Vec<Connection>,
+        acquire_timeout: Duration,
+    ) -> Self {
+        Self {
+            name,
+            size: connections.len(),
+            acquire_timeout,
+            permits: Arc::new(Semaphore::new(connections.len())),
+            idle: Mutex::new(connections),
+            waiting: AtomicU64::new(0),
+            acquired_total: AtomicU64::new(0),
+            timeouts_total: AtomicU64::new(0),
+            wait_micros_total: AtomicU64::new(0),
+        }
+    }
+
+    /// 接続を1つ取得して`f`を実行する
+    ///
+    /// SQLのエラー・`f`のパニック・取得待ちのタイムアウトは`InfrastructureError`として返す
+    pub async fn run<F, R>(self: &Arc<Self>, f: F) -> InfrastructureResult<R>
+    where
+        F: FnOnce(&mut Connection) -> rusqlite::Result<R> + Send + 'static,
+        R: Send + 'static,
+    {
+        let started = Instant::now();
+        self.waiting.fetch_add(1, Ordering::Relaxed);
+        let acquired =
+            tokio::time::timeout(self.acquire_timeout, self.permits.clone().acquire_owned()).await;
+        self.waiting.fetch_sub(1, Ordering::Relaxed);
+        self.wait_micros_total.fetch_add(
+            u64::try_from(started.elapsed().as_micros()).unwrap_or(u64::MAX),
+            Ordering::Relaxed,
+        );
+        let permit = match acquired {
+            Ok(Ok(permit)) => permit,
+            Ok(Err(_)) => {
+                return Err(InfrastructureError::DatabaseConnection {
+                    message: format!("{} pool is closed", self.name),
+                });
+            }
+            Err(_) => {
+                self.timeouts_total.fetch_add(1, Ordering::Relaxed);
+                return Err(InfrastructureError::Timeout {
+                    service: format!("sqlite {} pool", self.name),
+                    timeout_ms: u64::try_from(self.acquire_timeout.as_millis()).unwrap_or(u64::MAX),
+                });
+            }
+        };
+        self.acquired_total.fetch_add(1, Ordering::Relaxed);
+
+        // 呼び出し元が待つのをやめても接続がプールに戻るよう、返却までをブロッキングタスク内で行う
+        let pool = Arc::clone(self);
+        task::spawn_blocking(move || {
+            let mut conn = pool.take()?;
+            let result = panic::catch_unwind(AssertUnwindSafe(|| f(&mut conn)));
+            // 途中で終わったトランザクションは取り消してから戻す
+            if !conn.is_autocommit() {
+                let _ = conn.execute_batch("ROLLBACK");
+            }
+            pool.give_back(conn);
+            drop(permit);
+            match result {
+                Ok(result) => result.map_err(|e| InfrastructureError::DatabaseQuery {
+                    query: format!("{} connection", pool.name),
+                    message: e.to_string(),
+                }),
+                Err(payload) => Err(InfrastructureError::DatabaseTransaction {
+                    message: format!(
+                        "database operation panicked on {} connection: {}",
+                        pool.name,
+                        panic_message(payload.as_ref())
+                    ),
+                }),
+            }
+        })
+        .await
+        .map_err(|e| InfrastructureError::DatabaseConnection {
+            message: format!("database task failed: {}", e),
+        })?
+    }
+
+    /// 現在の利用状況
+    pub fn metrics(&self) -> PoolMetricsSnapshot {
+        let idle = self.permits.available_permits();
+        PoolMetricsSnapshot {
+            name: self.name.to_string(),
+            size: self.size as u64,
+            idle: idle as u64,
+            in_use: self.size.saturating_sub(idle) as u64,
+            waiting: self.waiting.load(Ordering::Relaxed),
+            acquired_total: self.acquired_total.load(Ordering::Relaxed),
+            timeouts_total: self.timeouts_total.load(Ordering::Relaxed),
+            wait_micros_total: self.wait_micros_total.load(Ordering::Relaxed),
+        }
+    }
+
+    fn take(&self) -> InfrastructureResult<Connection> {
+        self.idle
+            .lock()
+            .map_err(|_| InfrastructureError::DatabaseConnection {
+                message: format!("{} pool lock is poisoned", self.name),
+            })?
+            .pop()
+            .ok_or_else(|| InfrastructureError::DatabaseConnection {
+                message: format!("{} pool has no idle connection", self.name),
+            })
+    }
+
+    fn give_back(&self, conn: Connection) {
+        // 一覧の操作中にパニックすることはないため、ロックが汚染されていても一覧はそのまま使える
+        self.idle
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(conn);
+    }
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> &str {
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown panic")
+}
@@ -0,0 +1,481 @@
+//infrastructure/database/migration.rs
+// スキーマのマイグレーション（バージョン管理・チェックサム検証）
+// 2025/7/8
+
+use crate::infrastructure::config::app_config::{DatabaseConfig, DatabaseUrl};
+use crate::infrastructure::config::config_source::MigrateCommand;
+#[cfg(feature = "postgres")]
+use crate::infrastructure::database::postgres_connection::PostgresConnection;
+use crate::infrastructure::database::sqlite_connection::SqliteConnection;
+use crate::shared::error::infrastructure_error::{InfrastructureError, InfrastructureResult};
+use rusqlite::{Connection, params};
+use sha2::{Digest, Sha256};
+use std::fmt;
+use std::path::Path;
+
+/// マイグレーション（SQLはバイナリに埋め込む）
+///
+/// 適用済みのマイグレーションは書き換えず、変更は新しいバージョンとして追加すること
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Migration {
+    pub version: u32,
+    pub name: &'static str,
+    pub up: &'static str,
+    pub down: &'static str,
+}
+
+impl Migration {
+    /// 適用後にSQLが書き換えられていないか確認するためのチェックサム（upのSHA-256）
+    pub fn checksum(&self) -> String {
+        format!("{:x}", Sha256::digest(self.up.as_bytes()))
+    }
+}
+
+/// バイナリに埋め込んだマイグレーション（バージョン順）
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        name: "initial_schema",
+        up: include_str!("migrations/0001_initial_schema.up.sql"),
+        down: include_str!("migrations/0001_initial_schema.down.sql"),
+    },
+    Migration {
+        version: 2,
+        name: "session_invalidations",
+        up: include_str!("migrations/0002_session_invalidations.up.sql"),
+        down: include_str!("migrations/0002_session_invalidations.down.sql"),
+    },
+    Migration {
+        version: 3,
+        name: "password_reset_requests",
+        up: include_str!("migrations/0003_password_reset_requests.up.sql"),
+        down: include_str!("migrations/0003_password_reset_requests.down.sql"),
+    },
+];
+
+/// マイグレーションの状態
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MigrationState {
+    /// 適用済み
+    Applied { applied_at: String },
+    /// 未適用
+    Pending,
+    /// 適用後にSQLが書き換えられている
+    Modified { applied_at: String },
+    /// データベースにのみ記録がある（より新しいバイナリで適用された）
+    Unknown { applied_at: String },
+}
+
+/// バージョンごとの状態（`migrate status`）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationStatus {
+    pub version: u32,
+    pub name: String,
+    pub state: MigrationState,
+}
+
+impl fmt::Display for MigrationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (state, applied_at) = match &self.state {
+            MigrationState::Applied { applied_at } => ("applied", applied_at.as_str()),
+            MigrationState::Pending => ("pending", ""),
+            MigrationState::Modified { applied_at } => ("modified", applied_at.as_str()),
+            MigrationState::Unknown { applied_at } => ("unknown", applied_at.as_str()),
+        };
+        write!(
+            f,
+            "{:04}  {:<24} {:<9} {}",
+            self.version, self.name, state, applied_at
+        )
+    }
+}
+
+/// 適用済みのマイグレーション（`schema_migrations`の行）
+pub(crate) struct AppliedMigration {
+    pub(crate) version: u32,
+    pub(crate) name: String,
+    pub(crate) checksum: String,
+    pub(crate) applied_at: String,
+}
+
+/// マイグレーションの実行
+///
+/// 適用したバージョンは`schema_migrations`に記録し、1件ずつトランザクション内で適用する。
+/// データベースの方が新しい場合・適用済みのSQLが書き換えられている場合は何もせずエラーにする。
+pub struct Migrator {
+    migrations: Vec<Migration>,
+}
+
+impl Migrator {
+    /// バージョン順で重複のないマイグレーションの一覧から作る
+    pub fn new(migrations: Vec<Migration>) -> InfrastructureResult<Self> {
+        for pair in migrations.windows(2) {
+            if pair[0].version >= pair[1].version {
+                return Err(InfrastructureError::SchemaMigration {
+                    version: pair[1].version,
+                    message: "migrations must be ordered by strictly increasing version"
+                        .to_string(),
+                });
+            }
+        }
+        Ok(Self { migrations })
+    }
+
+    /// バイナリに埋め込んだマイグレーション
+    pub fn embedded() -> Self {
+        Self {
+            migrations: MIGRATIONS.to_vec(),
+        }
+    }
+
+    /// バイナリが対応する最新のバージョン
+    pub fn latest_version(&self) -> u32 {
+        self.migrations.last().map(|m| m.version).unwrap_or(0)
+    }
+
+    /// 全バージョンの状態（`schema_migrations`がなければ全て未適用）
+    pub fn status(&self, conn: &Connection) -> InfrastructureResult<Vec<MigrationStatus>> {
+        Ok(self.statuses(Self::applied(conn)?))
+    }
+
+    /// 適用済みの記録と照合した全バージョンの状態
+    pub(crate) fn statuses(&self, applied: Vec<AppliedMigration>) -> Vec<MigrationStatus> {
+        let mut statuses: Vec<MigrationStatus> = self
+            .migrations
+            .iter()
+            .map(|migration| {
+                let state = match applied.iter().find(|a| a.version == migration.version) {
+                    None => MigrationState::Pending,
+                    Some(a) if a.checksum == migration.checksum() => MigrationState::Applied {
+                        applied_at: a.applied_at.clone(),
+                    },
+                    Some(a) => MigrationState::Modified {
+                        applied_at: a.applied_at.clone(),
+                    },
+                };
+                MigrationStatus {
+                    version: migration.version,
+                    name: migration.name.to_string(),
+                    state,
+                }
+            })
+            .collect();
+        statuses.extend(
+            applied
+                .into_iter()
+                .filter(|a| !self.migrations.iter().any(|m| m.version == a.version))
+                .map(|a| MigrationStatus {
+                    version: a.version,
+                    name: a.name,
+                    state: MigrationState::Unknown {
+                        applied_at: a.applied_at,
+                    },
+                }),
+        );
+        statuses.sort_by_key(|s| s.version);
+        statuses
+    }
+
+    /// 未適用のマイグレーション（データベースが新しい・書き換えがある場合はエラー）
+    pub fn pending(&self, conn: &Connection) -> InfrastructureResult<Vec<Migration>> {
+        self.plan_up(&self.status(conn)?)
+    }
+
+    /// 状態から未適用のマイグレーションを求める（データベースが新しい・書き換えがある場合はエラー）
+    pub(crate) fn plan_up(
+        &self,
+        statuses: &[MigrationStatus],
+    ) -> InfrastructureResult<Vec<Migration>> {
+        if let Some(newest) = statuses
+            .iter()
+            .rev()
+            .find(|s| matches!(s.state, MigrationState::Unknown { .. }))
+        {
+            return Err(InfrastructureError::SchemaMigration {
+                version: newest.version,
+                message: format!(
+                    "database schema is newer than this binary supports (latest known version {})",
+                    self.latest_version()
+                ),
+            });
+        }
+        if let Some(modified) = statuses
+            .iter()
+            .find(|s| matches!(s.state, MigrationState::Modified { .. }))
+        {
+            return Err(InfrastructureError::SchemaMigration {
+                version: modified.version,
+                message: format!(
+                    "checksum of applied migration '{}' does not match",
+                    modified.name
+                ),
+            });
+        }
+        Ok(self
+            .migrations
+            .iter()
+            .filter(|m| {
+                statuses
+                    .iter()
+                    .any(|s| s.version == m.version && s.state == MigrationState::Pending)
+            })
+            .copied()
+            .collect())
+    }
+
+    /// 未適用のマイグレーションがないことを確認する（自動適用しない場合の起動時の検証）
+    pub fn ensure_up_to_date(&self, conn: &Connection) -> InfrastructureResult<()> {
+        match self.pending(conn)?.first() {
+            None => Ok(()),
+            Some(first) => Err(InfrastructureError::SchemaMigration {
+                version: first.version,
+                message: "database has pending migrations (run `migrate up`)".to_string(),
+            }),
+        }
+    }
+
+    /// 未適用のマイグレーションを順に適用する（`dry_run`では適用せず対象のみ返す）
+    pub fn migrate_up(
+        &self,
+        conn: &mut Connection,
+        dry_run: bool,
+    ) -> InfrastructureResult<Vec<Migration>> {
+        let pending = self.pending(conn)?;
+        if dry_run {
+            return Ok(pending);
+        }
+        Self::create_table(conn)?;
+        for migration in &pending {
+            let error = |e: rusqlite::Error| InfrastructureError::SchemaMigration {
+                version: migration.version,
+                message: e.to_string(),
+            };
+            let tx = conn.transaction().map_err(error)?;
+            tx.execute_batch(migration.up).map_err(error)?;
+            tx.execute(
+                "INSERT INTO schema_migrations (version, name, checksum, applied_at)
+                 VALUES (?1, ?2, ?3, ?4)",
+                params![
+                    migration.version,
+                    migration.name,
+                    migration.checksum(),
+                    chrono::Utc::now().to_rfc3339()
+                ],
+            )
+            .map_err(error)?;
+            tx.commit().map_err(error)?;
+        }
+        Ok(pending)
+    }
+
+    /// `target`より新しい適用済みのマイグレーションを新しい順に取り消す
+    /// （`dry_run`では取り消さず対象のみ返す）
+    pub fn migrate_down(
+        &self,
+        conn: &mut Connection,
+        target: u32,
+        dry_run: bool,
+    ) -> InfrastructureResult<Vec<Migration>> {
+        let rollback = self.plan_down(&self.pending(conn)?, target);
+        if dry_run {
+            return Ok(rollback);
+        }
+        for migration in &rollback {
+            let error = |e: rusqlite::Error| InfrastructureError::SchemaMigration {
+                version: migration.version,
+                message: e.to_string(),
+            };
+            let tx = conn.transaction().map_err(error)?;
+            tx.execute_batch(migration.down).map_err(error)?;
+            tx.execute(
+                "DELETE FROM schema_migrations WHERE version = ?1",
+                params![migration.version],
+            )
+            .map_err(error)?;
+            tx.commit().map_err(error)?;
+        }
+        Ok(rollback)
+    }
+
+    /// `target`より新しい適用済みのマイグレーション（新しい順）
+    pub(crate) fn plan_down(&self, pending: &[Migration], target: u32) -> Vec<Migration> {
+        self.migrations
+            .iter()
+            .rev()
+            .filter(|m| m.version > target && !pending.contains(m))
+            .copied()
+            .collect()
+    }
+
+    /// `migrate`サブコマンドを実行し、表示する内容を返す
+    pub fn run(
+        &self,
+        conn: &mut Connection,
+        command: MigrateCommand,
+    ) -> InfrastructureResult<String> {
+        Ok(match command {
+            MigrateCommand::Status => self.render_status(&self.status(conn)?),
+            MigrateCommand::Up { dry_run } => render_changes(
+                &self.migrate_up(conn, dry_run)?,
+                if dry_run { "would apply" } else { "applied" },
+            ),
+            MigrateCommand::Down { target, dry_run } => render_changes(
+                &self.migrate_down(conn, target, dry_run)?,
+                if dry_run {
+                    "would roll back"
+                } else {
+                    "rolled back"
+                },
+            ),
+        })
+    }
+
+    /// `migrate status`の表示
+    pub(crate) fn render_status(&self, statuses: &[MigrationStatus]) -> String {
+        let current = statuses
+            .iter()
+            .filter(|s| !matches!(s.state, MigrationState::Pending))
+            .map(|s| s.version)
+            .max()
+            .unwrap_or(0);
+        let mut output = format!(
+            "schema version: {} (latest: {})\n",
+            current,
+            self.latest_version()
+        );
+        for status in statuses {
+            output.push_str(&format!("{}\n", status));
+        }
+        output
+    }
+
+    fn create_table(conn: &Connection) -> InfrastructureResult<()> {
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS schema_migrations (
+                version INTEGER PRIMARY KEY,
+                name TEXT NOT NULL,
+                checksum TEXT NOT NULL,
+                applied_at DATETIME NOT NULL
+            )",
+        )
+        .map_err(|e| InfrastructureError::SchemaMigration {
+            version: 0,
+            message: e.to_string(),
+        })
+    }
+
+    fn applied(conn: &Connection) -> InfrastructureResult<Vec<AppliedMigration>> {
+        let error = |e: rusqlite::Error| InfrastructureError::DatabaseQuery {
+            query: "schema_migrations".to_string(),
+            message: e.to_string(),
+        };
+        let exists: bool = conn
+            .query_row(
+                "SELECT EXISTS (SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'schema_migrations')",
+                [],
+                |row| row.get(0),
+            )
+            .map_err(error)?;
+        if !exists {
+            return Ok(Vec::new());
+        }
+        let mut stmt = conn
+            .prepare(
+                "SELECT version, name, checksum, applied_at FROM schema_migrations ORDER BY version",
+            )
+            .map_err(error)?;
+        let rows = stmt
+            .query_map([], |row| {
+                Ok(AppliedMigration {
+                    version: row.get(0)?,
+                    name: row.get(1)?,
+                    checksum: row.get(2)?,
+                    applied_at: row.get(3)?,
+                })
+            })
+            .map_err(error)?;
+        rows.collect::<Result<Vec<_>, _>>().map_err(error)
+    }
+}
+
+/// `migrate up`・`migrate down`の表示
+pub(crate) fn render_changes(migrations: &[Migration], verb: &str) -> String {
+    if migrations.is_empty() {
+        return "nothing to do\n".to_string();
+    }
+    migrations
+        .iter()
+        .map(|m| format!("{} {:04} {}\n", verb, m.version, m.name))
+        .collect()
+}
+
+/// `migrate`サブコマンドを設定のデータベースに対して実行する（`DATABASE_AUTO_MIGRATE`に関係なく動く）
+///
+/// `DATABASE_URL`がPostgreSQLの場合は、PostgreSQLとSQLite（`DATABASE_SQLITE_URL`）の両方が対象
+pub async fn run_migrate_command(
+    config: &DatabaseConfig,
+    command: MigrateCommand,
+) -> InfrastructureResult<String> {
+    let mut output = String::new();
+    if let DatabaseUrl::Postgres(url) = &config.url {
+        output.push_str(&run_postgres_migrate_command(url, config, command).await?);
+    }
+    match &config.sqlite_url {
+        DatabaseUrl::SqliteFile(path) => {
+            output.push_str(&run_sqlite_migrate_command(path, config, command)?);
+        }
+        _ if output.is_empty() => {
+            return Err(InfrastructureError::Configuration {
+                key: "DATABASE_URL".to_string(),
+                message:
+                    "migrate requires a file database (in-memory databases are migrated on startup)"
+                        .to_string(),
+            });
+        }
+        _ => {}
+    }
+    Ok(output)
+}
+
+fn run_sqlite_migrate_command(
+    path: &Path,
+    config: &DatabaseConfig,
+    command: MigrateCommand,
+) -> InfrastructureResult<String> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent).map_err(|e| InfrastructureError::FileOperation {
+            path: parent.display().to_string(),
+            message: e.to_string(),
+        })?;
+    }
+    let mut conn = SqliteConnection::connect_file(path, config.busy_timeout).map_err(|e| {
+        InfrastructureError::DatabaseConnection {
+            message: e.to_string(),
+        }
+    })?;
+    let output = Migrator::embedded().run(&mut conn, command)?;
+    Ok(format!("database: {}\n{}", config.sqlite_url, output))
+}
+
+#[cfg(feature = "postgres")]
+async fn run_postgres_migrate_command(
+    url: &str,
+    config: &DatabaseConfig,
+    command: MigrateCommand,
+) -> InfrastructureResult<String> {
+    let db = PostgresConnection::connect(url, config)?;
+    let output = db.run_migrate_command(command).await?;
+    Ok(format!("database: {}\n{}", config.url, output))
+}
+
+#[cfg(not(feature = "postgres"))]
+async fn run_postgres_migrate_command(
+    _url: &str,
+    _config: &DatabaseConfig,
+    _command: MigrateCommand,
+) -> InfrastructureResult<String> {
+    Err(InfrastructureError::Configuration {
+        key: "DATABASE_URL".to_string(),
+        message: "postgres support is not compiled in (build with --features postgres)".to_string(),
+    })
+}
@@ -0,0 +1,3 @@
+-- ユーザー単位のセッション一括失効の削除
+
+DROP TABLE IF EXISTS user_session_invalidations;
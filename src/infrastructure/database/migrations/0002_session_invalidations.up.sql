@@ -0,0 +1,5 @@
+-- ユーザー単位のセッション一括失効（この日時までに発行したトークンはセッションの有無に関わらず無効）
+CREATE TABLE IF NOT EXISTS user_session_invalidations (
+    user_id TEXT PRIMARY KEY,
+    invalidated_at DATETIME NOT NULL
+);
@@ -0,0 +1,3 @@
+-- パスワードリセット要求の記録の削除
+
+DROP TABLE IF EXISTS password_reset_requests;
@@ -0,0 +1,9 @@
+-- パスワードリセット要求の記録（メールアドレス・接続元IPごとの回数制限に使い、1時間より古い行は削除する）
+CREATE TABLE IF NOT EXISTS password_reset_requests (
+    scope TEXT NOT NULL,
+    key TEXT NOT NULL,
+    requested_at DATETIME NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_password_reset_requests_key ON password_reset_requests(scope, key, requested_at);
+CREATE INDEX IF NOT EXISTS idx_password_reset_requests_requested_at ON password_reset_requests(requested_at);
@@ -0,0 +1,3 @@
+-- ユーザーの削除
+
+DROP TABLE IF EXISTS users;
@@ -0,0 +1,17 @@
+-- ユーザー（PostgreSQLではユーザーのRepositoryのみを扱う）
+
+CREATE TABLE IF NOT EXISTS users (
+    id TEXT PRIMARY KEY,
+    email TEXT UNIQUE NOT NULL,
+    name TEXT NOT NULL,
+    password TEXT NOT NULL,
+    phone TEXT,
+    birth_date TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    last_login_at TIMESTAMPTZ,
+    role TEXT NOT NULL DEFAULT 'user',
+    email_verified_at TIMESTAMPTZ
+);
+
+CREATE INDEX IF NOT EXISTS idx_users_created_at ON users(created_at);
+CREATE INDEX IF NOT EXISTS idx_users_last_login_at ON users(last_login_at);
@@ -0,0 +1,266 @@
+//infrastructure/database/postgres_connection.rs
+// PostgreSQLの接続プール（ユーザーのRepository用、`postgres`フィーチャー）
+// 2025/7/8
+
+use crate::infrastructure::config::app_config::DatabaseConfig;
+use crate::infrastructure::config::config_source::MigrateCommand;
+use crate::infrastructure::database::migration::{
+    AppliedMigration, Migration, MigrationStatus, Migrator, render_changes,
+};
+use crate::shared::error::infrastructure_error::{InfrastructureError, InfrastructureResult};
+use deadpool_postgres::{
+    Manager, ManagerConfig, Object, Pool, PoolError, RecyclingMethod, Runtime,
+};
+use std::sync::Arc;
+use tokio::sync::OnceCell;
+use tokio_postgres::NoTls;
+
+/// PostgreSQL用のマイグレーション（SQLiteとは別に管理する）
+pub const POSTGRES_MIGRATIONS: &[Migration] = &[Migration {
+    version: 1,
+    name: "users",
+    up: include_str!("migrations/postgres/0001_users.up.sql"),
+    down: include_str!("migrations/postgres/0001_users.down.sql"),
+}];
+
+/// PostgreSQLの接続
+///
+/// 接続は最初に使う時（起動時は`prepare`）に確認し、SQLiteと同じ規則でマイグレーションを適用する。
+/// データベースのスキーマの方が新しい場合・`auto_migrate`が無効で未適用がある場合はエラー
+#[derive(Clone)]
+pub struct PostgresConnection {
+    pool: Pool,
+    acquire_timeout_ms: u64,
+    auto_migrate: bool,
+    ready: Arc<OnceCell<()>>,
+}
+
+impl PostgresConnection {
+    /// 接続プールを作る（接続はまだ行わない）
+    ///
+    /// 最大接続数は`DATABASE_POOL_READERS` + 1（書き込み分）、空きは`acquire_timeout`まで待つ
+    pub fn connect(url: &str, config: &DatabaseConfig) -> InfrastructureResult<Self> {
+        let pg_config: tokio_postgres::Config = url.parse().map_err(
+            |e: tokio_postgres::Error| InfrastructureError::Configuration {
+                key: "DATABASE_URL".to_string(),
+                message: e.to_string(),
+            },
+        )?;
+        let manager = Manager::from_config(
+            pg_config,
+            NoTls,
+            ManagerConfig {
+                recycling_method: RecyclingMethod::Fast,
+            },
+        );
+        let pool = Pool::builder(manager)
+            .max_size(config.readers + 1)
+            .runtime(Runtime::Tokio1)
+            .wait_timeout(Some(config.acquire_timeout))
+            .create_timeout(Some(config.acquire_timeout))
+            .build()
+            .map_err(|e| InfrastructureError::DatabaseConnection {
+                message: e.to_string(),
+            })?;
+        Ok(Self {
+            pool,
+            acquire_timeout_ms: u64::try_from(config.acquire_timeout.as_millis())
+                .unwrap_or(u64::MAX),
+            auto_migrate: config.auto_migrate,
+            ready: Arc::new(OnceCell::new()),
+        })
+    }
+
+    /// 接続を確認し、マイグレーションを適用（または未適用がないことを確認）する
+    pub async fn prepare(&self) -> InfrastructureResult<()> {
+        self.ready
+            .get_or_try_init(|| async {
+                if self.auto_migrate {
+                    for migration in self.migrate_up(false).await? {
+                        println!(
+                            "✅ マイグレーションを適用しました(PostgreSQL): {:04} {}",
+                            migration.version, migration.name
+                        );
+                    }
+                    Ok(())
+                } else {
+                    match self.pending().await?.first() {
+                        None => Ok(()),
+                        Some(first) => Err(InfrastructureError::SchemaMigration {
+                            version: first.version,
+                            message: "database has pending migrations (run `migrate up`)"
+                                .to_string(),
+                        }),
+                    }
+                }
+            })
+            .await
+            .map(|_| ())
+    }
+
+    /// Repositoryが使う接続（初回は`prepare`を行う）
+    pub async fn client(&self) -> InfrastructureResult<Object> {
+        self.prepare().await?;
+        self.get().await
+    }
+
+    /// 全バージョンの状態
+    pub async fn status(&self) -> InfrastructureResult<Vec<MigrationStatus>> {
+        let client = self.get().await?;
+        Ok(migrator().statuses(Self::applied(&client).await?))
+    }
+
+    /// 未適用のマイグレーション（データベースが新しい・書き換えがある場合はエラー）
+    pub async fn pending(&self) -> InfrastructureResult<Vec<Migration>> {
+        migrator().plan_up(&self.status().await?)
+    }
+
+    /// 未適用のマイグレーションを順に適用する（`dry_run`では適用せず対象のみ返す）
+    pub async fn migrate_up(&self, dry_run: bool) -> InfrastructureResult<Vec<Migration>> {
+        let pending = self.pending().await?;
+        if dry_run {
+            return Ok(pending);
+        }
+        let mut client = self.get().await?;
+        client
+            .batch_execute(
+                "CREATE TABLE IF NOT EXISTS schema_migrations (
+                    version INTEGER PRIMARY KEY,
+                    name TEXT NOT NULL,
+                    checksum TEXT NOT NULL,
+                    applied_at TEXT NOT NULL
+                )",
+            )
+            .await
+            .map_err(|e| migration_error(0, e))?;
+        for migration in &pending {
+            let error = |e| migration_error(migration.version, e);
+            let tx = client.transaction().await.map_err(error)?;
+            tx.batch_execute(migration.up).await.map_err(error)?;
+            tx.execute(
+                "INSERT INTO schema_migrations (version, name, checksum, applied_at)
+                 VALUES ($1, $2, $3, $4)",
+                &[
+                    &(migration.version as i32),
+                    &migration.name,
+                    &migration.checksum(),
+                    &chrono::Utc::now().to_rfc3339(),
+                ],
+            )
+            .await
+            .map_err(error)?;
+            tx.commit().await.map_err(error)?;
+        }
+        Ok(pending)
+    }
+
+    /// `target`より新しい適用済みのマイグレーションを新しい順に取り消す
+    pub async fn migrate_down(
+        &self,
+        target: u32,
+        dry_run: bool,
+    ) -> InfrastructureResult<Vec<Migration>> {
+        let rollback = migrator().plan_down(&self.pending().await?, target);
+        if dry_run {
+            return Ok(rollback);
+        }
+        let mut client = self.get().await?;
+        for migration in &rollback {
+            let error = |e| migration_error(migration.version, e);
+            let tx = client.transaction().await.map_err(error)?;
+            tx.batch_execute(migration.down).await.map_err(error)?;
+            tx.execute(
+                "DELETE FROM schema_migrations WHERE version = $1",
+                &[&(migration.version as i32)],
+            )
+            .await
+            .map_err(error)?;
+            tx.commit().await.map_err(error)?;
+        }
+        Ok(rollback)
+    }
+
+    /// `migrate`サブコマンドを実行し、表示する内容を返す
+    pub async fn run_migrate_command(
+        &self,
+        command: MigrateCommand,
+    ) -> InfrastructureResult<String> {
+        Ok(match command {
+            MigrateCommand::Status => migrator().render_status(&self.status().await?),
+            MigrateCommand::Up { dry_run } => render_changes(
+                &self.migrate_up(dry_run).await?,
+                if dry_run { "would apply" } else { "applied" },
+            ),
+            MigrateCommand::Down { target, dry_run } => render_changes(
+                &self.migrate_down(target, dry_run).await?,
+                if dry_run {
+                    "would roll back"
+                } else {
+                    "rolled back"
+                },
+            ),
+        })
+    }
+
+    async fn get(&self) -> InfrastructureResult<Object> {
+        self.pool.get().await.map_err(|e| match e {
+            PoolError::Timeout(_) => InfrastructureError::Timeout {
+                service: "postgres pool".to_string(),
+                timeout_ms: self.acquire_timeout_ms,
+            },
+            e => InfrastructureError::DatabaseConnection {
+                message: e.to_string(),
+            },
+        })
+    }
+
+    async fn applied(client: &Object) -> InfrastructureResult<Vec<AppliedMigration>> {
+        let error = |e: tokio_postgres::Error| InfrastructureError::DatabaseQuery {
+            query: "schema_migrations".to_string(),
+            message: e.to_string(),
+        };
+        let exists: bool = client
+            .query_one("SELECT to_regclass('schema_migrations') IS NOT NULL", &[])
+            .await
+            .map_err(error)?
+            .get(0);
+        if !exists {
+            return Ok(Vec::new());
+        }
+        let rows = client
+            .query(
+                "SELECT version, name, checksum, applied_at FROM schema_migrations ORDER BY version",
+                &[],
+            )
+            .await
+            .map_err(error)?;
+        Ok(rows
+            .iter()
+            .map(|row| AppliedMigration {
+                version: row.get::<_, i32>(0) as u32,
+                name: row.get(1),
+                checksum: row.get(2),
+                applied_at: row.get(3),
+            })
+            .collect())
+    }
+}
+
+fn migrator() -> Migrator {
+    Migrator::new(POSTGRES_MIGRATIONS.to_vec()).expect("postgres migrations are ordered by version")
+}
+
+fn migration_error(version: u32, e: tokio_postgres::Error) -> InfrastructureError {
+    InfrastructureError::SchemaMigration {
+        version,
+        message: e.to_string(),
+    }
+}
+
+/// Repositoryの問い合わせのエラー
+pub fn query_error(query: &str, e: tokio_postgres::Error) -> InfrastructureError {
+    InfrastructureError::DatabaseQuery {
+        query: query.to_string(),
+        message: e.to_string(),
+    }
+}
@@ -1,70 +1,151 @@
+use crate::infrastructure::config::app_config::{DatabaseConfig, DatabaseUrl};
+use crate::infrastructure::database::connection_pool::ConnectionPool;
+use crate::infrastructure::database::migration::Migrator;
+use crate::shared::error::infrastructure_error::{InfrastructureError, InfrastructureResult};
+use crate::shared::metrics::collector::PoolMetricsSource;
+use crate::shared::metrics::types::PoolMetricsSnapshot;
 use rusqlite::{Connection, Result};
-use std::sync::{Arc, Mutex};
-use tokio::task;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
 
-#[derive(Clone)]
+/// インメモリのデータベースで接続の空きを待つ時間
+const IN_MEMORY_ACQUIRE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// SQLiteの接続（書き込み用1接続・読み取り用N接続のプール）
+///
+/// WALモードでは読み取りが書き込みを待たないため、`execute_query`は読み取り用の接続で並行に実行する。
+/// インメモリのデータベースは接続ごとに別のデータベースになるため、書き込み用の1接続を共有する。
+#[derive(Clone, Debug)]
 pub struct SqliteConnection {
-    conn: Arc<Mutex<Connection>>,
+    writer: Arc<ConnectionPool>,
+    readers: Option<Arc<ConnectionPool>>,
 }
 
 impl SqliteConnection {
-    pub fn new_in_memory() -> Result<Self> {
-        let conn = Connection::open(":memory:")?;
-        Self::run_migrations(&conn)?;
+    /// `DATABASE_SQLITE_URL`（既定値は`DATABASE_URL`）に従って開く（ファイルのディレクトリがなければ作成する）
+    pub fn open(config: &DatabaseConfig) -> InfrastructureResult<Self> {
+        match &config.sqlite_url {
+            DatabaseUrl::SqliteMemory => Self::in_memory(config.acquire_timeout),
+            DatabaseUrl::Postgres(_) => Err(InfrastructureError::Configuration {
+                key: "DATABASE_SQLITE_URL".to_string(),
+                message: "must be a sqlite url".to_string(),
+            }),
+            DatabaseUrl::SqliteFile(path) => {
+                if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+                    std::fs::create_dir_all(parent).map_err(|e| {
+                        InfrastructureError::FileOperation {
+                            path: parent.display().to_string(),
+                            message: e.to_string(),
+                        }
+                    })?;
+                }
+                Self::open_file(path, config)
+            }
+        }
+    }
+
+    /// インメモリのデータベース（全てのマイグレーションを適用する）
+    pub fn new_in_memory() -> InfrastructureResult<Self> {
+        Self::in_memory(IN_MEMORY_ACQUIRE_TIMEOUT)
+    }
+
+    fn in_memory(acquire_timeout: Duration) -> InfrastructureResult<Self> {
+        let mut conn = Connection::open(":memory:").map_err(connection_error)?;
+        conn.pragma_update(None, "foreign_keys", "ON")
+            .map_err(connection_error)?;
+        Migrator::embedded().migrate_up(&mut conn, false)?;
+        Ok(Self {
+            writer: Arc::new(ConnectionPool::new("writer", vec![conn], acquire_timeout)),
+            readers: None,
+        })
+    }
+
+    /// ファイルのデータベースを開く（なければ作成する）
+    ///
+    /// `auto_migrate`では未適用のマイグレーションを適用し、それ以外では未適用があればエラーにする。
+    /// データベースのスキーマの方が新しい場合はどちらでもエラー（起動しない）。
+    /// 読み取り用の接続はマイグレーションの後に`query_only`で開く
+    pub fn open_file(path: &Path, config: &DatabaseConfig) -> InfrastructureResult<Self> {
+        let mut conn = Self::connect_file(path, config.busy_timeout).map_err(connection_error)?;
+        let migrator = Migrator::embedded();
+        if config.auto_migrate {
+            let applied = migrator.migrate_up(&mut conn, false)?;
+            for migration in applied {
+                println!(
+                    "✅ マイグレーションを適用しました: {:04} {}",
+                    migration.version, migration.name
+                );
+            }
+        } else {
+            migrator.ensure_up_to_date(&conn)?;
+        }
+        let readers = (0..config.readers)
+            .map(|_| {
+                let reader = Self::connect_file(path, config.busy_timeout)?;
+                reader.pragma_update(None, "query_only", "ON")?;
+                Ok(reader)
+            })
+            .collect::<Result<Vec<_>>>()
+            .map_err(connection_error)?;
         Ok(Self {
-            conn: Arc::new(Mutex::new(conn)),
+            writer: Arc::new(ConnectionPool::new(
+                "writer",
+                vec![conn],
+                config.acquire_timeout,
+            )),
+            readers: (!readers.is_empty()).then(|| {
+                Arc::new(ConnectionPool::new(
+                    "reader",
+                    readers,
+                    config.acquire_timeout,
+                ))
+            }),
         })
     }
 
-    fn run_migrations(conn: &Connection) -> Result<()> {
-        conn.execute(
-            "CREATE TABLE IF NOT EXISTS users (
-                id TEXT PRIMARY KEY,
-                email TEXT UNIQUE NOT NULL,
-                name TEXT NOT NULL,
-                password TEXT NOT NULL,
-                phone TEXT,
-                birth_date TEXT,
-                created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
-                last_login_at DATETIME
-            )",
-            [],
-        )?;
-        conn.execute(
-            "CREATE INDEX IF NOT EXISTS idx_users_email ON users(email)",
-            [],
-        )?;
-        println!("Database migrations completed successfully");
-        Ok(())
+    /// ファイルのデータベースに接続する（マイグレーションは行わない）
+    ///
+    /// WALモードにして読み取りが書き込みを待たないようにし、他の接続のロックは`busy_timeout`まで待つ
+    pub fn connect_file(path: &Path, busy_timeout: Duration) -> Result<Connection> {
+        let conn = Connection::open(path)?;
+        conn.busy_timeout(busy_timeout)?;
+        conn.pragma_update_and_check(None, "journal_mode", "WAL", |row| row.get::<_, String>(0))?;
+        conn.pragma_update(None, "synchronous", "NORMAL")?;
+        conn.pragma_update(None, "foreign_keys", "ON")?;
+        Ok(conn)
     }
 
-    // Command用メソッド（書き込み操作）
-    pub async fn execute_command<F, R>(&self, f: F) -> Result<R>
+    // Command用メソッド（書き込み操作、書き込み用の接続で1件ずつ実行する）
+    pub async fn execute_command<F, R>(&self, f: F) -> InfrastructureResult<R>
     where
         F: FnOnce(&mut Connection) -> Result<R> + Send + 'static,
         R: Send + 'static,
     {
-        let conn = self.conn.clone();
-        task::spawn_blocking(move || {
-            let mut conn = conn.lock().unwrap();
-            f(&mut *conn)
-        })
-        .await
-        .unwrap()
+        self.writer.run(f).await
     }
 
-    // Query用メソッド（読み取り操作）
-    pub async fn execute_query<F, R>(&self, f: F) -> Result<R>
+    // Query用メソッド（読み取り操作、読み取り用の接続がなければ書き込み用の接続を使う）
+    pub async fn execute_query<F, R>(&self, f: F) -> InfrastructureResult<R>
     where
         F: FnOnce(&mut Connection) -> Result<R> + Send + 'static,
         R: Send + 'static,
     {
-        let conn = self.conn.clone();
-        task::spawn_blocking(move || {
-            let mut conn = conn.lock().unwrap();
-            f(&mut *conn)
-        })
-        .await
-        .unwrap()
+        self.readers.as_ref().unwrap_or(&self.writer).run(f).await
+    }
+}
+
+impl PoolMetricsSource for SqliteConnection {
+    fn pool_metrics(&self) -> Vec<PoolMetricsSnapshot> {
+        std::iter::once(&self.writer)
+            .chain(self.readers.as_ref())
+            .map(|pool| pool.metrics())
+            .collect()
+    }
+}
+
+fn connection_error(e: rusqlite::Error) -> InfrastructureError {
+    InfrastructureError::DatabaseConnection {
+        message: e.to_string(),
     }
 }
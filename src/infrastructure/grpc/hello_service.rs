@@ -8,7 +8,6 @@ use prost::Message;
 use std::sync::Arc;
 
 // build.rsで生成されたコードをインポート
-#[cfg(not(doctest))]
 pub mod hello {
     include!(concat!(env!("OUT_DIR"), "/hello.rs"));
 }
@@ -19,6 +18,12 @@ pub use hello::{HelloRequest, HelloResponse};
 #[derive(Clone)]
 pub struct HelloService;
 
+impl Default for HelloService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl HelloService {
     pub fn new() -> Self {
         Self
@@ -0,0 +1,49 @@
+//infrastructure/mail/file_mailer.rs
+// ファイル出力のMailer実装（開発用）
+// 2025/7/8
+
+use crate::domain::service::mailer::{EmailMessage, Mailer};
+use async_trait::async_trait;
+use std::path::PathBuf;
+
+/// メールを1通ずつ`.eml`ファイルとしてディレクトリに書き出す
+pub struct FileMailer {
+    directory: PathBuf,
+    from: String,
+}
+
+impl FileMailer {
+    pub fn new(directory: impl Into<PathBuf>, from: String) -> Self {
+        Self {
+            directory: directory.into(),
+            from,
+        }
+    }
+}
+
+#[async_trait]
+impl Mailer for FileMailer {
+    async fn send(
+        &self,
+        message: &EmailMessage,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        tokio::fs::create_dir_all(&self.directory).await?;
+        let now = chrono::Utc::now();
+        let path = self.directory.join(format!(
+            "{}_{}.eml",
+            now.format("%Y%m%d%H%M%S"),
+            uuid::Uuid::new_v4()
+        ));
+        let content = format!(
+            "From: {}\r\nTo: {}\r\nSubject: {}\r\nDate: {}\r\n\r\n{}\r\n",
+            self.from,
+            message.to,
+            message.subject,
+            now.to_rfc2822(),
+            message.body
+        );
+        tokio::fs::write(&path, content).await?;
+        println!("📧 メールをファイルに出力しました: {}", path.display());
+        Ok(())
+    }
+}
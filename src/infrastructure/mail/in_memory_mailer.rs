@@ -0,0 +1,49 @@
+//infrastructure/mail/in_memory_mailer.rs
+// メモリ保持のMailer実装（テスト用）
+// 2025/7/8
+
+use crate::domain::service::mailer::{EmailMessage, Mailer};
+use async_trait::async_trait;
+use std::sync::Mutex;
+
+/// 送信したメールをメモリに保持する（テストで内容を確認できる）
+#[derive(Default)]
+pub struct InMemoryMailer {
+    sent: Mutex<Vec<EmailMessage>>,
+}
+
+impl InMemoryMailer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 送信済みメールの一覧
+    pub fn sent(&self) -> Vec<EmailMessage> {
+        self.sent
+            .lock()
+            .map(|sent| sent.clone())
+            .unwrap_or_default()
+    }
+
+    /// 宛先ごとの最新のメール
+    pub fn last_to(&self, to: &str) -> Option<EmailMessage> {
+        self.sent()
+            .into_iter()
+            .rev()
+            .find(|message| message.to == to)
+    }
+}
+
+#[async_trait]
+impl Mailer for InMemoryMailer {
+    async fn send(
+        &self,
+        message: &EmailMessage,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        self.sent
+            .lock()
+            .map_err(|_| "in-memory mailer lock poisoned")?
+            .push(message.clone());
+        Ok(())
+    }
+}
@@ -0,0 +1,66 @@
+//infrastructure/mail/smtp_mailer.rs
+// SMTPのMailer実装
+// 2025/7/8
+
+use crate::domain::service::mailer::{EmailMessage, Mailer};
+use crate::infrastructure::config::app_config::MailerConfig;
+use crate::shared::error::infrastructure_error::{InfrastructureError, InfrastructureResult};
+use async_trait::async_trait;
+use lettre::message::Mailbox;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+
+/// SMTPサーバー経由でメールを送信する（STARTTLS、`SMTP_TLS=false`で平文）
+pub struct SmtpMailer {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+}
+
+impl SmtpMailer {
+    pub fn new(config: &MailerConfig) -> InfrastructureResult<Self> {
+        let config_error = |key: &str, message: String| InfrastructureError::Configuration {
+            key: key.to_string(),
+            message,
+        };
+        let host = config
+            .smtp_host
+            .as_deref()
+            .ok_or_else(|| config_error("SMTP_HOST", "required for the smtp mailer".to_string()))?;
+        let mut builder = if config.smtp_tls {
+            AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(host)
+                .map_err(|e| config_error("SMTP_HOST", e.to_string()))?
+        } else {
+            AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(host)
+        };
+        if let Some(port) = config.smtp_port {
+            builder = builder.port(port);
+        }
+        if let (Some(username), Some(password)) = (&config.smtp_username, &config.smtp_password) {
+            builder = builder.credentials(Credentials::new(username.clone(), password.clone()));
+        }
+        let from = config
+            .from
+            .parse()
+            .map_err(|e: lettre::address::AddressError| config_error("MAIL_FROM", e.to_string()))?;
+        Ok(Self {
+            transport: builder.build(),
+            from,
+        })
+    }
+}
+
+#[async_trait]
+impl Mailer for SmtpMailer {
+    async fn send(
+        &self,
+        message: &EmailMessage,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let email = Message::builder()
+            .from(self.from.clone())
+            .to(message.to.parse()?)
+            .subject(message.subject.clone())
+            .body(message.body.clone())?;
+        self.transport.send(email).await?;
+        Ok(())
+    }
+}
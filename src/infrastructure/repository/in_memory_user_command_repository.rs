@@ -1,113 +1,79 @@
 //infrastructure/repository/in_memory_user_command_repository.rs
-// SQLite Command Repository実装
+// インメモリ Command Repository実装（テスト用）
 // 2025/7/8
 
 use crate::domain::entity::user::User;
 use crate::domain::repository::user_command_repository::UserCommandRepositoryInterface;
-use crate::domain::value_object::{email::Email, user_id::UserId};
-use crate::infrastructure::database::sqlite_connection::SqliteConnection;
+use crate::domain::value_object::{
+    email::Email, password_hash::PasswordHash, role::Role, user_id::UserId,
+};
+use crate::infrastructure::repository::in_memory_user_store::InMemoryUserStore;
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
-use rusqlite::params;
+use std::collections::HashSet;
 
-pub struct SqliteUserCommandRepository {
-    db: SqliteConnection,
+pub struct InMemoryUserCommandRepository {
+    store: InMemoryUserStore,
 }
 
-impl SqliteUserCommandRepository {
-    pub fn new(db: SqliteConnection) -> Self {
-        Self { db }
+impl InMemoryUserCommandRepository {
+    pub fn new(store: InMemoryUserStore) -> Self {
+        Self { store }
     }
 }
 
 #[async_trait]
-impl UserCommandRepositoryInterface for SqliteUserCommandRepository {
+impl UserCommandRepositoryInterface for InMemoryUserCommandRepository {
     async fn save(&self, user: &User) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-        println!(
-            "SqliteUserCommandRepository: Saving user with ID: {}",
-            user.id.0
-        );
-        let user = user.clone();
-        let result: Result<(), rusqlite::Error> = self.db.execute_command(move |conn| {
-            println!("SqliteUserCommandRepository: Executing INSERT query...");
-            conn.execute(
-                "INSERT INTO users (id, email, name, password, phone, birth_date) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
-                params![
-                    user.id.0,
-                    user.email.0,
-                    user.name.0,
-                    user.password.0,
-                    user.phone.as_ref().map(|p| p.0.clone()),
-                    user.birth_date.as_ref().map(|b| b.0.clone()),
-                ],
-            )?;
-            println!("SqliteUserCommandRepository: INSERT query executed successfully");
-            Ok(())
-        }).await;
-        result.map_err(|e| {
-            println!("SqliteUserCommandRepository: Error saving user: {}", e);
-            Box::new(e) as Box<dyn std::error::Error + Send + Sync>
-        })
+        Ok(self.store.write().insert(user.clone())?)
     }
 
     async fn update(&self, user: &User) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-        let user = user.clone();
-        let result: Result<(), rusqlite::Error> = self.db.execute_command(move |conn| {
-            conn.execute(
-                "UPDATE users SET email = ?2, name = ?3, password = ?4, phone = ?5, birth_date = ?6 WHERE id = ?1",
-                params![
-                    user.id.0,
-                    user.email.0,
-                    user.name.0,
-                    user.password.0,
-                    user.phone.as_ref().map(|p| p.0.clone()),
-                    user.birth_date.as_ref().map(|b| b.0.clone()),
-                ],
-            )?;
-            Ok(())
-        }).await;
-        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+        let mut users = self.store.write();
+        let Some(current_email) = users.get(&user.id.0).map(|s| s.user.email.0.clone()) else {
+            return Ok(());
+        };
+        users.change_email(&user.id.0, &user.email.0)?;
+        let stored = users.get_mut(&user.id.0).expect("user exists");
+        // メールアドレスが変わった場合は確認済みの状態を取り消す
+        let email_verified_at = if current_email == user.email.0 {
+            stored.user.email_verified_at
+        } else {
+            None
+        };
+        stored.user = User {
+            email_verified_at,
+            ..user.clone()
+        };
+        Ok(())
     }
 
     async fn delete(
         &self,
         user_id: &UserId,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-        let user_id = user_id.clone();
-        let result: Result<(), rusqlite::Error> = self
-            .db
-            .execute_command(move |conn| {
-                conn.execute("DELETE FROM users WHERE id = ?", params![user_id.0])?;
-                Ok(())
-            })
-            .await;
-        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+        self.store.write().remove(&user_id.0);
+        Ok(())
     }
 
     async fn save_batch(
         &self,
         users: &[User],
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-        let users = users.to_vec();
-        let result: Result<(), rusqlite::Error> = self.db.execute_command(move |conn| {
-            let mut tx = conn.transaction()?;
-            for user in &users {
-                tx.execute(
-                    "INSERT INTO users (id, email, name, password, phone, birth_date) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
-                    params![
-                        user.id.0.clone(),
-                        user.email.0.clone(),
-                        user.name.0.clone(),
-                        user.password.0.clone(),
-                        user.phone.as_ref().map(|p| p.0.clone()),
-                        user.birth_date.as_ref().map(|b| b.0.clone()),
-                    ],
-                )?;
+        let mut stored = self.store.write();
+        // 1件でも保存できなければ何も保存しない
+        let mut ids = HashSet::new();
+        let mut emails = HashSet::new();
+        for user in users {
+            stored.check_insert(user)?;
+            if !ids.insert(&user.id.0) || !emails.insert(&user.email.0) {
+                return Err(format!("duplicate user in batch: {}", user.id.0).into());
             }
-            tx.commit()?;
-            Ok(())
-        }).await;
-        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+        }
+        for user in users {
+            stored.insert(user.clone())?;
+        }
+        Ok(())
     }
 
     async fn update_last_login(
@@ -115,37 +81,49 @@ impl UserCommandRepositoryInterface for SqliteUserCommandRepository {
         user_id: &UserId,
         login_time: DateTime<Utc>,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-        let user_id = user_id.clone();
-        let login_time = login_time.clone();
-        let result: Result<(), rusqlite::Error> = self
-            .db
-            .execute_command(move |conn| {
-                conn.execute(
-                    "UPDATE users SET last_login_at = ? WHERE id = ?",
-                    params![login_time.to_rfc3339(), user_id.0],
-                )?;
-                Ok(())
-            })
-            .await;
-        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+        if let Some(stored) = self.store.write().get_mut(&user_id.0) {
+            stored.last_login_at = Some(login_time);
+        }
+        Ok(())
+    }
+
+    async fn update_password_hash(
+        &self,
+        user_id: &UserId,
+        password_hash: &PasswordHash,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        if let Some(stored) = self.store.write().get_mut(&user_id.0) {
+            stored.user.password_hash = password_hash.clone();
+        }
+        Ok(())
+    }
+
+    async fn update_role(
+        &self,
+        user_id: &UserId,
+        role: Role,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        if let Some(stored) = self.store.write().get_mut(&user_id.0) {
+            stored.user.role = role;
+        }
+        Ok(())
+    }
+
+    async fn mark_email_verified(
+        &self,
+        user_id: &UserId,
+        verified_at: DateTime<Utc>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        if let Some(stored) = self.store.write().get_mut(&user_id.0) {
+            stored.user.email_verified_at = Some(verified_at);
+        }
+        Ok(())
     }
 
     async fn exists_by_email(
         &self,
         email: &Email,
     ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
-        let email = email.clone();
-        let result: Result<bool, rusqlite::Error> = self
-            .db
-            .execute_query(move |conn| {
-                let count: i64 = conn.query_row(
-                    "SELECT COUNT(*) FROM users WHERE email = ?",
-                    params![email.0],
-                    |row| row.get(0),
-                )?;
-                Ok(count > 0)
-            })
-            .await;
-        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+        Ok(self.store.read().find_by_email(&email.0).is_some())
     }
 }
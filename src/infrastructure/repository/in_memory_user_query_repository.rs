@@ -1,183 +1,110 @@
 //infrastructure/repository/in_memory_user_query_repository.rs
-// SQLite Query Repository実装
+// インメモリ Query Repository実装（テスト用）
 // 2025/7/8
 
 use crate::domain::entity::user::User;
 use crate::domain::repository::user_query_repository::UserQueryRepositoryInterface;
-use crate::domain::value_object::{
-    birth_date::BirthDate, email::Email, pagination::*, password::Password, phone::Phone,
-    user_id::UserId, user_name::UserName,
-};
-use crate::infrastructure::database::sqlite_connection::SqliteConnection;
+use crate::domain::value_object::{email::Email, pagination::*, user_id::UserId};
+use crate::infrastructure::repository::in_memory_user_store::{InMemoryUserStore, StoredUser};
 use async_trait::async_trait;
-use chrono::{DateTime, Utc};
-use rusqlite::{Row, params};
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, Utc};
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
 
-pub struct SqliteUserQueryRepository {
-    db: SqliteConnection,
+pub struct InMemoryUserQueryRepository {
+    store: InMemoryUserStore,
 }
 
-impl SqliteUserQueryRepository {
-    pub fn new(db: SqliteConnection) -> Self {
-        Self { db }
+impl InMemoryUserQueryRepository {
+    pub fn new(store: InMemoryUserStore) -> Self {
+        Self { store }
     }
 
-    fn row_to_user(row: &Row) -> rusqlite::Result<User> {
-        // データベースのカラム順序: id, email, name, password, phone, birth_date, created_at, last_login_at
-        let id: String = row.get(0)?;
-        let email: String = row.get(1)?;
-        let name: String = row.get(2)?;
-        let password: String = row.get(3)?;
-        let phone: Option<String> = row.get(4)?;
-        let birth_date: Option<String> = row.get(5)?;
-
-        // Value Objectの構築
-        let user_id = UserId::new(id);
-        let email_vo =
-            Email::new(email).map_err(|e| rusqlite::Error::InvalidParameterName(e.to_string()))?;
-        let name_vo = UserName::new(name)
-            .map_err(|e| rusqlite::Error::InvalidParameterName(e.to_string()))?;
-        let password_vo = Password::new(password)
-            .map_err(|e| rusqlite::Error::InvalidParameterName(e.to_string()))?;
-
-        let phone_vo = match phone {
-            Some(p) => Some(
-                Phone::new(p).map_err(|e| rusqlite::Error::InvalidParameterName(e.to_string()))?,
-            ),
-            None => None,
-        };
-
-        let birth_date_vo = match birth_date {
-            Some(b) => Some(
-                BirthDate::new(b)
-                    .map_err(|e| rusqlite::Error::InvalidParameterName(e.to_string()))?,
-            ),
-            None => None,
-        };
-
-        // Userエンティティの構築
-        let user = User::new(
-            user_id,
-            email_vo,
-            name_vo,
-            password_vo,
-            phone_vo,
-            birth_date_vo,
-        )
-        .map_err(|e| rusqlite::Error::InvalidParameterName(e.to_string()))?;
+    // 作成日時の新しい順（同じ場合は後に保存した順）
+    fn newest_first(a: &StoredUser, b: &StoredUser) -> Ordering {
+        (b.created_at, b.sequence).cmp(&(a.created_at, a.sequence))
+    }
 
-        Ok(user)
+    fn page(users: Vec<User>, pagination: &PaginationParams) -> PaginatedResult<User> {
+        let total_count = users.len() as u64;
+        let offset = ((pagination.page - 1) * pagination.limit) as usize;
+        let total_pages = total_count.div_ceil(pagination.limit as u64) as u32;
+        PaginatedResult {
+            data: users
+                .into_iter()
+                .skip(offset)
+                .take(pagination.limit as usize)
+                .collect(),
+            pagination: PaginationInfo {
+                current_page: pagination.page,
+                total_pages,
+                total_count,
+                per_page: pagination.limit,
+                has_next: pagination.page < total_pages,
+                has_prev: pagination.page > 1,
+            },
+        }
     }
+}
 
-    fn count_with_filters(&self, _filters: &UserSearchFilters) -> rusqlite::Result<u64> {
-        todo!("Implement count_with_filters")
+/// 検索条件の日時（RFC3339・`YYYY-MM-DD HH:MM:SS`・`YYYY-MM-DD`、タイムゾーンがなければUTC）
+fn parse_time(value: &str) -> Result<DateTime<Utc>, Box<dyn std::error::Error + Send + Sync>> {
+    if let Ok(time) = DateTime::parse_from_rfc3339(value) {
+        return Ok(time.with_timezone(&Utc));
     }
+    if let Ok(time) = NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S") {
+        return Ok(time.and_utc());
+    }
+    let date = NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map_err(|e| format!("invalid date '{}': {}", value, e))?;
+    Ok(date
+        .and_hms_opt(0, 0, 0)
+        .expect("midnight is valid")
+        .and_utc())
 }
 
 #[async_trait]
-impl UserQueryRepositoryInterface for SqliteUserQueryRepository {
+impl UserQueryRepositoryInterface for InMemoryUserQueryRepository {
     async fn find_by_id(
         &self,
         id: &UserId,
     ) -> Result<Option<User>, Box<dyn std::error::Error + Send + Sync>> {
-        let id = id.clone();
-        let result: Result<Option<User>, rusqlite::Error> = self
-            .db
-            .execute_query(move |conn| {
-                let mut stmt = conn.prepare("SELECT * FROM users WHERE id = ?")?;
-                let mut rows = stmt.query(params![id.0])?;
-                if let Some(row) = rows.next()? {
-                    Ok(Some(Self::row_to_user(row)?))
-                } else {
-                    Ok(None)
-                }
-            })
-            .await;
-        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+        Ok(self.store.read().get(&id.0).map(|s| s.user.clone()))
     }
 
     async fn find_by_email(
         &self,
         email: &Email,
     ) -> Result<Option<User>, Box<dyn std::error::Error + Send + Sync>> {
-        let email = email.clone();
-        let result: Result<Option<User>, rusqlite::Error> = self
-            .db
-            .execute_query(move |conn| {
-                let mut stmt = conn.prepare("SELECT * FROM users WHERE email = ?")?;
-                let mut rows = stmt.query(params![email.0])?;
-                if let Some(row) = rows.next()? {
-                    Ok(Some(Self::row_to_user(row)?))
-                } else {
-                    Ok(None)
-                }
-            })
-            .await;
-        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+        Ok(self
+            .store
+            .read()
+            .find_by_email(&email.0)
+            .map(|s| s.user.clone()))
     }
 
     async fn exists_by_email(
         &self,
         email: &Email,
     ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
-        let email = email.clone();
-        let result: Result<bool, rusqlite::Error> = self
-            .db
-            .execute_query(move |conn| {
-                let mut stmt = conn.prepare("SELECT COUNT(*) FROM users WHERE email = ?")?;
-                let count: i64 = stmt.query_row(params![email.0], |row| row.get(0))?;
-                Ok(count > 0)
-            })
-            .await;
-        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+        Ok(self.store.read().find_by_email(&email.0).is_some())
     }
 
     async fn find_all(
         &self,
         pagination: PaginationParams,
     ) -> Result<PaginatedResult<User>, Box<dyn std::error::Error + Send + Sync>> {
-        let pagination = pagination.clone();
-        let offset = (pagination.page - 1) * pagination.limit;
-        let result: Result<PaginatedResult<User>, rusqlite::Error> = self
-            .db
-            .execute_query(move |conn| {
-                let total_count: i64 =
-                    conn.query_row("SELECT COUNT(*) FROM users", [], |row| row.get(0))?;
-                let mut stmt =
-                    conn.prepare("SELECT * FROM users ORDER BY created_at DESC LIMIT ? OFFSET ?")?;
-                let mut rows = stmt.query(params![pagination.limit as i64, offset as i64])?;
-                let mut users = Vec::new();
-                while let Some(row) = rows.next()? {
-                    users.push(Self::row_to_user(row)?);
-                }
-                let total_pages = ((total_count as u64 + pagination.limit as u64 - 1)
-                    / pagination.limit as u64) as u32;
-                Ok(PaginatedResult {
-                    data: users,
-                    pagination: PaginationInfo {
-                        current_page: pagination.page,
-                        total_pages,
-                        total_count: total_count as u64,
-                        per_page: pagination.limit,
-                        has_next: pagination.page < total_pages,
-                        has_prev: pagination.page > 1,
-                    },
-                })
-            })
-            .await;
-        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+        let users = self.store.read();
+        let mut stored: Vec<&StoredUser> = users.iter().collect();
+        stored.sort_by(|a, b| Self::newest_first(a, b));
+        Ok(Self::page(
+            stored.into_iter().map(|s| s.user.clone()).collect(),
+            &pagination,
+        ))
     }
 
     async fn count_total(&self) -> Result<u64, Box<dyn std::error::Error + Send + Sync>> {
-        let result: Result<u64, rusqlite::Error> = self
-            .db
-            .execute_query(move |conn| {
-                let count: i64 =
-                    conn.query_row("SELECT COUNT(*) FROM users", [], |row| row.get(0))?;
-                Ok(count as u64)
-            })
-            .await;
-        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+        Ok(self.store.read().len() as u64)
     }
 
     async fn search_users(
@@ -186,73 +113,59 @@ impl UserQueryRepositoryInterface for SqliteUserQueryRepository {
         sort: SortParams,
         pagination: PaginationParams,
     ) -> Result<PaginatedResult<User>, Box<dyn std::error::Error + Send + Sync>> {
-        let filters = filters.clone();
-        let sort = sort.clone();
-        let pagination = pagination.clone();
-        let offset = (pagination.page - 1) * pagination.limit;
-        let result: Result<PaginatedResult<User>, rusqlite::Error> = self
-            .db
-            .execute_query(move |conn| {
-                let mut sql = "SELECT * FROM users WHERE 1=1".to_string();
-                let mut params_vec = Vec::new();
-                if let Some(email_domain) = &filters.email_domain {
-                    sql.push_str(" AND email LIKE ?");
-                    params_vec.push(format!("%{}", email_domain));
-                }
-                if let Some(name_contains) = &filters.name_contains {
-                    sql.push_str(" AND name LIKE ?");
-                    params_vec.push(format!("%{}%", name_contains));
-                }
-                if let Some(created_after) = &filters.created_after {
-                    sql.push_str(" AND created_at >= ?");
-                    params_vec.push(created_after.clone());
-                }
-                if let Some(created_before) = &filters.created_before {
-                    sql.push_str(" AND created_at <= ?");
-                    params_vec.push(created_before.clone());
-                }
-                if let Some(has_phone) = &filters.has_phone {
-                    if *has_phone {
-                        sql.push_str(" AND phone IS NOT NULL");
-                    } else {
-                        sql.push_str(" AND phone IS NULL");
-                    }
-                }
-                let order_str = match sort.order {
-                    SortOrder::Asc => "ASC",
-                    SortOrder::Desc => "DESC",
-                };
-                sql.push_str(&format!(" ORDER BY {} {}", sort.field, order_str));
-                sql.push_str(&format!(" LIMIT {} OFFSET {}", pagination.limit, offset));
-                let mut stmt = conn.prepare(&sql)?;
-                let mut rows = stmt.query(rusqlite::params_from_iter(params_vec.iter()))?;
-                let mut users = Vec::new();
-                while let Some(row) = rows.next()? {
-                    users.push(Self::row_to_user(row)?);
-                }
-                let count_sql_binding = sql.replace("SELECT *", "SELECT COUNT(*)");
-                let count_sql = count_sql_binding.split("ORDER BY").next().unwrap();
-                let mut count_stmt = conn.prepare(count_sql)?;
-                let total_count: i64 = count_stmt
-                    .query_row(rusqlite::params_from_iter(params_vec.iter()), |row| {
-                        row.get(0)
-                    })?;
-                let total_pages = ((total_count as u64 + pagination.limit as u64 - 1)
-                    / pagination.limit as u64) as u32;
-                Ok(PaginatedResult {
-                    data: users,
-                    pagination: PaginationInfo {
-                        current_page: pagination.page,
-                        total_pages,
-                        total_count: total_count as u64,
-                        per_page: pagination.limit,
-                        has_next: pagination.page < total_pages,
-                        has_prev: pagination.page > 1,
-                    },
-                })
+        let created_after = filters
+            .created_after
+            .as_deref()
+            .map(parse_time)
+            .transpose()?;
+        let created_before = filters
+            .created_before
+            .as_deref()
+            .map(parse_time)
+            .transpose()?;
+        // SQLiteのLIKEと同じく、英字の大文字・小文字を区別しない
+        let email_domain = filters.email_domain.map(|d| d.to_ascii_lowercase());
+        let name_contains = filters.name_contains.map(|n| n.to_ascii_lowercase());
+
+        let users = self.store.read();
+        let mut stored: Vec<&StoredUser> = users
+            .iter()
+            .filter(|s| {
+                email_domain
+                    .as_ref()
+                    .is_none_or(|d| s.user.email.0.to_ascii_lowercase().ends_with(d.as_str()))
+            })
+            .filter(|s| {
+                name_contains
+                    .as_ref()
+                    .is_none_or(|n| s.user.name.0.to_ascii_lowercase().contains(n.as_str()))
             })
-            .await;
-        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+            .filter(|s| created_after.is_none_or(|t| s.created_at >= t))
+            .filter(|s| created_before.is_none_or(|t| s.created_at <= t))
+            .filter(|s| {
+                filters
+                    .has_phone
+                    .is_none_or(|p| s.user.phone.is_some() == p)
+            })
+            .collect();
+        stored.sort_by(|a, b| {
+            let ordering = match sort.user_field() {
+                "last_login_at" => a.last_login_at.cmp(&b.last_login_at),
+                "email" => a.user.email.0.cmp(&b.user.email.0),
+                "name" => a.user.name.0.cmp(&b.user.name.0),
+                "role" => a.user.role.as_str().cmp(b.user.role.as_str()),
+                _ => a.created_at.cmp(&b.created_at),
+            }
+            .then(a.sequence.cmp(&b.sequence));
+            match sort.order {
+                SortOrder::Asc => ordering,
+                SortOrder::Desc => ordering.reverse(),
+            }
+        });
+        Ok(Self::page(
+            stored.into_iter().map(|s| s.user.clone()).collect(),
+            &pagination,
+        ))
     }
 
     async fn count_registrations_in_period(
@@ -260,20 +173,12 @@ impl UserQueryRepositoryInterface for SqliteUserQueryRepository {
         start: DateTime<Utc>,
         end: DateTime<Utc>,
     ) -> Result<u64, Box<dyn std::error::Error + Send + Sync>> {
-        let start = start.clone();
-        let end = end.clone();
-        let result: Result<u64, rusqlite::Error> = self
-            .db
-            .execute_query(move |conn| {
-                let count: i64 = conn.query_row(
-                    "SELECT COUNT(*) FROM users WHERE created_at BETWEEN ? AND ?",
-                    params![start.to_rfc3339(), end.to_rfc3339()],
-                    |row| row.get(0),
-                )?;
-                Ok(count as u64)
-            })
-            .await;
-        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+        Ok(self
+            .store
+            .read()
+            .iter()
+            .filter(|s| s.created_at >= start && s.created_at <= end)
+            .count() as u64)
     }
 
     async fn count_active_users_in_period(
@@ -281,20 +186,12 @@ impl UserQueryRepositoryInterface for SqliteUserQueryRepository {
         start: DateTime<Utc>,
         end: DateTime<Utc>,
     ) -> Result<u64, Box<dyn std::error::Error + Send + Sync>> {
-        let start = start.clone();
-        let end = end.clone();
-        let result: Result<u64, rusqlite::Error> = self
-            .db
-            .execute_query(move |conn| {
-                let count: i64 = conn.query_row(
-                    "SELECT COUNT(DISTINCT id) FROM users WHERE last_login_at BETWEEN ? AND ?",
-                    params![start.to_rfc3339(), end.to_rfc3339()],
-                    |row| row.get(0),
-                )?;
-                Ok(count as u64)
-            })
-            .await;
-        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+        Ok(self
+            .store
+            .read()
+            .iter()
+            .filter(|s| s.last_login_at.is_some_and(|t| t >= start && t <= end))
+            .count() as u64)
     }
 
     async fn get_registration_trend(
@@ -302,46 +199,35 @@ impl UserQueryRepositoryInterface for SqliteUserQueryRepository {
         period: TimePeriod,
         granularity: TimeGranularity,
     ) -> Result<Vec<TimeSeriesPoint>, Box<dyn std::error::Error + Send + Sync>> {
-        let period = period.clone();
-        let granularity = granularity.clone();
-        let result: Result<Vec<TimeSeriesPoint>, rusqlite::Error> = self
-            .db
-            .execute_query(move |conn| {
-                let (date_format, _interval) = match granularity {
-                    TimeGranularity::Hour => ("%Y-%m-%d %H:00:00", "1 hour"),
-                    TimeGranularity::Day => ("%Y-%m-%d", "1 day"),
-                    TimeGranularity::Week => ("%Y-%W", "1 week"),
-                    TimeGranularity::Month => ("%Y-%m", "1 month"),
-                    TimeGranularity::Minute => ("%Y-%m-%d %H:%M:00", "1 minute"),
-                };
-                let period_hours = match period {
-                    TimePeriod::Hour => 1,
-                    TimePeriod::Day => 24,
-                    TimePeriod::Week => 24 * 7,
-                    TimePeriod::Month => 24 * 30,
-                    TimePeriod::Year => 24 * 365,
-                };
-                let sql = format!(
-                    "SELECT strftime('{}', created_at) as time_bucket, COUNT(*) as count \
-                 FROM users \
-                 WHERE created_at >= datetime('now', '-{} hours')\
-                 GROUP BY time_bucket \
-                 ORDER BY time_bucket",
-                    date_format, period_hours
-                );
-                let mut stmt = conn.prepare(&sql)?;
-                let mut rows = stmt.query([])?;
-                let mut points = Vec::new();
-                while let Some(row) = rows.next()? {
-                    points.push(TimeSeriesPoint {
-                        timestamp: row.get::<_, String>(0)?,
-                        value: row.get::<_, i64>(1)? as u64,
-                        sample_count: None,
-                    });
-                }
-                Ok(points)
+        // SQLite（strftime）と同じ形式の区切りにする
+        let date_format = match granularity {
+            TimeGranularity::Hour => "%Y-%m-%d %H:00:00",
+            TimeGranularity::Day => "%Y-%m-%d",
+            TimeGranularity::Week => "%Y-%W",
+            TimeGranularity::Month => "%Y-%m",
+            TimeGranularity::Minute => "%Y-%m-%d %H:%M:00",
+        };
+        let period_hours = match period {
+            TimePeriod::Hour => 1,
+            TimePeriod::Day => 24,
+            TimePeriod::Week => 24 * 7,
+            TimePeriod::Month => 24 * 30,
+            TimePeriod::Year => 24 * 365,
+        };
+        let since = Utc::now() - Duration::hours(period_hours);
+        let mut buckets: BTreeMap<String, u64> = BTreeMap::new();
+        for stored in self.store.read().iter().filter(|s| s.created_at >= since) {
+            *buckets
+                .entry(stored.created_at.format(date_format).to_string())
+                .or_default() += 1;
+        }
+        Ok(buckets
+            .into_iter()
+            .map(|(timestamp, value)| TimeSeriesPoint {
+                timestamp,
+                value,
+                sample_count: None,
             })
-            .await;
-        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+            .collect())
     }
 }
@@ -0,0 +1,130 @@
+//infrastructure/repository/in_memory_user_store.rs
+// インメモリのユーザー保存先（Command/Query Repositoryで共有する）
+// 2025/7/8
+
+use crate::domain::entity::user::User;
+use crate::shared::error::infrastructure_error::{InfrastructureError, InfrastructureResult};
+use chrono::{DateTime, Utc};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+/// 保存したユーザーと、Userに含まれない列（作成日時・最終ログイン日時）
+#[derive(Clone, Debug)]
+pub struct StoredUser {
+    pub user: User,
+    pub created_at: DateTime<Utc>,
+    pub last_login_at: Option<DateTime<Utc>>,
+    // 作成日時が同じ場合の並び順（保存した順）
+    pub(crate) sequence: u64,
+}
+
+/// ユーザーの一覧（IDの順）とメールアドレスの索引
+#[derive(Debug, Default)]
+pub struct InMemoryUsers {
+    users: BTreeMap<String, StoredUser>,
+    emails: HashMap<String, String>,
+    next_sequence: u64,
+}
+
+impl InMemoryUsers {
+    pub fn get(&self, id: &str) -> Option<&StoredUser> {
+        self.users.get(id)
+    }
+
+    pub fn get_mut(&mut self, id: &str) -> Option<&mut StoredUser> {
+        self.users.get_mut(id)
+    }
+
+    pub fn find_by_email(&self, email: &str) -> Option<&StoredUser> {
+        self.emails.get(email).and_then(|id| self.users.get(id))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &StoredUser> {
+        self.users.values()
+    }
+
+    pub fn len(&self) -> usize {
+        self.users.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.users.is_empty()
+    }
+
+    /// IDとメールアドレスが他のユーザーと重複しないことを確認する（SQLの一意制約に相当）
+    pub fn check_insert(&self, user: &User) -> InfrastructureResult<()> {
+        if self.users.contains_key(&user.id.0) {
+            return Err(constraint_error("id", &user.id.0));
+        }
+        if self.emails.contains_key(&user.email.0) {
+            return Err(constraint_error("email", &user.email.0));
+        }
+        Ok(())
+    }
+
+    pub fn insert(&mut self, user: User) -> InfrastructureResult<()> {
+        self.check_insert(&user)?;
+        self.emails.insert(user.email.0.clone(), user.id.0.clone());
+        self.next_sequence += 1;
+        self.users.insert(
+            user.id.0.clone(),
+            StoredUser {
+                user,
+                created_at: Utc::now(),
+                last_login_at: None,
+                sequence: self.next_sequence,
+            },
+        );
+        Ok(())
+    }
+
+    /// メールアドレスを変更する（他のユーザーが使っている場合はエラー）
+    pub fn change_email(&mut self, id: &str, email: &str) -> InfrastructureResult<()> {
+        if let Some(owner) = self.emails.get(email) {
+            if owner != id {
+                return Err(constraint_error("email", email));
+            }
+            return Ok(());
+        }
+        self.emails.retain(|_, owner| owner != id);
+        self.emails.insert(email.to_string(), id.to_string());
+        Ok(())
+    }
+
+    pub fn remove(&mut self, id: &str) {
+        if let Some(stored) = self.users.remove(id) {
+            self.emails.remove(&stored.user.email.0);
+        }
+    }
+}
+
+/// インメモリのユーザー保存先
+///
+/// SQLiteのusersテーブルと同じ制約（ID・メールアドレスの一意性）で保存する。
+/// 複製したものは同じ一覧を共有するため、Command/Query Repositoryに同じものを渡す。
+#[derive(Clone, Debug, Default)]
+pub struct InMemoryUserStore {
+    users: Arc<RwLock<InMemoryUsers>>,
+}
+
+impl InMemoryUserStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // 一覧の操作中にパニックすることはないため、ロックが汚染されていても一覧はそのまま使える
+    pub fn read(&self) -> RwLockReadGuard<'_, InMemoryUsers> {
+        self.users.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    pub fn write(&self) -> RwLockWriteGuard<'_, InMemoryUsers> {
+        self.users.write().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+fn constraint_error(column: &str, value: &str) -> InfrastructureError {
+    InfrastructureError::DatabaseQuery {
+        query: "users".to_string(),
+        message: format!("UNIQUE constraint failed: users.{} ({})", column, value),
+    }
+}
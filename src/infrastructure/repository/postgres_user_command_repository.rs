@@ -0,0 +1,183 @@
+//infrastructure/repository/postgres_user_command_repository.rs
+// PostgreSQL Command Repository実装
+// 2025/7/8
+
+use crate::domain::entity::user::User;
+use crate::domain::repository::user_command_repository::UserCommandRepositoryInterface;
+use crate::domain::value_object::{
+    email::Email, password_hash::PasswordHash, role::Role, user_id::UserId,
+};
+use crate::infrastructure::database::postgres_connection::{PostgresConnection, query_error};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+const INSERT_USER: &str = "INSERT INTO users (id, email, name, password, phone, birth_date, role, email_verified_at) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)";
+
+pub struct PostgresUserCommandRepository {
+    db: PostgresConnection,
+}
+
+impl PostgresUserCommandRepository {
+    pub fn new(db: PostgresConnection) -> Self {
+        Self { db }
+    }
+
+    async fn execute(
+        &self,
+        sql: &str,
+        params: &[&(dyn tokio_postgres::types::ToSql + Sync)],
+    ) -> Result<u64, Box<dyn std::error::Error + Send + Sync>> {
+        let client = self.db.client().await?;
+        Ok(client
+            .execute(sql, params)
+            .await
+            .map_err(|e| query_error("users", e))?)
+    }
+}
+
+#[async_trait]
+impl UserCommandRepositoryInterface for PostgresUserCommandRepository {
+    async fn save(&self, user: &User) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        self.execute(
+            INSERT_USER,
+            &[
+                &user.id.0,
+                &user.email.0,
+                &user.name.0,
+                &user.password_hash.0,
+                &user.phone.as_ref().map(|p| p.0.clone()),
+                &user.birth_date.as_ref().map(|b| b.0.clone()),
+                &user.role.as_str(),
+                &user.email_verified_at,
+            ],
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn update(&self, user: &User) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        self.execute(
+            "UPDATE users SET
+                email_verified_at = CASE WHEN email = $2 THEN email_verified_at ELSE NULL END,
+                email = $2, name = $3, password = $4, phone = $5, birth_date = $6, role = $7
+             WHERE id = $1",
+            &[
+                &user.id.0,
+                &user.email.0,
+                &user.name.0,
+                &user.password_hash.0,
+                &user.phone.as_ref().map(|p| p.0.clone()),
+                &user.birth_date.as_ref().map(|b| b.0.clone()),
+                &user.role.as_str(),
+            ],
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn delete(
+        &self,
+        user_id: &UserId,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        self.execute("DELETE FROM users WHERE id = $1", &[&user_id.0])
+            .await?;
+        Ok(())
+    }
+
+    async fn save_batch(
+        &self,
+        users: &[User],
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let mut client = self.db.client().await?;
+        let tx = client
+            .transaction()
+            .await
+            .map_err(|e| query_error("users", e))?;
+        for user in users {
+            tx.execute(
+                INSERT_USER,
+                &[
+                    &user.id.0,
+                    &user.email.0,
+                    &user.name.0,
+                    &user.password_hash.0,
+                    &user.phone.as_ref().map(|p| p.0.clone()),
+                    &user.birth_date.as_ref().map(|b| b.0.clone()),
+                    &user.role.as_str(),
+                    &user.email_verified_at,
+                ],
+            )
+            .await
+            .map_err(|e| query_error("users", e))?;
+        }
+        tx.commit().await.map_err(|e| query_error("users", e))?;
+        Ok(())
+    }
+
+    async fn update_last_login(
+        &self,
+        user_id: &UserId,
+        login_time: DateTime<Utc>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        self.execute(
+            "UPDATE users SET last_login_at = $1 WHERE id = $2",
+            &[&login_time, &user_id.0],
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn update_password_hash(
+        &self,
+        user_id: &UserId,
+        password_hash: &PasswordHash,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        self.execute(
+            "UPDATE users SET password = $1 WHERE id = $2",
+            &[&password_hash.0, &user_id.0],
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn update_role(
+        &self,
+        user_id: &UserId,
+        role: Role,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        self.execute(
+            "UPDATE users SET role = $1 WHERE id = $2",
+            &[&role.as_str(), &user_id.0],
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn mark_email_verified(
+        &self,
+        user_id: &UserId,
+        verified_at: DateTime<Utc>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        self.execute(
+            "UPDATE users SET email_verified_at = $1 WHERE id = $2",
+            &[&verified_at, &user_id.0],
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn exists_by_email(
+        &self,
+        email: &Email,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+        let client = self.db.client().await?;
+        let row = client
+            .query_one(
+                "SELECT EXISTS (SELECT 1 FROM users WHERE email = $1)",
+                &[&email.0],
+            )
+            .await
+            .map_err(|e| query_error("users", e))?;
+        Ok(row.get(0))
+    }
+}
@@ -0,0 +1,289 @@
+//infrastructure/repository/postgres_user_query_repository.rs
+// PostgreSQL Query Repository実装
+// 2025/7/8
+
+use crate::domain::entity::user::User;
+use crate::domain::repository::user_query_repository::UserQueryRepositoryInterface;
+use crate::domain::value_object::{
+    birth_date::BirthDate, email::Email, pagination::*, password_hash::PasswordHash, phone::Phone,
+    role::Role, user_id::UserId, user_name::UserName,
+};
+use crate::infrastructure::database::postgres_connection::{PostgresConnection, query_error};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tokio_postgres::Row;
+use tokio_postgres::types::ToSql;
+
+const USER_COLUMNS: &str = "id, email, name, password, phone, birth_date, role, email_verified_at";
+
+pub struct PostgresUserQueryRepository {
+    db: PostgresConnection,
+}
+
+impl PostgresUserQueryRepository {
+    pub fn new(db: PostgresConnection) -> Self {
+        Self { db }
+    }
+
+    fn row_to_user(row: &Row) -> Result<User, Box<dyn std::error::Error + Send + Sync>> {
+        let phone: Option<String> = row.try_get("phone")?;
+        let birth_date: Option<String> = row.try_get("birth_date")?;
+        let role: String = row.try_get("role")?;
+        let user = User::new(
+            UserId::new(row.try_get("id")?),
+            Email::new(row.try_get("email")?)?,
+            UserName::new(row.try_get("name")?)?,
+            PasswordHash::new(row.try_get("password")?)?,
+            phone.map(Phone::new).transpose()?,
+            birth_date.map(BirthDate::new).transpose()?,
+        )?
+        .with_role(Role::new(&role)?)
+        .with_email_verified_at(row.try_get("email_verified_at")?);
+        Ok(user)
+    }
+
+    async fn query(
+        &self,
+        sql: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Vec<Row>, Box<dyn std::error::Error + Send + Sync>> {
+        let client = self.db.client().await?;
+        Ok(client
+            .query(sql, params)
+            .await
+            .map_err(|e| query_error("users", e))?)
+    }
+
+    async fn count(
+        &self,
+        sql: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<u64, Box<dyn std::error::Error + Send + Sync>> {
+        let rows = self.query(sql, params).await?;
+        Ok(rows.first().map(|row| row.get::<_, i64>(0)).unwrap_or(0) as u64)
+    }
+
+    async fn find_one(
+        &self,
+        sql: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<User>, Box<dyn std::error::Error + Send + Sync>> {
+        self.query(sql, params)
+            .await?
+            .first()
+            .map(Self::row_to_user)
+            .transpose()
+    }
+}
+
+fn paginated(
+    users: Vec<User>,
+    total_count: u64,
+    pagination: &PaginationParams,
+) -> PaginatedResult<User> {
+    let total_pages = total_count.div_ceil(pagination.limit as u64) as u32;
+    PaginatedResult {
+        data: users,
+        pagination: PaginationInfo {
+            current_page: pagination.page,
+            total_pages,
+            total_count,
+            per_page: pagination.limit,
+            has_next: pagination.page < total_pages,
+            has_prev: pagination.page > 1,
+        },
+    }
+}
+
+#[async_trait]
+impl UserQueryRepositoryInterface for PostgresUserQueryRepository {
+    async fn find_by_id(
+        &self,
+        id: &UserId,
+    ) -> Result<Option<User>, Box<dyn std::error::Error + Send + Sync>> {
+        self.find_one(
+            &format!("SELECT {} FROM users WHERE id = $1", USER_COLUMNS),
+            &[&id.0],
+        )
+        .await
+    }
+
+    async fn find_by_email(
+        &self,
+        email: &Email,
+    ) -> Result<Option<User>, Box<dyn std::error::Error + Send + Sync>> {
+        self.find_one(
+            &format!("SELECT {} FROM users WHERE email = $1", USER_COLUMNS),
+            &[&email.0],
+        )
+        .await
+    }
+
+    async fn exists_by_email(
+        &self,
+        email: &Email,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+        let count = self
+            .count("SELECT COUNT(*) FROM users WHERE email = $1", &[&email.0])
+            .await?;
+        Ok(count > 0)
+    }
+
+    async fn find_all(
+        &self,
+        pagination: PaginationParams,
+    ) -> Result<PaginatedResult<User>, Box<dyn std::error::Error + Send + Sync>> {
+        let offset = ((pagination.page - 1) * pagination.limit) as i64;
+        let total_count = self.count("SELECT COUNT(*) FROM users", &[]).await?;
+        let users = self
+            .query(
+                &format!(
+                    "SELECT {} FROM users ORDER BY created_at DESC LIMIT $1 OFFSET $2",
+                    USER_COLUMNS
+                ),
+                &[&(pagination.limit as i64), &offset],
+            )
+            .await?
+            .iter()
+            .map(Self::row_to_user)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(paginated(users, total_count, &pagination))
+    }
+
+    async fn count_total(&self) -> Result<u64, Box<dyn std::error::Error + Send + Sync>> {
+        self.count("SELECT COUNT(*) FROM users", &[]).await
+    }
+
+    async fn search_users(
+        &self,
+        filters: UserSearchFilters,
+        sort: SortParams,
+        pagination: PaginationParams,
+    ) -> Result<PaginatedResult<User>, Box<dyn std::error::Error + Send + Sync>> {
+        let mut conditions = String::new();
+        let mut params_vec: Vec<String> = Vec::new();
+        if let Some(email_domain) = &filters.email_domain {
+            params_vec.push(format!("%{}", email_domain));
+            conditions.push_str(&format!(" AND email LIKE ${}", params_vec.len()));
+        }
+        if let Some(name_contains) = &filters.name_contains {
+            params_vec.push(format!("%{}%", name_contains));
+            conditions.push_str(&format!(" AND name LIKE ${}", params_vec.len()));
+        }
+        if let Some(created_after) = &filters.created_after {
+            params_vec.push(created_after.clone());
+            conditions.push_str(&format!(
+                " AND created_at >= ${}::text::timestamptz",
+                params_vec.len()
+            ));
+        }
+        if let Some(created_before) = &filters.created_before {
+            params_vec.push(created_before.clone());
+            conditions.push_str(&format!(
+                " AND created_at <= ${}::text::timestamptz",
+                params_vec.len()
+            ));
+        }
+        if let Some(has_phone) = &filters.has_phone {
+            if *has_phone {
+                conditions.push_str(" AND phone IS NOT NULL");
+            } else {
+                conditions.push_str(" AND phone IS NULL");
+            }
+        }
+        let params: Vec<&(dyn ToSql + Sync)> = params_vec
+            .iter()
+            .map(|p| p as &(dyn ToSql + Sync))
+            .collect();
+
+        let sort_field = sort.user_field();
+        let order_str = match sort.order {
+            SortOrder::Asc => "ASC",
+            SortOrder::Desc => "DESC",
+        };
+        let offset = (pagination.page - 1) * pagination.limit;
+        let total_count = self
+            .count(
+                &format!("SELECT COUNT(*) FROM users WHERE TRUE{}", conditions),
+                &params,
+            )
+            .await?;
+        let users = self
+            .query(
+                &format!(
+                    "SELECT {} FROM users WHERE TRUE{} ORDER BY {} {} LIMIT {} OFFSET {}",
+                    USER_COLUMNS, conditions, sort_field, order_str, pagination.limit, offset
+                ),
+                &params,
+            )
+            .await?
+            .iter()
+            .map(Self::row_to_user)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(paginated(users, total_count, &pagination))
+    }
+
+    async fn count_registrations_in_period(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<u64, Box<dyn std::error::Error + Send + Sync>> {
+        self.count(
+            "SELECT COUNT(*) FROM users WHERE created_at BETWEEN $1 AND $2",
+            &[&start, &end],
+        )
+        .await
+    }
+
+    async fn count_active_users_in_period(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<u64, Box<dyn std::error::Error + Send + Sync>> {
+        self.count(
+            "SELECT COUNT(DISTINCT id) FROM users WHERE last_login_at BETWEEN $1 AND $2",
+            &[&start, &end],
+        )
+        .await
+    }
+
+    async fn get_registration_trend(
+        &self,
+        period: TimePeriod,
+        granularity: TimeGranularity,
+    ) -> Result<Vec<TimeSeriesPoint>, Box<dyn std::error::Error + Send + Sync>> {
+        // SQLite（strftime）と同じ形式の区切りにする
+        let date_format = match granularity {
+            TimeGranularity::Hour => "YYYY-MM-DD HH24:00:00",
+            TimeGranularity::Day => "YYYY-MM-DD",
+            TimeGranularity::Week => "IYYY-IW",
+            TimeGranularity::Month => "YYYY-MM",
+            TimeGranularity::Minute => "YYYY-MM-DD HH24:MI:00",
+        };
+        let period_hours: i32 = match period {
+            TimePeriod::Hour => 1,
+            TimePeriod::Day => 24,
+            TimePeriod::Week => 24 * 7,
+            TimePeriod::Month => 24 * 30,
+            TimePeriod::Year => 24 * 365,
+        };
+        let rows = self
+            .query(
+                "SELECT to_char(created_at AT TIME ZONE 'UTC', $1) AS time_bucket, COUNT(*) AS count
+                 FROM users
+                 WHERE created_at >= now() - make_interval(hours => $2)
+                 GROUP BY time_bucket
+                 ORDER BY time_bucket",
+                &[&date_format, &period_hours],
+            )
+            .await?;
+        Ok(rows
+            .iter()
+            .map(|row| TimeSeriesPoint {
+                timestamp: row.get(0),
+                value: row.get::<_, i64>(1) as u64,
+                sample_count: None,
+            })
+            .collect())
+    }
+}
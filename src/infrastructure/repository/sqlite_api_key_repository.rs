@@ -0,0 +1,195 @@
+//infrastructure/repository/sqlite_api_key_repository.rs
+// SQLite APIキー Repository実装
+// 2025/7/8
+
+use crate::domain::entity::api_key::ApiKey;
+use crate::domain::repository::api_key_repository::ApiKeyRepositoryInterface;
+use crate::domain::value_object::{permission::Permission, user_id::UserId};
+use crate::infrastructure::database::sqlite_connection::SqliteConnection;
+use crate::shared::error::infrastructure_error::InfrastructureResult;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rusqlite::{OptionalExtension, Row, params};
+
+const API_KEY_COLUMNS: &str = "id, owner_id, name, prefix, key_hash, scopes, expires_at, last_used_at, created_at, created_by";
+
+pub struct SqliteApiKeyRepository {
+    db: SqliteConnection,
+}
+
+impl SqliteApiKeyRepository {
+    pub fn new(db: SqliteConnection) -> Self {
+        Self { db }
+    }
+
+    fn parse_time(value: String) -> rusqlite::Result<DateTime<Utc>> {
+        DateTime::parse_from_rfc3339(&value)
+            .map(|t| t.with_timezone(&Utc))
+            .map_err(|e| rusqlite::Error::InvalidParameterName(e.to_string()))
+    }
+
+    fn row_to_api_key(row: &Row) -> rusqlite::Result<ApiKey> {
+        let scopes = row
+            .get::<_, String>("scopes")?
+            .split_whitespace()
+            .map(|scope| {
+                Permission::new(scope)
+                    .map_err(|e| rusqlite::Error::InvalidParameterName(e.to_string()))
+            })
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        Ok(ApiKey {
+            id: row.get("id")?,
+            owner_id: UserId::new(row.get::<_, String>("owner_id")?),
+            name: row.get("name")?,
+            prefix: row.get("prefix")?,
+            key_hash: row.get("key_hash")?,
+            scopes,
+            expires_at: row
+                .get::<_, Option<String>>("expires_at")?
+                .map(Self::parse_time)
+                .transpose()?,
+            last_used_at: row
+                .get::<_, Option<String>>("last_used_at")?
+                .map(Self::parse_time)
+                .transpose()?,
+            created_at: Self::parse_time(row.get("created_at")?)?,
+            created_by: UserId::new(row.get::<_, String>("created_by")?),
+        })
+    }
+
+    async fn find_one(
+        &self,
+        column: &'static str,
+        value: String,
+    ) -> Result<Option<ApiKey>, Box<dyn std::error::Error + Send + Sync>> {
+        let result: InfrastructureResult<Option<ApiKey>> = self
+            .db
+            .execute_query(move |conn| {
+                conn.query_row(
+                    &format!(
+                        "SELECT {} FROM api_keys WHERE {} = ?",
+                        API_KEY_COLUMNS, column
+                    ),
+                    params![value],
+                    Self::row_to_api_key,
+                )
+                .optional()
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+}
+
+#[async_trait]
+impl ApiKeyRepositoryInterface for SqliteApiKeyRepository {
+    async fn save(&self, api_key: &ApiKey) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let api_key = api_key.clone();
+        let result: InfrastructureResult<()> = self
+            .db
+            .execute_command(move |conn| {
+                conn.execute(
+                    &format!(
+                        "INSERT INTO api_keys ({}) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+                        API_KEY_COLUMNS
+                    ),
+                    params![
+                        api_key.id,
+                        api_key.owner_id.0,
+                        api_key.name,
+                        api_key.prefix,
+                        api_key.key_hash,
+                        api_key.scope_string(),
+                        api_key.expires_at.map(|t| t.to_rfc3339()),
+                        api_key.last_used_at.map(|t| t.to_rfc3339()),
+                        api_key.created_at.to_rfc3339(),
+                        api_key.created_by.0
+                    ],
+                )?;
+                Ok(())
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+
+    async fn find_by_id(
+        &self,
+        id: &str,
+    ) -> Result<Option<ApiKey>, Box<dyn std::error::Error + Send + Sync>> {
+        self.find_one("id", id.to_string()).await
+    }
+
+    async fn find_by_hash(
+        &self,
+        key_hash: &str,
+    ) -> Result<Option<ApiKey>, Box<dyn std::error::Error + Send + Sync>> {
+        self.find_one("key_hash", key_hash.to_string()).await
+    }
+
+    async fn find_by_owner(
+        &self,
+        owner_id: &UserId,
+    ) -> Result<Vec<ApiKey>, Box<dyn std::error::Error + Send + Sync>> {
+        let owner_id = owner_id.0.clone();
+        let result: InfrastructureResult<Vec<ApiKey>> = self
+            .db
+            .execute_query(move |conn| {
+                let mut stmt = conn.prepare(&format!(
+                    "SELECT {} FROM api_keys WHERE owner_id = ? ORDER BY created_at",
+                    API_KEY_COLUMNS
+                ))?;
+                let rows = stmt.query_map(params![owner_id], Self::row_to_api_key)?;
+                rows.collect()
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+
+    async fn update(
+        &self,
+        api_key: &ApiKey,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+        let api_key = api_key.clone();
+        let result: InfrastructureResult<bool> = self
+            .db
+            .execute_command(move |conn| {
+                let updated = conn.execute(
+                    "UPDATE api_keys SET name = ?2, scopes = ?3 WHERE id = ?1",
+                    params![api_key.id, api_key.name, api_key.scope_string()],
+                )?;
+                Ok(updated > 0)
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+
+    async fn touch_last_used(
+        &self,
+        id: &str,
+        used_at: DateTime<Utc>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let id = id.to_string();
+        let result: InfrastructureResult<()> = self
+            .db
+            .execute_command(move |conn| {
+                conn.execute(
+                    "UPDATE api_keys SET last_used_at = ?2 WHERE id = ?1",
+                    params![id, used_at.to_rfc3339()],
+                )?;
+                Ok(())
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+
+    async fn delete(&self, id: &str) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+        let id = id.to_string();
+        let result: InfrastructureResult<bool> = self
+            .db
+            .execute_command(move |conn| {
+                let deleted = conn.execute("DELETE FROM api_keys WHERE id = ?", params![id])?;
+                Ok(deleted > 0)
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+}
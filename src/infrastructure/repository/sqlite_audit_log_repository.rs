@@ -0,0 +1,88 @@
+//infrastructure/repository/sqlite_audit_log_repository.rs
+// SQLite 監査ログ Repository実装
+// 2025/7/8
+
+use crate::domain::entity::audit_event::AuditEvent;
+use crate::domain::repository::audit_log_repository::AuditLogRepositoryInterface;
+use crate::infrastructure::database::sqlite_connection::SqliteConnection;
+use crate::shared::error::infrastructure_error::InfrastructureResult;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rusqlite::params;
+
+pub struct SqliteAuditLogRepository {
+    db: SqliteConnection,
+}
+
+impl SqliteAuditLogRepository {
+    pub fn new(db: SqliteConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl AuditLogRepositoryInterface for SqliteAuditLogRepository {
+    async fn record(
+        &self,
+        event: &AuditEvent,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let event = event.clone();
+        let result: InfrastructureResult<()> = self
+            .db
+            .execute_command(move |conn| {
+                conn.execute(
+                    "INSERT INTO audit_log (id, action, actor, subject, detail, occurred_at)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                    params![
+                        event.id,
+                        event.action,
+                        event.actor,
+                        event.subject,
+                        event.detail,
+                        event.occurred_at.to_rfc3339(),
+                    ],
+                )?;
+                Ok(())
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+
+    async fn find_by_subject(
+        &self,
+        subject: &str,
+        limit: usize,
+    ) -> Result<Vec<AuditEvent>, Box<dyn std::error::Error + Send + Sync>> {
+        let subject = subject.to_string();
+        let result: InfrastructureResult<Vec<AuditEvent>> = self
+            .db
+            .execute_query(move |conn| {
+                let mut stmt = conn.prepare(
+                    "SELECT id, action, actor, subject, detail, occurred_at FROM audit_log
+                     WHERE subject = ?1 ORDER BY occurred_at DESC, rowid DESC LIMIT ?2",
+                )?;
+                let rows = stmt.query_map(params![subject, limit as i64], |row| {
+                    let occurred_at: String = row.get(5)?;
+                    Ok(AuditEvent {
+                        id: row.get(0)?,
+                        action: row.get(1)?,
+                        actor: row.get(2)?,
+                        subject: row.get(3)?,
+                        detail: row.get(4)?,
+                        occurred_at: DateTime::parse_from_rfc3339(&occurred_at)
+                            .map(|dt| dt.with_timezone(&Utc))
+                            .map_err(|e| {
+                                rusqlite::Error::FromSqlConversionFailure(
+                                    5,
+                                    rusqlite::types::Type::Text,
+                                    Box::new(e),
+                                )
+                            })?,
+                    })
+                })?;
+                rows.collect()
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+}
@@ -0,0 +1,130 @@
+//infrastructure/repository/sqlite_email_verification_token_repository.rs
+// SQLite メールアドレス確認トークン Repository実装
+// 2025/7/8
+
+use crate::domain::entity::email_verification_token::EmailVerificationToken;
+use crate::domain::repository::email_verification_token_repository::EmailVerificationTokenRepositoryInterface;
+use crate::domain::value_object::user_id::UserId;
+use crate::infrastructure::database::sqlite_connection::SqliteConnection;
+use crate::shared::error::infrastructure_error::InfrastructureResult;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rusqlite::{OptionalExtension, Row, params};
+
+pub struct SqliteEmailVerificationTokenRepository {
+    db: SqliteConnection,
+}
+
+impl SqliteEmailVerificationTokenRepository {
+    pub fn new(db: SqliteConnection) -> Self {
+        Self { db }
+    }
+
+    fn parse_time(value: String) -> rusqlite::Result<DateTime<Utc>> {
+        DateTime::parse_from_rfc3339(&value)
+            .map(|t| t.with_timezone(&Utc))
+            .map_err(|e| rusqlite::Error::InvalidParameterName(e.to_string()))
+    }
+
+    fn row_to_token(row: &Row) -> rusqlite::Result<EmailVerificationToken> {
+        Ok(EmailVerificationToken::new(
+            row.get("token_hash")?,
+            UserId::new(row.get::<_, String>("user_id")?),
+            row.get("email")?,
+            Self::parse_time(row.get("issued_at")?)?,
+            Self::parse_time(row.get("expires_at")?)?,
+        ))
+    }
+}
+
+#[async_trait]
+impl EmailVerificationTokenRepositoryInterface for SqliteEmailVerificationTokenRepository {
+    async fn save(
+        &self,
+        token: &EmailVerificationToken,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let token = token.clone();
+        let result: InfrastructureResult<()> = self
+            .db
+            .execute_command(move |conn| {
+                conn.execute(
+                    "INSERT INTO email_verification_tokens (token_hash, user_id, email, issued_at, expires_at)
+                     VALUES (?1, ?2, ?3, ?4, ?5)",
+                    params![
+                        token.token_hash,
+                        token.user_id.0,
+                        token.email,
+                        token.issued_at.to_rfc3339(),
+                        token.expires_at.to_rfc3339()
+                    ],
+                )?;
+                Ok(())
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+
+    async fn consume(
+        &self,
+        token_hash: &str,
+        now: DateTime<Utc>,
+    ) -> Result<Option<EmailVerificationToken>, Box<dyn std::error::Error + Send + Sync>> {
+        let token_hash = token_hash.to_string();
+        let result: InfrastructureResult<Option<EmailVerificationToken>> = self
+            .db
+            .execute_command(move |conn| {
+                // 使用済みへの更新に成功した1リクエストだけがトークンを得る
+                conn.query_row(
+                    "UPDATE email_verification_tokens SET used_at = ?2
+                     WHERE token_hash = ?1 AND used_at IS NULL AND expires_at > ?2
+                     RETURNING token_hash, user_id, email, issued_at, expires_at",
+                    params![token_hash, now.to_rfc3339()],
+                    Self::row_to_token,
+                )
+                .optional()
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+
+    async fn invalidate_user_tokens(
+        &self,
+        user_id: &UserId,
+    ) -> Result<usize, Box<dyn std::error::Error + Send + Sync>> {
+        let user_id = user_id.clone();
+        let result: InfrastructureResult<usize> = self
+            .db
+            .execute_command(move |conn| {
+                conn.execute(
+                    "UPDATE email_verification_tokens SET used_at = ?2
+                     WHERE user_id = ?1 AND used_at IS NULL",
+                    params![user_id.0, Utc::now().to_rfc3339()],
+                )
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+
+    async fn issued_since(
+        &self,
+        user_id: &UserId,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<DateTime<Utc>>, Box<dyn std::error::Error + Send + Sync>> {
+        let user_id = user_id.clone();
+        let result: InfrastructureResult<Vec<DateTime<Utc>>> = self
+            .db
+            .execute_query(move |conn| {
+                let mut stmt = conn.prepare(
+                    "SELECT issued_at FROM email_verification_tokens
+                     WHERE user_id = ?1 AND issued_at >= ?2
+                     ORDER BY issued_at",
+                )?;
+                let rows = stmt.query_map(params![user_id.0, since.to_rfc3339()], |row| {
+                    Self::parse_time(row.get(0)?)
+                })?;
+                rows.collect()
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+}
@@ -0,0 +1,153 @@
+//infrastructure/repository/sqlite_login_attempt_repository.rs
+// SQLite ログイン失敗カウンター Repository実装
+// 2025/7/8
+
+use crate::domain::entity::login_attempt::{LoginAttempt, LoginAttemptScope};
+use crate::domain::repository::login_attempt_repository::LoginAttemptRepositoryInterface;
+use crate::domain::service::lockout_policy::LockoutPolicy;
+use crate::infrastructure::database::sqlite_connection::SqliteConnection;
+use crate::shared::error::infrastructure_error::InfrastructureResult;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rusqlite::{Connection, OptionalExtension, TransactionBehavior, params};
+
+pub struct SqliteLoginAttemptRepository {
+    db: SqliteConnection,
+}
+
+impl SqliteLoginAttemptRepository {
+    pub fn new(db: SqliteConnection) -> Self {
+        Self { db }
+    }
+
+    fn parse_datetime(value: &str) -> rusqlite::Result<DateTime<Utc>> {
+        DateTime::parse_from_rfc3339(value)
+            .map(|dt| dt.with_timezone(&Utc))
+            .map_err(|e| {
+                rusqlite::Error::FromSqlConversionFailure(
+                    0,
+                    rusqlite::types::Type::Text,
+                    Box::new(e),
+                )
+            })
+    }
+
+    fn select(
+        conn: &Connection,
+        scope: LoginAttemptScope,
+        key: &str,
+    ) -> rusqlite::Result<Option<LoginAttempt>> {
+        conn.query_row(
+            "SELECT failed_count, last_failed_at, locked_until FROM login_attempts
+             WHERE scope = ?1 AND key = ?2",
+            params![scope.as_str(), key],
+            |row| {
+                let last_failed_at: String = row.get(1)?;
+                let locked_until: Option<String> = row.get(2)?;
+                Ok(LoginAttempt {
+                    scope,
+                    key: key.to_string(),
+                    failed_count: row.get(0)?,
+                    last_failed_at: Self::parse_datetime(&last_failed_at)?,
+                    locked_until: locked_until
+                        .as_deref()
+                        .map(Self::parse_datetime)
+                        .transpose()?,
+                })
+            },
+        )
+        .optional()
+    }
+}
+
+#[async_trait]
+impl LoginAttemptRepositoryInterface for SqliteLoginAttemptRepository {
+    async fn find(
+        &self,
+        scope: LoginAttemptScope,
+        key: &str,
+    ) -> Result<Option<LoginAttempt>, Box<dyn std::error::Error + Send + Sync>> {
+        let key = key.to_string();
+        let result: InfrastructureResult<Option<LoginAttempt>> = self
+            .db
+            .execute_query(move |conn| Self::select(conn, scope, &key))
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+
+    async fn register_failure(
+        &self,
+        scope: LoginAttemptScope,
+        key: &str,
+        now: DateTime<Utc>,
+        policy: &LockoutPolicy,
+    ) -> Result<(LoginAttempt, bool), Box<dyn std::error::Error + Send + Sync>> {
+        let key = key.to_string();
+        let policy = *policy;
+        let result: InfrastructureResult<(LoginAttempt, bool)> = self
+            .db
+            .execute_command(move |conn| {
+                // 他の接続（別プロセス）の加算とも重ならないよう、読み取り前に書き込みロックを取る
+                let tx = conn.transaction_with_behavior(TransactionBehavior::Immediate)?;
+                let mut attempt = Self::select(&tx, scope, &key)?
+                    .unwrap_or_else(|| LoginAttempt::new(scope, key, now));
+                let locked = attempt.register_failure(now, &policy);
+                tx.execute(
+                    "INSERT INTO login_attempts (scope, key, failed_count, last_failed_at, locked_until)
+                     VALUES (?1, ?2, ?3, ?4, ?5)
+                     ON CONFLICT(scope, key) DO UPDATE SET
+                        failed_count = excluded.failed_count,
+                        last_failed_at = excluded.last_failed_at,
+                        locked_until = excluded.locked_until",
+                    params![
+                        attempt.scope.as_str(),
+                        attempt.key,
+                        attempt.failed_count,
+                        attempt.last_failed_at.to_rfc3339(),
+                        attempt.locked_until.map(|until| until.to_rfc3339()),
+                    ],
+                )?;
+                tx.commit()?;
+                Ok((attempt, locked))
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+
+    async fn delete(
+        &self,
+        scope: LoginAttemptScope,
+        key: &str,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+        let key = key.to_string();
+        let result: InfrastructureResult<usize> = self
+            .db
+            .execute_command(move |conn| {
+                conn.execute(
+                    "DELETE FROM login_attempts WHERE scope = ?1 AND key = ?2",
+                    params![scope.as_str(), key],
+                )
+            })
+            .await;
+        result
+            .map(|deleted| deleted > 0)
+            .map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+
+    async fn purge_stale(
+        &self,
+        before: DateTime<Utc>,
+    ) -> Result<usize, Box<dyn std::error::Error + Send + Sync>> {
+        let result: InfrastructureResult<usize> = self
+            .db
+            .execute_command(move |conn| {
+                conn.execute(
+                    "DELETE FROM login_attempts
+                     WHERE last_failed_at < ?1 AND (locked_until IS NULL OR locked_until < ?1)",
+                    params![before.to_rfc3339()],
+                )
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+}
@@ -0,0 +1,215 @@
+//infrastructure/repository/sqlite_mfa_credential_repository.rs
+// SQLite 二要素認証 Repository実装
+// 2025/7/8
+
+use crate::domain::entity::mfa_credential::MfaCredential;
+use crate::domain::repository::mfa_credential_repository::MfaCredentialRepositoryInterface;
+use crate::domain::value_object::user_id::UserId;
+use crate::infrastructure::database::sqlite_connection::SqliteConnection;
+use crate::shared::error::infrastructure_error::InfrastructureResult;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rusqlite::{OptionalExtension, params};
+
+pub struct SqliteMfaCredentialRepository {
+    db: SqliteConnection,
+}
+
+impl SqliteMfaCredentialRepository {
+    pub fn new(db: SqliteConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl MfaCredentialRepositoryInterface for SqliteMfaCredentialRepository {
+    async fn find(
+        &self,
+        user_id: &UserId,
+    ) -> Result<Option<MfaCredential>, Box<dyn std::error::Error + Send + Sync>> {
+        let user_id = user_id.clone();
+        let result: InfrastructureResult<Option<MfaCredential>> = self
+            .db
+            .execute_query(move |conn| {
+                conn.query_row(
+                    "SELECT secret, confirmed_at, last_used_step FROM mfa_credentials WHERE user_id = ?",
+                    params![user_id.0],
+                    |row| {
+                        let confirmed_at = row
+                            .get::<_, Option<String>>(1)?
+                            .map(|v| {
+                                DateTime::parse_from_rfc3339(&v)
+                                    .map(|t| t.with_timezone(&Utc))
+                                    .map_err(|e| {
+                                        rusqlite::Error::InvalidParameterName(e.to_string())
+                                    })
+                            })
+                            .transpose()?;
+                        Ok(MfaCredential {
+                            user_id: user_id.clone(),
+                            secret: row.get(0)?,
+                            confirmed_at,
+                            last_used_step: row.get(2)?,
+                        })
+                    },
+                )
+                .optional()
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+
+    async fn save_pending(
+        &self,
+        credential: &MfaCredential,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let credential = credential.clone();
+        let result: InfrastructureResult<()> = self
+            .db
+            .execute_command(move |conn| {
+                // 確認済みの登録は上書きしない
+                conn.execute(
+                    "INSERT INTO mfa_credentials (user_id, secret) VALUES (?1, ?2)
+                     ON CONFLICT(user_id) DO UPDATE SET secret = excluded.secret, last_used_step = NULL
+                     WHERE mfa_credentials.confirmed_at IS NULL",
+                    params![credential.user_id.0, credential.secret],
+                )?;
+                Ok(())
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+
+    async fn confirm(
+        &self,
+        user_id: &UserId,
+        confirmed_at: DateTime<Utc>,
+        step: i64,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+        let user_id = user_id.clone();
+        let result: InfrastructureResult<bool> = self
+            .db
+            .execute_command(move |conn| {
+                let updated = conn.execute(
+                    "UPDATE mfa_credentials SET confirmed_at = ?2, last_used_step = ?3
+                     WHERE user_id = ?1 AND confirmed_at IS NULL",
+                    params![user_id.0, confirmed_at.to_rfc3339(), step],
+                )?;
+                Ok(updated > 0)
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+
+    async fn record_used_step(
+        &self,
+        user_id: &UserId,
+        step: i64,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+        let user_id = user_id.clone();
+        let result: InfrastructureResult<bool> = self
+            .db
+            .execute_command(move |conn| {
+                let updated = conn.execute(
+                    "UPDATE mfa_credentials SET last_used_step = ?2
+                     WHERE user_id = ?1 AND (last_used_step IS NULL OR last_used_step < ?2)",
+                    params![user_id.0, step],
+                )?;
+                Ok(updated > 0)
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+
+    async fn delete(
+        &self,
+        user_id: &UserId,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+        let user_id = user_id.clone();
+        let result: InfrastructureResult<bool> = self
+            .db
+            .execute_command(move |conn| {
+                let tx = conn.transaction()?;
+                tx.execute(
+                    "DELETE FROM mfa_recovery_codes WHERE user_id = ?",
+                    params![user_id.0],
+                )?;
+                let deleted = tx.execute(
+                    "DELETE FROM mfa_credentials WHERE user_id = ?",
+                    params![user_id.0],
+                )?;
+                tx.commit()?;
+                Ok(deleted > 0)
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+
+    async fn replace_recovery_codes(
+        &self,
+        user_id: &UserId,
+        code_hashes: &[String],
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let user_id = user_id.clone();
+        let code_hashes = code_hashes.to_vec();
+        let result: InfrastructureResult<()> = self
+            .db
+            .execute_command(move |conn| {
+                let tx = conn.transaction()?;
+                tx.execute(
+                    "DELETE FROM mfa_recovery_codes WHERE user_id = ?",
+                    params![user_id.0],
+                )?;
+                for code_hash in &code_hashes {
+                    tx.execute(
+                        "INSERT INTO mfa_recovery_codes (user_id, code_hash) VALUES (?1, ?2)",
+                        params![user_id.0, code_hash],
+                    )?;
+                }
+                tx.commit()?;
+                Ok(())
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+
+    async fn consume_recovery_code(
+        &self,
+        user_id: &UserId,
+        code_hash: &str,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+        let user_id = user_id.clone();
+        let code_hash = code_hash.to_string();
+        let result: InfrastructureResult<bool> = self
+            .db
+            .execute_command(move |conn| {
+                let updated = conn.execute(
+                    "UPDATE mfa_recovery_codes SET used_at = ?3
+                     WHERE user_id = ?1 AND code_hash = ?2 AND used_at IS NULL",
+                    params![user_id.0, code_hash, Utc::now().to_rfc3339()],
+                )?;
+                Ok(updated > 0)
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+
+    async fn remaining_recovery_codes(
+        &self,
+        user_id: &UserId,
+    ) -> Result<usize, Box<dyn std::error::Error + Send + Sync>> {
+        let user_id = user_id.clone();
+        let result: InfrastructureResult<usize> = self
+            .db
+            .execute_query(move |conn| {
+                let count: i64 = conn.query_row(
+                    "SELECT COUNT(*) FROM mfa_recovery_codes WHERE user_id = ? AND used_at IS NULL",
+                    params![user_id.0],
+                    |row| row.get(0),
+                )?;
+                Ok(count as usize)
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+}
@@ -0,0 +1,101 @@
+//infrastructure/repository/sqlite_oauth_authorization_code_repository.rs
+// SQLite OAuth認可コード Repository実装
+// 2025/7/8
+
+use crate::domain::entity::oauth_authorization_code::OAuthAuthorizationCode;
+use crate::domain::repository::oauth_authorization_code_repository::OAuthAuthorizationCodeRepositoryInterface;
+use crate::domain::value_object::user_id::UserId;
+use crate::infrastructure::database::sqlite_connection::SqliteConnection;
+use crate::shared::error::infrastructure_error::InfrastructureResult;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rusqlite::{OptionalExtension, Row, params};
+
+pub struct SqliteOAuthAuthorizationCodeRepository {
+    db: SqliteConnection,
+}
+
+impl SqliteOAuthAuthorizationCodeRepository {
+    pub fn new(db: SqliteConnection) -> Self {
+        Self { db }
+    }
+
+    fn parse_time(value: String) -> rusqlite::Result<DateTime<Utc>> {
+        DateTime::parse_from_rfc3339(&value)
+            .map(|t| t.with_timezone(&Utc))
+            .map_err(|e| rusqlite::Error::InvalidParameterName(e.to_string()))
+    }
+
+    fn row_to_code(row: &Row) -> rusqlite::Result<OAuthAuthorizationCode> {
+        Ok(OAuthAuthorizationCode {
+            code_hash: row.get("code_hash")?,
+            client_id: row.get("client_id")?,
+            user_id: UserId::new(row.get::<_, String>("user_id")?),
+            redirect_uri: row.get("redirect_uri")?,
+            scope: row.get("scope")?,
+            code_challenge: row.get("code_challenge")?,
+            nonce: row.get("nonce")?,
+            expires_at: Self::parse_time(row.get("expires_at")?)?,
+        })
+    }
+}
+
+#[async_trait]
+impl OAuthAuthorizationCodeRepositoryInterface for SqliteOAuthAuthorizationCodeRepository {
+    async fn save(
+        &self,
+        code: &OAuthAuthorizationCode,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let code = code.clone();
+        let result: InfrastructureResult<()> = self
+            .db
+            .execute_command(move |conn| {
+                let tx = conn.transaction()?;
+                tx.execute(
+                    "DELETE FROM oauth_authorization_codes WHERE expires_at <= ?",
+                    params![Utc::now().to_rfc3339()],
+                )?;
+                tx.execute(
+                    "INSERT INTO oauth_authorization_codes
+                     (code_hash, client_id, user_id, redirect_uri, scope, code_challenge, nonce, expires_at)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                    params![
+                        code.code_hash,
+                        code.client_id,
+                        code.user_id.0,
+                        code.redirect_uri,
+                        code.scope,
+                        code.code_challenge,
+                        code.nonce,
+                        code.expires_at.to_rfc3339()
+                    ],
+                )?;
+                tx.commit()
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+
+    async fn consume(
+        &self,
+        code_hash: &str,
+        used_at: DateTime<Utc>,
+    ) -> Result<Option<OAuthAuthorizationCode>, Box<dyn std::error::Error + Send + Sync>> {
+        let code_hash = code_hash.to_string();
+        let result: InfrastructureResult<Option<OAuthAuthorizationCode>> = self
+            .db
+            .execute_command(move |conn| {
+                // 使用済みへの更新に成功した1リクエストだけがコードを得る
+                conn.query_row(
+                    "UPDATE oauth_authorization_codes SET used_at = ?2
+                     WHERE code_hash = ?1 AND used_at IS NULL
+                     RETURNING code_hash, client_id, user_id, redirect_uri, scope, code_challenge, nonce, expires_at",
+                    params![code_hash, used_at.to_rfc3339()],
+                    Self::row_to_code,
+                )
+                .optional()
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+}
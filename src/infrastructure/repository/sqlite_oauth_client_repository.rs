@@ -0,0 +1,164 @@
+//infrastructure/repository/sqlite_oauth_client_repository.rs
+// SQLite OAuthクライアント Repository実装
+// 2025/7/8
+
+use crate::domain::entity::oauth_client::OAuthClient;
+use crate::domain::repository::oauth_client_repository::OAuthClientRepositoryInterface;
+use crate::domain::value_object::{
+    oauth_grant_type::OAuthGrantType, permission::Permission, user_id::UserId,
+};
+use crate::infrastructure::database::sqlite_connection::SqliteConnection;
+use crate::shared::error::infrastructure_error::InfrastructureResult;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rusqlite::{OptionalExtension, Row, params};
+
+const OAUTH_CLIENT_COLUMNS: &str = "client_id, client_secret_hash, name, redirect_uris, grant_types, scopes, service_account_id, created_at, created_by";
+
+pub struct SqliteOAuthClientRepository {
+    db: SqliteConnection,
+}
+
+impl SqliteOAuthClientRepository {
+    pub fn new(db: SqliteConnection) -> Self {
+        Self { db }
+    }
+
+    fn parse_time(value: String) -> rusqlite::Result<DateTime<Utc>> {
+        DateTime::parse_from_rfc3339(&value)
+            .map(|t| t.with_timezone(&Utc))
+            .map_err(|e| rusqlite::Error::InvalidParameterName(e.to_string()))
+    }
+
+    /// 空白区切りの列を値オブジェクトの一覧として読む
+    fn parse_list<T, E: std::fmt::Display>(
+        value: String,
+        parse: impl Fn(&str) -> Result<T, E>,
+    ) -> rusqlite::Result<Vec<T>> {
+        value
+            .split_whitespace()
+            .map(|item| {
+                parse(item).map_err(|e| rusqlite::Error::InvalidParameterName(e.to_string()))
+            })
+            .collect()
+    }
+
+    fn join<T>(items: &[T], as_str: impl Fn(&T) -> &str) -> String {
+        items.iter().map(as_str).collect::<Vec<_>>().join(" ")
+    }
+
+    fn row_to_client(row: &Row) -> rusqlite::Result<OAuthClient> {
+        Ok(OAuthClient {
+            client_id: row.get("client_id")?,
+            client_secret_hash: row.get("client_secret_hash")?,
+            name: row.get("name")?,
+            redirect_uris: row
+                .get::<_, String>("redirect_uris")?
+                .split_whitespace()
+                .map(str::to_string)
+                .collect(),
+            grant_types: Self::parse_list(row.get("grant_types")?, OAuthGrantType::new)?,
+            scopes: Self::parse_list(row.get("scopes")?, Permission::new)?,
+            service_account_id: row
+                .get::<_, Option<String>>("service_account_id")?
+                .map(UserId::new),
+            created_at: Self::parse_time(row.get("created_at")?)?,
+            created_by: UserId::new(row.get::<_, String>("created_by")?),
+        })
+    }
+}
+
+#[async_trait]
+impl OAuthClientRepositoryInterface for SqliteOAuthClientRepository {
+    async fn save(
+        &self,
+        client: &OAuthClient,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let client = client.clone();
+        let result: InfrastructureResult<()> = self
+            .db
+            .execute_command(move |conn| {
+                conn.execute(
+                    &format!(
+                        "INSERT INTO oauth_clients ({}) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+                        OAUTH_CLIENT_COLUMNS
+                    ),
+                    params![
+                        client.client_id,
+                        client.client_secret_hash,
+                        client.name,
+                        client.redirect_uris.join(" "),
+                        Self::join(&client.grant_types, |g| g.as_str()),
+                        Self::join(&client.scopes, |p| p.as_str()),
+                        client.service_account_id.as_ref().map(|id| id.0.clone()),
+                        client.created_at.to_rfc3339(),
+                        client.created_by.0
+                    ],
+                )?;
+                Ok(())
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+
+    async fn find_by_id(
+        &self,
+        client_id: &str,
+    ) -> Result<Option<OAuthClient>, Box<dyn std::error::Error + Send + Sync>> {
+        let client_id = client_id.to_string();
+        let result: InfrastructureResult<Option<OAuthClient>> = self
+            .db
+            .execute_query(move |conn| {
+                conn.query_row(
+                    &format!(
+                        "SELECT {} FROM oauth_clients WHERE client_id = ?",
+                        OAUTH_CLIENT_COLUMNS
+                    ),
+                    params![client_id],
+                    Self::row_to_client,
+                )
+                .optional()
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+
+    async fn find_all(&self) -> Result<Vec<OAuthClient>, Box<dyn std::error::Error + Send + Sync>> {
+        let result: InfrastructureResult<Vec<OAuthClient>> = self
+            .db
+            .execute_query(move |conn| {
+                let mut stmt = conn.prepare(&format!(
+                    "SELECT {} FROM oauth_clients ORDER BY created_at",
+                    OAUTH_CLIENT_COLUMNS
+                ))?;
+                let rows = stmt.query_map([], Self::row_to_client)?;
+                rows.collect()
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+
+    async fn delete(
+        &self,
+        client_id: &str,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+        let client_id = client_id.to_string();
+        let result: InfrastructureResult<bool> = self
+            .db
+            .execute_command(move |conn| {
+                let tx = conn.transaction()?;
+                tx.execute(
+                    "DELETE FROM oauth_authorization_codes WHERE client_id = ?",
+                    params![client_id],
+                )?;
+                let deleted = tx.execute(
+                    "DELETE FROM oauth_clients WHERE client_id = ?",
+                    params![client_id],
+                )?;
+                tx.commit()?;
+                Ok(deleted > 0)
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+}
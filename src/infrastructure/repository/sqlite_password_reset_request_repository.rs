@@ -0,0 +1,74 @@
+//infrastructure/repository/sqlite_password_reset_request_repository.rs
+// SQLite パスワードリセット要求の記録 Repository実装
+// 2025/7/8
+
+use crate::domain::repository::password_reset_request_repository::{
+    PasswordResetRequestRepositoryInterface, PasswordResetRequestScope,
+};
+use crate::domain::service::request_rate_policy::RequestRatePolicy;
+use crate::infrastructure::database::sqlite_connection::SqliteConnection;
+use crate::shared::error::infrastructure_error::InfrastructureResult;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rusqlite::{TransactionBehavior, params};
+
+pub struct SqlitePasswordResetRequestRepository {
+    db: SqliteConnection,
+}
+
+impl SqlitePasswordResetRequestRepository {
+    pub fn new(db: SqliteConnection) -> Self {
+        Self { db }
+    }
+
+    fn parse_time(value: String) -> rusqlite::Result<DateTime<Utc>> {
+        DateTime::parse_from_rfc3339(&value)
+            .map(|t| t.with_timezone(&Utc))
+            .map_err(|e| rusqlite::Error::InvalidParameterName(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl PasswordResetRequestRepositoryInterface for SqlitePasswordResetRequestRepository {
+    async fn record_if_allowed(
+        &self,
+        scope: PasswordResetRequestScope,
+        key: &str,
+        now: DateTime<Utc>,
+        policy: &RequestRatePolicy,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+        let key = key.to_string();
+        let policy = *policy;
+        let result: InfrastructureResult<bool> = self
+            .db
+            .execute_command(move |conn| {
+                // 他の接続（別プロセス）の記録とも重ならないよう、読み取り前に書き込みロックを取る
+                let tx = conn.transaction_with_behavior(TransactionBehavior::Immediate)?;
+                tx.execute(
+                    "DELETE FROM password_reset_requests WHERE requested_at <= ?1",
+                    params![(now - RequestRatePolicy::window()).to_rfc3339()],
+                )?;
+                let recent = {
+                    let mut stmt = tx.prepare(
+                        "SELECT requested_at FROM password_reset_requests
+                         WHERE scope = ?1 AND key = ?2 ORDER BY requested_at",
+                    )?;
+                    let rows = stmt.query_map(params![scope.as_str(), key], |row| {
+                        Self::parse_time(row.get(0)?)
+                    })?;
+                    rows.collect::<rusqlite::Result<Vec<_>>>()?
+                };
+                let allowed = policy.allows(&recent, now);
+                if allowed {
+                    tx.execute(
+                        "INSERT INTO password_reset_requests (scope, key, requested_at) VALUES (?1, ?2, ?3)",
+                        params![scope.as_str(), key, now.to_rfc3339()],
+                    )?;
+                }
+                tx.commit()?;
+                Ok(allowed)
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+}
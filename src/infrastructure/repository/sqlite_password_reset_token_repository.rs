@@ -0,0 +1,105 @@
+//infrastructure/repository/sqlite_password_reset_token_repository.rs
+// SQLite パスワードリセットトークン Repository実装
+// 2025/7/8
+
+use crate::domain::entity::password_reset_token::PasswordResetToken;
+use crate::domain::repository::password_reset_token_repository::PasswordResetTokenRepositoryInterface;
+use crate::domain::value_object::user_id::UserId;
+use crate::infrastructure::database::sqlite_connection::SqliteConnection;
+use crate::shared::error::infrastructure_error::InfrastructureResult;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rusqlite::{OptionalExtension, params};
+
+pub struct SqlitePasswordResetTokenRepository {
+    db: SqliteConnection,
+}
+
+impl SqlitePasswordResetTokenRepository {
+    pub fn new(db: SqliteConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl PasswordResetTokenRepositoryInterface for SqlitePasswordResetTokenRepository {
+    async fn save(
+        &self,
+        token: &PasswordResetToken,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let token = token.clone();
+        let result: InfrastructureResult<()> = self
+            .db
+            .execute_command(move |conn| {
+                conn.execute(
+                    "INSERT INTO password_reset_tokens (token_hash, user_id, expires_at) VALUES (?1, ?2, ?3)",
+                    params![token.token_hash, token.user_id.0, token.expires_at.to_rfc3339()],
+                )?;
+                Ok(())
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+
+    async fn find_user_id(
+        &self,
+        token_hash: &str,
+        now: DateTime<Utc>,
+    ) -> Result<Option<UserId>, Box<dyn std::error::Error + Send + Sync>> {
+        let token_hash = token_hash.to_string();
+        let result: InfrastructureResult<Option<UserId>> = self
+            .db
+            .execute_query(move |conn| {
+                conn.query_row(
+                    "SELECT user_id FROM password_reset_tokens
+                     WHERE token_hash = ?1 AND used_at IS NULL AND expires_at > ?2",
+                    params![token_hash, now.to_rfc3339()],
+                    |row| row.get::<_, String>(0).map(UserId::new),
+                )
+                .optional()
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+
+    async fn consume(
+        &self,
+        token_hash: &str,
+        now: DateTime<Utc>,
+    ) -> Result<Option<UserId>, Box<dyn std::error::Error + Send + Sync>> {
+        let token_hash = token_hash.to_string();
+        let result: InfrastructureResult<Option<UserId>> = self
+            .db
+            .execute_command(move |conn| {
+                // 使用済みへの更新に成功した1リクエストだけがユーザーIDを得る
+                conn.query_row(
+                    "UPDATE password_reset_tokens SET used_at = ?2
+                     WHERE token_hash = ?1 AND used_at IS NULL AND expires_at > ?2
+                     RETURNING user_id",
+                    params![token_hash, now.to_rfc3339()],
+                    |row| row.get::<_, String>(0).map(UserId::new),
+                )
+                .optional()
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+
+    async fn invalidate_user_tokens(
+        &self,
+        user_id: &UserId,
+    ) -> Result<usize, Box<dyn std::error::Error + Send + Sync>> {
+        let user_id = user_id.clone();
+        let result: InfrastructureResult<usize> = self
+            .db
+            .execute_command(move |conn| {
+                conn.execute(
+                    "UPDATE password_reset_tokens SET used_at = ?2
+                     WHERE user_id = ?1 AND used_at IS NULL",
+                    params![user_id.0, Utc::now().to_rfc3339()],
+                )
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+}
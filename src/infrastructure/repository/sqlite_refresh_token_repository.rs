@@ -0,0 +1,246 @@
+//infrastructure/repository/sqlite_refresh_token_repository.rs
+// SQLite リフレッシュトークン Repository実装
+// 2025/7/8
+
+use crate::domain::entity::refresh_token::RefreshToken;
+use crate::domain::repository::refresh_token_repository::{
+    RefreshTokenConsumption, RefreshTokenRepositoryInterface,
+};
+use crate::domain::value_object::user_id::UserId;
+use crate::infrastructure::database::sqlite_connection::SqliteConnection;
+use crate::shared::error::infrastructure_error::InfrastructureResult;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rusqlite::{OptionalExtension, params};
+
+pub struct SqliteRefreshTokenRepository {
+    db: SqliteConnection,
+}
+
+impl SqliteRefreshTokenRepository {
+    pub fn new(db: SqliteConnection) -> Self {
+        Self { db }
+    }
+
+    fn parse_datetime(index: usize, value: &str) -> rusqlite::Result<DateTime<Utc>> {
+        DateTime::parse_from_rfc3339(value)
+            .map(|dt| dt.with_timezone(&Utc))
+            .map_err(|e| {
+                rusqlite::Error::FromSqlConversionFailure(
+                    index,
+                    rusqlite::types::Type::Text,
+                    Box::new(e),
+                )
+            })
+    }
+}
+
+#[async_trait]
+impl RefreshTokenRepositoryInterface for SqliteRefreshTokenRepository {
+    async fn create_family(
+        &self,
+        family_id: &str,
+        user_id: &UserId,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let family_id = family_id.to_string();
+        let user_id = user_id.clone();
+        let result: InfrastructureResult<()> = self
+            .db
+            .execute_command(move |conn| {
+                conn.execute(
+                    "INSERT INTO refresh_token_families (family_id, user_id) VALUES (?1, ?2)",
+                    params![family_id, user_id.0],
+                )?;
+                Ok(())
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+
+    async fn save(
+        &self,
+        token: &RefreshToken,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let token = token.clone();
+        let result: InfrastructureResult<()> = self
+            .db
+            .execute_command(move |conn| {
+                conn.execute(
+                    "INSERT INTO refresh_tokens (jti, family_id, user_id, expires_at) VALUES (?1, ?2, ?3, ?4)",
+                    params![
+                        token.jti,
+                        token.family_id,
+                        token.user_id.0,
+                        token.expires_at.to_rfc3339()
+                    ],
+                )?;
+                Ok(())
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+
+    async fn consume(
+        &self,
+        jti: &str,
+        used_at: DateTime<Utc>,
+    ) -> Result<RefreshTokenConsumption, Box<dyn std::error::Error + Send + Sync>> {
+        let jti = jti.to_string();
+        let result: InfrastructureResult<RefreshTokenConsumption> = self
+            .db
+            .execute_command(move |conn| {
+                let tx = conn.transaction()?;
+                let row = tx
+                    .query_row(
+                        "SELECT t.family_id, t.user_id, t.expires_at, t.used_at, f.revoked_at
+                         FROM refresh_tokens t
+                         JOIN refresh_token_families f ON f.family_id = t.family_id
+                         WHERE t.jti = ?1",
+                        params![jti],
+                        |row| {
+                            Ok((
+                                row.get::<_, String>(0)?,
+                                row.get::<_, String>(1)?,
+                                row.get::<_, String>(2)?,
+                                row.get::<_, Option<String>>(3)?,
+                                row.get::<_, Option<String>>(4)?,
+                            ))
+                        },
+                    )
+                    .optional()?;
+                let Some((family_id, user_id, expires_at, used, revoked)) = row else {
+                    return Ok(RefreshTokenConsumption::NotFound);
+                };
+                if revoked.is_some() {
+                    return Ok(RefreshTokenConsumption::FamilyRevoked);
+                }
+                let token = RefreshToken::new(
+                    jti.clone(),
+                    family_id,
+                    UserId::new(user_id),
+                    Self::parse_datetime(2, &expires_at)?,
+                );
+                if used.is_some() {
+                    return Ok(RefreshTokenConsumption::Reused(token));
+                }
+                // 同時使用に備え、未使用の場合のみ更新する
+                let updated = tx.execute(
+                    "UPDATE refresh_tokens SET used_at = ?1 WHERE jti = ?2 AND used_at IS NULL",
+                    params![used_at.to_rfc3339(), jti],
+                )?;
+                tx.commit()?;
+                if updated == 1 {
+                    Ok(RefreshTokenConsumption::Consumed(token))
+                } else {
+                    Ok(RefreshTokenConsumption::Reused(token))
+                }
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+
+    async fn revoke_family(
+        &self,
+        family_id: &str,
+        revoked_at: DateTime<Utc>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let family_id = family_id.to_string();
+        let result: InfrastructureResult<()> = self
+            .db
+            .execute_command(move |conn| {
+                conn.execute(
+                    "UPDATE refresh_token_families SET revoked_at = ?1 WHERE family_id = ?2 AND revoked_at IS NULL",
+                    params![revoked_at.to_rfc3339(), family_id],
+                )?;
+                Ok(())
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+
+    async fn revoke_user_families(
+        &self,
+        user_id: &UserId,
+        revoked_at: DateTime<Utc>,
+    ) -> Result<usize, Box<dyn std::error::Error + Send + Sync>> {
+        let user_id = user_id.clone();
+        let result: InfrastructureResult<usize> = self
+            .db
+            .execute_command(move |conn| {
+                conn.execute(
+                    "UPDATE refresh_token_families SET revoked_at = ?1 WHERE user_id = ?2 AND revoked_at IS NULL",
+                    params![revoked_at.to_rfc3339(), user_id.0],
+                )
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+
+    async fn is_family_active(
+        &self,
+        family_id: &str,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+        let family_id = family_id.to_string();
+        let result: InfrastructureResult<bool> = self
+            .db
+            .execute_query(move |conn| {
+                conn.query_row(
+                    "SELECT EXISTS(SELECT 1 FROM refresh_token_families WHERE family_id = ?1 AND revoked_at IS NULL)",
+                    params![family_id],
+                    |row| row.get(0),
+                )
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+
+    async fn is_token_active(
+        &self,
+        jti: &str,
+        now: DateTime<Utc>,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+        let jti = jti.to_string();
+        let result: InfrastructureResult<bool> = self
+            .db
+            .execute_query(move |conn| {
+                conn.query_row(
+                    "SELECT EXISTS(
+                        SELECT 1 FROM refresh_tokens t
+                        JOIN refresh_token_families f ON f.family_id = t.family_id
+                        WHERE t.jti = ?1 AND t.used_at IS NULL AND t.expires_at > ?2
+                          AND f.revoked_at IS NULL
+                     )",
+                    params![jti, now.to_rfc3339()],
+                    |row| row.get(0),
+                )
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+
+    async fn purge_expired(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<usize, Box<dyn std::error::Error + Send + Sync>> {
+        let result: InfrastructureResult<usize> = self
+            .db
+            .execute_command(move |conn| {
+                let tx = conn.transaction()?;
+                let tokens = tx.execute(
+                    "DELETE FROM refresh_tokens WHERE expires_at < ?1",
+                    params![now.to_rfc3339()],
+                )?;
+                // 発行直後（トークン登録前）のファミリーを消さないよう作成から1時間は残す
+                let families = tx.execute(
+                    "DELETE FROM refresh_token_families
+                     WHERE created_at < datetime('now', '-1 hour')
+                       AND NOT EXISTS (SELECT 1 FROM refresh_tokens t WHERE t.family_id = refresh_token_families.family_id)",
+                    [],
+                )?;
+                tx.commit()?;
+                Ok(tokens + families)
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+}
@@ -0,0 +1,132 @@
+//infrastructure/repository/sqlite_token_revocation_repository.rs
+// SQLite アクセストークン失効リスト Repository実装
+// 2025/7/8
+
+use crate::domain::repository::token_revocation_repository::TokenRevocationRepositoryInterface;
+use crate::domain::value_object::user_id::UserId;
+use crate::infrastructure::database::sqlite_connection::SqliteConnection;
+use crate::shared::error::infrastructure_error::InfrastructureResult;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rusqlite::{OptionalExtension, params};
+
+pub struct SqliteTokenRevocationRepository {
+    db: SqliteConnection,
+}
+
+impl SqliteTokenRevocationRepository {
+    pub fn new(db: SqliteConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl TokenRevocationRepositoryInterface for SqliteTokenRevocationRepository {
+    async fn revoke(
+        &self,
+        jti: &str,
+        user_id: &UserId,
+        expires_at: DateTime<Utc>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let jti = jti.to_string();
+        let user_id = user_id.clone();
+        let result: InfrastructureResult<()> = self
+            .db
+            .execute_command(move |conn| {
+                conn.execute(
+                    "INSERT OR IGNORE INTO revoked_tokens (jti, user_id, expires_at) VALUES (?1, ?2, ?3)",
+                    params![jti, user_id.0, expires_at.to_rfc3339()],
+                )?;
+                Ok(())
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+
+    async fn is_revoked(
+        &self,
+        jti: &str,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+        let jti = jti.to_string();
+        let result: InfrastructureResult<bool> = self
+            .db
+            .execute_query(move |conn| {
+                conn.query_row(
+                    "SELECT EXISTS(SELECT 1 FROM revoked_tokens WHERE jti = ?1)",
+                    params![jti],
+                    |row| row.get(0),
+                )
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+
+    async fn invalidate_user_sessions(
+        &self,
+        user_id: &UserId,
+        at: DateTime<Utc>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let user_id = user_id.clone();
+        let result: InfrastructureResult<()> = self
+            .db
+            .execute_command(move |conn| {
+                conn.execute(
+                    "INSERT INTO user_session_invalidations (user_id, invalidated_at) VALUES (?1, ?2)
+                     ON CONFLICT(user_id) DO UPDATE SET invalidated_at = excluded.invalidated_at",
+                    params![user_id.0, at.to_rfc3339()],
+                )?;
+                Ok(())
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+
+    async fn sessions_invalidated_at(
+        &self,
+        user_id: &UserId,
+    ) -> Result<Option<DateTime<Utc>>, Box<dyn std::error::Error + Send + Sync>> {
+        let user_id = user_id.clone();
+        let result: InfrastructureResult<Option<DateTime<Utc>>> = self
+            .db
+            .execute_query(move |conn| {
+                let value: Option<String> = conn
+                    .query_row(
+                        "SELECT invalidated_at FROM user_session_invalidations WHERE user_id = ?1",
+                        params![user_id.0],
+                        |row| row.get(0),
+                    )
+                    .optional()?;
+                value
+                    .map(|value| {
+                        DateTime::parse_from_rfc3339(&value)
+                            .map(|dt| dt.with_timezone(&Utc))
+                            .map_err(|e| {
+                                rusqlite::Error::FromSqlConversionFailure(
+                                    0,
+                                    rusqlite::types::Type::Text,
+                                    Box::new(e),
+                                )
+                            })
+                    })
+                    .transpose()
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+
+    async fn purge_expired(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<usize, Box<dyn std::error::Error + Send + Sync>> {
+        let result: InfrastructureResult<usize> = self
+            .db
+            .execute_command(move |conn| {
+                conn.execute(
+                    "DELETE FROM revoked_tokens WHERE expires_at < ?1",
+                    params![now.to_rfc3339()],
+                )
+            })
+            .await;
+        result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+    }
+}
@@ -1,10 +1,8 @@
+use crate::infrastructure::config::app_config::AppConfig;
 use axum::http::{HeaderName, Method};
 
 pub fn allowed_origins() -> Vec<String> {
-    vec![
-        "http://localhost:3000".to_string(),
-        "https://your-production-domain.com".to_string(),
-    ]
+    AppConfig::current().cors.allowed_origins.clone()
 }
 
 pub fn allowed_methods() -> Vec<Method> {
@@ -21,5 +19,7 @@ pub fn allowed_headers() -> Vec<HeaderName> {
     vec![
         HeaderName::from_static("authorization"),
         HeaderName::from_static("content-type"),
+        HeaderName::from_static("x-csrf-token"),
+        HeaderName::from_static("x-session-mode"),
     ]
 }
@@ -0,0 +1,45 @@
+//infrastructure/utils/session_gc.rs
+// 期限切れのセッション・失効記録を定期的に削除する
+// 2025/7/8
+
+use crate::application::services::login_throttle_service::LoginThrottleService;
+use crate::application::services::session_revocation_service::SessionRevocationService;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+
+/// 失効リスト・期限切れリフレッシュトークンを定期的に削除するタスクを起動する
+pub fn spawn_session_gc(
+    service: Arc<SessionRevocationService>,
+    interval: Duration,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            match service.purge_expired().await {
+                Ok(0) => {}
+                Ok(purged) => println!("🧹 期限切れのセッション情報を{}件削除しました", purged),
+                Err(e) => println!("⚠️ セッション情報の削除に失敗しました: {}", e),
+            }
+        }
+    })
+}
+
+/// 失敗から時間が経ったログイン失敗カウンターを定期的に削除するタスクを起動する
+pub fn spawn_login_attempt_gc(
+    service: Arc<LoginThrottleService>,
+    interval: Duration,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            match service.purge_stale().await {
+                Ok(0) => {}
+                Ok(purged) => println!("🧹 古いログイン失敗記録を{}件削除しました", purged),
+                Err(e) => println!("⚠️ ログイン失敗記録の削除に失敗しました: {}", e),
+            }
+        }
+    })
+}
@@ -0,0 +1,218 @@
+//infrastructure/web/listener.rs
+// 待ち受けソケット（TCP・Unixドメインソケット）とHTTP接続の処理
+// 2025/7/8
+
+use crate::infrastructure::config::app_config::ListenAddr;
+use crate::shared::error::infrastructure_error::{InfrastructureError, InfrastructureResult};
+use crate::shared::middleware::client_cert_middleware::ClientCertificate;
+use axum::Router;
+use axum::extract::ConnectInfo;
+use hyper::body::Incoming;
+use hyper::service::service_fn;
+use hyper_util::rt::{TokioExecutor, TokioIo};
+use hyper_util::server::conn::auto::Builder;
+use hyper_util::server::graceful::{GracefulShutdown, Watcher};
+use std::net::SocketAddr;
+use std::time::Duration;
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::net::TcpListener;
+use tokio::sync::watch;
+use tokio_rustls::TlsAcceptor;
+use tower::Service;
+
+/// シャットダウン時に処理中の接続の完了を待つ最長時間
+const SHUTDOWN_GRACE_PERIOD: Duration = Duration::from_secs(30);
+
+/// TLSハンドシェイクの最長時間（ハンドシェイクを終えない接続で枠を占有させない）
+const TLS_HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+enum Socket {
+    Tcp(TcpListener),
+    #[cfg(unix)]
+    Unix(tokio::net::UnixListener, std::path::PathBuf),
+}
+
+/// 待ち受け中のソケット
+pub struct Listener {
+    socket: Socket,
+    tls: Option<TlsAcceptor>,
+}
+
+impl Listener {
+    /// アドレスにバインドする
+    ///
+    /// Unixドメインソケットは、前回の起動で残ったソケットファイルがあれば削除してからバインドする
+    pub async fn bind(addr: &ListenAddr) -> InfrastructureResult<Self> {
+        let bind_error = |e: std::io::Error| InfrastructureError::ResourceUnavailable {
+            resource: addr.to_string(),
+            message: e.to_string(),
+        };
+        let socket = match addr {
+            ListenAddr::Tcp(socket_addr) => TcpListener::bind(socket_addr)
+                .await
+                .map(Socket::Tcp)
+                .map_err(bind_error)?,
+            #[cfg(unix)]
+            ListenAddr::Unix(path) => {
+                use std::os::unix::fs::FileTypeExt;
+                if std::fs::symlink_metadata(path).is_ok_and(|meta| meta.file_type().is_socket()) {
+                    std::fs::remove_file(path).map_err(bind_error)?;
+                }
+                tokio::net::UnixListener::bind(path)
+                    .map(|listener| Socket::Unix(listener, path.clone()))
+                    .map_err(bind_error)?
+            }
+            #[cfg(not(unix))]
+            ListenAddr::Unix(_) => {
+                return Err(InfrastructureError::ResourceUnavailable {
+                    resource: addr.to_string(),
+                    message: "unix domain sockets are not supported on this platform".to_string(),
+                });
+            }
+        };
+        Ok(Self { socket, tls: None })
+    }
+
+    /// TLSで受け付ける（ALPNの結果に関わらず、HTTP/1.1とHTTP/2のどちらも処理できる）
+    pub fn with_tls(mut self, acceptor: TlsAcceptor) -> Self {
+        self.tls = Some(acceptor);
+        self
+    }
+
+    pub fn is_tls(&self) -> bool {
+        self.tls.is_some()
+    }
+
+    /// 実際に待ち受けているアドレス（ポート0を指定した場合は割り当てられたポート）
+    pub fn local_addr(&self) -> InfrastructureResult<ListenAddr> {
+        match &self.socket {
+            Socket::Tcp(listener) => listener.local_addr().map(ListenAddr::Tcp).map_err(|e| {
+                InfrastructureError::ResourceUnavailable {
+                    resource: "tcp listener".to_string(),
+                    message: e.to_string(),
+                }
+            }),
+            #[cfg(unix)]
+            Socket::Unix(_, path) => Ok(ListenAddr::Unix(path.clone())),
+        }
+    }
+}
+
+/// シャットダウンが通知されるまで接続を受け付け、ルーターで処理する
+///
+/// TCP接続では接続元アドレスを`ConnectInfo<SocketAddr>`としてリクエストに付ける。
+/// Unixドメインソケットには接続元IPがないため付けない（`ClientIp`はNoneになる）。
+/// TLSでクライアント証明書が検証できた場合は`ClientCertificate`も付ける。
+/// HTTP/1.1とHTTP/2（平文ではh2c）のどちらも受け付ける。
+pub async fn serve(listener: Listener, router: Router, mut shutdown: watch::Receiver<bool>) {
+    let builder = Builder::new(TokioExecutor::new());
+    let graceful = GracefulShutdown::new();
+    loop {
+        tokio::select! {
+            _ = shutdown.changed() => break,
+            accepted = accept(&listener.socket) => match accepted {
+                Ok(Accepted::Tcp(stream, remote)) => {
+                    let _ = stream.set_nodelay(true);
+                    spawn_connection(stream, Some(remote), &listener, &router, &builder, &graceful);
+                }
+                #[cfg(unix)]
+                Ok(Accepted::Unix(stream)) => {
+                    spawn_connection(stream, None, &listener, &router, &builder, &graceful);
+                }
+                Err(e) => {
+                    // ファイルディスクリプタ枯渇などは時間を置いて再試行する
+                    println!("⚠️ 接続の受け付けに失敗しました: {}", e);
+                    tokio::time::sleep(Duration::from_secs(1)).await;
+                }
+            },
+        }
+    }
+    #[cfg(unix)]
+    if let Socket::Unix(_, path) = &listener.socket {
+        let _ = std::fs::remove_file(path);
+    }
+    drop(listener);
+    if tokio::time::timeout(SHUTDOWN_GRACE_PERIOD, graceful.shutdown())
+        .await
+        .is_err()
+    {
+        println!("⚠️ 処理中の接続を待たずに終了します");
+    }
+}
+
+enum Accepted {
+    Tcp(tokio::net::TcpStream, SocketAddr),
+    #[cfg(unix)]
+    Unix(tokio::net::UnixStream),
+}
+
+async fn accept(socket: &Socket) -> std::io::Result<Accepted> {
+    match socket {
+        Socket::Tcp(listener) => listener
+            .accept()
+            .await
+            .map(|(stream, remote)| Accepted::Tcp(stream, remote)),
+        #[cfg(unix)]
+        Socket::Unix(listener, _) => listener
+            .accept()
+            .await
+            .map(|(stream, _)| Accepted::Unix(stream)),
+    }
+}
+
+fn spawn_connection<I>(
+    io: I,
+    remote: Option<SocketAddr>,
+    listener: &Listener,
+    router: &Router,
+    builder: &Builder<TokioExecutor>,
+    graceful: &GracefulShutdown,
+) where
+    I: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+{
+    let tls = listener.tls.clone();
+    let router = router.clone();
+    let builder = builder.clone();
+    let watcher = graceful.watcher();
+    tokio::spawn(async move {
+        let Some(acceptor) = tls else {
+            return serve_connection(io, remote, None, router, builder, watcher).await;
+        };
+        // ハンドシェイクの失敗（証明書の検証失敗など）は接続ごとの失敗として扱う
+        let Ok(Ok(stream)) = tokio::time::timeout(TLS_HANDSHAKE_TIMEOUT, acceptor.accept(io)).await
+        else {
+            return;
+        };
+        let client_certificate = stream
+            .get_ref()
+            .1
+            .peer_certificates()
+            .and_then(|certificates| certificates.first())
+            .and_then(|certificate| ClientCertificate::from_der(certificate));
+        serve_connection(stream, remote, client_certificate, router, builder, watcher).await;
+    });
+}
+
+async fn serve_connection<I>(
+    io: I,
+    remote: Option<SocketAddr>,
+    client_certificate: Option<ClientCertificate>,
+    router: Router,
+    builder: Builder<TokioExecutor>,
+    watcher: Watcher,
+) where
+    I: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+{
+    let service = service_fn(move |mut request: axum::http::Request<Incoming>| {
+        if let Some(remote) = remote {
+            request.extensions_mut().insert(ConnectInfo(remote));
+        }
+        if let Some(client_certificate) = &client_certificate {
+            request.extensions_mut().insert(client_certificate.clone());
+        }
+        router.clone().call(request)
+    });
+    let connection = builder.serve_connection_with_upgrades(TokioIo::new(io), service);
+    // クライアントの切断などの接続エラーは個別のリクエストの失敗として扱わない
+    let _ = watcher.watch(connection).await;
+}
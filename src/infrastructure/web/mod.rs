@@ -2,4 +2,6 @@
 // Webサーバーモジュール
 // 2025/7/8
 
+pub mod listener;
 pub mod run;
+pub mod tls;
@@ -2,31 +2,45 @@
 // Webサーバー実行関数
 // 2025/7/8
 
-use axum::{
-    Router,
-    routing::{get, post},
-    serve,
-};
-use std::net::SocketAddr;
 use std::sync::Arc;
 
-use crate::infrastructure::config::app_config::AppConfig;
+use axum::middleware;
+use tokio::sync::watch;
+use tokio::task::JoinSet;
+
+use crate::infrastructure::config::app_config::{AppConfig, ClientAuthMode};
 use crate::infrastructure::di::container::DIContainer;
 use crate::infrastructure::grpc::server::create_grpc_router;
 use crate::infrastructure::utils::graceful_shutdown::shutdown_signal;
-use crate::presentation::router::app_router::create_app_router;
+use crate::infrastructure::utils::session_gc::{spawn_login_attempt_gc, spawn_session_gc};
+use crate::infrastructure::web::listener::{Listener, serve};
+use crate::infrastructure::web::tls::{build_tls_acceptor, spawn_cert_reload};
+use crate::presentation::router::app_router::{create_admin_listener_router, create_app_router};
+use crate::shared::middleware::auth_middleware::JwtConfig;
+use crate::shared::middleware::metrics_middleware::metrics_middleware;
 
-/// Webサーバーを起動する
-pub async fn run() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    // 1. DIコンテナの初期化
+/// Webサーバーを起動する（設定は`AppConfig::load`で検証済みのもの）
+pub async fn run(app_config: AppConfig) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    // 1. アプリケーション設定の登録とDIコンテナの初期化
+    println!(
+        "✅ アプリケーション設定を読み込みました: profile={}",
+        app_config.profile.as_str()
+    );
+    if AppConfig::install(app_config.clone()).is_err() {
+        println!("⚠️ アプリケーション設定は既に初期化済みです");
+    }
     let di_container = DIContainer::new();
 
-    // 2. データベース接続のテスト
-    let db_connection = di_container.create_database_connection()?;
-    println!("✅ データベース接続の作成が完了しました");
+    // 2. データベース接続の作成（全てのコンポーネントがこの接続を共有する）
+    //    PostgreSQLの場合は接続とスキーマもここで確認する
+    di_container.prepare_database().await?;
+    println!(
+        "✅ データベース接続の作成が完了しました: {}",
+        app_config.database.url
+    );
 
     // 3. Repository実装のテスト
-    let (command_repo, query_repo) = di_container.create_repositories()?;
+    di_container.create_repositories()?;
     println!("✅ Repository実装の作成が完了しました");
 
     // 4. ID生成器のテスト
@@ -34,26 +48,143 @@ pub async fn run() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     let test_id = id_generator();
     println!("✅ ID生成器のテストが完了しました: {}", test_id.0);
 
-    // 5. アプリケーション設定の読み込み
-    let app_config = AppConfig::from_env();
+    // 5. Discord通知設定
     let discord_config = Arc::new(app_config.discord);
 
-    // 6. ルーティング設定（HTTP + gRPC統合）
+    // 待ち受けアドレスの検証（公開用と管理用が重なる場合は起動しない）
+    let listeners = app_config.server.resolve()?;
+
+    // TLS設定の読み込み（証明書・秘密鍵が読めない場合は起動しない）
+    let tls_acceptor = match &app_config.tls {
+        Some(tls_config) => {
+            let (acceptor, resolver) = build_tls_acceptor(tls_config)?;
+            spawn_cert_reload(resolver, tls_config.reload_interval);
+            println!(
+                "✅ TLS証明書を読み込みました: {}（クライアント証明書: {}）",
+                tls_config.cert_path.display(),
+                match (&tls_config.client_ca_path, tls_config.client_auth) {
+                    (None, _) => "検証しない",
+                    (Some(_), ClientAuthMode::Required) => "必須",
+                    (Some(_), ClientAuthMode::Optional) => "任意",
+                }
+            );
+            Some(acceptor)
+        }
+        None => None,
+    };
+
+    // JWT鍵設定の検証（不正な場合は起動しない）
+    let jwt_config = JwtConfig::from_key_config(&app_config.jwt)?;
+    println!(
+        "✅ JWT鍵設定を読み込みました: alg={:?}, kid={}, 公開鍵{}件",
+        jwt_config.algorithm,
+        jwt_config.key_id,
+        jwt_config.jwks().keys.len()
+    );
+    if JwtConfig::install(jwt_config).is_err() {
+        println!("⚠️ JWT鍵設定は既に初期化済みです");
+    }
+
+    // 6. 初期ユーザーの登録
+    if let Some(bootstrap_user) = &app_config.bootstrap_user {
+        di_container.seed_bootstrap_user(bootstrap_user).await?;
+    }
+
+    // 7. 失効済みセッション情報の定期削除
+    spawn_session_gc(
+        di_container.create_session_revocation_service()?,
+        app_config.session.gc_interval,
+    );
+    spawn_login_attempt_gc(
+        di_container.create_login_throttle_service()?,
+        app_config.session.gc_interval,
+    );
+
+    // 8. ルーティング設定（HTTP + gRPC統合）
     let user_controller = di_container.build_user_controller()?;
-    let http_router = create_app_router(user_controller, discord_config);
+    let auth_controller = di_container.build_auth_controller()?;
+    let admin_controller = di_container.build_admin_controller()?;
+    let api_key_controller = di_container.build_api_key_controller()?;
+    let oauth_controller = di_container.build_oauth_controller()?;
+    let oidc_controller = di_container.build_oidc_controller()?;
+    let auth_services = di_container.build_auth_services()?;
+    let http_router = create_app_router(
+        user_controller,
+        auth_controller,
+        admin_controller,
+        api_key_controller,
+        oauth_controller,
+        oidc_controller,
+        auth_services,
+        discord_config,
+        Arc::from(app_config.log.dir.as_str()),
+    );
     let grpc_router = create_grpc_router();
 
-    // HTTPとgRPCルーターを統合
-    let app = http_router.merge(grpc_router);
+    // HTTPとgRPCルーターを統合し、公開用リスナーへのリクエストをメトリクスに記録する
+    let metrics = di_container.create_metrics_collector();
+    let app = http_router
+        .merge(grpc_router)
+        .layer(middleware::from_fn_with_state(
+            metrics.clone(),
+            metrics_middleware,
+        ));
+    let admin_app = create_admin_listener_router(metrics);
 
-    // 6. サーバー起動
-    let addr = SocketAddr::from(([127, 0, 0, 1], 3000));
-    let listener = tokio::net::TcpListener::bind(addr).await?;
-    println!("🚀 Server starting on {}", addr);
+    // 9. サーバー起動（全てのアドレスにバインドできた場合のみ受け付けを始める）
+    let mut public_listeners = Vec::new();
+    for addr in &listeners.public {
+        let mut listener = Listener::bind(addr).await?;
+        if let Some(acceptor) = &tls_acceptor {
+            listener = listener.with_tls(acceptor.clone());
+        }
+        println!(
+            "🚀 Server starting on {} ({})",
+            listener.local_addr()?,
+            if listener.is_tls() { "https" } else { "http" }
+        );
+        public_listeners.push(listener);
+    }
+    let mut admin_listeners = Vec::new();
+    for addr in &listeners.admin {
+        let listener = Listener::bind(addr).await?;
+        println!(
+            "🩺 Admin listener (health/metrics) on {}",
+            listener.local_addr()?
+        );
+        admin_listeners.push(listener);
+    }
     println!("📋 利用可能なエンドポイント:");
     println!("  - POST /api/auth/login - ログイン(ユーザー認証)");
-    println!("  - GET  /health - ヘルスチェック");
-    println!("  - GET  /api/health - APIヘルスチェック");
+    println!("  - POST /api/auth/login/mfa - ログイン2段階目(TOTPコード/リカバリーコード)");
+    println!("  - POST /api/auth/mfa/enroll - 二要素認証の登録開始");
+    println!("  - POST /api/auth/mfa/enroll/confirm - 二要素認証の登録確定(リカバリーコード発行)");
+    println!("  - POST /api/auth/mfa/disable - 二要素認証の解除");
+    println!("  - POST /api/auth/mfa/recovery-codes - リカバリーコードの再発行");
+    println!("  - POST /api/auth/refresh - トークン更新(リフレッシュトークンのローテーション)");
+    println!("  - POST /api/auth/password-reset/request - パスワードリセットメールの送信");
+    println!("  - POST /api/auth/password-reset/confirm - パスワードの再設定(全セッション終了)");
+    println!("  - GET /api/auth/verify-email?token=... - メールアドレスの確認");
+    println!("  - POST /api/auth/verify-email/resend - 確認メールの再送");
+    println!("  - POST /api/auth/logout - ログアウト(現在のセッションを終了)");
+    println!("  - POST /api/auth/logout-all - 全セッションからログアウト");
+    println!("  - DELETE /api/admin/users/:id/sessions - 対象ユーザーの全セッションを終了(管理者)");
+    println!(
+        "  - DELETE /api/admin/users/:id/lockout - 対象ユーザーのログインロックを解除(管理者)"
+    );
+    println!("  - GET/POST /api/api-keys - APIキーの一覧・作成(X-API-Keyヘッダーで認証)");
+    println!("  - GET/PATCH/DELETE /api/api-keys/:id - APIキーの取得・更新・削除");
+    println!("  - GET  /oauth/authorize - 認可コードの発行(PKCE必須、ログイン中のユーザーとして)");
+    println!(
+        "  - POST /oauth/token - トークン発行(authorization_code/client_credentials/refresh_token)"
+    );
+    println!("  - POST /oauth/introspect - トークンのイントロスペクション(RFC 7662)");
+    println!("  - POST /oauth/revoke - トークンの失効(RFC 7009)");
+    println!("  - GET/POST /api/oauth/clients - OAuthクライアントの一覧・登録(管理者)");
+    println!("  - DELETE /api/oauth/clients/:client_id - OAuthクライアントの削除(管理者)");
+    println!("  - GET/POST /userinfo - OpenID ConnectのUserInfo(openidスコープ)");
+    println!("  - GET  /.well-known/openid-configuration - OpenID Connectのディスカバリー");
+    println!("  - GET  /.well-known/jwks.json - トークン検証用公開鍵(JWKS)");
     println!("  - POST /api/users - ユーザー作成");
     println!("  - GET  /api/users/:id - ユーザー取得");
     println!("  - PUT  /api/users/:id - ユーザー更新");
@@ -61,19 +192,27 @@ pub async fn run() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     println!("  - GET  /api/fortune - ランダム癒し系おみくじ");
     println!("  - POST /grpc/hello - gRPC Hello Service (Protocol Buffers)");
     println!("  - Discord通知: エラー発生時に自動通知");
+    println!("📋 管理用リスナーのエンドポイント:");
+    println!("  - GET  /health - ヘルスチェック");
+    println!("  - GET  /api/health - APIヘルスチェック");
+    println!("  - GET  /metrics - メトリクス(Prometheus形式)");
 
-    axum::serve(listener, app)
-        .with_graceful_shutdown(shutdown_signal())
-        .await?;
+    // Ctrl+Cで全てのリスナーの受け付けを止め、処理中の接続の完了を待つ
+    let (shutdown_tx, shutdown_rx) = watch::channel(false);
+    tokio::spawn(async move {
+        shutdown_signal().await;
+        let _ = shutdown_tx.send(true);
+    });
+    let mut servers = JoinSet::new();
+    for listener in public_listeners {
+        servers.spawn(serve(listener, app.clone(), shutdown_rx.clone()));
+    }
+    for listener in admin_listeners {
+        servers.spawn(serve(listener, admin_app.clone(), shutdown_rx.clone()));
+    }
+    while let Some(result) = servers.join_next().await {
+        result?;
+    }
 
     Ok(())
 }
-
-/// APIヘルスチェックエンドポイント
-async fn api_health_check() -> axum::Json<serde_json::Value> {
-    axum::Json(serde_json::json!({
-        "status": "ok",
-        "message": "API is running",
-        "timestamp": chrono::Utc::now().to_rfc3339()
-    }))
-}
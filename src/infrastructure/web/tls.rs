@@ -0,0 +1,177 @@
+//infrastructure/web/tls.rs
+// TLS（rustls）の設定と証明書の再読み込み
+// 2025/7/8
+
+use crate::infrastructure::config::app_config::{ClientAuthMode, TlsConfig};
+use crate::shared::error::infrastructure_error::{InfrastructureError, InfrastructureResult};
+use rustls::crypto::ring::{default_provider, sign::any_supported_type};
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use rustls::server::{ClientHello, ResolvesServerCert, WebPkiClientVerifier};
+use rustls::sign::CertifiedKey;
+use rustls::{RootCertStore, ServerConfig};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
+use std::time::{Duration, SystemTime};
+use tokio::task::JoinHandle;
+use tokio_rustls::TlsAcceptor;
+
+/// ALPNで提示するプロトコル（HTTP/2を優先）
+const ALPN_PROTOCOLS: [&[u8]; 2] = [b"h2", b"http/1.1"];
+
+fn file_error(path: &Path, message: impl ToString) -> InfrastructureError {
+    InfrastructureError::FileOperation {
+        path: path.display().to_string(),
+        message: message.to_string(),
+    }
+}
+
+fn require_path<'a>(path: &'a Path, key: &str) -> InfrastructureResult<&'a Path> {
+    if path.as_os_str().is_empty() {
+        return Err(InfrastructureError::Configuration {
+            key: key.to_string(),
+            message: "TLS_CERT_PATH and TLS_KEY_PATH must be set together".to_string(),
+        });
+    }
+    Ok(path)
+}
+
+/// PEM形式の証明書チェーンと秘密鍵を読み込む
+fn load_certified_key(cert_path: &Path, key_path: &Path) -> InfrastructureResult<CertifiedKey> {
+    let certs = CertificateDer::pem_file_iter(require_path(cert_path, "TLS_CERT_PATH")?)
+        .and_then(|certs| certs.collect::<Result<Vec<_>, _>>())
+        .map_err(|e| file_error(cert_path, e))?;
+    if certs.is_empty() {
+        return Err(file_error(cert_path, "no certificates found"));
+    }
+    let key = PrivateKeyDer::from_pem_file(require_path(key_path, "TLS_KEY_PATH")?)
+        .map_err(|e| file_error(key_path, e))?;
+    let signing_key = any_supported_type(&key).map_err(|e| file_error(key_path, e))?;
+    let certified_key = CertifiedKey::new(certs, signing_key);
+    // 証明書と秘密鍵の組み合わせ違いは起動時・再読み込み時に検出する
+    certified_key
+        .keys_match()
+        .map_err(|e| file_error(key_path, e))?;
+    Ok(certified_key)
+}
+
+/// 証明書ファイルの更新を反映するサーバー証明書
+///
+/// 新しいハンドシェイクから読み込み直した証明書を使い、確立済みの接続には影響しない
+#[derive(Debug)]
+pub struct ReloadableCertResolver {
+    cert_path: PathBuf,
+    key_path: PathBuf,
+    current: RwLock<Arc<CertifiedKey>>,
+    modified: Mutex<Option<(SystemTime, SystemTime)>>,
+}
+
+impl ReloadableCertResolver {
+    pub fn load(cert_path: &Path, key_path: &Path) -> InfrastructureResult<Self> {
+        let certified_key = load_certified_key(cert_path, key_path)?;
+        Ok(Self {
+            cert_path: cert_path.to_path_buf(),
+            key_path: key_path.to_path_buf(),
+            current: RwLock::new(Arc::new(certified_key)),
+            modified: Mutex::new(Self::modified_times(cert_path, key_path)),
+        })
+    }
+
+    fn modified_times(cert_path: &Path, key_path: &Path) -> Option<(SystemTime, SystemTime)> {
+        let modified = |path: &Path| std::fs::metadata(path).and_then(|meta| meta.modified());
+        Some((modified(cert_path).ok()?, modified(key_path).ok()?))
+    }
+
+    /// 現在の証明書チェーン（先頭がサーバー証明書）
+    pub fn current(&self) -> Arc<CertifiedKey> {
+        self.current
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// ファイルが更新されていれば読み込み直す（更新した場合はtrue）
+    ///
+    /// 読み込めない場合（書き込み途中など）はエラーを返し、今の証明書を使い続ける
+    pub fn reload_if_changed(&self) -> InfrastructureResult<bool> {
+        let modified = Self::modified_times(&self.cert_path, &self.key_path);
+        let mut last_modified = self.modified.lock().unwrap_or_else(PoisonError::into_inner);
+        if modified.is_none() || modified == *last_modified {
+            return Ok(false);
+        }
+        let certified_key = load_certified_key(&self.cert_path, &self.key_path)?;
+        *self.current.write().unwrap_or_else(PoisonError::into_inner) = Arc::new(certified_key);
+        *last_modified = modified;
+        Ok(true)
+    }
+}
+
+impl ResolvesServerCert for ReloadableCertResolver {
+    fn resolve(&self, _client_hello: ClientHello<'_>) -> Option<Arc<CertifiedKey>> {
+        Some(self.current())
+    }
+}
+
+/// TLSの受け付け設定を組み立てる
+///
+/// ALPNでh2とhttp/1.1を提示する。`client_ca_path`がある場合はそのCAが発行した
+/// クライアント証明書を検証する（`ClientAuthMode::Optional`では証明書なしの接続も受け付ける）。
+pub fn build_tls_acceptor(
+    config: &TlsConfig,
+) -> InfrastructureResult<(TlsAcceptor, Arc<ReloadableCertResolver>)> {
+    let resolver = Arc::new(ReloadableCertResolver::load(
+        &config.cert_path,
+        &config.key_path,
+    )?);
+    let provider = Arc::new(default_provider());
+    let builder = ServerConfig::builder_with_provider(provider.clone())
+        .with_safe_default_protocol_versions()
+        .map_err(|e| InfrastructureError::Configuration {
+            key: "TLS".to_string(),
+            message: e.to_string(),
+        })?;
+    let mut server_config = match &config.client_ca_path {
+        None => builder.with_no_client_auth(),
+        Some(ca_path) => {
+            let mut roots = RootCertStore::empty();
+            for cert in
+                CertificateDer::pem_file_iter(ca_path).map_err(|e| file_error(ca_path, e))?
+            {
+                let cert = cert.map_err(|e| file_error(ca_path, e))?;
+                roots.add(cert).map_err(|e| file_error(ca_path, e))?;
+            }
+            let verifier = WebPkiClientVerifier::builder_with_provider(Arc::new(roots), provider);
+            let verifier = match config.client_auth {
+                ClientAuthMode::Required => verifier,
+                ClientAuthMode::Optional => verifier.allow_unauthenticated(),
+            }
+            .build()
+            .map_err(|e| InfrastructureError::Configuration {
+                key: "TLS_CLIENT_CA_PATH".to_string(),
+                message: e.to_string(),
+            })?;
+            builder.with_client_cert_verifier(verifier)
+        }
+    }
+    .with_cert_resolver(resolver.clone());
+    server_config.alpn_protocols = ALPN_PROTOCOLS.iter().map(|p| p.to_vec()).collect();
+    Ok((TlsAcceptor::from(Arc::new(server_config)), resolver))
+}
+
+/// 証明書ファイルの更新を定期的に確認するタスクを起動する
+pub fn spawn_cert_reload(
+    resolver: Arc<ReloadableCertResolver>,
+    interval: Duration,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            match resolver.reload_if_changed() {
+                Ok(false) => {}
+                Ok(true) => println!("🔐 TLS証明書を読み込み直しました"),
+                Err(e) => println!("⚠️ TLS証明書の読み込みに失敗しました: {}", e),
+            }
+        }
+    })
+}
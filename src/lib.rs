@@ -31,13 +31,17 @@ pub mod shared {
 
     pub mod middleware {
         pub mod auth_middleware;
+        pub mod client_cert_middleware;
+        pub mod client_ip_middleware;
         pub mod cors_middleware;
         pub mod discord_middleware;
         pub mod metrics_middleware;
         pub mod security_headers_middleware;
+        pub mod session_cookie_middleware;
         pub mod watch_middleware;
 
         // pub use auth_middleware::*;
+        // pub use client_ip_middleware::*;
         // pub use cors_middleware::*;
         // pub use metrics_middleware::*;
         // pub use watch_middleware::*;
@@ -45,11 +49,21 @@ pub mod shared {
 
     pub mod utils {
         pub mod date_time_utils;
+        pub mod jwt_keys;
         pub mod password_hasher;
+        pub mod percent_encoding;
+        pub mod pkce;
+        pub mod secure_token;
+        pub mod totp;
         pub mod uuid_generator;
 
         // pub use date_time_utils::*;
+        // pub use jwt_keys::*;
         // pub use password_hasher::*;
+        // pub use percent_encoding::*;
+        // pub use pkce::*;
+        // pub use secure_token::*;
+        // pub use totp::*;
         // pub use uuid_generator::*;
     }
 
@@ -63,6 +77,15 @@ pub mod shared {
 // ===== Domain Layer =====
 pub mod domain {
     pub mod entity {
+        pub mod api_key;
+        pub mod audit_event;
+        pub mod email_verification_token;
+        pub mod login_attempt;
+        pub mod mfa_credential;
+        pub mod oauth_authorization_code;
+        pub mod oauth_client;
+        pub mod password_reset_token;
+        pub mod refresh_token;
         pub mod user;
 
         // pub use user::*;
@@ -71,22 +94,43 @@ pub mod domain {
     pub mod value_object {
         pub mod birth_date;
         pub mod email;
+        pub mod oauth_grant_type;
+        pub mod oidc_scope;
         pub mod pagination;
         pub mod password;
+        pub mod password_hash;
+        pub mod permission;
         pub mod phone;
+        pub mod role;
         pub mod user_id;
         pub mod user_name;
 
         pub use birth_date::*;
         pub use email::*;
+        pub use oauth_grant_type::*;
+        pub use oidc_scope::*;
         pub use pagination::*;
         pub use password::*;
+        pub use password_hash::*;
+        pub use permission::*;
         pub use phone::*;
+        pub use role::*;
         pub use user_id::*;
         pub use user_name::*;
     }
 
     pub mod repository {
+        pub mod api_key_repository;
+        pub mod audit_log_repository;
+        pub mod email_verification_token_repository;
+        pub mod login_attempt_repository;
+        pub mod mfa_credential_repository;
+        pub mod oauth_authorization_code_repository;
+        pub mod oauth_client_repository;
+        pub mod password_reset_request_repository;
+        pub mod password_reset_token_repository;
+        pub mod refresh_token_repository;
+        pub mod token_revocation_repository;
         pub mod user_command_repository;
         pub mod user_query_repository;
 
@@ -96,6 +140,11 @@ pub mod domain {
 
     pub mod service {
         pub mod id_generator;
+        pub mod lockout_policy;
+        pub mod mailer;
+        pub mod password_policy;
+        pub mod permission_policy;
+        pub mod request_rate_policy;
         pub mod user_domain_service;
 
         // pub use user_domain_service::*;
@@ -105,10 +154,17 @@ pub mod domain {
 // ===== Application Layer =====
 pub mod application {
     pub mod dto {
+        pub mod api_key_dto;
+        pub mod auth_dto;
+        pub mod oauth_dto;
+        pub mod oidc_dto;
         pub mod user_command_dto;
         pub mod user_request_dto;
         pub mod user_response_dto;
 
+        // pub use api_key_dto::*;
+        // pub use oauth_dto::*;
+        // pub use oidc_dto::*;
         // pub use user_command_dto::*;
         // pub use user_request_dto::*;
         // pub use user_response_dto::*;
@@ -132,19 +188,62 @@ pub mod application {
         // pub use search_users_query::*;
     }
 
+    pub mod services {
+        pub mod api_key_service;
+        pub mod client_cert_service;
+        pub mod email_verification_service;
+        pub mod login_throttle_service;
+        pub mod mfa_service;
+        pub mod password_reset_throttle_service;
+        pub mod session_revocation_service;
+        pub mod session_token_service;
+        pub mod user_access_policy;
+
+        // pub use api_key_service::*;
+        // pub use client_cert_service::*;
+        // pub use email_verification_service::*;
+        // pub use login_throttle_service::*;
+        // pub use mfa_service::*;
+        // pub use session_revocation_service::*;
+        // pub use session_token_service::*;
+        // pub use user_access_policy::*;
+    }
+
     pub mod usecases {
+        pub mod api_key_usecase;
         pub mod create_user_usecase;
         pub mod delete_user_usecase;
+        pub mod email_verification_usecase;
         pub mod get_user_usecase;
+        pub mod impersonation_usecase;
         pub mod list_users_usecase;
         pub mod login_usecase;
+        pub mod logout_usecase;
+        pub mod mfa_usecase;
+        pub mod oauth_client_usecase;
+        pub mod oauth_usecase;
+        pub mod oidc_usecase;
+        pub mod password_reset_usecase;
+        pub mod refresh_token_usecase;
+        pub mod unlock_account_usecase;
         pub mod update_user_usecase;
 
+        // pub use api_key_usecase::*;
         // pub use create_user_usecase::*;
         // pub use delete_user_usecase::*;
+        // pub use email_verification_usecase::*;
         // pub use get_user_usecase::*;
+        // pub use impersonation_usecase::*;
         // pub use list_users_usecase::*;
         // pub use login_usecase::*;
+        // pub use logout_usecase::*;
+        // pub use mfa_usecase::*;
+        // pub use oauth_client_usecase::*;
+        // pub use oauth_usecase::*;
+        // pub use oidc_usecase::*;
+        // pub use password_reset_usecase::*;
+        // pub use refresh_token_usecase::*;
+        // pub use unlock_account_usecase::*;
         // pub use update_user_usecase::*;
     }
 
@@ -160,10 +259,30 @@ pub mod infrastructure {
     pub mod repository {
         pub mod in_memory_user_command_repository;
         pub mod in_memory_user_query_repository;
+        pub mod in_memory_user_store;
         pub mod monitored_repository;
+        #[cfg(feature = "postgres")]
+        pub mod postgres_user_command_repository;
+        #[cfg(feature = "postgres")]
+        pub mod postgres_user_query_repository;
+        pub mod sqlite_api_key_repository;
+        pub mod sqlite_audit_log_repository;
+        pub mod sqlite_email_verification_token_repository;
+        pub mod sqlite_login_attempt_repository;
+        pub mod sqlite_mfa_credential_repository;
+        pub mod sqlite_oauth_authorization_code_repository;
+        pub mod sqlite_oauth_client_repository;
+        pub mod sqlite_password_reset_request_repository;
+        pub mod sqlite_password_reset_token_repository;
+        pub mod sqlite_refresh_token_repository;
+        pub mod sqlite_token_revocation_repository;
+        pub mod sqlite_user_command_repository;
+        pub mod sqlite_user_query_repository;
 
         pub use in_memory_user_command_repository::*;
         pub use in_memory_user_query_repository::*;
+        pub use sqlite_user_command_repository::*;
+        pub use sqlite_user_query_repository::*;
         // pub use monitored_repository::*;
     }
 
@@ -187,21 +306,35 @@ pub mod infrastructure {
 
     pub mod config {
         pub mod app_config;
+        pub mod config_file;
+        pub mod config_source;
         pub mod metrics_config;
 
         // pub use app_config::*;
         // pub use metrics_config::*;
     }
     pub mod database {
+        pub mod connection_pool;
+        pub mod migration;
+        #[cfg(feature = "postgres")]
+        pub mod postgres_connection;
         pub mod sqlite_connection;
     }
 
+    pub mod mail {
+        pub mod file_mailer;
+        pub mod in_memory_mailer;
+        pub mod smtp_mailer;
+    }
+
     pub mod di {
         pub mod container;
     }
 
     pub mod web {
+        pub mod listener;
         pub mod run;
+        pub mod tls;
     }
 
     pub mod grpc {
@@ -212,51 +345,93 @@ pub mod infrastructure {
     pub mod utils {
         pub mod cors_settings;
         pub mod graceful_shutdown;
+        pub mod session_gc;
     }
 }
 
 // ===== Presentation Layer =====
 pub mod presentation {
     pub mod controller {
+        pub mod admin_controller;
+        pub mod api_key_controller;
         pub mod auth_controller;
         pub mod fortune_controller;
         pub mod health_controller;
         pub mod metrics_controller;
+        pub mod oauth_controller;
+        pub mod oidc_controller;
         pub mod user_controller;
+        pub mod well_known_controller;
 
+        // pub use admin_controller::*;
+        // pub use api_key_controller::*;
         // pub use auth_controller::*;
         // pub use health_controller::*;
         // pub use metrics_controller::*;
+        // pub use oauth_controller::*;
+        // pub use oidc_controller::*;
         // pub use user_controller::*;
     }
 
     pub mod dto {
+        pub mod api_key_request;
+        pub mod api_key_response;
         pub mod api_response;
         pub mod create_user_request;
         pub mod delete_user_request;
+        pub mod email_verification_request;
+        pub mod impersonation_response;
+        pub mod lockout_response;
         pub mod login_request;
         pub mod login_response;
         pub mod metrics_response;
+        pub mod mfa_request;
+        pub mod mfa_response;
+        pub mod oauth_request;
+        pub mod oauth_response;
+        pub mod oidc_response;
+        pub mod password_reset_request;
+        pub mod refresh_token_request;
+        pub mod session_response;
         pub mod update_user_request;
         pub mod user_response;
 
+        // pub use api_key_request::*;
+        // pub use api_key_response::*;
         // pub use api_response::*;
         // pub use create_user_request::*;
         // pub use delete_user_request::*;
+        // pub use email_verification_request::*;
+        // pub use impersonation_response::*;
         // pub use login_request::*;
         // pub use login_response::*;
         // pub use metrics_response::*;
+        // pub use mfa_request::*;
+        // pub use mfa_response::*;
+        // pub use oauth_request::*;
+        // pub use oauth_response::*;
+        // pub use oidc_response::*;
+        // pub use password_reset_request::*;
+        // pub use lockout_response::*;
+        // pub use refresh_token_request::*;
+        // pub use session_response::*;
         // pub use update_user_request::*;
         // pub use user_response::*;
     }
 
     pub mod router {
+        pub mod admin_router;
+        pub mod api_key_router;
         pub mod app_router;
         pub mod auth_router;
         pub mod fortune_router;
         pub mod grpc_router;
+        pub mod health_router;
         pub mod metrics_router;
+        pub mod oauth_router;
+        pub mod oidc_router;
         pub mod user_router;
+        pub mod well_known_router;
 
         // pub use app_router::*;
         // pub use auth_router::*;
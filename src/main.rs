@@ -3,15 +3,52 @@
 // 2025/7/8
 
 use rusted_ca::infrastructure::config::app_config::AppConfig;
+use rusted_ca::infrastructure::config::config_source::{CliArgs, CliCommand, ConfigSource};
+use rusted_ca::infrastructure::database::migration::run_migrate_command;
 use rusted_ca::infrastructure::web::run::run;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    let dotenv_result = dotenvy::from_path("C:/Users/yoshi/rusted-ca/rusted-ca/.env");
-    println!("DEBUG: dotenvy result = {:?}", dotenv_result);
+    // カレントディレクトリの.envを環境変数として読み込む（なくてもよい）
+    let _ = dotenvy::dotenv();
 
-    for (key, value) in std::env::vars() {
-        println!("ENV: {} = {}", key, value);
+    let cli = match CliArgs::parse(std::env::args().skip(1)) {
+        Ok(cli) => cli,
+        Err(errors) => {
+            eprint!("{}", errors);
+            eprint!("{}", CliArgs::USAGE);
+            std::process::exit(2);
+        }
+    };
+    if cli.help {
+        print!("{}", CliArgs::USAGE);
+        return Ok(());
+    }
+
+    // アプリケーション設定を読み込み（不正な値があれば全て表示して終了）
+    let (app_config, report) =
+        match ConfigSource::load(&cli).and_then(|source| AppConfig::load(&source)) {
+            Ok(loaded) => loaded,
+            Err(errors) => {
+                eprint!("{}", errors);
+                std::process::exit(2);
+            }
+        };
+    if cli.print_config {
+        print!("{}", report);
+        return Ok(());
+    }
+
+    // マイグレーションのサブコマンド（サーバーは起動しない）
+    if let CliCommand::Migrate(command) = cli.command {
+        match run_migrate_command(&app_config.database, command).await {
+            Ok(output) => print!("{}", output),
+            Err(e) => {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+        }
+        return Ok(());
     }
 
     println!("🚀 クリーンアーキテクチャ + CQRS + DIコンテナのWebサーバーを起動します");
@@ -23,11 +60,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     println!("  - gRPCサーバー: Protocol Buffers + Prost + Axum");
     println!("  - Discord通知: リアルタイム監視・アラート");
 
-    // アプリケーション設定を読み込み
-    let _app_config = AppConfig::from_env();
-
     // HTTP + gRPC統合サーバーを起動
-    run().await?;
+    run(app_config).await?;
 
     Ok(())
 }
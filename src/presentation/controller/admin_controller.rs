@@ -0,0 +1,151 @@
+//presentation/controller/admin_controller.rs
+// 管理者用エンドポイント
+// 2025/7/8
+
+use crate::application::usecases::impersonation_usecase::ImpersonationUsecaseInterface;
+use crate::application::usecases::logout_usecase::LogoutUsecaseInterface;
+use crate::application::usecases::unlock_account_usecase::UnlockAccountUsecaseInterface;
+use crate::presentation::dto::api_response::ApiResponse;
+use crate::presentation::dto::impersonation_response::ImpersonationResponse;
+use crate::presentation::dto::lockout_response::AccountUnlockResponse;
+use crate::presentation::dto::session_response::SessionRevocationResponse;
+use crate::shared::error::application_error::ApplicationError;
+use crate::shared::middleware::auth_middleware::{
+    AuthError, RequirePermission, SuperAdminUser, permissions,
+};
+use axum::{extract::Path, http::StatusCode, response::Json};
+use serde_json::{Value, json};
+use std::sync::Arc;
+
+/// 管理者Controller
+///
+/// 責務:
+/// 1. 管理者専用HTTPリクエストの受信（RequirePermission・SuperAdminUserで認可）
+/// 2. UseCase実行
+/// 3. HTTPレスポンスの生成（ステータスコード + JSON）
+pub struct AdminController {
+    logout_usecase: Arc<dyn LogoutUsecaseInterface>,
+    unlock_account_usecase: Arc<dyn UnlockAccountUsecaseInterface>,
+    impersonation_usecase: Arc<dyn ImpersonationUsecaseInterface>,
+}
+
+impl AdminController {
+    pub fn new(
+        logout_usecase: Arc<dyn LogoutUsecaseInterface>,
+        unlock_account_usecase: Arc<dyn UnlockAccountUsecaseInterface>,
+        impersonation_usecase: Arc<dyn ImpersonationUsecaseInterface>,
+    ) -> Self {
+        Self {
+            logout_usecase,
+            unlock_account_usecase,
+            impersonation_usecase,
+        }
+    }
+
+    /// DELETE /api/admin/users/{id}/sessions - 対象ユーザーの全セッションを終了
+    pub async fn revoke_user_sessions(
+        &self,
+        admin: RequirePermission<permissions::SessionsRevoke>,
+        Path(user_id): Path<String>,
+    ) -> Result<Json<ApiResponse<SessionRevocationResponse>>, (StatusCode, Json<Value>)> {
+        println!(
+            "AdminController: {} revoking all sessions of user {}",
+            admin.claims.sub, user_id
+        );
+        match self.logout_usecase.revoke_user_sessions(user_id).await {
+            Ok(revoked_sessions) => Ok(Json(ApiResponse {
+                success: true,
+                data: Some(SessionRevocationResponse { revoked_sessions }),
+                message: "User sessions revoked".to_string(),
+                request_id: format!("req_{}", uuid::Uuid::new_v4()),
+                processing_time_ms: 0,
+            })),
+            Err(error) => Err(Self::map_application_error(error)),
+        }
+    }
+
+    /// DELETE /api/admin/users/{id}/lockout - 対象ユーザーのログインロックを解除
+    pub async fn unlock_user(
+        &self,
+        admin: RequirePermission<permissions::UsersWrite>,
+        Path(user_id): Path<String>,
+    ) -> Result<Json<ApiResponse<AccountUnlockResponse>>, (StatusCode, Json<Value>)> {
+        match self
+            .unlock_account_usecase
+            .execute(admin.claims.sub, user_id)
+            .await
+        {
+            Ok(unlocked) => Ok(Json(ApiResponse {
+                success: true,
+                data: Some(AccountUnlockResponse { unlocked }),
+                message: "User lockout cleared".to_string(),
+                request_id: format!("req_{}", uuid::Uuid::new_v4()),
+                processing_time_ms: 0,
+            })),
+            Err(error) => Err(Self::map_application_error(error)),
+        }
+    }
+
+    /// POST /api/admin/users/{id}/impersonate - 対象ユーザーとして操作する短期間のトークンを発行
+    pub async fn impersonate_user(
+        &self,
+        SuperAdminUser(claims): SuperAdminUser,
+        Path(user_id): Path<String>,
+    ) -> Result<(StatusCode, Json<ApiResponse<ImpersonationResponse>>), (StatusCode, Json<Value>)>
+    {
+        match self
+            .impersonation_usecase
+            .impersonate(claims.sub, user_id)
+            .await
+        {
+            Ok(token) => Ok((
+                StatusCode::CREATED,
+                Json(ApiResponse {
+                    success: true,
+                    data: Some(token.into()),
+                    message: "Impersonation token issued".to_string(),
+                    request_id: format!("req_{}", uuid::Uuid::new_v4()),
+                    processing_time_ms: 0,
+                }),
+            )),
+            Err(error) => Err(Self::map_application_error(error)),
+        }
+    }
+
+    /// ApplicationエラーをHTTPレスポンスにマッピング
+    fn map_application_error(error: ApplicationError) -> (StatusCode, Json<Value>) {
+        let (status, code, message) = match &error {
+            ApplicationError::UserNotFound { id } => (
+                StatusCode::NOT_FOUND,
+                "USER_NOT_FOUND",
+                format!("User with ID '{}' not found", id),
+            ),
+            ApplicationError::OperationNotPermitted { operation, reason } => {
+                let (status, body) = AuthError::OperationNotPermitted {
+                    operation: operation.clone(),
+                    reason: reason.clone(),
+                }
+                .status_and_body();
+                return (status, Json(body));
+            }
+            other => {
+                println!("AdminController: {}", other);
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "INTERNAL_SERVER_ERROR",
+                    "An unexpected error occurred".to_string(),
+                )
+            }
+        };
+        (
+            status,
+            Json(json!({
+                "success": false,
+                "error": {
+                    "code": code,
+                    "message": message,
+                }
+            })),
+        )
+    }
+}
@@ -0,0 +1,214 @@
+//presentation/controller/api_key_controller.rs
+// APIキー管理エンドポイント
+// 2025/7/8
+
+use crate::application::dto::api_key_dto::{CreateApiKeyDto, UpdateApiKeyDto};
+use crate::application::dto::auth_dto::ActorDto;
+use crate::application::usecases::api_key_usecase::ApiKeyUsecaseInterface;
+use crate::presentation::dto::api_key_request::{
+    ApiKeyListQuery, CreateApiKeyRequest, UpdateApiKeyRequest,
+};
+use crate::presentation::dto::api_key_response::{ApiKeyResponse, CreatedApiKeyResponse};
+use crate::presentation::dto::api_response::ApiResponse;
+use crate::shared::error::application_error::ApplicationError;
+use crate::shared::middleware::auth_middleware::{AuthError, AuthenticatedUser};
+use axum::{
+    extract::{Path, Query},
+    http::StatusCode,
+    response::Json,
+};
+use serde_json::{Value, json};
+use std::sync::Arc;
+
+type ErrorResponse = (StatusCode, Json<Value>);
+
+/// APIキーController
+///
+/// 責務:
+/// 1. APIキー管理HTTPリクエストの受信（ログインセッションのみ、APIキーでの管理は不可）
+/// 2. UseCase実行
+/// 3. HTTPレスポンスの生成（ステータスコード + JSON）
+pub struct ApiKeyController {
+    api_key_usecase: Arc<dyn ApiKeyUsecaseInterface>,
+}
+
+impl ApiKeyController {
+    pub fn new(api_key_usecase: Arc<dyn ApiKeyUsecaseInterface>) -> Self {
+        Self { api_key_usecase }
+    }
+
+    /// POST /api/api-keys - APIキーを作成（キー本体はこの応答でのみ返す）
+    pub async fn create_api_key(
+        &self,
+        auth: AuthenticatedUser,
+        Json(payload): Json<CreateApiKeyRequest>,
+    ) -> Result<(StatusCode, Json<ApiResponse<CreatedApiKeyResponse>>), ErrorResponse> {
+        // なりすまし終了後も使える資格情報は発行させない
+        auth.0
+            .forbid_impersonation("api_key.create")
+            .map_err(Self::auth_error_response)?;
+        let actor = Self::actor(auth, "api_key.create")?;
+        let created = self
+            .api_key_usecase
+            .create(
+                actor,
+                CreateApiKeyDto {
+                    owner_id: payload.owner_id,
+                    name: payload.name,
+                    scopes: payload.scopes,
+                    expires_in_days: payload.expires_in_days,
+                },
+            )
+            .await
+            .map_err(Self::map_application_error)?;
+        Ok((
+            StatusCode::CREATED,
+            Json(Self::ok(created.into(), "API key created")),
+        ))
+    }
+
+    /// GET /api/api-keys - APIキー一覧（`owner_id`で他のユーザーを指定）
+    pub async fn list_api_keys(
+        &self,
+        auth: AuthenticatedUser,
+        Query(query): Query<ApiKeyListQuery>,
+    ) -> Result<Json<ApiResponse<Vec<ApiKeyResponse>>>, ErrorResponse> {
+        let actor = Self::actor(auth, "api_key.list")?;
+        let api_keys = self
+            .api_key_usecase
+            .list(actor, query.owner_id)
+            .await
+            .map_err(Self::map_application_error)?;
+        Ok(Json(Self::ok(
+            api_keys.into_iter().map(ApiKeyResponse::from).collect(),
+            "API keys retrieved",
+        )))
+    }
+
+    /// GET /api/api-keys/{id} - APIキーを取得
+    pub async fn get_api_key(
+        &self,
+        auth: AuthenticatedUser,
+        Path(id): Path<String>,
+    ) -> Result<Json<ApiResponse<ApiKeyResponse>>, ErrorResponse> {
+        let actor = Self::actor(auth, "api_key.get")?;
+        let api_key = self
+            .api_key_usecase
+            .get(actor, id)
+            .await
+            .map_err(Self::map_application_error)?;
+        Ok(Json(Self::ok(api_key.into(), "API key retrieved")))
+    }
+
+    /// PATCH /api/api-keys/{id} - 名前・スコープを更新
+    pub async fn update_api_key(
+        &self,
+        auth: AuthenticatedUser,
+        Path(id): Path<String>,
+        Json(payload): Json<UpdateApiKeyRequest>,
+    ) -> Result<Json<ApiResponse<ApiKeyResponse>>, ErrorResponse> {
+        let actor = Self::actor(auth, "api_key.update")?;
+        let api_key = self
+            .api_key_usecase
+            .update(
+                actor,
+                id,
+                UpdateApiKeyDto {
+                    name: payload.name,
+                    scopes: payload.scopes,
+                },
+            )
+            .await
+            .map_err(Self::map_application_error)?;
+        Ok(Json(Self::ok(api_key.into(), "API key updated")))
+    }
+
+    /// DELETE /api/api-keys/{id} - APIキーを削除
+    pub async fn delete_api_key(
+        &self,
+        auth: AuthenticatedUser,
+        Path(id): Path<String>,
+    ) -> Result<StatusCode, ErrorResponse> {
+        let actor = Self::actor(auth, "api_key.delete")?;
+        self.api_key_usecase
+            .delete(actor, id)
+            .await
+            .map_err(Self::map_application_error)?;
+        Ok(StatusCode::NO_CONTENT)
+    }
+
+    /// APIキー自身・OAuthクライアントのトークンでのキー管理は拒否する
+    /// （漏洩したキーや狭いスコープの委任から、所有者の全権限を持つキーを作れないように）
+    fn actor(auth: AuthenticatedUser, operation: &str) -> Result<ActorDto, ErrorResponse> {
+        let claims = auth
+            .require_session(operation)
+            .map_err(Self::auth_error_response)?;
+        claims
+            .forbid_client_token(operation)
+            .map_err(Self::auth_error_response)?;
+        Ok(ActorDto {
+            user_id: claims.sub,
+            role: claims.role,
+            scope: claims.scope,
+        })
+    }
+
+    fn ok<T>(data: T, message: &str) -> ApiResponse<T> {
+        ApiResponse {
+            success: true,
+            data: Some(data),
+            message: message.to_string(),
+            request_id: format!("req_{}", uuid::Uuid::new_v4()),
+            processing_time_ms: 0,
+        }
+    }
+
+    fn auth_error_response(error: AuthError) -> ErrorResponse {
+        let (status, body) = error.status_and_body();
+        (status, Json(body))
+    }
+
+    /// ApplicationエラーをHTTPレスポンスにマッピング
+    fn map_application_error(error: ApplicationError) -> ErrorResponse {
+        let (status, code, message) = match &error {
+            ApplicationError::UserNotFound { id } => (
+                StatusCode::NOT_FOUND,
+                "USER_NOT_FOUND",
+                format!("User with ID '{}' not found", id),
+            ),
+            ApplicationError::ResourceNotFound { resource, id } => (
+                StatusCode::NOT_FOUND,
+                "RESOURCE_NOT_FOUND",
+                format!("{} '{}' not found", resource, id),
+            ),
+            ApplicationError::AuthorizationFailed { message } => {
+                println!("ApiKeyController: Authorization failed: {}", message);
+                return Self::auth_error_response(AuthError::InsufficientPermissions);
+            }
+            ApplicationError::ValidationFailed { field, message } => {
+                return Self::auth_error_response(AuthError::ValidationFailed {
+                    field: field.clone(),
+                    message: message.clone(),
+                });
+            }
+            other => {
+                println!("ApiKeyController: {}", other);
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "INTERNAL_SERVER_ERROR",
+                    "An unexpected error occurred".to_string(),
+                )
+            }
+        };
+        (
+            status,
+            Json(json!({
+                "success": false,
+                "error": {
+                    "code": code,
+                    "message": message,
+                }
+            })),
+        )
+    }
+}
@@ -1,7 +1,48 @@
 //presentation/controller/auth_controller.rs
+// 認証エンドポイント
+// 2025/7/8
+
+use crate::application::dto::auth_dto::{
+    AuthUserDto, AuthenticatedSessionDto, EmailVerificationConfirmDto, EmailVerificationResendDto,
+    LoginRequestDto, LoginResultDto, MfaCallerDto, PasswordResetConfirmDto,
+    PasswordResetRequestDto, RefreshTokenRequestDto, TokenPairDto,
+};
+use crate::application::usecases::email_verification_usecase::EmailVerificationUsecaseInterface;
+use crate::application::usecases::login_usecase::LoginUsecaseInterface;
+use crate::application::usecases::logout_usecase::LogoutUsecaseInterface;
+use crate::application::usecases::mfa_usecase::MfaUsecaseInterface;
+use crate::application::usecases::password_reset_usecase::PasswordResetUsecaseInterface;
+use crate::application::usecases::refresh_token_usecase::RefreshTokenUsecaseInterface;
+use crate::presentation::dto::api_response::ApiResponse;
+use crate::presentation::dto::email_verification_request::{
+    ResendVerificationRequest, VerifyEmailQuery,
+};
 use crate::presentation::dto::login_request::LoginRequest;
-use crate::shared::middleware::auth_middleware::{AuthError, JwtService};
-use axum::Json;
+use crate::presentation::dto::mfa_request::MfaCodeRequest;
+use crate::presentation::dto::mfa_response::{
+    MfaChallengeResponse, MfaEnrollmentResponse, RecoveryCodesResponse,
+};
+use crate::presentation::dto::password_reset_request::{
+    PasswordResetConfirmRequest, PasswordResetRequest,
+};
+use crate::presentation::dto::refresh_token_request::RefreshTokenRequest;
+use crate::presentation::dto::session_response::SessionRevocationResponse;
+use crate::shared::error::application_error::ApplicationError;
+use crate::shared::error::domain_error::DomainError;
+use crate::shared::middleware::auth_middleware::{
+    AuthError, AuthenticatedUser, JwtClaims, MfaEnrollmentUser, MfaPendingUser, TokenType,
+};
+use crate::shared::middleware::client_ip_middleware::ClientIp;
+use crate::shared::middleware::session_cookie_middleware::{
+    REFRESH_TOKEN_COOKIE, SessionCookies, SessionMode, cookie_value, verify_csrf,
+};
+use axum::{
+    Json,
+    extract::Query,
+    http::{HeaderMap, Method, StatusCode},
+    response::{IntoResponse, Response},
+};
+use std::sync::Arc;
 
 #[derive(Debug, serde::Serialize)]
 pub struct UserInfo {
@@ -20,46 +61,450 @@ pub struct UserLoginResponse {
     pub user: UserInfo,
 }
 
-pub async fn login(
-    Json(payload): Json<LoginRequest>,
-) -> Result<Json<UserLoginResponse>, AuthError> {
-    // 統合テスト用の認証情報設定
-    let (auth_user, auth_pass) = if cfg!(test) || std::env::var("TEST_MODE").is_ok() {
-        ("auth_user".to_string(), "auth_password".to_string())
-    } else {
-        (
-            std::env::var("AUTH_USER").unwrap_or_else(|_| "auth_user".to_string()),
-            std::env::var("AUTH_PASS").unwrap_or_else(|_| "auth_password".to_string()),
-        )
-    };
-
-    if payload.username != auth_user || payload.password != auth_pass {
-        return Err(AuthError::WrongCredentials);
-    }
-
-    // ダミー情報でJwtClaimsを生成（必要に応じてemail/name/roleを.envやDBから取得）
-    let user_id = "dummy-id".to_string();
-    let name = "Dummy User".to_string();
-    let role = "user".to_string();
-
-    let jwt_service = JwtService::new();
-    let (access_token, refresh_token) = jwt_service.generate_token_pair(
-        user_id.clone(),
-        payload.username.clone(),
-        name.clone(),
-        role.clone(),
-    )?;
-
-    Ok(Json(UserLoginResponse {
-        access_token,
-        refresh_token,
-        token_type: "Bearer".to_string(),
-        expires_in: 3600,
-        user: UserInfo {
-            id: user_id,
-            email: payload.username,
-            name,
-            role,
-        },
-    }))
+impl From<AuthUserDto> for UserInfo {
+    fn from(dto: AuthUserDto) -> Self {
+        Self {
+            id: dto.id,
+            email: dto.email,
+            name: dto.name,
+            role: dto.role,
+        }
+    }
+}
+
+impl From<TokenPairDto> for UserLoginResponse {
+    fn from(dto: TokenPairDto) -> Self {
+        Self {
+            access_token: dto.access_token,
+            refresh_token: dto.refresh_token,
+            token_type: dto.token_type,
+            expires_in: dto.expires_in,
+            user: dto.user.into(),
+        }
+    }
+}
+
+/// Cookieセッションの応答（トークンは本文に含めず、CSRFトークンのみ返す）
+#[derive(Debug, serde::Serialize)]
+pub struct CookieSessionResponse {
+    pub csrf_token: String,
+    pub expires_in: i64,
+    pub user: UserInfo,
+}
+
+/// セッション開始・更新の応答本文
+#[derive(Debug, serde::Serialize)]
+#[serde(untagged)]
+pub enum SessionResponse {
+    Bearer(UserLoginResponse),
+    Cookie(CookieSessionResponse),
+}
+
+/// 二要素認証の登録確認の応答（ログイン途中の場合は`session`にセッション）
+#[derive(Debug, serde::Serialize)]
+pub struct MfaConfirmResponse {
+    pub recovery_codes: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session: Option<SessionResponse>,
+}
+
+/// 認証Controller
+///
+/// 責務:
+/// 1. 認証系HTTPリクエストの受信
+/// 2. UseCase実行
+/// 3. ApplicationエラーのAuthErrorへの変換
+pub struct AuthController {
+    login_usecase: Arc<dyn LoginUsecaseInterface>,
+    refresh_token_usecase: Arc<dyn RefreshTokenUsecaseInterface>,
+    logout_usecase: Arc<dyn LogoutUsecaseInterface>,
+    password_reset_usecase: Arc<dyn PasswordResetUsecaseInterface>,
+    email_verification_usecase: Arc<dyn EmailVerificationUsecaseInterface>,
+    mfa_usecase: Arc<dyn MfaUsecaseInterface>,
+}
+
+impl AuthController {
+    pub fn new(
+        login_usecase: Arc<dyn LoginUsecaseInterface>,
+        refresh_token_usecase: Arc<dyn RefreshTokenUsecaseInterface>,
+        logout_usecase: Arc<dyn LogoutUsecaseInterface>,
+        password_reset_usecase: Arc<dyn PasswordResetUsecaseInterface>,
+        email_verification_usecase: Arc<dyn EmailVerificationUsecaseInterface>,
+        mfa_usecase: Arc<dyn MfaUsecaseInterface>,
+    ) -> Self {
+        Self {
+            login_usecase,
+            refresh_token_usecase,
+            logout_usecase,
+            password_reset_usecase,
+            email_verification_usecase,
+            mfa_usecase,
+        }
+    }
+
+    /// POST /api/auth/login - ログイン
+    ///
+    /// 二要素認証が必要な場合はトークンペアの代わりに`mfa_token`を返す。
+    /// `X-Session-Mode: cookie`の場合はトークンをCookieに設定する。
+    pub async fn login(
+        &self,
+        mode: SessionMode,
+        ClientIp(client_ip): ClientIp,
+        Json(payload): Json<LoginRequest>,
+    ) -> Result<Response, AuthError> {
+        let app_request = LoginRequestDto {
+            email: payload.email,
+            password: payload.password,
+            client_ip: client_ip.map(|ip| ip.to_string()),
+        };
+        let result = self
+            .login_usecase
+            .execute(app_request)
+            .await
+            .map_err(Self::map_application_error)?;
+        Ok(match result {
+            LoginResultDto::Authenticated(token_pair) => Self::session_response(mode, token_pair),
+            LoginResultDto::MfaRequired(challenge) => {
+                Json(MfaChallengeResponse::from(challenge)).into_response()
+            }
+        })
+    }
+
+    /// POST /api/auth/login/mfa - ログインの2段階目（`mfa_token`をBearerで送る）
+    pub async fn verify_mfa_login(
+        &self,
+        mode: SessionMode,
+        MfaPendingUser(claims): MfaPendingUser,
+        ClientIp(client_ip): ClientIp,
+        Json(payload): Json<MfaCodeRequest>,
+    ) -> Result<Response, AuthError> {
+        let token_pair = self
+            .mfa_usecase
+            .verify_login(Self::mfa_caller(claims, client_ip), payload.code)
+            .await
+            .map_err(Self::map_application_error)?;
+        Ok(Self::session_response(mode, token_pair))
+    }
+
+    /// POST /api/auth/mfa/enroll - 二要素認証の登録開始
+    pub async fn enroll_mfa(
+        &self,
+        MfaEnrollmentUser(claims): MfaEnrollmentUser,
+    ) -> Result<Json<MfaEnrollmentResponse>, AuthError> {
+        claims.forbid_impersonation("mfa.enroll")?;
+        claims.forbid_client_token("mfa.enroll")?;
+        let enrollment = self
+            .mfa_usecase
+            .enroll(Self::mfa_caller(claims, None))
+            .await
+            .map_err(Self::map_application_error)?;
+        Ok(Json(enrollment.into()))
+    }
+
+    /// POST /api/auth/mfa/enroll/confirm - 二要素認証の登録確定
+    pub async fn confirm_mfa_enrollment(
+        &self,
+        mode: SessionMode,
+        MfaEnrollmentUser(claims): MfaEnrollmentUser,
+        ClientIp(client_ip): ClientIp,
+        Json(payload): Json<MfaCodeRequest>,
+    ) -> Result<Response, AuthError> {
+        claims.forbid_impersonation("mfa.enroll")?;
+        claims.forbid_client_token("mfa.enroll")?;
+        let result = self
+            .mfa_usecase
+            .confirm_enrollment(Self::mfa_caller(claims, client_ip), payload.code)
+            .await
+            .map_err(Self::map_application_error)?;
+        let (cookies, session) = match result.session {
+            Some(token_pair) => {
+                let (cookies, session) = Self::session_body(mode, token_pair);
+                (cookies, Some(session))
+            }
+            None => (None, None),
+        };
+        let response = Json(MfaConfirmResponse {
+            recovery_codes: result.recovery_codes,
+            session,
+        })
+        .into_response();
+        Ok(match cookies {
+            Some(cookies) => cookies.apply(response),
+            None => response,
+        })
+    }
+
+    /// POST /api/auth/mfa/disable - 二要素認証の解除
+    pub async fn disable_mfa(
+        &self,
+        auth: AuthenticatedUser,
+        Json(payload): Json<MfaCodeRequest>,
+    ) -> Result<StatusCode, AuthError> {
+        let claims = auth.require_session("mfa.disable")?;
+        claims.forbid_impersonation("mfa.disable")?;
+        claims.forbid_client_token("mfa.disable")?;
+        self.mfa_usecase
+            .disable(Self::mfa_caller(claims, None), payload.code)
+            .await
+            .map_err(Self::map_application_error)?;
+        Ok(StatusCode::NO_CONTENT)
+    }
+
+    /// POST /api/auth/mfa/recovery-codes - リカバリーコードの再発行
+    pub async fn regenerate_recovery_codes(
+        &self,
+        auth: AuthenticatedUser,
+        Json(payload): Json<MfaCodeRequest>,
+    ) -> Result<Json<RecoveryCodesResponse>, AuthError> {
+        let claims = auth.require_session("mfa.recovery_codes")?;
+        claims.forbid_impersonation("mfa.recovery_codes")?;
+        claims.forbid_client_token("mfa.recovery_codes")?;
+        let recovery_codes = self
+            .mfa_usecase
+            .regenerate_recovery_codes(Self::mfa_caller(claims, None), payload.code)
+            .await
+            .map_err(Self::map_application_error)?;
+        Ok(Json(RecoveryCodesResponse { recovery_codes }))
+    }
+
+    /// POST /api/auth/refresh - リフレッシュトークンのローテーション
+    ///
+    /// 本文に`refresh_token`がない場合はCookieセッションとして、
+    /// リフレッシュトークンのCookieとCSRFトークンで更新する
+    pub async fn refresh(
+        &self,
+        mode: SessionMode,
+        headers: HeaderMap,
+        Json(payload): Json<RefreshTokenRequest>,
+    ) -> Result<Response, AuthError> {
+        let (refresh_token, mode) = match payload.refresh_token {
+            Some(refresh_token) => (refresh_token, mode),
+            None => {
+                let refresh_token = cookie_value(&headers, REFRESH_TOKEN_COOKIE)
+                    .ok_or(AuthError::MissingCredentials)?
+                    .to_string();
+                verify_csrf(&Method::POST, &headers)?;
+                (refresh_token, SessionMode::Cookie)
+            }
+        };
+        let app_request = RefreshTokenRequestDto {
+            refresh_token,
+            client_id: None,
+        };
+        let token_pair = self
+            .refresh_token_usecase
+            .execute(app_request)
+            .await
+            .map_err(Self::map_application_error)?;
+        Ok(Self::session_response(mode, token_pair))
+    }
+
+    /// POST /api/auth/logout - 現在のセッションを終了
+    pub async fn logout(
+        &self,
+        mode: SessionMode,
+        auth: AuthenticatedUser,
+    ) -> Result<Response, AuthError> {
+        let claims = auth.require_session("logout")?;
+        self.logout_usecase
+            .logout(Self::session_from_claims(claims))
+            .await
+            .map_err(Self::map_application_error)?;
+        Ok(Self::end_session_response(
+            mode,
+            StatusCode::NO_CONTENT.into_response(),
+        ))
+    }
+
+    /// POST /api/auth/logout-all - 自分の全セッションを終了
+    pub async fn logout_all(
+        &self,
+        mode: SessionMode,
+        auth: AuthenticatedUser,
+    ) -> Result<Response, AuthError> {
+        let claims = auth.require_session("logout_all")?;
+        let revoked_sessions = self
+            .logout_usecase
+            .logout_all(Self::session_from_claims(claims))
+            .await
+            .map_err(Self::map_application_error)?;
+        let response = Json(ApiResponse {
+            success: true,
+            data: Some(SessionRevocationResponse { revoked_sessions }),
+            message: "All sessions revoked".to_string(),
+            request_id: format!("req_{}", uuid::Uuid::new_v4()),
+            processing_time_ms: 0,
+        })
+        .into_response();
+        Ok(Self::end_session_response(mode, response))
+    }
+
+    /// POST /api/auth/password-reset/request - リセットメールの送信
+    ///
+    /// アカウントの有無・回数制限に関わらず202を返す
+    pub async fn request_password_reset(
+        &self,
+        ClientIp(client_ip): ClientIp,
+        Json(payload): Json<PasswordResetRequest>,
+    ) -> Result<(StatusCode, Json<ApiResponse<()>>), AuthError> {
+        self.password_reset_usecase
+            .request_reset(PasswordResetRequestDto {
+                email: payload.email,
+                client_ip: client_ip.map(|ip| ip.to_string()),
+            })
+            .await
+            .map_err(Self::map_application_error)?;
+        Ok((
+            StatusCode::ACCEPTED,
+            Json(ApiResponse {
+                success: true,
+                data: None,
+                message: "If the account exists, a reset link has been sent".to_string(),
+                request_id: format!("req_{}", uuid::Uuid::new_v4()),
+                processing_time_ms: 0,
+            }),
+        ))
+    }
+
+    /// POST /api/auth/password-reset/confirm - 新しいパスワードの設定
+    pub async fn confirm_password_reset(
+        &self,
+        Json(payload): Json<PasswordResetConfirmRequest>,
+    ) -> Result<StatusCode, AuthError> {
+        self.password_reset_usecase
+            .confirm_reset(PasswordResetConfirmDto {
+                token: payload.token,
+                new_password: payload.new_password,
+            })
+            .await
+            .map_err(Self::map_application_error)?;
+        Ok(StatusCode::NO_CONTENT)
+    }
+
+    /// GET /api/auth/verify-email?token=... - メールアドレスの確認
+    pub async fn verify_email(
+        &self,
+        Query(query): Query<VerifyEmailQuery>,
+    ) -> Result<Json<ApiResponse<()>>, AuthError> {
+        self.email_verification_usecase
+            .verify(EmailVerificationConfirmDto { token: query.token })
+            .await
+            .map_err(Self::map_application_error)?;
+        Ok(Json(ApiResponse {
+            success: true,
+            data: None,
+            message: "Email address verified".to_string(),
+            request_id: format!("req_{}", uuid::Uuid::new_v4()),
+            processing_time_ms: 0,
+        }))
+    }
+
+    /// POST /api/auth/verify-email/resend - 確認メールの再送
+    ///
+    /// アカウントの有無・確認状況・回数制限に関わらず202を返す
+    pub async fn resend_verification_email(
+        &self,
+        Json(payload): Json<ResendVerificationRequest>,
+    ) -> Result<(StatusCode, Json<ApiResponse<()>>), AuthError> {
+        self.email_verification_usecase
+            .resend(EmailVerificationResendDto {
+                email: payload.email,
+            })
+            .await
+            .map_err(Self::map_application_error)?;
+        Ok((
+            StatusCode::ACCEPTED,
+            Json(ApiResponse {
+                success: true,
+                data: None,
+                message: "If the account requires verification, an email has been sent".to_string(),
+                request_id: format!("req_{}", uuid::Uuid::new_v4()),
+                processing_time_ms: 0,
+            }),
+        ))
+    }
+
+    /// トークンペアを受け渡し方法に応じた応答本文にする（Cookieセッションでは設定するCookieも返す）
+    fn session_body(
+        mode: SessionMode,
+        token_pair: TokenPairDto,
+    ) -> (Option<SessionCookies>, SessionResponse) {
+        match mode {
+            SessionMode::Bearer => (None, SessionResponse::Bearer(token_pair.into())),
+            SessionMode::Cookie => {
+                let cookies = SessionCookies::issue(
+                    &token_pair.access_token,
+                    token_pair.expires_in,
+                    &token_pair.refresh_token,
+                );
+                let body = CookieSessionResponse {
+                    csrf_token: cookies.csrf_token.clone(),
+                    expires_in: token_pair.expires_in,
+                    user: token_pair.user.into(),
+                };
+                (Some(cookies), SessionResponse::Cookie(body))
+            }
+        }
+    }
+
+    fn session_response(mode: SessionMode, token_pair: TokenPairDto) -> Response {
+        let (cookies, body) = Self::session_body(mode, token_pair);
+        let response = Json(body).into_response();
+        match cookies {
+            Some(cookies) => cookies.apply(response),
+            None => response,
+        }
+    }
+
+    /// Cookieセッションの終了時はCookieを削除する
+    fn end_session_response(mode: SessionMode, response: Response) -> Response {
+        match mode {
+            SessionMode::Cookie => SessionCookies::clear().apply(response),
+            SessionMode::Bearer => response,
+        }
+    }
+
+    fn mfa_caller(claims: JwtClaims, client_ip: Option<std::net::IpAddr>) -> MfaCallerDto {
+        MfaCallerDto {
+            pending: claims.token_type == TokenType::MfaPending,
+            user_id: claims.sub,
+            jti: claims.jti,
+            expires_at: claims.exp,
+            client_ip: client_ip.map(|ip| ip.to_string()),
+        }
+    }
+
+    fn session_from_claims(claims: JwtClaims) -> AuthenticatedSessionDto {
+        AuthenticatedSessionDto {
+            user_id: claims.sub,
+            jti: claims.jti,
+            session_id: claims.sid,
+            expires_at: claims.exp,
+        }
+    }
+
+    /// ApplicationエラーをAuthErrorにマッピング
+    fn map_application_error(error: ApplicationError) -> AuthError {
+        match error {
+            ApplicationError::InvalidCredentials => AuthError::WrongCredentials,
+            ApplicationError::InvalidToken => AuthError::InvalidToken,
+            ApplicationError::EmailNotVerified => AuthError::EmailNotVerified,
+            ApplicationError::TooManyAttempts { retry_after_secs } => {
+                AuthError::TooManyAttempts { retry_after_secs }
+            }
+            ApplicationError::OperationNotPermitted { operation, reason } => {
+                AuthError::OperationNotPermitted { operation, reason }
+            }
+            ApplicationError::ValidationFailed { field, message } => {
+                AuthError::ValidationFailed { field, message }
+            }
+            ApplicationError::Domain(DomainError::PasswordPolicyViolation { violations }) => {
+                AuthError::PasswordPolicyViolation { violations }
+            }
+            other => {
+                println!("AuthController: {}", other);
+                AuthError::Internal
+            }
+        }
+    }
 }
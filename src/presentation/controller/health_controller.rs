@@ -1,3 +1,19 @@
 //presentation/controller/health_controller.rs
 // ヘルスチェックエンドポイント
 // 2025/7/8
+
+use axum::Json;
+
+/// 死活監視用（本文は`OK`のみ）
+pub async fn health() -> &'static str {
+    "OK"
+}
+
+/// 状態と時刻を返すヘルスチェック
+pub async fn api_health() -> Json<serde_json::Value> {
+    Json(serde_json::json!({
+        "status": "ok",
+        "message": "API is running",
+        "timestamp": chrono::Utc::now().to_rfc3339()
+    }))
+}
@@ -1,3 +1,19 @@
 //presentation/controller/metrics_controller.rs
 // メトリクス取得エンドポイント
 // 2025/7/8
+
+use crate::shared::metrics::collector::MetricsCollector;
+use axum::Extension;
+use axum::http::header::CONTENT_TYPE;
+use axum::response::IntoResponse;
+use std::sync::Arc;
+
+/// Prometheusのテキスト形式でメトリクスを返す
+pub async fn get_metrics(
+    Extension(collector): Extension<Arc<MetricsCollector>>,
+) -> impl IntoResponse {
+    (
+        [(CONTENT_TYPE, "text/plain; version=0.0.4")],
+        collector.render_prometheus(),
+    )
+}
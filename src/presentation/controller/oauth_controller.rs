@@ -0,0 +1,405 @@
+//presentation/controller/oauth_controller.rs
+// OAuth認可サーバーエンドポイント・クライアント管理エンドポイント
+// 2025/7/8
+
+use crate::application::dto::auth_dto::ActorDto;
+use crate::application::dto::oauth_dto::{
+    AuthorizeRequestDto, ClientAuthDto, RegisterOAuthClientDto, TokenRequestDto,
+};
+use crate::application::usecases::oauth_client_usecase::OAuthClientUsecaseInterface;
+use crate::application::usecases::oauth_usecase::OAuthUsecaseInterface;
+use crate::presentation::dto::api_response::ApiResponse;
+use crate::presentation::dto::oauth_request::{
+    AuthorizeQuery, RegisterOAuthClientRequest, TokenActionForm, TokenForm,
+};
+use crate::presentation::dto::oauth_response::{
+    OAuthClientResponse, OAuthTokenResponse, RegisteredOAuthClientResponse,
+    TokenIntrospectionResponse,
+};
+use crate::shared::error::application_error::ApplicationError;
+use crate::shared::middleware::auth_middleware::{
+    AuthError, AuthenticatedUser, RequirePermission, permissions,
+};
+use crate::shared::utils::percent_encoding::percent_encode;
+use axum::{
+    extract::{Form, Path, Query},
+    http::{
+        HeaderMap, StatusCode,
+        header::{AUTHORIZATION, CACHE_CONTROL, LOCATION, PRAGMA, WWW_AUTHENTICATE},
+    },
+    response::{IntoResponse, Json, Response},
+};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use serde_json::{Value, json};
+use std::sync::Arc;
+
+type ErrorResponse = (StatusCode, Json<Value>);
+
+/// OAuth Controller
+///
+/// 責務:
+/// 1. `/oauth/*`のHTTPリクエストの受信（クライアント認証情報・フォームの解釈）
+/// 2. UseCase実行
+/// 3. RFC 6749形式のレスポンス生成（リダイレクト・`no-store`・`error`コード）
+/// 4. クライアント管理（`oauth_clients:manage`権限）
+pub struct OAuthController {
+    oauth_usecase: Arc<dyn OAuthUsecaseInterface>,
+    oauth_client_usecase: Arc<dyn OAuthClientUsecaseInterface>,
+}
+
+impl OAuthController {
+    pub fn new(
+        oauth_usecase: Arc<dyn OAuthUsecaseInterface>,
+        oauth_client_usecase: Arc<dyn OAuthClientUsecaseInterface>,
+    ) -> Self {
+        Self {
+            oauth_usecase,
+            oauth_client_usecase,
+        }
+    }
+
+    /// GET /oauth/authorize - ログイン中のユーザーとして認可コードを発行し、クライアントへリダイレクト
+    pub async fn authorize(
+        &self,
+        auth: AuthenticatedUser,
+        Query(query): Query<AuthorizeQuery>,
+    ) -> Response {
+        let claims = match auth.require_session("oauth.authorize") {
+            Ok(claims) => claims,
+            Err(error) => return error.into_response(),
+        };
+        // クライアントに委任されたトークンで別の委任はできない
+        if let Err(error) = claims.forbid_client_token("oauth.authorize") {
+            return error.into_response();
+        }
+        // なりすまし終了後も使える委任は作らせない
+        if let Err(error) = claims.forbid_impersonation("oauth.authorize") {
+            return error.into_response();
+        }
+        let actor = ActorDto {
+            user_id: claims.sub,
+            role: claims.role,
+            scope: claims.scope,
+        };
+        let redirect_uri = query.redirect_uri.clone();
+        let state = query.state;
+        let result = self
+            .oauth_usecase
+            .authorize(
+                actor,
+                AuthorizeRequestDto {
+                    response_type: query.response_type,
+                    client_id: query.client_id,
+                    redirect_uri: query.redirect_uri,
+                    scope: query.scope,
+                    code_challenge: query.code_challenge,
+                    code_challenge_method: query.code_challenge_method,
+                    nonce: query.nonce,
+                },
+            )
+            .await;
+        match result {
+            Ok(issued) => Self::redirect(
+                &issued.redirect_uri,
+                &[("code", Some(&issued.code))],
+                &state,
+            ),
+            // クライアント・リダイレクトURIが不正な場合はリダイレクトしない（RFC 6749 4.1.2.1）
+            Err(ApplicationError::InvalidInput { input, reason }) => (
+                StatusCode::BAD_REQUEST,
+                Json(json!({
+                    "error": "invalid_request",
+                    "error_description": format!("{}: {}", input, reason),
+                })),
+            )
+                .into_response(),
+            Err(ApplicationError::OAuthProtocol { error, description }) => Self::redirect(
+                &redirect_uri,
+                &[
+                    ("error", Some(&error)),
+                    ("error_description", Some(&description)),
+                ],
+                &state,
+            ),
+            Err(other) => Self::oauth_error_response(other),
+        }
+    }
+
+    /// POST /oauth/token - トークン発行（authorization_code / client_credentials / refresh_token）
+    pub async fn token(&self, headers: HeaderMap, Form(form): Form<TokenForm>) -> Response {
+        let client = match Self::client_auth(&headers, form.client_id, form.client_secret) {
+            Ok(client) => client,
+            Err(error) => return Self::oauth_error_response(error),
+        };
+        let result = self
+            .oauth_usecase
+            .token(
+                client,
+                TokenRequestDto {
+                    grant_type: form.grant_type,
+                    code: form.code,
+                    redirect_uri: form.redirect_uri,
+                    code_verifier: form.code_verifier,
+                    refresh_token: form.refresh_token,
+                    scope: form.scope,
+                },
+            )
+            .await;
+        match result {
+            Ok(token) => Self::no_store(Json(OAuthTokenResponse::from(token))),
+            Err(error) => Self::oauth_error_response(error),
+        }
+    }
+
+    /// POST /oauth/introspect - トークンのイントロスペクション（RFC 7662）
+    pub async fn introspect(
+        &self,
+        headers: HeaderMap,
+        Form(form): Form<TokenActionForm>,
+    ) -> Response {
+        let client = match Self::client_auth(&headers, form.client_id, form.client_secret) {
+            Ok(client) => client,
+            Err(error) => return Self::oauth_error_response(error),
+        };
+        match self.oauth_usecase.introspect(client, form.token).await {
+            Ok(introspection) => {
+                Self::no_store(Json(TokenIntrospectionResponse::from(introspection)))
+            }
+            Err(error) => Self::oauth_error_response(error),
+        }
+    }
+
+    /// POST /oauth/revoke - トークンの失効（RFC 7009、無効なトークンでも200）
+    pub async fn revoke(&self, headers: HeaderMap, Form(form): Form<TokenActionForm>) -> Response {
+        let client = match Self::client_auth(&headers, form.client_id, form.client_secret) {
+            Ok(client) => client,
+            Err(error) => return Self::oauth_error_response(error),
+        };
+        match self.oauth_usecase.revoke(client, form.token).await {
+            Ok(()) => StatusCode::OK.into_response(),
+            Err(error) => Self::oauth_error_response(error),
+        }
+    }
+
+    /// POST /api/oauth/clients - クライアントを登録（シークレットはこの応答でのみ返す）
+    pub async fn register_client(
+        &self,
+        admin: RequirePermission<permissions::OAuthClientsManage>,
+        Json(payload): Json<RegisterOAuthClientRequest>,
+    ) -> Result<(StatusCode, Json<ApiResponse<RegisteredOAuthClientResponse>>), ErrorResponse> {
+        let registered = self
+            .oauth_client_usecase
+            .register(
+                Self::actor(&admin),
+                RegisterOAuthClientDto {
+                    name: payload.name,
+                    redirect_uris: payload.redirect_uris,
+                    grant_types: payload.grant_types,
+                    scopes: payload.scopes,
+                    confidential: payload.confidential,
+                    service_account_id: payload.service_account_id,
+                },
+            )
+            .await
+            .map_err(Self::map_application_error)?;
+        Ok((
+            StatusCode::CREATED,
+            Json(Self::ok(registered.into(), "OAuth client registered")),
+        ))
+    }
+
+    /// GET /api/oauth/clients - 登録済みクライアントの一覧
+    pub async fn list_clients(
+        &self,
+        _admin: RequirePermission<permissions::OAuthClientsManage>,
+    ) -> Result<Json<ApiResponse<Vec<OAuthClientResponse>>>, ErrorResponse> {
+        let clients = self
+            .oauth_client_usecase
+            .list()
+            .await
+            .map_err(Self::map_application_error)?;
+        Ok(Json(Self::ok(
+            clients.into_iter().map(OAuthClientResponse::from).collect(),
+            "OAuth clients retrieved",
+        )))
+    }
+
+    /// DELETE /api/oauth/clients/{client_id} - クライアントを削除
+    pub async fn delete_client(
+        &self,
+        admin: RequirePermission<permissions::OAuthClientsManage>,
+        Path(client_id): Path<String>,
+    ) -> Result<StatusCode, ErrorResponse> {
+        self.oauth_client_usecase
+            .delete(Self::actor(&admin), client_id)
+            .await
+            .map_err(Self::map_application_error)?;
+        Ok(StatusCode::NO_CONTENT)
+    }
+
+    fn actor(admin: &RequirePermission<permissions::OAuthClientsManage>) -> ActorDto {
+        ActorDto {
+            user_id: admin.claims.sub.clone(),
+            role: admin.claims.role.clone(),
+            scope: admin.claims.scope.clone(),
+        }
+    }
+
+    /// クライアント認証情報（Basic認証またはフォーム、両方の指定は不可）
+    ///
+    /// クライアントIDとシークレットは記号を含まないため、Basic認証の値はURLデコードしない
+    fn client_auth(
+        headers: &HeaderMap,
+        client_id: Option<String>,
+        client_secret: Option<String>,
+    ) -> Result<ClientAuthDto, ApplicationError> {
+        let oauth_error = |error: &str, description: &str| ApplicationError::OAuthProtocol {
+            error: error.to_string(),
+            description: description.to_string(),
+        };
+        let basic = headers
+            .get(AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Basic "));
+        match (basic, client_id) {
+            (Some(_), Some(_)) => Err(oauth_error(
+                "invalid_request",
+                "use only one client authentication method",
+            )),
+            (Some(credentials), None) => {
+                let decoded = STANDARD
+                    .decode(credentials.trim())
+                    .ok()
+                    .and_then(|bytes| String::from_utf8(bytes).ok())
+                    .ok_or_else(|| oauth_error("invalid_client", "malformed Basic credentials"))?;
+                let (client_id, client_secret) = decoded
+                    .split_once(':')
+                    .ok_or_else(|| oauth_error("invalid_client", "malformed Basic credentials"))?;
+                Ok(ClientAuthDto {
+                    client_id: client_id.to_string(),
+                    client_secret: Some(client_secret.to_string()),
+                })
+            }
+            (None, Some(client_id)) => Ok(ClientAuthDto {
+                client_id,
+                client_secret: client_secret.filter(|secret| !secret.is_empty()),
+            }),
+            (None, None) => Err(oauth_error(
+                "invalid_client",
+                "client authentication required",
+            )),
+        }
+    }
+
+    /// `redirect_uri`にクエリを付けた302リダイレクト
+    fn redirect(
+        redirect_uri: &str,
+        params: &[(&str, Option<&String>)],
+        state: &Option<String>,
+    ) -> Response {
+        let query = params
+            .iter()
+            .copied()
+            .chain(std::iter::once(("state", state.as_ref())))
+            .filter_map(|(name, value)| value.map(|v| format!("{}={}", name, percent_encode(v))))
+            .collect::<Vec<_>>()
+            .join("&");
+        let separator = if redirect_uri.contains('?') { '&' } else { '?' };
+        (
+            StatusCode::FOUND,
+            [(LOCATION, format!("{}{}{}", redirect_uri, separator, query))],
+        )
+            .into_response()
+    }
+
+    /// トークンを含む応答はキャッシュさせない（RFC 6749 5.1）
+    fn no_store(body: impl IntoResponse) -> Response {
+        ([(CACHE_CONTROL, "no-store"), (PRAGMA, "no-cache")], body).into_response()
+    }
+
+    /// RFC 6749 5.2形式のエラーレスポンス
+    fn oauth_error_response(error: ApplicationError) -> Response {
+        match error {
+            ApplicationError::OAuthProtocol { error, description } => {
+                let body = Json(json!({
+                    "error": error,
+                    "error_description": description,
+                }));
+                if error == "invalid_client" {
+                    (
+                        StatusCode::UNAUTHORIZED,
+                        [(WWW_AUTHENTICATE, "Basic realm=\"oauth\"")],
+                        body,
+                    )
+                        .into_response()
+                } else {
+                    (StatusCode::BAD_REQUEST, body).into_response()
+                }
+            }
+            other => {
+                println!("OAuthController: {}", other);
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({
+                        "error": "server_error",
+                        "error_description": "An unexpected error occurred",
+                    })),
+                )
+                    .into_response()
+            }
+        }
+    }
+
+    fn ok<T>(data: T, message: &str) -> ApiResponse<T> {
+        ApiResponse {
+            success: true,
+            data: Some(data),
+            message: message.to_string(),
+            request_id: format!("req_{}", uuid::Uuid::new_v4()),
+            processing_time_ms: 0,
+        }
+    }
+
+    /// クライアント管理のApplicationエラーをHTTPレスポンスにマッピング
+    fn map_application_error(error: ApplicationError) -> ErrorResponse {
+        let (status, code, message) = match &error {
+            ApplicationError::UserNotFound { id } => (
+                StatusCode::NOT_FOUND,
+                "USER_NOT_FOUND",
+                format!("User with ID '{}' not found", id),
+            ),
+            ApplicationError::ResourceNotFound { resource, id } => (
+                StatusCode::NOT_FOUND,
+                "RESOURCE_NOT_FOUND",
+                format!("{} '{}' not found", resource, id),
+            ),
+            ApplicationError::ValidationFailed { field, message } => {
+                let (status, body) = AuthError::ValidationFailed {
+                    field: field.clone(),
+                    message: message.clone(),
+                }
+                .status_and_body();
+                return (status, Json(body));
+            }
+            other => {
+                println!("OAuthController: {}", other);
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "INTERNAL_SERVER_ERROR",
+                    "An unexpected error occurred".to_string(),
+                )
+            }
+        };
+        (
+            status,
+            Json(json!({
+                "success": false,
+                "error": {
+                    "code": code,
+                    "message": message,
+                }
+            })),
+        )
+    }
+}
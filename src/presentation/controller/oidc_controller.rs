@@ -0,0 +1,107 @@
+//presentation/controller/oidc_controller.rs
+// OpenID Connectエンドポイント（UserInfo・ディスカバリー）
+// 2025/7/8
+
+use crate::application::dto::auth_dto::ActorDto;
+use crate::application::usecases::oidc_usecase::OidcUsecaseInterface;
+use crate::presentation::dto::oidc_response::{OpenIdConfigurationResponse, UserInfoResponse};
+use crate::shared::error::application_error::ApplicationError;
+use crate::shared::middleware::auth_middleware::AuthenticatedUser;
+use axum::{
+    http::{
+        StatusCode,
+        header::{CACHE_CONTROL, WWW_AUTHENTICATE},
+    },
+    response::{IntoResponse, Json, Response},
+};
+use serde_json::json;
+use std::sync::Arc;
+
+/// OIDC Controller
+///
+/// 責務:
+/// 1. `/userinfo`・`/.well-known/openid-configuration`のHTTPリクエストの受信
+/// 2. UseCase実行
+/// 3. RFC 6750形式のエラーレスポンス生成（`WWW-Authenticate: Bearer`）
+pub struct OidcController {
+    oidc_usecase: Arc<dyn OidcUsecaseInterface>,
+}
+
+impl OidcController {
+    pub fn new(oidc_usecase: Arc<dyn OidcUsecaseInterface>) -> Self {
+        Self { oidc_usecase }
+    }
+
+    /// GET/POST /userinfo - アクセストークンの主体のクレーム（APIキーは不可）
+    pub async fn userinfo(&self, auth: AuthenticatedUser) -> Response {
+        let claims = match auth.require_session("oidc.userinfo") {
+            Ok(claims) => claims,
+            Err(error) => return error.into_response(),
+        };
+        let actor = ActorDto {
+            user_id: claims.sub,
+            role: claims.role,
+            scope: claims.scope,
+        };
+        match self.oidc_usecase.userinfo(actor).await {
+            Ok(userinfo) => (
+                [(CACHE_CONTROL, "no-store")],
+                Json(UserInfoResponse::from(userinfo)),
+            )
+                .into_response(),
+            Err(error) => Self::bearer_error_response(error),
+        }
+    }
+
+    /// GET /.well-known/openid-configuration - OpenID Providerのメタデータ
+    pub async fn openid_configuration(&self) -> Response {
+        (
+            [(CACHE_CONTROL, "public, max-age=300")],
+            Json(OpenIdConfigurationResponse::from(
+                self.oidc_usecase.configuration(),
+            )),
+        )
+            .into_response()
+    }
+
+    /// RFC 6750 3.1形式のエラーレスポンス
+    fn bearer_error_response(error: ApplicationError) -> Response {
+        let (status, code, description) = match error {
+            ApplicationError::AuthorizationFailed { message } => {
+                (StatusCode::FORBIDDEN, "insufficient_scope", message)
+            }
+            // トークン発行後にユーザーが削除された
+            ApplicationError::UserNotFound { .. } => (
+                StatusCode::UNAUTHORIZED,
+                "invalid_token",
+                "the resource owner no longer exists".to_string(),
+            ),
+            other => {
+                println!("OidcController: {}", other);
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({
+                        "error": "server_error",
+                        "error_description": "An unexpected error occurred",
+                    })),
+                )
+                    .into_response();
+            }
+        };
+        (
+            status,
+            [(
+                WWW_AUTHENTICATE,
+                format!(
+                    "Bearer error=\"{}\", error_description=\"{}\"",
+                    code, description
+                ),
+            )],
+            Json(json!({
+                "error": code,
+                "error_description": description,
+            })),
+        )
+            .into_response()
+    }
+}
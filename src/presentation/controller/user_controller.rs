@@ -30,8 +30,8 @@
 // 正しい配置: presentation/controller/user_controller.rs
 // =============================================================================
 
+use crate::application::dto::auth_dto::ActorDto;
 use crate::application::dto::user_request_dto::{CreateUserRequestDto, UpdateUserRequestDto};
-use crate::application::dto::user_response_dto::UserResponseDto;
 use crate::application::usecases::create_user_usecase::CreateUserUsecaseInterface;
 use crate::application::usecases::delete_user_usecase::DeleteUserUsecaseInterface;
 use crate::application::usecases::get_user_usecase::GetUserQueryUsecaseInterface;
@@ -41,12 +41,9 @@ use crate::presentation::dto::create_user_request::CreateUserRequest;
 use crate::presentation::dto::update_user_request::UpdateUserRequest;
 use crate::presentation::dto::user_response::UserResponse;
 use crate::shared::error::application_error::ApplicationError;
-use axum::{
-    Json as JsonRequest,
-    extract::{Path, State},
-    http::StatusCode,
-    response::Json,
-};
+use crate::shared::error::domain_error::DomainError;
+use crate::shared::middleware::auth_middleware::{AuthError, AuthenticatedUser};
+use axum::{Json as JsonRequest, extract::Path, http::StatusCode, response::Json};
 use serde_json::{Value, json};
 use std::sync::Arc;
 
@@ -95,7 +92,7 @@ where
     /// POST /api/users - ユーザー作成
     pub async fn create_user(
         &self,
-        _auth: crate::shared::middleware::auth_middleware::AuthenticatedUser,
+        _auth: AuthenticatedUser,
         JsonRequest(request): JsonRequest<CreateUserRequest>,
     ) -> Result<(StatusCode, Json<ApiResponse<UserResponse>>), (StatusCode, Json<Value>)> {
         // 1. プレゼンテーション層でのバリデーション
@@ -210,7 +207,7 @@ where
     /// PUT /api/users/{id} - ユーザー更新
     pub async fn update_user(
         &self,
-        _auth: crate::shared::middleware::auth_middleware::AuthenticatedUser,
+        AuthenticatedUser(claims): AuthenticatedUser,
         Path(user_id): Path<String>,
         JsonRequest(request): JsonRequest<UpdateUserRequest>,
     ) -> Result<(StatusCode, Json<ApiResponse<UserResponse>>), (StatusCode, Json<Value>)> {
@@ -251,7 +248,12 @@ where
         };
 
         // 4. UseCase実行
-        match self.update_user_usecase.execute(app_request).await {
+        let actor = ActorDto {
+            user_id: claims.sub,
+            role: claims.role,
+            scope: claims.scope,
+        };
+        match self.update_user_usecase.execute(actor, app_request).await {
             Ok(app_response) => {
                 // 5. Application DTO → Presentation DTO 変換
                 let presentation_response = UserResponse {
@@ -288,7 +290,7 @@ where
     /// DELETE /api/users/{id} - ユーザー削除
     pub async fn delete_user(
         &self,
-        _auth: crate::shared::middleware::auth_middleware::AuthenticatedUser,
+        AuthenticatedUser(claims): AuthenticatedUser,
         Path(user_id): Path<String>,
     ) -> Result<(StatusCode, Json<ApiResponse<UserResponse>>), (StatusCode, Json<Value>)> {
         // 1. UUID形式チェック
@@ -310,7 +312,12 @@ where
             crate::application::dto::user_request_dto::DeleteUserRequestDto { id: user_id };
 
         // 3. UseCase実行
-        match self.delete_user_usecase.execute(app_request).await {
+        let actor = ActorDto {
+            user_id: claims.sub,
+            role: claims.role,
+            scope: claims.scope,
+        };
+        match self.delete_user_usecase.execute(actor, app_request).await {
             Ok(app_response) => {
                 // 4. Application DTO → Presentation DTO 変換
                 let presentation_response = UserResponse {
@@ -353,8 +360,9 @@ where
         if request.name.is_empty() {
             return Err("Name is required".to_string());
         }
-        if request.password.len() < 8 {
-            return Err("Password must be at least 8 characters".to_string());
+        // 長さ・文字種などはUseCaseのパスワードポリシーで検証する
+        if request.password.is_empty() {
+            return Err("Password is required".to_string());
         }
         Ok(())
     }
@@ -400,6 +408,9 @@ where
                     }
                 }),
             ),
+            ApplicationError::Domain(DomainError::PasswordPolicyViolation { violations }) => {
+                AuthError::PasswordPolicyViolation { violations }.status_and_body()
+            }
             ApplicationError::Domain(domain_error) => (
                 StatusCode::BAD_REQUEST,
                 json!({
@@ -430,21 +441,72 @@ where
                     }
                 }),
             ),
-            ApplicationError::AuthorizationFailed { message } => (
-                StatusCode::FORBIDDEN,
+            ApplicationError::InvalidCredentials => (
+                StatusCode::UNAUTHORIZED,
+                json!({
+                    "success": false,
+                    "error": {
+                        "code": "INVALID_CREDENTIALS",
+                        "message": "Invalid credentials",
+                        "details": {
+                            "layer": "application",
+                            "operation": "authentication",
+                            "timestamp": chrono::Utc::now().to_rfc3339()
+                        }
+                    }
+                }),
+            ),
+            ApplicationError::InvalidToken => (
+                StatusCode::UNAUTHORIZED,
+                json!({
+                    "success": false,
+                    "error": {
+                        "code": "INVALID_TOKEN",
+                        "message": "Invalid or revoked token",
+                        "details": {
+                            "layer": "application",
+                            "operation": "authentication",
+                            "timestamp": chrono::Utc::now().to_rfc3339()
+                        }
+                    }
+                }),
+            ),
+            ApplicationError::TooManyAttempts { retry_after_secs } => (
+                StatusCode::TOO_MANY_REQUESTS,
+                json!({
+                    "success": false,
+                    "error": {
+                        "code": "TOO_MANY_ATTEMPTS",
+                        "message": format!("Too many attempts, retry after {}s", retry_after_secs),
+                        "details": {
+                            "layer": "application",
+                            "operation": "throttling",
+                            "timestamp": chrono::Utc::now().to_rfc3339()
+                        }
+                    }
+                }),
+            ),
+            ApplicationError::ResourceNotFound { resource, id } => (
+                StatusCode::NOT_FOUND,
                 json!({
                     "success": false,
                     "error": {
-                        "code": "AUTHORIZATION_FAILED",
-                        "message": format!("Authorization failed: {}", message),
+                        "code": "RESOURCE_NOT_FOUND",
+                        "message": format!("{} '{}' not found", resource, id),
                         "details": {
                             "layer": "application",
-                            "operation": "authorization",
+                            "operation": "lookup",
                             "timestamp": chrono::Utc::now().to_rfc3339()
                         }
                     }
                 }),
             ),
+            ApplicationError::EmailNotVerified => AuthError::EmailNotVerified.status_and_body(),
+            // 認可エラーは認証Extractorの権限不足と同じ形式で返す
+            ApplicationError::AuthorizationFailed { message } => {
+                println!("UserController: Authorization failed: {}", message);
+                AuthError::InsufficientPermissions.status_and_body()
+            }
             ApplicationError::OperationNotPermitted { operation, reason } => (
                 StatusCode::FORBIDDEN,
                 json!({
@@ -460,6 +522,13 @@ where
                     }
                 }),
             ),
+            ApplicationError::OAuthProtocol { error, description } => (
+                StatusCode::BAD_REQUEST,
+                json!({
+                    "error": error,
+                    "error_description": description,
+                }),
+            ),
             ApplicationError::ValidationFailed { field, message } => (
                 StatusCode::BAD_REQUEST,
                 json!({
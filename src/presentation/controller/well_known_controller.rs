@@ -0,0 +1,13 @@
+//presentation/controller/well_known_controller.rs
+// /.well-known 配下の公開メタデータ
+// 2025/7/8
+use crate::shared::middleware::auth_middleware::JWT_CONFIG;
+use axum::{Json, http::header, response::IntoResponse};
+
+/// GET /.well-known/jwks.json - トークン検証用の公開鍵（JWK Set）
+pub async fn get_jwks() -> impl IntoResponse {
+    (
+        [(header::CACHE_CONTROL, "public, max-age=300")],
+        Json(JWT_CONFIG.jwks()),
+    )
+}
@@ -0,0 +1,26 @@
+//presentation/dto/api_key_request.rs
+// APIキーのリクエストDTO
+// 2025/7/8
+
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct CreateApiKeyRequest {
+    /// 所有者（省略時は自分、他人・サービスアカウントの場合は`api_keys:manage`が必要）
+    pub owner_id: Option<String>,
+    pub name: String,
+    /// `users:read`などの権限名
+    pub scopes: Vec<String>,
+    pub expires_in_days: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateApiKeyRequest {
+    pub name: Option<String>,
+    pub scopes: Option<Vec<String>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ApiKeyListQuery {
+    pub owner_id: Option<String>,
+}
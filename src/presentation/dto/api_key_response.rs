@@ -0,0 +1,52 @@
+//presentation/dto/api_key_response.rs
+// APIキーのレスポンスDTO
+// 2025/7/8
+
+use crate::application::dto::api_key_dto::{ApiKeyDto, CreatedApiKeyDto};
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct ApiKeyResponse {
+    pub id: String,
+    pub owner_id: String,
+    pub name: String,
+    pub prefix: String,
+    pub scopes: Vec<String>,
+    pub expires_at: Option<String>,
+    pub last_used_at: Option<String>,
+    pub created_at: String,
+    pub created_by: String,
+}
+
+impl From<ApiKeyDto> for ApiKeyResponse {
+    fn from(dto: ApiKeyDto) -> Self {
+        Self {
+            id: dto.id,
+            owner_id: dto.owner_id,
+            name: dto.name,
+            prefix: dto.prefix,
+            scopes: dto.scopes,
+            expires_at: dto.expires_at,
+            last_used_at: dto.last_used_at,
+            created_at: dto.created_at,
+            created_by: dto.created_by,
+        }
+    }
+}
+
+/// 作成したAPIキー（`key`は再表示できない）
+#[derive(Debug, Serialize)]
+pub struct CreatedApiKeyResponse {
+    pub key: String,
+    #[serde(flatten)]
+    pub api_key: ApiKeyResponse,
+}
+
+impl From<CreatedApiKeyDto> for CreatedApiKeyResponse {
+    fn from(dto: CreatedApiKeyDto) -> Self {
+        Self {
+            key: dto.key,
+            api_key: dto.api_key.into(),
+        }
+    }
+}
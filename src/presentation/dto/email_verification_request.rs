@@ -0,0 +1,15 @@
+//presentation/dto/email_verification_request.rs
+// メールアドレス確認のリクエストDTO
+// 2025/7/8
+
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct VerifyEmailQuery {
+    pub token: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ResendVerificationRequest {
+    pub email: String,
+}
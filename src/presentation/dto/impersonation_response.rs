@@ -0,0 +1,34 @@
+//presentation/dto/impersonation_response.rs
+// なりすましトークンのレスポンスDTO
+// 2025/7/8
+
+use crate::application::dto::auth_dto::ImpersonationTokenDto;
+use crate::presentation::controller::auth_controller::UserInfo;
+use serde::Serialize;
+
+/// なりすましトークンのレスポンス（リフレッシュトークンは含まない）
+#[derive(Debug, Serialize)]
+pub struct ImpersonationResponse {
+    pub access_token: String,
+    pub token_type: String,
+    pub expires_in: i64,
+    pub user: UserInfo,
+    pub impersonator_id: String,
+}
+
+impl From<ImpersonationTokenDto> for ImpersonationResponse {
+    fn from(dto: ImpersonationTokenDto) -> Self {
+        Self {
+            access_token: dto.access_token,
+            token_type: dto.token_type,
+            expires_in: dto.expires_in,
+            user: UserInfo {
+                id: dto.user.id,
+                email: dto.user.email,
+                name: dto.user.name,
+                role: dto.user.role,
+            },
+            impersonator_id: dto.impersonator_id,
+        }
+    }
+}
@@ -0,0 +1,10 @@
+//presentation/dto/lockout_response.rs
+// ログイン試行制限のレスポンスDTO
+// 2025/7/8
+
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct AccountUnlockResponse {
+    pub unlocked: bool,
+}
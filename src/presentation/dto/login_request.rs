@@ -3,6 +3,8 @@ use serde::Deserialize;
 
 #[derive(Debug, Deserialize)]
 pub struct LoginRequest {
-    pub username: String,
+    // 旧クライアント互換のため`username`も受け付ける
+    #[serde(alias = "username")]
+    pub email: String,
     pub password: String,
 }
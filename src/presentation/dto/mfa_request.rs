@@ -0,0 +1,11 @@
+//presentation/dto/mfa_request.rs
+// 多要素認証のリクエストDTO
+// 2025/7/8
+
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct MfaCodeRequest {
+    /// TOTPコード（6桁）またはリカバリーコード
+    pub code: String,
+}
@@ -0,0 +1,48 @@
+//presentation/dto/mfa_response.rs
+// 多要素認証のレスポンスDTO
+// 2025/7/8
+
+use crate::application::dto::auth_dto::{MfaChallengeDto, MfaEnrollmentDto};
+use serde::Serialize;
+
+/// パスワード確認後、二要素認証が必要な場合のログイン応答
+#[derive(Debug, Serialize)]
+pub struct MfaChallengeResponse {
+    pub mfa_required: bool,
+    pub mfa_token: String,
+    pub token_type: String,
+    pub expires_in: i64,
+    pub enrollment_required: bool,
+}
+
+impl From<MfaChallengeDto> for MfaChallengeResponse {
+    fn from(dto: MfaChallengeDto) -> Self {
+        Self {
+            mfa_required: true,
+            mfa_token: dto.mfa_token,
+            token_type: "Bearer".to_string(),
+            expires_in: dto.expires_in,
+            enrollment_required: dto.enrollment_required,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct MfaEnrollmentResponse {
+    pub secret: String,
+    pub provisioning_uri: String,
+}
+
+impl From<MfaEnrollmentDto> for MfaEnrollmentResponse {
+    fn from(dto: MfaEnrollmentDto) -> Self {
+        Self {
+            secret: dto.secret,
+            provisioning_uri: dto.provisioning_uri,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct RecoveryCodesResponse {
+    pub recovery_codes: Vec<String>,
+}
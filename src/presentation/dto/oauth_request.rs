@@ -0,0 +1,65 @@
+//presentation/dto/oauth_request.rs
+// OAuth2認可サーバーのリクエストDTO
+// 2025/7/8
+
+use serde::Deserialize;
+
+/// GET /oauth/authorize のクエリ
+#[derive(Debug, Deserialize)]
+pub struct AuthorizeQuery {
+    #[serde(default)]
+    pub response_type: String,
+    pub client_id: String,
+    pub redirect_uri: String,
+    pub scope: Option<String>,
+    /// リダイレクト時にそのまま返す値
+    pub state: Option<String>,
+    pub code_challenge: Option<String>,
+    pub code_challenge_method: Option<String>,
+    /// OpenID Connectの`nonce`（IDトークンにそのまま入る）
+    pub nonce: Option<String>,
+}
+
+/// POST /oauth/token のフォーム（`client_id`・`client_secret`はBasic認証の代わり）
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct TokenForm {
+    pub grant_type: String,
+    pub code: Option<String>,
+    pub redirect_uri: Option<String>,
+    pub code_verifier: Option<String>,
+    pub refresh_token: Option<String>,
+    pub scope: Option<String>,
+    pub client_id: Option<String>,
+    pub client_secret: Option<String>,
+}
+
+/// POST /oauth/introspect・/oauth/revoke のフォーム
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct TokenActionForm {
+    pub token: String,
+    /// 受け付けるが判定には使わない（トークン自体に種別が含まれるため）
+    pub token_type_hint: Option<String>,
+    pub client_id: Option<String>,
+    pub client_secret: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RegisterOAuthClientRequest {
+    pub name: String,
+    #[serde(default)]
+    pub redirect_uris: Vec<String>,
+    /// `authorization_code` / `client_credentials` / `refresh_token`
+    pub grant_types: Vec<String>,
+    /// `users:read`などの権限名
+    pub scopes: Vec<String>,
+    /// falseの場合はシークレットを発行しない公開クライアント（既定はtrue）
+    #[serde(default = "default_confidential")]
+    pub confidential: bool,
+    pub service_account_id: Option<String>,
+}
+
+fn default_confidential() -> bool {
+    true
+}
@@ -0,0 +1,119 @@
+//presentation/dto/oauth_response.rs
+// OAuth2認可サーバーのレスポンスDTO
+// 2025/7/8
+
+use crate::application::dto::oauth_dto::{
+    OAuthClientDto, OAuthTokenDto, RegisteredOAuthClientDto, TokenIntrospectionDto,
+};
+use serde::Serialize;
+
+/// トークンレスポンス（RFC 6749 5.1、`ApiResponse`で包まない）
+#[derive(Debug, Serialize)]
+pub struct OAuthTokenResponse {
+    pub access_token: String,
+    pub token_type: String,
+    pub expires_in: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<String>,
+    pub scope: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id_token: Option<String>,
+}
+
+impl From<OAuthTokenDto> for OAuthTokenResponse {
+    fn from(dto: OAuthTokenDto) -> Self {
+        Self {
+            access_token: dto.access_token,
+            token_type: dto.token_type,
+            expires_in: dto.expires_in,
+            refresh_token: dto.refresh_token,
+            scope: dto.scope,
+            id_token: dto.id_token,
+        }
+    }
+}
+
+/// イントロスペクションのレスポンス（RFC 7662 2.2）
+#[derive(Debug, Serialize)]
+pub struct TokenIntrospectionResponse {
+    pub active: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exp: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iat: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sub: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jti: Option<String>,
+}
+
+impl From<TokenIntrospectionDto> for TokenIntrospectionResponse {
+    fn from(dto: TokenIntrospectionDto) -> Self {
+        Self {
+            active: dto.active,
+            scope: dto.scope,
+            client_id: dto.client_id,
+            username: dto.username,
+            token_type: dto.token_type,
+            exp: dto.exp,
+            iat: dto.iat,
+            sub: dto.sub,
+            jti: dto.jti,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct OAuthClientResponse {
+    pub client_id: String,
+    pub name: String,
+    pub confidential: bool,
+    pub redirect_uris: Vec<String>,
+    pub grant_types: Vec<String>,
+    pub scopes: Vec<String>,
+    pub service_account_id: Option<String>,
+    pub created_at: String,
+    pub created_by: String,
+}
+
+impl From<OAuthClientDto> for OAuthClientResponse {
+    fn from(dto: OAuthClientDto) -> Self {
+        Self {
+            client_id: dto.client_id,
+            name: dto.name,
+            confidential: dto.confidential,
+            redirect_uris: dto.redirect_uris,
+            grant_types: dto.grant_types,
+            scopes: dto.scopes,
+            service_account_id: dto.service_account_id,
+            created_at: dto.created_at,
+            created_by: dto.created_by,
+        }
+    }
+}
+
+/// 登録したクライアント（`client_secret`は再表示できない）
+#[derive(Debug, Serialize)]
+pub struct RegisteredOAuthClientResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_secret: Option<String>,
+    #[serde(flatten)]
+    pub client: OAuthClientResponse,
+}
+
+impl From<RegisteredOAuthClientDto> for RegisteredOAuthClientResponse {
+    fn from(dto: RegisteredOAuthClientDto) -> Self {
+        Self {
+            client_secret: dto.client_secret,
+            client: dto.client.into(),
+        }
+    }
+}
@@ -0,0 +1,77 @@
+//presentation/dto/oidc_response.rs
+// OpenID Connect（ディスカバリ・JWKS・UserInfo）のレスポンスDTO
+// 2025/7/8
+
+use crate::application::dto::oidc_dto::{OpenIdConfigurationDto, UserInfoDto};
+use serde::Serialize;
+
+/// UserInfoレスポンス（OpenID Connect Core 5.3.2、`ApiResponse`で包まない）
+#[derive(Debug, Serialize)]
+pub struct UserInfoResponse {
+    pub sub: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_verified: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub birthdate: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<String>,
+}
+
+impl From<UserInfoDto> for UserInfoResponse {
+    fn from(dto: UserInfoDto) -> Self {
+        Self {
+            sub: dto.sub,
+            email: dto.email,
+            email_verified: dto.email_verified,
+            name: dto.name,
+            birthdate: dto.birthdate,
+            phone_number: dto.phone_number,
+        }
+    }
+}
+
+/// ディスカバリーのレスポンス（OpenID Connect Discovery 3）
+#[derive(Debug, Serialize)]
+pub struct OpenIdConfigurationResponse {
+    pub issuer: String,
+    pub authorization_endpoint: String,
+    pub token_endpoint: String,
+    pub userinfo_endpoint: String,
+    pub jwks_uri: String,
+    pub introspection_endpoint: String,
+    pub revocation_endpoint: String,
+    pub scopes_supported: Vec<String>,
+    pub response_types_supported: Vec<String>,
+    pub grant_types_supported: Vec<String>,
+    pub subject_types_supported: Vec<String>,
+    pub id_token_signing_alg_values_supported: Vec<String>,
+    pub token_endpoint_auth_methods_supported: Vec<String>,
+    pub code_challenge_methods_supported: Vec<String>,
+    pub claims_supported: Vec<String>,
+}
+
+impl From<OpenIdConfigurationDto> for OpenIdConfigurationResponse {
+    fn from(dto: OpenIdConfigurationDto) -> Self {
+        Self {
+            issuer: dto.issuer,
+            authorization_endpoint: dto.authorization_endpoint,
+            token_endpoint: dto.token_endpoint,
+            userinfo_endpoint: dto.userinfo_endpoint,
+            jwks_uri: dto.jwks_uri,
+            introspection_endpoint: dto.introspection_endpoint,
+            revocation_endpoint: dto.revocation_endpoint,
+            scopes_supported: dto.scopes_supported,
+            response_types_supported: dto.response_types_supported,
+            grant_types_supported: dto.grant_types_supported,
+            subject_types_supported: dto.subject_types_supported,
+            id_token_signing_alg_values_supported: dto.id_token_signing_alg_values_supported,
+            token_endpoint_auth_methods_supported: dto.token_endpoint_auth_methods_supported,
+            code_challenge_methods_supported: dto.code_challenge_methods_supported,
+            claims_supported: dto.claims_supported,
+        }
+    }
+}
@@ -0,0 +1,16 @@
+//presentation/dto/password_reset_request.rs
+// パスワードリセットのリクエストDTO
+// 2025/7/8
+
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct PasswordResetRequest {
+    pub email: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PasswordResetConfirmRequest {
+    pub token: String,
+    pub new_password: String,
+}
@@ -0,0 +1,12 @@
+//presentation/dto/refresh_token_request.rs
+// リフレッシュトークンのリクエストDTO
+// 2025/7/8
+
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct RefreshTokenRequest {
+    // Cookieセッションでは省略し、リフレッシュトークンのCookieを使う
+    #[serde(default)]
+    pub refresh_token: Option<String>,
+}
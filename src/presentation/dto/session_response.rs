@@ -0,0 +1,10 @@
+//presentation/dto/session_response.rs
+// セッション一覧のレスポンスDTO
+// 2025/7/8
+
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct SessionRevocationResponse {
+    pub revoked_sessions: usize,
+}
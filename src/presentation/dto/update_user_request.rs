@@ -44,7 +44,7 @@ impl UpdateUserRequest {
                 return Err("Phone number cannot be empty".to_string());
             }
             // 簡単な電話番号形式チェック
-            if !phone.chars().any(|c| c.is_digit(10)) {
+            if !phone.chars().any(|c| c.is_ascii_digit()) {
                 return Err("Phone number must contain digits".to_string());
             }
         }
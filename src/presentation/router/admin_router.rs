@@ -0,0 +1,46 @@
+//presentation/router/admin_router.rs
+// 管理者用ルーティング
+// 2025/7/8
+
+use crate::presentation::controller::admin_controller::AdminController;
+use crate::shared::middleware::auth_middleware::{RequirePermission, SuperAdminUser, permissions};
+use axum::{
+    Router,
+    routing::{delete, post},
+};
+use std::sync::Arc;
+
+/// 管理者用のルーティング設定（認可は各ハンドラのRequirePermission・SuperAdminUserで行う）
+pub fn create_admin_routes(controller: Arc<AdminController>) -> Router {
+    Router::new()
+        .route(
+            "/admin/users/:id/sessions",
+            delete({
+                let controller = controller.clone();
+                move |admin: RequirePermission<permissions::SessionsRevoke>, path| {
+                    let controller = controller.clone();
+                    async move { controller.revoke_user_sessions(admin, path).await }
+                }
+            }),
+        )
+        .route(
+            "/admin/users/:id/lockout",
+            delete({
+                let controller = controller.clone();
+                move |admin: RequirePermission<permissions::UsersWrite>, path| {
+                    let controller = controller.clone();
+                    async move { controller.unlock_user(admin, path).await }
+                }
+            }),
+        )
+        .route(
+            "/admin/users/:id/impersonate",
+            post({
+                let controller = controller.clone();
+                move |superadmin: SuperAdminUser, path| {
+                    let controller = controller.clone();
+                    async move { controller.impersonate_user(superadmin, path).await }
+                }
+            }),
+        )
+}
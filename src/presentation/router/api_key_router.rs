@@ -0,0 +1,54 @@
+//presentation/router/api_key_router.rs
+// APIキー管理ルーティング
+// 2025/7/8
+
+use crate::presentation::controller::api_key_controller::ApiKeyController;
+use crate::shared::middleware::auth_middleware::AuthenticatedUser;
+use axum::{Router, routing::get};
+use std::sync::Arc;
+
+/// APIキー管理のルーティング設定（所有者の確認はユースケースで行う）
+pub fn create_api_key_routes(controller: Arc<ApiKeyController>) -> Router {
+    Router::new()
+        .route(
+            "/api-keys",
+            get({
+                let controller = controller.clone();
+                move |auth: AuthenticatedUser, query| {
+                    let controller = controller.clone();
+                    async move { controller.list_api_keys(auth, query).await }
+                }
+            })
+            .post({
+                let controller = controller.clone();
+                move |auth: AuthenticatedUser, request| {
+                    let controller = controller.clone();
+                    async move { controller.create_api_key(auth, request).await }
+                }
+            }),
+        )
+        .route(
+            "/api-keys/:id",
+            get({
+                let controller = controller.clone();
+                move |auth: AuthenticatedUser, path| {
+                    let controller = controller.clone();
+                    async move { controller.get_api_key(auth, path).await }
+                }
+            })
+            .patch({
+                let controller = controller.clone();
+                move |auth: AuthenticatedUser, path, request| {
+                    let controller = controller.clone();
+                    async move { controller.update_api_key(auth, path, request).await }
+                }
+            })
+            .delete({
+                let controller = controller.clone();
+                move |auth: AuthenticatedUser, path| {
+                    let controller = controller.clone();
+                    async move { controller.delete_api_key(auth, path).await }
+                }
+            }),
+        )
+}
@@ -7,30 +7,53 @@ use crate::application::usecases::delete_user_usecase::DeleteUserUsecaseInterfac
 use crate::application::usecases::get_user_usecase::GetUserQueryUsecaseInterface;
 use crate::application::usecases::update_user_usecase::UpdateUserUsecaseInterface;
 use crate::infrastructure::config::app_config::DiscordConfig;
+use crate::presentation::controller::admin_controller::AdminController;
+use crate::presentation::controller::api_key_controller::ApiKeyController;
+use crate::presentation::controller::auth_controller::AuthController;
+use crate::presentation::controller::oauth_controller::OAuthController;
+use crate::presentation::controller::oidc_controller::OidcController;
 use crate::presentation::controller::user_controller::UserController;
+use crate::presentation::router::admin_router::create_admin_routes;
+use crate::presentation::router::api_key_router::create_api_key_routes;
 use crate::presentation::router::auth_router::create_auth_routes;
 use crate::presentation::router::fortune_router::create_fortune_routes;
 use crate::presentation::router::grpc_router::create_grpc_routes;
+use crate::presentation::router::health_router::create_health_routes;
+use crate::presentation::router::metrics_router::create_metrics_routes;
+use crate::presentation::router::oauth_router::{create_oauth_client_routes, create_oauth_routes};
+use crate::presentation::router::oidc_router::create_oidc_routes;
 use crate::presentation::router::user_router::create_user_routes;
+use crate::presentation::router::well_known_router::create_well_known_routes;
+use crate::shared::metrics::collector::MetricsCollector;
+use crate::shared::middleware::auth_middleware::AuthServices;
 use crate::shared::middleware::cors_middleware::build_cors_layer;
-use crate::shared::middleware::discord_middleware::{
-    discord_notification_middleware, try_notify_startup,
-};
+use crate::shared::middleware::discord_middleware::discord_notification_middleware;
 use crate::shared::middleware::security_headers_middleware::security_headers_middleware;
 use crate::shared::middleware::watch_middleware;
-use axum::{Json, Router, middleware, routing::get};
+use axum::{Extension, Router, middleware};
 use std::sync::Arc;
 
 /// メインアプリケーションルーター
 ///
 /// 責務:
 /// 1. 全ルーターの統合
-/// 2. ヘルスチェックエンドポイント
-/// 3. APIプレフィックスの設定
-/// 4. ログ・メトリクス収集ミドルウェア
+/// 2. APIプレフィックスの設定
+/// 3. ログ収集ミドルウェア
+/// 4. 認証Extractor用サービスの登録
+///
+/// ヘルスチェック・メトリクスは公開用のポートに出さないよう、
+/// `create_admin_listener_router`で管理用リスナーにのみ登録する
+#[allow(clippy::too_many_arguments)]
 pub fn create_app_router<T, U, V, W>(
     user_controller: Arc<UserController<T, U, V, W>>,
+    auth_controller: Arc<AuthController>,
+    admin_controller: Arc<AdminController>,
+    api_key_controller: Arc<ApiKeyController>,
+    oauth_controller: Arc<OAuthController>,
+    oidc_controller: Arc<OidcController>,
+    auth_services: AuthServices,
     discord_config: Arc<DiscordConfig>,
+    log_dir: Arc<str>,
 ) -> Router
 where
     T: CreateUserUsecaseInterface + Send + Sync + 'static,
@@ -42,26 +65,33 @@ where
     // tokio::spawn(try_notify_startup(discord_config.clone()));
 
     Router::new()
-        .route("/health", get(|| async { "OK" }))
-        .route(
-            "/api/health",
-            get(|| async {
-                Json(serde_json::json!({
-                    "status": "ok",
-                    "message": "API is running",
-                    "timestamp": chrono::Utc::now().to_rfc3339()
-                }))
-            }),
-        )
+        .merge(create_well_known_routes())
+        .merge(create_oauth_routes(oauth_controller.clone()))
+        .merge(create_oidc_routes(oidc_controller))
         .nest("/api", create_user_routes(user_controller))
-        .nest("/api", create_auth_routes())
+        .nest("/api", create_auth_routes(auth_controller))
+        .nest("/api", create_admin_routes(admin_controller))
+        .nest("/api", create_api_key_routes(api_key_controller))
+        .nest("/api", create_oauth_client_routes(oauth_controller))
         .nest("/api", create_fortune_routes())
         .nest("/api", create_grpc_routes())
+        .layer(Extension(auth_services))
         .layer(build_cors_layer())
-        .layer(middleware::from_fn(watch_middleware::watch_middleware))
+        .layer(middleware::from_fn_with_state(
+            log_dir,
+            watch_middleware::watch_middleware,
+        ))
         .layer(middleware::from_fn_with_state(
             discord_config,
             discord_notification_middleware,
         ))
         .layer(middleware::from_fn(security_headers_middleware))
 }
+
+/// 管理用リスナーのルーター（ヘルスチェック・メトリクス）
+pub fn create_admin_listener_router(metrics: Arc<MetricsCollector>) -> Router {
+    Router::new()
+        .merge(create_health_routes())
+        .merge(create_metrics_routes(metrics))
+        .layer(middleware::from_fn(security_headers_middleware))
+}
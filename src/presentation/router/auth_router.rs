@@ -1,7 +1,155 @@
 //presentation/router/auth_router.rs
-use crate::presentation::controller::auth_controller;
-use axum::{Router, routing::post};
+use crate::presentation::controller::auth_controller::AuthController;
+use crate::shared::middleware::auth_middleware::{
+    AuthenticatedUser, MfaEnrollmentUser, MfaPendingUser,
+};
+use crate::shared::middleware::client_ip_middleware::ClientIp;
+use crate::shared::middleware::session_cookie_middleware::SessionMode;
+use axum::{
+    Router,
+    http::HeaderMap,
+    routing::{get, post},
+};
+use std::sync::Arc;
 
-pub fn create_auth_routes() -> Router {
-    Router::new().route("/auth/login", post(auth_controller::login))
+pub fn create_auth_routes(controller: Arc<AuthController>) -> Router {
+    Router::new()
+        .route(
+            "/auth/login",
+            post({
+                let controller = controller.clone();
+                move |mode: SessionMode, client_ip: ClientIp, request| {
+                    let controller = controller.clone();
+                    async move { controller.login(mode, client_ip, request).await }
+                }
+            }),
+        )
+        .route(
+            "/auth/login/mfa",
+            post({
+                let controller = controller.clone();
+                move |mode: SessionMode, auth: MfaPendingUser, client_ip: ClientIp, request| {
+                    let controller = controller.clone();
+                    async move {
+                        controller
+                            .verify_mfa_login(mode, auth, client_ip, request)
+                            .await
+                    }
+                }
+            }),
+        )
+        .route(
+            "/auth/mfa/enroll",
+            post({
+                let controller = controller.clone();
+                move |auth: MfaEnrollmentUser| {
+                    let controller = controller.clone();
+                    async move { controller.enroll_mfa(auth).await }
+                }
+            }),
+        )
+        .route(
+            "/auth/mfa/enroll/confirm",
+            post({
+                let controller = controller.clone();
+                move |mode: SessionMode, auth: MfaEnrollmentUser, client_ip: ClientIp, request| {
+                    let controller = controller.clone();
+                    async move {
+                        controller
+                            .confirm_mfa_enrollment(mode, auth, client_ip, request)
+                            .await
+                    }
+                }
+            }),
+        )
+        .route(
+            "/auth/mfa/disable",
+            post({
+                let controller = controller.clone();
+                move |auth: AuthenticatedUser, request| {
+                    let controller = controller.clone();
+                    async move { controller.disable_mfa(auth, request).await }
+                }
+            }),
+        )
+        .route(
+            "/auth/mfa/recovery-codes",
+            post({
+                let controller = controller.clone();
+                move |auth: AuthenticatedUser, request| {
+                    let controller = controller.clone();
+                    async move { controller.regenerate_recovery_codes(auth, request).await }
+                }
+            }),
+        )
+        .route(
+            "/auth/refresh",
+            post({
+                let controller = controller.clone();
+                move |mode: SessionMode, headers: HeaderMap, request| {
+                    let controller = controller.clone();
+                    async move { controller.refresh(mode, headers, request).await }
+                }
+            }),
+        )
+        .route(
+            "/auth/password-reset/request",
+            post({
+                let controller = controller.clone();
+                move |client_ip: ClientIp, request| {
+                    let controller = controller.clone();
+                    async move { controller.request_password_reset(client_ip, request).await }
+                }
+            }),
+        )
+        .route(
+            "/auth/password-reset/confirm",
+            post({
+                let controller = controller.clone();
+                move |request| {
+                    let controller = controller.clone();
+                    async move { controller.confirm_password_reset(request).await }
+                }
+            }),
+        )
+        .route(
+            "/auth/verify-email",
+            get({
+                let controller = controller.clone();
+                move |query| {
+                    let controller = controller.clone();
+                    async move { controller.verify_email(query).await }
+                }
+            }),
+        )
+        .route(
+            "/auth/verify-email/resend",
+            post({
+                let controller = controller.clone();
+                move |request| {
+                    let controller = controller.clone();
+                    async move { controller.resend_verification_email(request).await }
+                }
+            }),
+        )
+        .route(
+            "/auth/logout",
+            post({
+                let controller = controller.clone();
+                move |mode: SessionMode, auth: AuthenticatedUser| {
+                    let controller = controller.clone();
+                    async move { controller.logout(mode, auth).await }
+                }
+            }),
+        )
+        .route(
+            "/auth/logout-all",
+            post({
+                let controller = controller.clone();
+                move |mode: SessionMode, auth: AuthenticatedUser| {
+                    let controller = controller.clone();
+                    async move { controller.logout_all(mode, auth).await }
+                }
+            }),
+        )
 }
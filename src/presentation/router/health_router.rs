@@ -0,0 +1,12 @@
+//presentation/router/health_router.rs
+// ヘルスチェックルーティング
+// 2025/7/8
+
+use crate::presentation::controller::health_controller::{api_health, health};
+use axum::{Router, routing::get};
+
+pub fn create_health_routes() -> Router {
+    Router::new()
+        .route("/health", get(health))
+        .route("/api/health", get(api_health))
+}
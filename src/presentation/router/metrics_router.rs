@@ -1,3 +1,14 @@
 //presentation/router/metrics_router.rs
 // メトリクスルーティング
 // 2025/7/8
+
+use crate::presentation::controller::metrics_controller::get_metrics;
+use crate::shared::metrics::collector::MetricsCollector;
+use axum::{Extension, Router, routing::get};
+use std::sync::Arc;
+
+pub fn create_metrics_routes(collector: Arc<MetricsCollector>) -> Router {
+    Router::new()
+        .route("/metrics", get(get_metrics))
+        .layer(Extension(collector))
+}
@@ -0,0 +1,90 @@
+//presentation/router/oauth_router.rs
+// OAuth認可サーバー・クライアント管理ルーティング
+// 2025/7/8
+
+use crate::presentation::controller::oauth_controller::OAuthController;
+use crate::shared::middleware::auth_middleware::{
+    AuthenticatedUser, RequirePermission, permissions,
+};
+use axum::{
+    Router,
+    routing::{delete, get, post},
+};
+use std::sync::Arc;
+
+/// 認可サーバーのルーティング設定（`/oauth`直下、クライアント認証はコントローラーで行う）
+pub fn create_oauth_routes(controller: Arc<OAuthController>) -> Router {
+    Router::new()
+        .route(
+            "/oauth/authorize",
+            get({
+                let controller = controller.clone();
+                move |auth: AuthenticatedUser, query| {
+                    let controller = controller.clone();
+                    async move { controller.authorize(auth, query).await }
+                }
+            }),
+        )
+        .route(
+            "/oauth/token",
+            post({
+                let controller = controller.clone();
+                move |headers, form| {
+                    let controller = controller.clone();
+                    async move { controller.token(headers, form).await }
+                }
+            }),
+        )
+        .route(
+            "/oauth/introspect",
+            post({
+                let controller = controller.clone();
+                move |headers, form| {
+                    let controller = controller.clone();
+                    async move { controller.introspect(headers, form).await }
+                }
+            }),
+        )
+        .route(
+            "/oauth/revoke",
+            post({
+                let controller = controller.clone();
+                move |headers, form| {
+                    let controller = controller.clone();
+                    async move { controller.revoke(headers, form).await }
+                }
+            }),
+        )
+}
+
+/// クライアント管理のルーティング設定（認可は各ハンドラのRequirePermissionで行う）
+pub fn create_oauth_client_routes(controller: Arc<OAuthController>) -> Router {
+    Router::new()
+        .route(
+            "/oauth/clients",
+            get({
+                let controller = controller.clone();
+                move |admin: RequirePermission<permissions::OAuthClientsManage>| {
+                    let controller = controller.clone();
+                    async move { controller.list_clients(admin).await }
+                }
+            })
+            .post({
+                let controller = controller.clone();
+                move |admin: RequirePermission<permissions::OAuthClientsManage>, request| {
+                    let controller = controller.clone();
+                    async move { controller.register_client(admin, request).await }
+                }
+            }),
+        )
+        .route(
+            "/oauth/clients/:client_id",
+            delete({
+                let controller = controller.clone();
+                move |admin: RequirePermission<permissions::OAuthClientsManage>, path| {
+                    let controller = controller.clone();
+                    async move { controller.delete_client(admin, path).await }
+                }
+            }),
+        )
+}
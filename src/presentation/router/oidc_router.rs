@@ -0,0 +1,40 @@
+//presentation/router/oidc_router.rs
+// OpenID Connectルーティング
+// 2025/7/8
+
+use crate::presentation::controller::oidc_controller::OidcController;
+use crate::shared::middleware::auth_middleware::AuthenticatedUser;
+use axum::{Router, routing::get};
+use std::sync::Arc;
+
+/// UserInfo・ディスカバリーのルーティング設定（APIプレフィックスなし）
+pub fn create_oidc_routes(controller: Arc<OidcController>) -> Router {
+    Router::new()
+        .route(
+            "/userinfo",
+            get({
+                let controller = controller.clone();
+                move |auth: AuthenticatedUser| {
+                    let controller = controller.clone();
+                    async move { controller.userinfo(auth).await }
+                }
+            })
+            .post({
+                let controller = controller.clone();
+                move |auth: AuthenticatedUser| {
+                    let controller = controller.clone();
+                    async move { controller.userinfo(auth).await }
+                }
+            }),
+        )
+        .route(
+            "/.well-known/openid-configuration",
+            get({
+                let controller = controller.clone();
+                move || {
+                    let controller = controller.clone();
+                    async move { controller.openid_configuration().await }
+                }
+            }),
+        )
+}
@@ -7,8 +7,7 @@ use crate::application::usecases::delete_user_usecase::DeleteUserUsecaseInterfac
 use crate::application::usecases::get_user_usecase::GetUserQueryUsecaseInterface;
 use crate::application::usecases::update_user_usecase::UpdateUserUsecaseInterface;
 use crate::presentation::controller::user_controller::UserController;
-use crate::shared::middleware::auth_middleware::{AdminUser, AuthenticatedUser};
-use axum::middleware::from_fn;
+use crate::shared::middleware::auth_middleware::AuthenticatedUser;
 use axum::{
     Router,
     routing::{delete, get, post, put},
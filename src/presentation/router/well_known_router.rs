@@ -0,0 +1,8 @@
+//presentation/router/well_known_router.rs
+use crate::presentation::controller::well_known_controller::get_jwks;
+use axum::{Router, routing::get};
+
+/// /.well-known 配下のルーティング（APIプレフィックスなし）
+pub fn create_well_known_routes() -> Router {
+    Router::new().route("/.well-known/jwks.json", get(get_jwks))
+}
@@ -10,15 +10,34 @@ pub enum ApplicationError {
     #[error("User not found: {id}")]
     UserNotFound { id: String },
 
+    #[error("{resource} not found: {id}")]
+    ResourceNotFound { resource: String, id: String },
+
     #[error("Email already exists: {email}")]
     EmailAlreadyExists { email: String },
 
+    #[error("Invalid credentials")]
+    InvalidCredentials,
+
+    #[error("Invalid or revoked token")]
+    InvalidToken,
+
+    #[error("Email address not verified")]
+    EmailNotVerified,
+
+    #[error("Too many attempts, retry after {retry_after_secs}s")]
+    TooManyAttempts { retry_after_secs: u64 },
+
     #[error("Authorization failed: {message}")]
     AuthorizationFailed { message: String },
 
     #[error("Operation not permitted: {operation} - {reason}")]
     OperationNotPermitted { operation: String, reason: String },
 
+    /// OAuth 2.0のエラーレスポンス（`error`はRFC 6749のエラーコード）
+    #[error("OAuth error: {error} - {description}")]
+    OAuthProtocol { error: String, description: String },
+
     // Input Validation Errors
     #[error("Validation failed: {field} - {message}")]
     ValidationFailed { field: String, message: String },
@@ -1,5 +1,6 @@
 // src/shared/domain_error.rs
 
+use crate::domain::service::password_policy::PasswordViolation;
 use thiserror::Error;
 
 #[derive(Error, Debug, Clone, PartialEq)]
@@ -14,6 +15,9 @@ pub enum DomainError {
     #[error("Invalid password: {reason}")]
     InvalidPassword { reason: String },
 
+    #[error("Password policy violation: {}", join_violations(.violations))]
+    PasswordPolicyViolation { violations: Vec<PasswordViolation> },
+
     // Business Rule Violations
     #[error("Business rule violation: {rule} - {message}")]
     BusinessRuleViolation { rule: String, message: String },
@@ -30,5 +34,13 @@ pub enum DomainError {
     InvariantViolation { message: String },
 }
 
+fn join_violations(violations: &[PasswordViolation]) -> String {
+    violations
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
 // Domain Layer Result Type
 pub type DomainResult<T> = Result<T, DomainError>;
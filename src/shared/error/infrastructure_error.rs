@@ -14,6 +14,9 @@ pub enum InfrastructureError {
     #[error("Database transaction failed: {message}")]
     DatabaseTransaction { message: String },
 
+    #[error("Schema migration failed: version {version} - {message}")]
+    SchemaMigration { version: u32, message: String },
+
     #[error("Data serialization failed: {data_type} - {message}")]
     DataSerialization { data_type: String, message: String },
 
@@ -78,6 +78,10 @@ impl PresentationError {
             PresentationError::Application(app_error) => match app_error {
                 ApplicationError::UserNotFound { .. } => StatusCode::NOT_FOUND,
                 ApplicationError::EmailAlreadyExists { .. } => StatusCode::CONFLICT,
+                ApplicationError::InvalidCredentials => StatusCode::UNAUTHORIZED,
+                ApplicationError::InvalidToken => StatusCode::UNAUTHORIZED,
+                ApplicationError::EmailNotVerified => StatusCode::FORBIDDEN,
+                ApplicationError::TooManyAttempts { .. } => StatusCode::TOO_MANY_REQUESTS,
                 ApplicationError::AuthorizationFailed { .. } => StatusCode::FORBIDDEN,
                 ApplicationError::ValidationFailed { .. } => StatusCode::BAD_REQUEST,
                 ApplicationError::InvalidInput { .. } => StatusCode::BAD_REQUEST,
@@ -1,3 +1,177 @@
 //shared/metrics/collector.rs
 // メトリクス収集器
 // 2025/7/8
+
+use crate::shared::metrics::types::{MetricsSnapshot, PoolMetricsSnapshot};
+use std::fmt::{self, Write};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
+
+/// 出力時に現在の利用状況を読み取る接続プール
+pub trait PoolMetricsSource: Send + Sync + fmt::Debug {
+    fn pool_metrics(&self) -> Vec<PoolMetricsSnapshot>;
+}
+
+/// HTTPリクエストのメトリクス収集器
+///
+/// 値はすべてアトミックなカウンターで保持し、リクエストの処理をロックで待たせない
+#[derive(Debug)]
+pub struct MetricsCollector {
+    started_at: Instant,
+    requests_total: AtomicU64,
+    requests_in_flight: AtomicU64,
+    responses_by_class: [AtomicU64; 5],
+    request_duration_micros_total: AtomicU64,
+    pools: OnceLock<Arc<dyn PoolMetricsSource>>,
+}
+
+impl Default for MetricsCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MetricsCollector {
+    pub fn new() -> Self {
+        Self {
+            started_at: Instant::now(),
+            requests_total: AtomicU64::new(0),
+            requests_in_flight: AtomicU64::new(0),
+            responses_by_class: Default::default(),
+            request_duration_micros_total: AtomicU64::new(0),
+            pools: OnceLock::new(),
+        }
+    }
+
+    /// 接続プールの利用状況を出力に含める（最初に登録したもののみ）
+    pub fn register_pools(&self, source: Arc<dyn PoolMetricsSource>) {
+        let _ = self.pools.set(source);
+    }
+
+    /// 登録した接続プールの利用状況
+    pub fn pool_snapshots(&self) -> Vec<PoolMetricsSnapshot> {
+        self.pools
+            .get()
+            .map(|source| source.pool_metrics())
+            .unwrap_or_default()
+    }
+
+    /// リクエストの処理開始を記録する
+    pub fn start_request(&self) {
+        self.requests_in_flight.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// リクエストの処理完了を記録する
+    pub fn end_request(&self, status: u16, elapsed: Duration) {
+        self.requests_in_flight.fetch_sub(1, Ordering::Relaxed);
+        self.requests_total.fetch_add(1, Ordering::Relaxed);
+        if let Some(counter) = (status / 100)
+            .checked_sub(1)
+            .and_then(|class| self.responses_by_class.get(class as usize))
+        {
+            counter.fetch_add(1, Ordering::Relaxed);
+        }
+        self.request_duration_micros_total.fetch_add(
+            u64::try_from(elapsed.as_micros()).unwrap_or(u64::MAX),
+            Ordering::Relaxed,
+        );
+    }
+
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        MetricsSnapshot {
+            uptime_secs: self.started_at.elapsed().as_secs(),
+            requests_total: self.requests_total.load(Ordering::Relaxed),
+            requests_in_flight: self.requests_in_flight.load(Ordering::Relaxed),
+            responses_by_class: std::array::from_fn(|i| {
+                self.responses_by_class[i].load(Ordering::Relaxed)
+            }),
+            request_duration_micros_total: self
+                .request_duration_micros_total
+                .load(Ordering::Relaxed),
+        }
+    }
+
+    /// Prometheusのテキスト形式で出力する
+    pub fn render_prometheus(&self) -> String {
+        let snapshot = self.snapshot();
+        let mut out = String::new();
+        let _ = writeln!(out, "# TYPE process_uptime_seconds gauge");
+        let _ = writeln!(out, "process_uptime_seconds {}", snapshot.uptime_secs);
+        let _ = writeln!(out, "# TYPE http_requests_total counter");
+        let _ = writeln!(out, "http_requests_total {}", snapshot.requests_total);
+        let _ = writeln!(out, "# TYPE http_requests_in_flight gauge");
+        let _ = writeln!(
+            out,
+            "http_requests_in_flight {}",
+            snapshot.requests_in_flight
+        );
+        let _ = writeln!(out, "# TYPE http_responses_total counter");
+        for (i, count) in snapshot.responses_by_class.iter().enumerate() {
+            let _ = writeln!(
+                out,
+                "http_responses_total{{class=\"{}xx\"}} {}",
+                i + 1,
+                count
+            );
+        }
+        let _ = writeln!(out, "# TYPE http_request_duration_seconds_sum counter");
+        let _ = writeln!(
+            out,
+            "http_request_duration_seconds_sum {:.6}",
+            snapshot.request_duration_micros_total as f64 / 1_000_000.0
+        );
+        self.render_pools(&mut out);
+        out
+    }
+
+    fn render_pools(&self, out: &mut String) {
+        let pools = self.pool_snapshots();
+        if pools.is_empty() {
+            return;
+        }
+        let _ = writeln!(out, "# TYPE db_pool_connections gauge");
+        for pool in &pools {
+            for (state, count) in [("idle", pool.idle), ("in_use", pool.in_use)] {
+                let _ = writeln!(
+                    out,
+                    "db_pool_connections{{pool=\"{}\",state=\"{}\"}} {}",
+                    pool.name, state, count
+                );
+            }
+        }
+        let _ = writeln!(out, "# TYPE db_pool_waiting gauge");
+        for pool in &pools {
+            let _ = writeln!(
+                out,
+                "db_pool_waiting{{pool=\"{}\"}} {}",
+                pool.name, pool.waiting
+            );
+        }
+        let _ = writeln!(out, "# TYPE db_pool_acquire_total counter");
+        for pool in &pools {
+            let _ = writeln!(
+                out,
+                "db_pool_acquire_total{{pool=\"{}\"}} {}",
+                pool.name, pool.acquired_total
+            );
+        }
+        let _ = writeln!(out, "# TYPE db_pool_acquire_timeouts_total counter");
+        for pool in &pools {
+            let _ = writeln!(
+                out,
+                "db_pool_acquire_timeouts_total{{pool=\"{}\"}} {}",
+                pool.name, pool.timeouts_total
+            );
+        }
+        let _ = writeln!(out, "# TYPE db_pool_acquire_wait_seconds_sum counter");
+        for pool in &pools {
+            let _ = writeln!(
+                out,
+                "db_pool_acquire_wait_seconds_sum{{pool=\"{}\"}} {:.6}",
+                pool.name,
+                pool.wait_micros_total as f64 / 1_000_000.0
+            );
+        }
+    }
+}
@@ -1,3 +1,39 @@
 //shared/metrics/types.rs
 // メトリクス型定義
 // 2025/7/8
+
+/// ある時点のHTTPリクエストのメトリクス
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MetricsSnapshot {
+    /// 起動からの経過秒数
+    pub uptime_secs: u64,
+    /// 完了したリクエスト数
+    pub requests_total: u64,
+    /// 処理中のリクエスト数
+    pub requests_in_flight: u64,
+    /// ステータスコードの種類（1xx〜5xx）ごとのレスポンス数
+    pub responses_by_class: [u64; 5],
+    /// 完了したリクエストの処理時間の合計（マイクロ秒）
+    pub request_duration_micros_total: u64,
+}
+
+/// ある時点のデータベース接続プールの利用状況
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PoolMetricsSnapshot {
+    /// プールの名前（writer / reader）
+    pub name: String,
+    /// 接続数
+    pub size: u64,
+    /// 空いている接続数
+    pub idle: u64,
+    /// 使用中の接続数
+    pub in_use: u64,
+    /// 接続の空きを待っている処理の数
+    pub waiting: u64,
+    /// 接続を取得できた回数
+    pub acquired_total: u64,
+    /// 取得待ちがタイムアウトした回数
+    pub timeouts_total: u64,
+    /// 取得待ちの時間の合計（マイクロ秒）
+    pub wait_micros_total: u64,
+}
@@ -1,17 +1,40 @@
+use crate::domain::entity::audit_event::AuditEvent;
+use crate::domain::repository::audit_log_repository::AuditLogRepositoryInterface;
+use crate::domain::service::password_policy::PasswordViolation;
+use crate::domain::service::permission_policy::PermissionPolicy;
+use crate::domain::value_object::{oidc_scope::OidcScope, permission::Permission, role::Role};
+#[cfg(not(feature = "testmode"))]
+use crate::infrastructure::config::app_config::AppConfig;
+use crate::infrastructure::config::app_config::JwtKeyConfig;
+use crate::shared::error::infrastructure_error::{InfrastructureError, InfrastructureResult};
+use crate::shared::middleware::client_cert_middleware::ClientCertificate;
+use crate::shared::middleware::session_cookie_middleware::{
+    ACCESS_TOKEN_COOKIE, cookie_value, verify_csrf,
+};
+use crate::shared::utils::jwt_keys::{
+    VerificationKey, encoding_key_from_private_pem_file, parse_algorithm,
+};
 use axum::{
     Json, RequestPartsExt, async_trait,
-    extract::FromRequestParts,
-    http::{StatusCode, request::Parts},
+    extract::{FromRequestParts, OriginalUri},
+    http::{
+        HeaderValue, StatusCode,
+        header::{AUTHORIZATION, RETRY_AFTER},
+        request::Parts,
+    },
     response::{IntoResponse, Response},
 };
 use axum_extra::{
     TypedHeader,
     headers::{Authorization, authorization::Bearer},
 };
-use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Header, Validation, decode, encode};
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{Algorithm, EncodingKey, Header, Validation, decode, decode_header, encode};
+use rand::Rng;
 use serde::{Deserialize, Serialize};
-use serde_json::json;
-use std::sync::LazyLock;
+use serde_json::{Value, json};
+use std::marker::PhantomData;
+use std::sync::{Arc, LazyLock, OnceLock};
 use thiserror::Error;
 use uuid::Uuid;
 
@@ -21,35 +44,151 @@ use uuid::Uuid;
 
 #[derive(Clone)]
 pub struct JwtConfig {
-    pub secret: String,
+    /// 署名アルゴリズム
     pub algorithm: Algorithm,
+    /// 署名鍵のID（全トークンの`kid`ヘッダーに入る）
+    pub key_id: String,
     pub expiration_hours: i64,
+    encoding_key: EncodingKey,
+    /// 検証鍵（ローテーション中は旧鍵も含む）
+    verification_keys: Vec<VerificationKey>,
 }
 
 impl JwtConfig {
+    /// 共通鍵（HS256）の設定
     pub fn new(secret: String) -> Self {
+        Self::with_secret(DEFAULT_KEY_ID.to_string(), secret.as_bytes())
+    }
+
+    fn with_secret(key_id: String, secret: &[u8]) -> Self {
         Self {
-            secret,
             algorithm: Algorithm::HS256,
+            encoding_key: EncodingKey::from_secret(secret),
+            verification_keys: vec![VerificationKey::from_secret(key_id.clone(), secret)],
+            key_id,
             expiration_hours: 1,
         }
     }
-    pub fn encoding_key(&self) -> EncodingKey {
-        EncodingKey::from_secret(self.secret.as_ref())
+
+    /// 鍵設定から組み立てる（鍵ファイルの読み込み・検証を含む）
+    pub fn from_key_config(config: &JwtKeyConfig) -> InfrastructureResult<Self> {
+        let algorithm = parse_algorithm(&config.algorithm)?;
+        let mut jwt_config = if algorithm == Algorithm::HS256 {
+            let key_id = config
+                .key_id
+                .clone()
+                .unwrap_or_else(|| DEFAULT_KEY_ID.to_string());
+            match &config.secret {
+                Some(secret) => Self::with_secret(key_id, secret.as_bytes()),
+                None => {
+                    // 固定の既定値は使わず、起動ごとのランダム鍵にする（再起動でトークンは無効になる）
+                    println!(
+                        "⚠️ JWT_SECRETが未設定のため、一時的な署名鍵を生成しました（再起動で発行済みトークンは無効になります）"
+                    );
+                    let secret: String = rand::thread_rng()
+                        .sample_iter(&rand::distributions::Alphanumeric)
+                        .take(64)
+                        .map(char::from)
+                        .collect();
+                    Self::with_secret(key_id, secret.as_bytes())
+                }
+            }
+        } else {
+            let required = |value: &Option<String>, name: &str| {
+                value
+                    .clone()
+                    .ok_or_else(|| InfrastructureError::Configuration {
+                        key: name.to_string(),
+                        message: format!("required for {:?}", algorithm),
+                    })
+            };
+            let key_id = required(&config.key_id, "JWT_KEY_ID")?;
+            let private_key_path = required(&config.private_key_path, "JWT_PRIVATE_KEY_PATH")?;
+            let public_key_path = required(&config.public_key_path, "JWT_PUBLIC_KEY_PATH")?;
+            let encoding_key =
+                encoding_key_from_private_pem_file(&key_id, algorithm, &private_key_path)?;
+            let verification_key =
+                VerificationKey::from_public_pem_file(key_id.clone(), &public_key_path)?;
+            if verification_key.algorithm != algorithm {
+                return Err(InfrastructureError::Configuration {
+                    key: "JWT_PUBLIC_KEY_PATH".to_string(),
+                    message: format!(
+                        "public key is for {:?}, but JWT_ALGORITHM is {:?}",
+                        verification_key.algorithm, algorithm
+                    ),
+                });
+            }
+            Self {
+                algorithm,
+                key_id,
+                expiration_hours: 1,
+                encoding_key,
+                verification_keys: vec![verification_key],
+            }
+        };
+        for (kid, path) in &config.verification_keys {
+            jwt_config
+                .add_verification_key(VerificationKey::from_public_pem_file(kid.clone(), path)?)?;
+        }
+        Ok(jwt_config)
+    }
+
+    /// ローテーション用の検証鍵を追加する
+    pub fn add_verification_key(&mut self, key: VerificationKey) -> InfrastructureResult<()> {
+        if self.verification_keys.iter().any(|k| k.kid == key.kid) {
+            return Err(InfrastructureError::Configuration {
+                key: "JWT_VERIFICATION_KEYS".to_string(),
+                message: format!("duplicate kid '{}'", key.kid),
+            });
+        }
+        self.verification_keys.push(key);
+        Ok(())
+    }
+
+    pub fn encoding_key(&self) -> &EncodingKey {
+        &self.encoding_key
+    }
+
+    /// `kid`に対応する検証鍵（`kid`なしの場合は現在の署名鍵）
+    pub fn verification_key(&self, kid: Option<&str>) -> Option<&VerificationKey> {
+        let kid = kid.unwrap_or(&self.key_id);
+        self.verification_keys.iter().find(|k| k.kid == kid)
     }
-    pub fn decoding_key(&self) -> DecodingKey {
-        DecodingKey::from_secret(self.secret.as_ref())
+
+    /// 公開可能な検証鍵のJWK Set
+    pub fn jwks(&self) -> JwkSet {
+        JwkSet {
+            keys: self
+                .verification_keys
+                .iter()
+                .filter_map(|k| k.jwk.clone())
+                .collect(),
+        }
+    }
+}
+
+/// HS256で`JWT_KEY_ID`未設定時に使う`kid`
+const DEFAULT_KEY_ID: &str = "default";
+
+/// 起動時に`JwtConfig::install`で登録された設定
+static INSTALLED_JWT_CONFIG: OnceLock<JwtConfig> = OnceLock::new();
+
+impl JwtConfig {
+    /// グローバル設定として登録する（`JWT_CONFIG`の初回参照前に呼ぶこと）
+    pub fn install(config: JwtConfig) -> Result<(), JwtConfig> {
+        INSTALLED_JWT_CONFIG.set(config)
     }
 }
 
 pub static JWT_CONFIG: LazyLock<JwtConfig> = LazyLock::new(|| {
+    if let Some(config) = INSTALLED_JWT_CONFIG.get() {
+        return config.clone();
+    }
     #[cfg(feature = "testmode")]
-    let secret = "test_jwt_secret".to_string();
+    return JwtConfig::new("test_jwt_secret".to_string());
     #[cfg(not(feature = "testmode"))]
-    let secret = std::env::var("JWT_SECRET").unwrap_or_else(|_| {
-        "mK8vL9jN2pQ3rS4tU5vW6xY7zA1bC2dE3fG4hI5jK6lM7nO8pQ9rS0tU1vW2xY3z4A5bC6dE7fG8h".to_string()
-    });
-    JwtConfig::new(secret)
+    JwtConfig::from_key_config(&AppConfig::current().jwt)
+        .unwrap_or_else(|e| panic!("JWT設定の読み込みに失敗しました: {}", e))
 });
 
 // =============================================================================
@@ -65,6 +204,51 @@ pub struct JwtClaims {
     pub iat: i64,
     pub exp: i64,
     pub jti: String,
+    /// セッションID（リフレッシュトークンファミリーID）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sid: Option<String>,
+    /// トークン種別（アクセス/リフレッシュ/二要素認証待ち/APIキー）
+    pub token_type: TokenType,
+    /// 権限の絞り込み（空白区切りの`リソース:操作`、Noneはロールの権限すべて）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    /// OAuthクライアント経由で発行したトークンのクライアントID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+    /// なりすましトークンの場合、実際に操作しているユーザー（RFC 8693の`act`）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub act: Option<ActorClaim>,
+}
+
+/// なりすましトークンの`act`クレーム
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct ActorClaim {
+    pub sub: String,
+}
+
+/// OAuthクライアントへの委任（トークンの`client_id`と`scope`）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientGrant {
+    pub client_id: String,
+    pub scope: String,
+}
+
+/// トークン種別
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum TokenType {
+    #[default]
+    Access,
+    Refresh,
+    /// パスワード確認済み・二要素認証待ち（`/auth/login/mfa`と登録APIでのみ有効）
+    #[serde(rename = "mfa_pending")]
+    MfaPending,
+    /// `X-API-Key`ヘッダーのAPIキーから組み立てたクレーム（トークンとしては発行しない）
+    #[serde(rename = "api_key")]
+    ApiKey,
+    /// mTLSのクライアント証明書から組み立てたクレーム（トークンとしては発行しない）
+    #[serde(rename = "client_cert")]
+    ClientCert,
 }
 
 impl JwtClaims {
@@ -79,29 +263,174 @@ impl JwtClaims {
             iat: now.timestamp(),
             exp: exp.timestamp(),
             jti: Uuid::new_v4().to_string(),
+            sid: None,
+            token_type: TokenType::Access,
+            scope: None,
+            client_id: None,
+            act: None,
         }
     }
     pub fn to_token(&self) -> Result<String, AuthError> {
-        let header = Header::new(JWT_CONFIG.algorithm);
-        encode(&header, self, &JWT_CONFIG.encoding_key()).map_err(|_| AuthError::TokenCreation)
+        self.to_token_with(&JWT_CONFIG)
+    }
+    /// 指定した鍵設定で署名する（`kid`ヘッダー付き）
+    pub fn to_token_with(&self, config: &JwtConfig) -> Result<String, AuthError> {
+        let mut header = Header::new(config.algorithm);
+        header.kid = Some(config.key_id.clone());
+        encode(&header, self, config.encoding_key()).map_err(|_| AuthError::TokenCreation)
     }
     pub fn from_token(token: &str) -> Result<Self, AuthError> {
-        let validation = Validation::new(JWT_CONFIG.algorithm);
-        let token_data = decode::<JwtClaims>(token, &JWT_CONFIG.decoding_key(), &validation)
+        Self::from_token_with(token, &JWT_CONFIG)
+    }
+    /// `kid`ヘッダーで検証鍵を選択して検証する
+    pub fn from_token_with(token: &str, config: &JwtConfig) -> Result<Self, AuthError> {
+        let header = decode_header(token).map_err(|_| AuthError::InvalidToken)?;
+        let key = config
+            .verification_key(header.kid.as_deref())
+            .ok_or(AuthError::InvalidToken)?;
+        if header.alg != key.algorithm {
+            return Err(AuthError::InvalidToken);
+        }
+        let validation = Validation::new(key.algorithm);
+        let token_data = decode::<Value>(token, &key.decoding_key, &validation)
             .map_err(|_| AuthError::InvalidToken)?;
-        Ok(token_data.claims)
+        Self::from_claims_value(token_data.claims)
     }
-    pub fn has_role(&self, required_role: &str) -> bool {
-        match (self.role.as_str(), required_role) {
-            ("superadmin", _) => true,
-            ("admin", "admin" | "user") => true,
-            ("user", "user") => true,
-            _ => false,
+    /// `token_type`導入前に発行されたトークンは、リフレッシュトークン（`role = "refresh"`）を
+    /// アクセストークンとして受け付けないよう種別を補ってから読み込む
+    fn from_claims_value(mut claims: Value) -> Result<Self, AuthError> {
+        if let Some(map) = claims.as_object_mut()
+            && !map.contains_key("token_type")
+        {
+            let token_type = if map.get("role").and_then(Value::as_str) == Some("refresh") {
+                TokenType::Refresh
+            } else {
+                TokenType::Access
+            };
+            map.insert("token_type".to_string(), json!(token_type));
         }
+        serde_json::from_value(claims).map_err(|_| AuthError::InvalidToken)
+    }
+    /// ロール（未知のロール文字列の場合はNone）
+    pub fn role(&self) -> Option<Role> {
+        Role::new(&self.role).ok()
     }
     pub fn is_expired(&self) -> bool {
         chrono::Utc::now().timestamp() > self.exp
     }
+    /// OAuthクライアント経由のトークンの場合はその委任内容
+    pub fn client_grant(&self) -> Option<ClientGrant> {
+        self.client_id.as_ref().map(|client_id| ClientGrant {
+            client_id: client_id.clone(),
+            scope: self.scope.clone().unwrap_or_default(),
+        })
+    }
+    /// なりすましトークンの場合は実際に操作しているユーザーID
+    pub fn impersonator(&self) -> Option<&str> {
+        self.act.as_ref().map(|act| act.sub.as_str())
+    }
+    /// なりすましトークンでは行えない操作（パスワード・二要素認証の変更など）を拒否する
+    pub fn forbid_impersonation(&self, operation: &str) -> Result<(), AuthError> {
+        if self.act.is_some() {
+            return Err(AuthError::OperationNotPermitted {
+                operation: operation.to_string(),
+                reason: "not available while impersonating".to_string(),
+            });
+        }
+        Ok(())
+    }
+    /// OAuthクライアントに委任されたトークンでは行えない操作（APIキー・二要素認証の管理など）を拒否する
+    pub fn forbid_client_token(&self, operation: &str) -> Result<(), AuthError> {
+        if self.client_id.is_some() {
+            return Err(AuthError::OperationNotPermitted {
+                operation: operation.to_string(),
+                reason: "not available with a client token".to_string(),
+            });
+        }
+        Ok(())
+    }
+    /// `scope`が権限を含むか（`scope`がない場合は常にtrue）
+    pub fn has_scope(&self, permission: Permission) -> bool {
+        self.scope
+            .as_deref()
+            .is_none_or(|scope| scope.split_whitespace().any(|s| s == permission.as_str()))
+    }
+}
+
+/// OpenID ConnectのIDトークンのクレーム
+///
+/// アクセストークンと同じ鍵で署名するが、`role`・`jti`を持たないため
+/// `JwtClaims`としては検証に通らない（APIの認証には使えない）。
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct IdTokenClaims {
+    pub iss: String,
+    pub sub: String,
+    /// 発行先のクライアントID
+    pub aud: String,
+    pub iat: i64,
+    pub exp: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<String>,
+    /// `email`スコープ
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email_verified: Option<bool>,
+    /// `profile`スコープ
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub birthdate: Option<String>,
+    /// `phone`スコープ
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<String>,
+}
+
+impl IdTokenClaims {
+    /// 同時に発行したアクセストークンのクレームから組み立てる
+    /// （`email`・`name`はアクセストークンの`scope`に応じて含める、`client_id`がない場合はNone）
+    pub fn from_access_claims(
+        claims: &JwtClaims,
+        issuer: &str,
+        nonce: Option<String>,
+    ) -> Option<Self> {
+        let audience = claims.client_id.clone()?;
+        let scope = claims.scope.as_deref().unwrap_or_default();
+        Some(Self {
+            iss: issuer.to_string(),
+            sub: claims.sub.clone(),
+            aud: audience,
+            iat: claims.iat,
+            exp: claims.exp,
+            nonce,
+            email: OidcScope::Email.is_in(scope).then(|| claims.email.clone()),
+            email_verified: None,
+            name: OidcScope::Profile.is_in(scope).then(|| claims.name.clone()),
+            birthdate: None,
+            phone_number: None,
+        })
+    }
+    pub fn to_token(&self) -> Result<String, AuthError> {
+        let mut header = Header::new(JWT_CONFIG.algorithm);
+        header.kid = Some(JWT_CONFIG.key_id.clone());
+        encode(&header, self, JWT_CONFIG.encoding_key()).map_err(|_| AuthError::TokenCreation)
+    }
+    /// 署名・期限・発行者・発行先を検証する
+    pub fn from_token(token: &str, issuer: &str, audience: &str) -> Result<Self, AuthError> {
+        let header = decode_header(token).map_err(|_| AuthError::InvalidToken)?;
+        let key = JWT_CONFIG
+            .verification_key(header.kid.as_deref())
+            .ok_or(AuthError::InvalidToken)?;
+        if header.alg != key.algorithm {
+            return Err(AuthError::InvalidToken);
+        }
+        let mut validation = Validation::new(key.algorithm);
+        validation.set_issuer(&[issuer]);
+        validation.set_audience(&[audience]);
+        let token_data = decode::<IdTokenClaims>(token, &key.decoding_key, &validation)
+            .map_err(|_| AuthError::InvalidToken)?;
+        Ok(token_data.claims)
+    }
 }
 
 // =============================================================================
@@ -122,10 +451,65 @@ pub enum AuthError {
     InsufficientPermissions,
     #[error("Token expired")]
     TokenExpired,
+    #[error("Token revoked")]
+    TokenRevoked,
+    #[error("Email not verified")]
+    EmailNotVerified,
+    #[error("Operation not permitted: {operation} - {reason}")]
+    OperationNotPermitted { operation: String, reason: String },
+    #[error("Too many attempts")]
+    TooManyAttempts { retry_after_secs: u64 },
+    #[error("Password policy violation")]
+    PasswordPolicyViolation { violations: Vec<PasswordViolation> },
+    #[error("CSRF token missing or invalid")]
+    CsrfTokenInvalid,
+    #[error("Validation failed: {field} - {message}")]
+    ValidationFailed { field: String, message: String },
+    #[error("Internal error")]
+    Internal,
 }
 
-impl IntoResponse for AuthError {
-    fn into_response(self) -> Response {
+impl AuthError {
+    /// HTTPステータスとエラーレスポンスのJSON
+    pub fn status_and_body(&self) -> (StatusCode, Value) {
+        if let AuthError::OperationNotPermitted { operation, reason } = self {
+            let body = json!({
+                "success": false,
+                "error": {
+                    "code": "OPERATION_NOT_PERMITTED",
+                    "message": format!("Operation '{}' not permitted: {}", operation, reason),
+                }
+            });
+            return (StatusCode::FORBIDDEN, body);
+        }
+        if let AuthError::ValidationFailed { field, message } = self {
+            let body = json!({
+                "success": false,
+                "error": {
+                    "code": "VALIDATION_FAILED",
+                    "message": format!("Validation failed for field '{}': {}", field, message),
+                    "field": field,
+                }
+            });
+            return (StatusCode::BAD_REQUEST, body);
+        }
+        if let AuthError::PasswordPolicyViolation { violations } = self {
+            let body = json!({
+                "success": false,
+                "error": {
+                    "code": "PASSWORD_POLICY_VIOLATION",
+                    "message": "Password does not meet the password policy",
+                    "violations": violations
+                        .iter()
+                        .map(|violation| json!({
+                            "code": violation.code(),
+                            "message": violation.to_string(),
+                        }))
+                        .collect::<Vec<_>>(),
+                }
+            });
+            return (StatusCode::BAD_REQUEST, body);
+        }
         let (status, error_message, error_code) = match self {
             AuthError::InvalidToken => (StatusCode::UNAUTHORIZED, "Invalid token", "INVALID_TOKEN"),
             AuthError::MissingCredentials => (
@@ -149,15 +533,134 @@ impl IntoResponse for AuthError {
                 "INSUFFICIENT_PERMISSIONS",
             ),
             AuthError::TokenExpired => (StatusCode::UNAUTHORIZED, "Token expired", "TOKEN_EXPIRED"),
+            AuthError::TokenRevoked => (StatusCode::UNAUTHORIZED, "Token revoked", "TOKEN_REVOKED"),
+            AuthError::EmailNotVerified => (
+                StatusCode::FORBIDDEN,
+                "Email address not verified",
+                "EMAIL_NOT_VERIFIED",
+            ),
+            AuthError::CsrfTokenInvalid => (
+                StatusCode::FORBIDDEN,
+                "CSRF token missing or invalid",
+                "CSRF_TOKEN_INVALID",
+            ),
+            AuthError::TooManyAttempts { .. } => (
+                StatusCode::TOO_MANY_REQUESTS,
+                "Too many attempts, try again later",
+                "TOO_MANY_ATTEMPTS",
+            ),
+            AuthError::Internal
+            | AuthError::ValidationFailed { .. }
+            | AuthError::PasswordPolicyViolation { .. }
+            | AuthError::OperationNotPermitted { .. } => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Internal error",
+                "INTERNAL_ERROR",
+            ),
         };
-        let body = Json(json!({
+        let body = json!({
             "success": false,
             "error": {
                 "code": error_code,
                 "message": error_message,
             }
-        }));
-        (status, body).into_response()
+        });
+        (status, body)
+    }
+}
+
+impl IntoResponse for AuthError {
+    fn into_response(self) -> Response {
+        let (status, body) = self.status_and_body();
+        let mut response = (status, Json(body)).into_response();
+        if let AuthError::TooManyAttempts { retry_after_secs } = self {
+            response
+                .headers_mut()
+                .insert(RETRY_AFTER, HeaderValue::from(retry_after_secs));
+        }
+        response
+    }
+}
+
+// =============================================================================
+// Session Validation - 失効チェック
+// =============================================================================
+
+/// 署名・期限の検証後にトークンが失効していないかを判定する
+#[async_trait]
+pub trait SessionValidatorInterface: Send + Sync {
+    async fn is_active(&self, claims: &JwtClaims) -> Result<bool, AuthError>;
+}
+
+/// `X-API-Key`ヘッダーのAPIキーを検証し、所有者のクレームを組み立てる
+#[async_trait]
+pub trait ApiKeyAuthenticatorInterface: Send + Sync {
+    async fn authenticate(&self, api_key: &str) -> Result<JwtClaims, AuthError>;
+}
+
+/// 検証済みのクライアント証明書（mTLS）の主体に対応するユーザーのクレームを組み立てる
+#[async_trait]
+pub trait ClientCertAuthenticatorInterface: Send + Sync {
+    async fn authenticate(&self, certificate: &ClientCertificate) -> Result<JwtClaims, AuthError>;
+}
+
+/// 認証Extractorが参照するサービス群（ルーターに`Extension`として登録する）
+#[derive(Clone)]
+pub struct AuthServices {
+    pub session_validator: Arc<dyn SessionValidatorInterface>,
+    pub permission_policy: Arc<PermissionPolicy>,
+    /// 未登録の場合、`X-API-Key`ヘッダーは受け付けない
+    pub api_key_authenticator: Option<Arc<dyn ApiKeyAuthenticatorInterface>>,
+    /// 未登録の場合、クライアント証明書はユーザーとしての認証に使わない
+    pub client_cert_authenticator: Option<Arc<dyn ClientCertAuthenticatorInterface>>,
+    /// なりすましトークンでのリクエストの記録先（未登録の場合は記録しない）
+    pub audit_log: Option<Arc<dyn AuditLogRepositoryInterface>>,
+}
+
+impl AuthServices {
+    pub fn new(
+        session_validator: Arc<dyn SessionValidatorInterface>,
+        permission_policy: Arc<PermissionPolicy>,
+    ) -> Self {
+        Self {
+            session_validator,
+            permission_policy,
+            api_key_authenticator: None,
+            client_cert_authenticator: None,
+            audit_log: None,
+        }
+    }
+
+    /// APIキー認証を有効にする
+    pub fn with_api_key_authenticator(
+        mut self,
+        authenticator: Arc<dyn ApiKeyAuthenticatorInterface>,
+    ) -> Self {
+        self.api_key_authenticator = Some(authenticator);
+        self
+    }
+
+    /// クライアント証明書（mTLS）での認証を有効にする
+    pub fn with_client_cert_authenticator(
+        mut self,
+        authenticator: Arc<dyn ClientCertAuthenticatorInterface>,
+    ) -> Self {
+        self.client_cert_authenticator = Some(authenticator);
+        self
+    }
+
+    /// なりすましトークンでのリクエストを監査ログに記録する
+    pub fn with_audit_log(mut self, audit_log: Arc<dyn AuditLogRepositoryInterface>) -> Self {
+        self.audit_log = Some(audit_log);
+        self
+    }
+
+    /// トークンのロールが権限を持ち、`scope`でも許可されているか（未知のロールは常に拒否）
+    pub fn is_allowed(&self, claims: &JwtClaims, permission: Permission) -> bool {
+        claims.has_scope(permission)
+            && claims
+                .role()
+                .is_some_and(|role| self.permission_policy.allows(role, permission))
     }
 }
 
@@ -165,65 +668,281 @@ impl IntoResponse for AuthError {
 // JWT Extractor - 基本認証
 // =============================================================================
 
+/// 認証済みユーザー
+///
+/// `Authorization`ヘッダーのBearerトークン、またはCookieセッションのアクセストークンで認証する。
+/// `Authorization`ヘッダーがなく`X-API-Key`ヘッダーがある場合はAPIキーで認証する。
+/// どちらもない場合は、mTLSで検証済みのクライアント証明書に対応するユーザーとして認証する
 #[derive(Debug, Clone)]
 pub struct AuthenticatedUser(pub JwtClaims);
 
+impl AuthenticatedUser {
+    /// ログインセッション（アクセストークン）を要求する操作で、APIキー・クライアント証明書での呼び出しを拒否する
+    pub fn require_session(self, operation: &str) -> Result<JwtClaims, AuthError> {
+        let reason = match self.0.token_type {
+            TokenType::ApiKey => "not available with an API key",
+            TokenType::ClientCert => "not available with a client certificate",
+            _ => return Ok(self.0),
+        };
+        Err(AuthError::OperationNotPermitted {
+            operation: operation.to_string(),
+            reason: reason.to_string(),
+        })
+    }
+}
+
+/// APIキーのヘッダー名
+pub const API_KEY_HEADER: &str = "x-api-key";
+
+fn auth_services(parts: &Parts) -> Result<AuthServices, AuthError> {
+    parts
+        .extensions
+        .get::<AuthServices>()
+        .cloned()
+        .ok_or_else(|| {
+            println!("AuthenticatedUser: AuthServices extension is not registered");
+            AuthError::Internal
+        })
+}
+
+/// Bearerトークンを検証する（種別・期限・失効）
+///
+/// `Authorization`ヘッダーがない場合はアクセストークンのCookieを使い、
+/// 状態を変更するメソッドではCSRFトークンも検証する
+async fn authenticate_bearer(
+    parts: &mut Parts,
+    accepted: &[TokenType],
+) -> Result<JwtClaims, AuthError> {
+    let token = if parts.headers.contains_key(AUTHORIZATION) {
+        let TypedHeader(Authorization(bearer)) = parts
+            .extract::<TypedHeader<Authorization<Bearer>>>()
+            .await
+            .map_err(|_| AuthError::MissingCredentials)?;
+        bearer.token().to_string()
+    } else {
+        let token = cookie_value(&parts.headers, ACCESS_TOKEN_COOKIE)
+            .ok_or(AuthError::MissingCredentials)?
+            .to_string();
+        verify_csrf(&parts.method, &parts.headers)?;
+        token
+    };
+    let claims = JwtClaims::from_token(&token)?;
+    if !accepted.contains(&claims.token_type) {
+        return Err(AuthError::InvalidToken);
+    }
+    if claims.is_expired() {
+        return Err(AuthError::TokenExpired);
+    }
+    // 失効チェック（サービス未登録の場合は安全側に倒して拒否）
+    let services = auth_services(parts)?;
+    if !services.session_validator.is_active(&claims).await? {
+        return Err(AuthError::TokenRevoked);
+    }
+    if let (Some(impersonator), Some(audit_log)) = (claims.impersonator(), &services.audit_log) {
+        // ネストしたルーターではプレフィックスが外れるため、元のパスを記録する
+        let path = parts
+            .extensions
+            .get::<OriginalUri>()
+            .map(|uri| uri.0.path())
+            .unwrap_or(parts.uri.path());
+        let event = AuditEvent::new(
+            "impersonation.request",
+            Some(impersonator.to_string()),
+            format!("user:{}", claims.sub),
+            Some(format!("{} {}", parts.method, path)),
+        );
+        if let Err(e) = audit_log.record(&event).await {
+            println!("AuthenticatedUser: Failed to write audit log: {}", e);
+        }
+    }
+    Ok(claims)
+}
+
 #[async_trait]
 impl<S> FromRequestParts<S> for AuthenticatedUser
 where
     S: Send + Sync,
 {
     type Rejection = AuthError;
-    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
-        let TypedHeader(Authorization(bearer)) = parts
-            .extract::<TypedHeader<Authorization<Bearer>>>()
-            .await
-            .map_err(|_| AuthError::MissingCredentials)?;
-        let claims = JwtClaims::from_token(bearer.token())?;
-        if claims.is_expired() {
-            return Err(AuthError::TokenExpired);
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        if !parts.headers.contains_key(AUTHORIZATION) && parts.headers.contains_key(API_KEY_HEADER)
+        {
+            let ApiKeyAuth(claims) = ApiKeyAuth::from_request_parts(parts, state).await?;
+            return Ok(AuthenticatedUser(claims));
         }
-        Ok(AuthenticatedUser(claims))
+        if !parts.headers.contains_key(AUTHORIZATION)
+            && cookie_value(&parts.headers, ACCESS_TOKEN_COOKIE).is_none()
+            && let Some(certificate) = parts.extensions.get::<ClientCertificate>().cloned()
+            && let Some(authenticator) = auth_services(parts)?.client_cert_authenticator
+        {
+            return authenticator
+                .authenticate(&certificate)
+                .await
+                .map(AuthenticatedUser);
+        }
+        authenticate_bearer(parts, &[TokenType::Access])
+            .await
+            .map(AuthenticatedUser)
     }
 }
 
-// =============================================================================
-// Role-based Extractors
-// =============================================================================
-
+/// スーパー管理者のログインセッションのみを受け付けるExtractor
+///
+/// 権限ポリシーでは他のロールに委ねられない操作（なりすましなど）に使う。
+/// APIキー・OAuthクライアント・なりすましのトークンは受け付けない。
 #[derive(Debug, Clone)]
-pub struct AdminUser(pub JwtClaims);
+pub struct SuperAdminUser(pub JwtClaims);
 
 #[async_trait]
-impl<S> FromRequestParts<S> for AdminUser
+impl<S> FromRequestParts<S> for SuperAdminUser
 where
     S: Send + Sync,
 {
     type Rejection = AuthError;
-    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
-        let AuthenticatedUser(claims) = AuthenticatedUser::from_request_parts(parts, state).await?;
-        if !claims.has_role("admin") {
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let claims = authenticate_bearer(parts, &[TokenType::Access]).await?;
+        if claims.role() != Some(Role::SuperAdmin)
+            || claims.client_id.is_some()
+            || claims.act.is_some()
+        {
             return Err(AuthError::InsufficientPermissions);
         }
-        Ok(AdminUser(claims))
+        Ok(SuperAdminUser(claims))
     }
 }
 
+/// `X-API-Key`ヘッダーのAPIキーのみを受け付けるExtractor
 #[derive(Debug, Clone)]
-pub struct SuperAdminUser(pub JwtClaims);
+pub struct ApiKeyAuth(pub JwtClaims);
 
 #[async_trait]
-impl<S> FromRequestParts<S> for SuperAdminUser
+impl<S> FromRequestParts<S> for ApiKeyAuth
+where
+    S: Send + Sync,
+{
+    type Rejection = AuthError;
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let api_key = parts
+            .headers
+            .get(API_KEY_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .ok_or(AuthError::MissingCredentials)?
+            .to_string();
+        let authenticator = auth_services(parts)?
+            .api_key_authenticator
+            .ok_or(AuthError::InvalidToken)?;
+        authenticator.authenticate(&api_key).await.map(ApiKeyAuth)
+    }
+}
+
+/// 二要素認証待ちトークン（`mfa_pending`）のみを受け付けるExtractor
+#[derive(Debug, Clone)]
+pub struct MfaPendingUser(pub JwtClaims);
+
+#[async_trait]
+impl<S> FromRequestParts<S> for MfaPendingUser
+where
+    S: Send + Sync,
+{
+    type Rejection = AuthError;
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        authenticate_bearer(parts, &[TokenType::MfaPending])
+            .await
+            .map(MfaPendingUser)
+    }
+}
+
+/// 二要素認証の登録用Extractor
+///
+/// アクセストークンに加え、登録必須ロールがログイン途中で登録できるよう`mfa_pending`も受け付ける
+#[derive(Debug, Clone)]
+pub struct MfaEnrollmentUser(pub JwtClaims);
+
+#[async_trait]
+impl<S> FromRequestParts<S> for MfaEnrollmentUser
+where
+    S: Send + Sync,
+{
+    type Rejection = AuthError;
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        authenticate_bearer(parts, &[TokenType::Access, TokenType::MfaPending])
+            .await
+            .map(MfaEnrollmentUser)
+    }
+}
+
+// =============================================================================
+// Permission-based Extractor
+// =============================================================================
+
+/// `RequirePermission<P>`で要求する権限を型で表すマーカー
+pub trait PermissionMarker: Send + Sync + 'static {
+    const PERMISSION: Permission;
+}
+
+macro_rules! permission_markers {
+    ($($(#[$meta:meta])* $name:ident => $permission:expr),* $(,)?) => {
+        $(
+            $(#[$meta])*
+            #[derive(Debug, Clone, Copy)]
+            pub struct $name;
+            impl PermissionMarker for $name {
+                const PERMISSION: Permission = $permission;
+            }
+        )*
+    };
+}
+
+/// `RequirePermission`用の権限マーカー
+pub mod permissions {
+    use super::PermissionMarker;
+    use crate::domain::value_object::permission::Permission;
+
+    permission_markers! {
+        /// users:read
+        UsersRead => Permission::UsersRead,
+        /// users:write
+        UsersWrite => Permission::UsersWrite,
+        /// users:delete
+        UsersDelete => Permission::UsersDelete,
+        /// sessions:revoke
+        SessionsRevoke => Permission::SessionsRevoke,
+        /// api_keys:manage
+        ApiKeysManage => Permission::ApiKeysManage,
+        /// oauth_clients:manage
+        OAuthClientsManage => Permission::OAuthClientsManage,
+    }
+}
+
+/// ロールがポリシー上で権限`P`を持つ場合のみ通す認証Extractor
+#[derive(Debug, Clone)]
+pub struct RequirePermission<P: PermissionMarker> {
+    pub claims: JwtClaims,
+    _permission: PhantomData<P>,
+}
+
+#[async_trait]
+impl<S, P> FromRequestParts<S> for RequirePermission<P>
 where
     S: Send + Sync,
+    P: PermissionMarker,
 {
     type Rejection = AuthError;
     async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
         let AuthenticatedUser(claims) = AuthenticatedUser::from_request_parts(parts, state).await?;
-        if claims.role != "superadmin" {
+        let services = parts
+            .extensions
+            .get::<AuthServices>()
+            .ok_or(AuthError::Internal)?;
+        if !services.is_allowed(&claims, P::PERMISSION) {
             return Err(AuthError::InsufficientPermissions);
         }
-        Ok(SuperAdminUser(claims))
+        Ok(RequirePermission {
+            claims,
+            _permission: PhantomData,
+        })
     }
 }
 
@@ -252,40 +971,116 @@ where
 // JWT Service Implementation
 // =============================================================================
 
+/// リフレッシュトークンの有効期間（日）
+pub const REFRESH_TOKEN_EXPIRATION_DAYS: i64 = 30;
+
+/// セッションに紐づけて発行したトークンペア
+#[derive(Debug, Clone)]
+pub struct IssuedTokenPair {
+    pub access_token: String,
+    pub refresh_token: String,
+    pub refresh_claims: JwtClaims,
+}
+
+/// トークンの発行
+///
+/// ユーザーのトークンペアは`issue_session_tokens`でセッションに紐づけてのみ発行し、
+/// 更新はリフレッシュトークンのローテーション（再利用検知付き）を通す
 #[derive(Clone)]
 pub struct JwtService;
 
+impl Default for JwtService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl JwtService {
     pub fn new() -> Self {
         Self
     }
-    pub fn generate_token_pair(
+    /// アクセストークンと同じ主体・セッションのリフレッシュトークン用クレーム
+    fn refresh_claims_for(access_claims: &JwtClaims) -> JwtClaims {
+        let refresh_exp =
+            chrono::Utc::now() + chrono::Duration::days(REFRESH_TOKEN_EXPIRATION_DAYS);
+        JwtClaims {
+            exp: refresh_exp.timestamp(),
+            jti: Uuid::new_v4().to_string(),
+            token_type: TokenType::Refresh,
+            ..access_claims.clone()
+        }
+    }
+    /// セッションID（`sid`）付きのトークンペアを発行する（OAuthクライアント経由の場合は委任内容も含める）
+    pub fn issue_session_tokens(
         &self,
         user_id: String,
         email: String,
         name: String,
         role: String,
-    ) -> Result<(String, String), AuthError> {
-        let access_claims = JwtClaims::new(user_id.clone(), email.clone(), name, role);
+        session_id: &str,
+        grant: Option<&ClientGrant>,
+    ) -> Result<IssuedTokenPair, AuthError> {
+        let mut access_claims = JwtClaims::new(user_id, email, name, role);
+        access_claims.sid = Some(session_id.to_string());
+        if let Some(grant) = grant {
+            access_claims.client_id = Some(grant.client_id.clone());
+            access_claims.scope = Some(grant.scope.clone());
+        }
         let access_token = access_claims.to_token()?;
-        let mut refresh_claims =
-            JwtClaims::new(user_id, email, "refresh".to_string(), "refresh".to_string());
-        let refresh_exp = chrono::Utc::now() + chrono::Duration::days(30);
-        refresh_claims.exp = refresh_exp.timestamp();
+        let refresh_claims = Self::refresh_claims_for(&access_claims);
         let refresh_token = refresh_claims.to_token()?;
-        Ok((access_token, refresh_token))
+        Ok(IssuedTokenPair {
+            access_token,
+            refresh_token,
+            refresh_claims,
+        })
     }
-    pub fn refresh_access_token(
+    /// OAuthクライアント向けのアクセストークンを発行する（セッション・リフレッシュトークンなし）
+    pub fn issue_client_access_token(
         &self,
-        refresh_token: &str,
+        user_id: String,
+        email: String,
         name: String,
         role: String,
-    ) -> Result<String, AuthError> {
-        let refresh_claims = JwtClaims::from_token(refresh_token)?;
-        if refresh_claims.role != "refresh" || refresh_claims.is_expired() {
-            return Err(AuthError::InvalidToken);
-        }
-        let access_claims = JwtClaims::new(refresh_claims.sub, refresh_claims.email, name, role);
-        access_claims.to_token()
+        grant: &ClientGrant,
+    ) -> Result<(String, JwtClaims), AuthError> {
+        let mut claims = JwtClaims::new(user_id, email, name, role);
+        claims.client_id = Some(grant.client_id.clone());
+        claims.scope = Some(grant.scope.clone());
+        let token = claims.to_token()?;
+        Ok((token, claims))
+    }
+    /// なりすましトークンを発行する（`act`付き、セッション・リフレッシュトークンなし）
+    pub fn issue_impersonation_token(
+        &self,
+        user_id: String,
+        email: String,
+        name: String,
+        role: String,
+        impersonator_id: String,
+        ttl: chrono::Duration,
+    ) -> Result<(String, JwtClaims), AuthError> {
+        let mut claims = JwtClaims::new(user_id, email, name, role);
+        claims.exp = claims.iat + ttl.num_seconds();
+        claims.act = Some(ActorClaim {
+            sub: impersonator_id,
+        });
+        let token = claims.to_token()?;
+        Ok((token, claims))
+    }
+    /// 二要素認証待ちトークンを発行する（セッションには紐づけない）
+    pub fn issue_mfa_pending_token(
+        &self,
+        user_id: String,
+        email: String,
+        name: String,
+        role: String,
+        ttl: chrono::Duration,
+    ) -> Result<(String, JwtClaims), AuthError> {
+        let mut claims = JwtClaims::new(user_id, email, name, role);
+        claims.exp = claims.iat + ttl.num_seconds();
+        claims.token_type = TokenType::MfaPending;
+        let token = claims.to_token()?;
+        Ok((token, claims))
     }
 }
@@ -0,0 +1,71 @@
+//shared/middleware/client_cert_middleware.rs
+// mTLSのクライアント証明書による認証
+// 2025/7/8
+
+use crate::shared::middleware::auth_middleware::AuthError;
+use axum::async_trait;
+use axum::extract::FromRequestParts;
+use axum::http::request::Parts;
+use sha2::{Digest, Sha256};
+use x509_parser::prelude::{FromDer, X509Certificate};
+
+/// 検証済みのクライアント証明書
+///
+/// TLSリスナーがハンドシェイクで検証できた証明書のみをリクエストに付けるため、
+/// ヘッダーなどで偽装することはできない
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientCertificate {
+    /// サブジェクトの識別名（例: `CN=billing-service, O=Example`）
+    pub subject: String,
+    /// サブジェクトのCN（ない場合はNone）
+    pub common_name: Option<String>,
+    /// シリアル番号（`:`区切りの16進）
+    pub serial: String,
+    /// 証明書（DER）のSHA-256フィンガープリント（16進）
+    pub fingerprint_sha256: String,
+}
+
+impl ClientCertificate {
+    /// DER形式の証明書から組み立てる（解析できない場合はNone）
+    pub fn from_der(der: &[u8]) -> Option<Self> {
+        let (_, certificate) = X509Certificate::from_der(der).ok()?;
+        let subject = certificate.subject();
+        Some(Self {
+            subject: subject.to_string(),
+            common_name: subject
+                .iter_common_name()
+                .next()
+                .and_then(|cn| cn.as_str().ok())
+                .map(str::to_string),
+            serial: certificate.tbs_certificate.raw_serial_as_string(),
+            fingerprint_sha256: format!("{:x}", Sha256::digest(der)),
+        })
+    }
+
+    /// 主体の識別子（CNがあればCN、なければ識別名全体）
+    pub fn principal(&self) -> &str {
+        self.common_name.as_deref().unwrap_or(&self.subject)
+    }
+}
+
+/// クライアント証明書で認証された主体のExtractor
+///
+/// mTLSのリスナー経由で、検証済みの証明書を提示した接続のみ受け付ける
+#[derive(Debug, Clone)]
+pub struct ClientCertAuth(pub ClientCertificate);
+
+#[async_trait]
+impl<S> FromRequestParts<S> for ClientCertAuth
+where
+    S: Send + Sync,
+{
+    type Rejection = AuthError;
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        parts
+            .extensions
+            .get::<ClientCertificate>()
+            .cloned()
+            .map(ClientCertAuth)
+            .ok_or(AuthError::MissingCredentials)
+    }
+}
@@ -0,0 +1,53 @@
+//shared/middleware/client_ip_middleware.rs
+// 接続元IPアドレスの取得
+// 2025/7/8
+
+use crate::infrastructure::config::app_config::AppConfig;
+use axum::async_trait;
+use axum::extract::{ConnectInfo, FromRequestParts};
+use axum::http::request::Parts;
+use std::convert::Infallible;
+use std::net::{IpAddr, SocketAddr};
+
+/// 接続元IPアドレス
+///
+/// 通常はTCP接続のアドレス（`ConnectInfo`）を使う。リバースプロキシ配下で
+/// `TRUST_FORWARDED_FOR=true`の場合のみ、`X-Forwarded-For`の末尾（直前のプロキシが付与した値）を使う。
+/// どちらも取得できない場合はNone。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClientIp(pub Option<IpAddr>);
+
+impl ClientIp {
+    fn from_forwarded_for(parts: &Parts) -> Option<IpAddr> {
+        parts
+            .headers
+            .get("x-forwarded-for")?
+            .to_str()
+            .ok()?
+            .rsplit(',')
+            .next()?
+            .trim()
+            .parse()
+            .ok()
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for ClientIp
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        if AppConfig::current().client_ip.trust_forwarded_for
+            && let Some(ip) = Self::from_forwarded_for(parts)
+        {
+            return Ok(ClientIp(Some(ip)));
+        }
+        let connected = parts
+            .extensions
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| addr.ip());
+        Ok(ClientIp(connected))
+    }
+}
@@ -15,4 +15,6 @@ pub fn build_cors_layer() -> CorsLayer {
         .allow_origin(origins)
         .allow_methods(cors_settings::allowed_methods())
         .allow_headers(cors_settings::allowed_headers())
+        // Cookieセッションの資格情報を送らせる（許可するオリジンは明示したもののみ）
+        .allow_credentials(true)
 }
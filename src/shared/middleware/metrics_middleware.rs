@@ -1,3 +1,25 @@
 //shared/middleware/metrics_middleware.rs
 // メトリクス収集ミドルウェア
 // 2025/7/8
+
+use crate::shared::metrics::collector::MetricsCollector;
+use axum::{
+    extract::{Request, State},
+    middleware::Next,
+    response::Response,
+};
+use std::sync::Arc;
+use std::time::Instant;
+
+/// リクエスト数・処理中のリクエスト数・ステータスコード・処理時間を記録する
+pub async fn metrics_middleware(
+    State(collector): State<Arc<MetricsCollector>>,
+    req: Request,
+    next: Next,
+) -> Response {
+    let started = Instant::now();
+    collector.start_request();
+    let response = next.run(req).await;
+    collector.end_request(response.status().as_u16(), started.elapsed());
+    response
+}
@@ -0,0 +1,182 @@
+//shared/middleware/session_cookie_middleware.rs
+// ブラウザ向けCookieセッション（HttpOnly Cookie + ダブルサブミットCSRF）
+// 2025/7/8
+
+use crate::infrastructure::config::app_config::AppConfig;
+use crate::shared::middleware::auth_middleware::{AuthError, REFRESH_TOKEN_EXPIRATION_DAYS};
+use crate::shared::utils::secure_token::{constant_time_eq, generate_token};
+use axum::async_trait;
+use axum::extract::FromRequestParts;
+use axum::http::header::{AUTHORIZATION, COOKIE, SET_COOKIE};
+use axum::http::request::Parts;
+use axum::http::{HeaderMap, HeaderValue, Method};
+use axum::response::Response;
+use std::convert::Infallible;
+
+/// アクセストークンのCookie名
+pub const ACCESS_TOKEN_COOKIE: &str = "access_token";
+/// リフレッシュトークンのCookie名
+pub const REFRESH_TOKEN_COOKIE: &str = "refresh_token";
+/// CSRFトークンのCookie名（JavaScriptから読めるようHttpOnlyは付けない）
+pub const CSRF_COOKIE: &str = "csrf_token";
+/// CSRFトークンを送り返すヘッダー名
+pub const CSRF_HEADER: &str = "x-csrf-token";
+/// ログイン時にCookieセッションを要求するヘッダー名（値は`cookie`）
+pub const SESSION_MODE_HEADER: &str = "x-session-mode";
+
+/// リフレッシュトークンのCookieは認証エンドポイントにのみ送らせる
+const REFRESH_TOKEN_COOKIE_PATH: &str = "/api/auth";
+
+/// `Cookie`ヘッダーから指定した名前の値を取り出す（空の値は無視する）
+pub fn cookie_value<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers
+        .get_all(COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(';'))
+        .filter_map(|pair| pair.trim().split_once('='))
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| value.trim())
+        .filter(|value| !value.is_empty())
+}
+
+/// トークンの受け渡し方法
+///
+/// `X-Session-Mode: cookie`を指定した場合、または`Authorization`ヘッダーがなく
+/// アクセストークンのCookieがある場合はCookieセッションとして扱う
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionMode {
+    Bearer,
+    Cookie,
+}
+
+impl SessionMode {
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        let requested = headers
+            .get(SESSION_MODE_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|value| value.trim().eq_ignore_ascii_case("cookie"));
+        let has_cookie_session = !headers.contains_key(AUTHORIZATION)
+            && cookie_value(headers, ACCESS_TOKEN_COOKIE).is_some();
+        if requested || has_cookie_session {
+            SessionMode::Cookie
+        } else {
+            SessionMode::Bearer
+        }
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for SessionMode
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(SessionMode::from_headers(&parts.headers))
+    }
+}
+
+/// Cookieの資格情報で送られた状態を変更するリクエストのCSRFトークンを検証する
+///
+/// CSRFトークンのCookieと`X-CSRF-Token`ヘッダーが一致する場合のみ通す（ダブルサブミット）。
+/// GET・HEAD・OPTIONSは検証しない。
+pub fn verify_csrf(method: &Method, headers: &HeaderMap) -> Result<(), AuthError> {
+    if matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS) {
+        return Ok(());
+    }
+    let cookie = cookie_value(headers, CSRF_COOKIE);
+    let header = headers
+        .get(CSRF_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim);
+    match (cookie, header) {
+        (Some(cookie), Some(header)) if constant_time_eq(cookie, header) => Ok(()),
+        _ => Err(AuthError::CsrfTokenInvalid),
+    }
+}
+
+/// ログイン・リフレッシュ時に設定するCookie
+pub struct SessionCookies {
+    /// 応答本文でも返すCSRFトークン
+    pub csrf_token: String,
+    set_cookies: Vec<HeaderValue>,
+}
+
+impl SessionCookies {
+    /// アクセストークン・リフレッシュトークン・新しいCSRFトークンのCookieを組み立てる
+    pub fn issue(access_token: &str, access_max_age_secs: i64, refresh_token: &str) -> Self {
+        let refresh_max_age_secs = REFRESH_TOKEN_EXPIRATION_DAYS * 24 * 60 * 60;
+        let csrf_token = generate_token();
+        let set_cookies = vec![
+            build_cookie(
+                ACCESS_TOKEN_COOKIE,
+                access_token,
+                "/",
+                access_max_age_secs,
+                true,
+            ),
+            build_cookie(
+                REFRESH_TOKEN_COOKIE,
+                refresh_token,
+                REFRESH_TOKEN_COOKIE_PATH,
+                refresh_max_age_secs,
+                true,
+            ),
+            build_cookie(CSRF_COOKIE, &csrf_token, "/", refresh_max_age_secs, false),
+        ];
+        Self {
+            csrf_token,
+            set_cookies,
+        }
+    }
+
+    /// ログアウト時に全てのCookieを削除する
+    pub fn clear() -> Self {
+        Self {
+            csrf_token: String::new(),
+            set_cookies: vec![
+                build_cookie(ACCESS_TOKEN_COOKIE, "", "/", 0, true),
+                build_cookie(REFRESH_TOKEN_COOKIE, "", REFRESH_TOKEN_COOKIE_PATH, 0, true),
+                build_cookie(CSRF_COOKIE, "", "/", 0, false),
+            ],
+        }
+    }
+
+    /// レスポンスに`Set-Cookie`ヘッダーを追加する
+    pub fn apply(self, mut response: Response) -> Response {
+        for cookie in self.set_cookies {
+            response.headers_mut().append(SET_COOKIE, cookie);
+        }
+        response
+    }
+}
+
+fn build_cookie(
+    name: &str,
+    value: &str,
+    path: &str,
+    max_age_secs: i64,
+    http_only: bool,
+) -> HeaderValue {
+    let config = &AppConfig::current().session_cookie;
+    let mut cookie = format!(
+        "{}={}; Path={}; Max-Age={}; SameSite={}",
+        name,
+        value,
+        path,
+        max_age_secs.max(0),
+        config.same_site.as_str()
+    );
+    if let Some(domain) = &config.domain {
+        cookie.push_str(&format!("; Domain={}", domain));
+    }
+    if config.secure {
+        cookie.push_str("; Secure");
+    }
+    if http_only {
+        cookie.push_str("; HttpOnly");
+    }
+    // トークンはJWT・URL安全なBase64、ドメインは設定読み込み時に検証済みのため常に有効
+    HeaderValue::from_str(&cookie).expect("cookie values are ASCII without control characters")
+}
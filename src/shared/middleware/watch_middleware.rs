@@ -2,11 +2,17 @@
 // 軽量ログ・メトリクス収集ミドルウェア
 // 2025/1/27
 
-use axum::{body::Body, extract::Request, http::Response, middleware::Next};
+use crate::shared::middleware::auth_middleware::JwtClaims;
+use axum::{
+    body::Body,
+    extract::{Request, State},
+    http::{Response, header::AUTHORIZATION},
+    middleware::Next,
+};
 use chrono::{DateTime, Timelike, Utc};
 use serde::Serialize;
 use std::collections::HashMap;
-use std::time::Instant;
+use std::sync::Arc;
 use tokio::fs::OpenOptions;
 use tokio::io::AsyncWriteExt;
 
@@ -40,6 +46,27 @@ fn generate_lightweight_id() -> String {
     format!("req_{:06}", rng.gen_range(0..999999))
 }
 
+/// なりすましトークンのリクエストには両方の主体を付ける（署名を検証できたトークンのみ）
+fn impersonation_context(req: &Request<Body>) -> HashMap<String, String> {
+    let mut context = HashMap::new();
+    let claims = req
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .and_then(|token| JwtClaims::from_token(token.trim()).ok());
+    if let Some(JwtClaims {
+        sub,
+        act: Some(act),
+        ..
+    }) = claims
+    {
+        context.insert("user_id".to_string(), sub);
+        context.insert("impersonator_id".to_string(), act.sub);
+    }
+    context
+}
+
 /// ログファイルマネージャー
 struct LogFileManager {
     base_path: String,
@@ -102,9 +129,13 @@ async fn save_log_async(
     Ok(())
 }
 
-/// 軽量ログ・メトリクス収集ミドルウェア（本番用: ./logs固定）
-pub async fn watch_middleware(req: Request<Body>, next: Next) -> Response<Body> {
-    watch_middleware_with_base_path(req, next, "./logs").await
+/// 軽量ログ・メトリクス収集ミドルウェア（出力先は`LOG_DIR`、既定: ./logs）
+pub async fn watch_middleware(
+    State(base_path): State<Arc<str>>,
+    req: Request<Body>,
+    next: Next,
+) -> Response<Body> {
+    watch_middleware_with_base_path(req, next, &base_path).await
 }
 
 /// テスト用: base_pathを指定できるミドルウェア
@@ -113,7 +144,6 @@ pub async fn watch_middleware_with_base_path(
     next: Next,
     base_path: &str,
 ) -> Response<Body> {
-    let start_time = Instant::now();
     let _request_id = generate_lightweight_id();
 
     // 最小限の情報のみ収集
@@ -122,6 +152,7 @@ pub async fn watch_middleware_with_base_path(
         path: req.uri().path().to_string(),
         timestamp: Utc::now(),
     };
+    let context = impersonation_context(&req);
 
     // レスポンス取得
     let response = next.run(req).await;
@@ -140,7 +171,7 @@ pub async fn watch_middleware_with_base_path(
             request_info.path,
             response.status()
         ),
-        context: HashMap::new(),
+        context,
     };
 
     // 非同期でログ保存（レスポンスをブロックしない）
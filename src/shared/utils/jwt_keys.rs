@@ -0,0 +1,213 @@
+//shared/utils/jwt_keys.rs
+// JWT署名鍵・検証鍵の読み込み（PEM / JWK変換）
+// 2025/7/8
+
+use crate::shared::error::infrastructure_error::{InfrastructureError, InfrastructureResult};
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use jsonwebtoken::jwk::{
+    AlgorithmParameters, CommonParameters, EllipticCurve, EllipticCurveKeyParameters,
+    EllipticCurveKeyType, Jwk, KeyAlgorithm, OctetKeyPairParameters, OctetKeyPairType,
+    PublicKeyUse, RSAKeyParameters, RSAKeyType,
+};
+use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey};
+use x509_parser::oid_registry::{
+    OID_EC_P256, OID_KEY_TYPE_EC_PUBLIC_KEY, OID_PKCS1_RSAENCRYPTION, OID_SIG_ED25519,
+};
+use x509_parser::prelude::FromDer;
+use x509_parser::public_key::PublicKey;
+use x509_parser::x509::SubjectPublicKeyInfo;
+
+/// トークン検証に使う鍵（`kid`で選択される）
+#[derive(Clone)]
+pub struct VerificationKey {
+    pub kid: String,
+    pub algorithm: Algorithm,
+    pub decoding_key: DecodingKey,
+    /// 公開可能な鍵のJWK表現（共通鍵の場合はNone）
+    pub jwk: Option<Jwk>,
+}
+
+impl VerificationKey {
+    /// 共通鍵（HS256）の検証鍵
+    pub fn from_secret(kid: String, secret: &[u8]) -> Self {
+        Self {
+            kid,
+            algorithm: Algorithm::HS256,
+            decoding_key: DecodingKey::from_secret(secret),
+            jwk: None,
+        }
+    }
+
+    /// 公開鍵PEM（SubjectPublicKeyInfo）から検証鍵を作成する
+    ///
+    /// アルゴリズムは鍵の種類から決定する（RSA→RS256, P-256→ES256, Ed25519→EdDSA）。
+    pub fn from_public_pem(kid: String, pem: &[u8]) -> InfrastructureResult<Self> {
+        let (_, pem) = x509_parser::pem::parse_x509_pem(pem)
+            .map_err(|e| key_error(&kid, format!("invalid PEM: {}", e)))?;
+        if pem.label != "PUBLIC KEY" {
+            return Err(key_error(
+                &kid,
+                format!("expected a PUBLIC KEY PEM, found {}", pem.label),
+            ));
+        }
+        let (_, spki) = SubjectPublicKeyInfo::from_der(&pem.contents)
+            .map_err(|e| key_error(&kid, format!("invalid public key: {}", e)))?;
+
+        let (algorithm, parameters) = if spki.algorithm.algorithm == OID_PKCS1_RSAENCRYPTION {
+            let Ok(PublicKey::RSA(rsa)) = spki.parsed() else {
+                return Err(key_error(&kid, "invalid RSA public key".to_string()));
+            };
+            (
+                Algorithm::RS256,
+                AlgorithmParameters::RSA(RSAKeyParameters {
+                    key_type: RSAKeyType::RSA,
+                    n: URL_SAFE_NO_PAD.encode(strip_leading_zeros(rsa.modulus)),
+                    e: URL_SAFE_NO_PAD.encode(strip_leading_zeros(rsa.exponent)),
+                }),
+            )
+        } else if spki.algorithm.algorithm == OID_KEY_TYPE_EC_PUBLIC_KEY {
+            let curve = spki
+                .algorithm
+                .parameters
+                .as_ref()
+                .and_then(|p| p.as_oid().ok());
+            if curve != Some(OID_EC_P256) {
+                return Err(key_error(
+                    &kid,
+                    "only the P-256 curve is supported".to_string(),
+                ));
+            }
+            // 非圧縮形式: 0x04 || X(32) || Y(32)
+            let point = &spki.subject_public_key.data;
+            if point.len() != 65 || point[0] != 0x04 {
+                return Err(key_error(&kid, "invalid P-256 public key".to_string()));
+            }
+            (
+                Algorithm::ES256,
+                AlgorithmParameters::EllipticCurve(EllipticCurveKeyParameters {
+                    key_type: EllipticCurveKeyType::EC,
+                    curve: EllipticCurve::P256,
+                    x: URL_SAFE_NO_PAD.encode(&point[1..33]),
+                    y: URL_SAFE_NO_PAD.encode(&point[33..65]),
+                }),
+            )
+        } else if spki.algorithm.algorithm == OID_SIG_ED25519 {
+            let point = &spki.subject_public_key.data;
+            if point.len() != 32 {
+                return Err(key_error(&kid, "invalid Ed25519 public key".to_string()));
+            }
+            (
+                Algorithm::EdDSA,
+                AlgorithmParameters::OctetKeyPair(OctetKeyPairParameters {
+                    key_type: OctetKeyPairType::OctetKeyPair,
+                    curve: EllipticCurve::Ed25519,
+                    x: URL_SAFE_NO_PAD.encode(point.as_ref()),
+                }),
+            )
+        } else {
+            return Err(key_error(
+                &kid,
+                format!("unsupported key type {}", spki.algorithm.algorithm),
+            ));
+        };
+
+        let jwk = Jwk {
+            common: CommonParameters {
+                public_key_use: Some(PublicKeyUse::Signature),
+                key_algorithm: Some(key_algorithm(algorithm)),
+                key_id: Some(kid.clone()),
+                ..Default::default()
+            },
+            algorithm: parameters,
+        };
+        let decoding_key =
+            DecodingKey::from_jwk(&jwk).map_err(|e| key_error(&kid, e.to_string()))?;
+        Ok(Self {
+            kid,
+            algorithm,
+            decoding_key,
+            jwk: Some(jwk),
+        })
+    }
+
+    /// 公開鍵PEMファイルから検証鍵を読み込む
+    pub fn from_public_pem_file(kid: String, path: &str) -> InfrastructureResult<Self> {
+        let pem = read_key_file(&kid, path)?;
+        Self::from_public_pem(kid, &pem)
+    }
+}
+
+/// 秘密鍵PEM（PKCS#8、RSAはPKCS#1も可）から署名鍵を作成する
+pub fn encoding_key_from_private_pem(
+    kid: &str,
+    algorithm: Algorithm,
+    pem: &[u8],
+) -> InfrastructureResult<EncodingKey> {
+    let result = match algorithm {
+        Algorithm::RS256 => EncodingKey::from_rsa_pem(pem),
+        Algorithm::ES256 => EncodingKey::from_ec_pem(pem),
+        Algorithm::EdDSA => EncodingKey::from_ed_pem(pem),
+        other => {
+            return Err(key_error(
+                kid,
+                format!("{:?} is not an asymmetric algorithm", other),
+            ));
+        }
+    };
+    result.map_err(|e| key_error(kid, format!("invalid private key: {}", e)))
+}
+
+/// 秘密鍵PEMファイルから署名鍵を読み込む
+pub fn encoding_key_from_private_pem_file(
+    kid: &str,
+    algorithm: Algorithm,
+    path: &str,
+) -> InfrastructureResult<EncodingKey> {
+    let pem = read_key_file(kid, path)?;
+    encoding_key_from_private_pem(kid, algorithm, &pem)
+}
+
+/// 設定値からアルゴリズムを解釈する
+pub fn parse_algorithm(value: &str) -> InfrastructureResult<Algorithm> {
+    match value.to_ascii_uppercase().as_str() {
+        "HS256" => Ok(Algorithm::HS256),
+        "RS256" => Ok(Algorithm::RS256),
+        "ES256" => Ok(Algorithm::ES256),
+        "EDDSA" => Ok(Algorithm::EdDSA),
+        _ => Err(InfrastructureError::Configuration {
+            key: "JWT_ALGORITHM".to_string(),
+            message: format!(
+                "unsupported algorithm '{}' (expected HS256, RS256, ES256 or EdDSA)",
+                value
+            ),
+        }),
+    }
+}
+
+fn key_algorithm(algorithm: Algorithm) -> KeyAlgorithm {
+    match algorithm {
+        Algorithm::RS256 => KeyAlgorithm::RS256,
+        Algorithm::ES256 => KeyAlgorithm::ES256,
+        _ => KeyAlgorithm::EdDSA,
+    }
+}
+
+fn strip_leading_zeros(bytes: &[u8]) -> &[u8] {
+    let start = bytes
+        .iter()
+        .position(|b| *b != 0)
+        .unwrap_or(bytes.len().saturating_sub(1));
+    &bytes[start..]
+}
+
+fn read_key_file(kid: &str, path: &str) -> InfrastructureResult<Vec<u8>> {
+    std::fs::read(path).map_err(|e| key_error(kid, format!("cannot read {}: {}", path, e)))
+}
+
+fn key_error(kid: &str, message: String) -> InfrastructureError {
+    InfrastructureError::Configuration {
+        key: format!("jwt key '{}'", kid),
+        message,
+    }
+}
@@ -1,3 +1,145 @@
 //shared/utils/password_hasher.rs
-// パスワードハッシュ化
+// パスワードハッシュ化サービス（Argon2id / PHC文字列）
 // 2025/7/8
+
+use crate::domain::value_object::{password::Password, password_hash::PasswordHash};
+use crate::infrastructure::config::app_config::PasswordHashConfig;
+use crate::shared::error::infrastructure_error::{InfrastructureError, InfrastructureResult};
+use argon2::password_hash::{
+    PasswordHash as PhcString, PasswordHasher as _, PasswordVerifier, SaltString, rand_core::OsRng,
+};
+use argon2::{Algorithm, Argon2, Params, Version};
+
+/// パスワード照合結果
+#[derive(Debug, Clone, PartialEq)]
+pub enum PasswordVerification {
+    /// パスワード不一致
+    Mismatch,
+    /// 一致（ハッシュは現在のコストパラメータのまま）
+    Match,
+    /// 一致したが、保存済みハッシュのコストが古いため再ハッシュした
+    MatchRehashed(PasswordHash),
+}
+
+impl PasswordVerification {
+    pub fn is_match(&self) -> bool {
+        !matches!(self, PasswordVerification::Mismatch)
+    }
+}
+
+/// Argon2idによるパスワードハッシュ化サービス
+///
+/// 責務:
+/// 1. 平文パスワードをPHC文字列へハッシュ化
+/// 2. 保存済みハッシュとの照合（ブロッキングスレッド上で実行）
+/// 3. コストパラメータ変更時の透過的な再ハッシュ
+#[derive(Clone)]
+pub struct PasswordHasher {
+    argon2: Argon2<'static>,
+    params: Params,
+}
+
+impl PasswordHasher {
+    pub fn new(config: &PasswordHashConfig) -> InfrastructureResult<Self> {
+        let params = Params::new(
+            config.memory_kib,
+            config.iterations,
+            config.parallelism,
+            None,
+        )
+        .map_err(|e| InfrastructureError::Configuration {
+            key: "password_hash".to_string(),
+            message: e.to_string(),
+        })?;
+        Ok(Self {
+            argon2: Argon2::new(Algorithm::Argon2id, Version::V0x13, params.clone()),
+            params,
+        })
+    }
+
+    /// 平文パスワードをハッシュ化する
+    pub async fn hash(&self, password: &Password) -> InfrastructureResult<PasswordHash> {
+        let hasher = self.clone();
+        let plaintext = password.0.clone();
+        tokio::task::spawn_blocking(move || hasher.hash_blocking(&plaintext))
+            .await
+            .map_err(Self::join_error)?
+    }
+
+    /// 平文パスワードと保存済みハッシュを照合する
+    ///
+    /// 一致し、かつハッシュのコストパラメータが現在の設定と異なる場合は
+    /// 新しいハッシュを`PasswordVerification::MatchRehashed`で返す。
+    pub async fn verify(
+        &self,
+        plaintext: &str,
+        hash: &PasswordHash,
+    ) -> InfrastructureResult<PasswordVerification> {
+        let hasher = self.clone();
+        let plaintext = plaintext.to_string();
+        let hash = hash.clone();
+        tokio::task::spawn_blocking(move || {
+            let parsed = PhcString::new(hash.value()).map_err(Self::phc_error)?;
+            if Argon2::default()
+                .verify_password(plaintext.as_bytes(), &parsed)
+                .is_err()
+            {
+                return Ok(PasswordVerification::Mismatch);
+            }
+            if hasher.needs_rehash(&hash) {
+                return Ok(PasswordVerification::MatchRehashed(
+                    hasher.hash_blocking(&plaintext)?,
+                ));
+            }
+            Ok(PasswordVerification::Match)
+        })
+        .await
+        .map_err(Self::join_error)?
+    }
+
+    /// 保存済みハッシュが現在のアルゴリズム・コストパラメータと異なるか
+    pub fn needs_rehash(&self, hash: &PasswordHash) -> bool {
+        let Ok(parsed) = PhcString::new(hash.value()) else {
+            return true;
+        };
+        if parsed.algorithm != Algorithm::Argon2id.ident()
+            || parsed.version != Some(Version::V0x13.into())
+        {
+            return true;
+        }
+        match Params::try_from(&parsed) {
+            Ok(stored) => {
+                stored.m_cost() != self.params.m_cost()
+                    || stored.t_cost() != self.params.t_cost()
+                    || stored.p_cost() != self.params.p_cost()
+            }
+            Err(_) => true,
+        }
+    }
+
+    fn hash_blocking(&self, plaintext: &str) -> InfrastructureResult<PasswordHash> {
+        let salt = SaltString::generate(&mut OsRng);
+        let phc = self
+            .argon2
+            .hash_password(plaintext.as_bytes(), &salt)
+            .map_err(Self::phc_error)?;
+        PasswordHash::new(phc.to_string()).map_err(|e| InfrastructureError::DataSerialization {
+            data_type: "PasswordHash".to_string(),
+            message: e.to_string(),
+        })
+    }
+
+    fn phc_error(e: argon2::password_hash::Error) -> InfrastructureError {
+        InfrastructureError::DataSerialization {
+            data_type: "PasswordHash".to_string(),
+            message: e.to_string(),
+        }
+    }
+
+    fn join_error(e: tokio::task::JoinError) -> InfrastructureError {
+        InfrastructureError::ResourceUnavailable {
+            resource: "password_hasher".to_string(),
+            message: e.to_string(),
+        }
+    }
+}
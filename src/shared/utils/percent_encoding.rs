@@ -0,0 +1,16 @@
+//shared/utils/percent_encoding.rs
+// URLのクエリ・パス要素のパーセントエンコード
+// 2025/7/8
+
+/// RFC 3986の非予約文字以外をパーセントエンコードする
+pub fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
@@ -0,0 +1,45 @@
+//shared/utils/pkce.rs
+// PKCE（RFC 7636）のコードチャレンジ計算・検証
+// 2025/7/8
+
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use sha2::{Digest, Sha256};
+
+/// サポートするコードチャレンジ方式（`plain`は受け付けない）
+pub const CODE_CHALLENGE_METHOD_S256: &str = "S256";
+
+/// `code_verifier`のS256コードチャレンジ
+pub fn s256_challenge(verifier: &str) -> String {
+    URL_SAFE_NO_PAD.encode(Sha256::digest(verifier.as_bytes()))
+}
+
+/// `code_verifier`の形式（43〜128文字の非予約文字）
+pub fn is_valid_verifier(verifier: &str) -> bool {
+    (43..=128).contains(&verifier.len())
+        && verifier
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_' | b'~'))
+}
+
+/// `code_challenge`の形式（SHA-256のBase64URL、パディングなし）
+pub fn is_valid_challenge(challenge: &str) -> bool {
+    challenge.len() == 43
+        && challenge
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_'))
+}
+
+/// `code_verifier`が保存済みのコードチャレンジと一致するか
+pub fn verify_s256(verifier: &str, challenge: &str) -> bool {
+    if !is_valid_verifier(verifier) {
+        return false;
+    }
+    let computed = s256_challenge(verifier);
+    computed.len() == challenge.len()
+        && computed
+            .bytes()
+            .zip(challenge.bytes())
+            .fold(0u8, |acc, (x, y)| acc | (x ^ y))
+            == 0
+}
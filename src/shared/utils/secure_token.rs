@@ -0,0 +1,31 @@
+//shared/utils/secure_token.rs
+// ワンタイムトークンの生成・ハッシュ化
+// 2025/7/8
+
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+
+/// URLに埋め込めるランダムなトークン（256bit）を生成する
+pub fn generate_token() -> String {
+    let mut bytes = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+/// 保存用のトークンハッシュ（SHA-256の16進文字列）
+///
+/// トークン自体が十分なエントロピーを持つため、パスワードと違い低速ハッシュは使わない
+pub fn hash_token(token: &str) -> String {
+    format!("{:x}", Sha256::digest(token.as_bytes()))
+}
+
+/// 長さ以外の情報を処理時間から漏らさない文字列比較
+pub fn constant_time_eq(a: &str, b: &str) -> bool {
+    a.len() == b.len()
+        && a.bytes()
+            .zip(b.bytes())
+            .fold(0u8, |acc, (x, y)| acc | (x ^ y))
+            == 0
+}
@@ -0,0 +1,83 @@
+//shared/utils/totp.rs
+// TOTP（RFC 6238）のシークレット生成・コード計算・照合
+// 2025/7/8
+
+use crate::shared::utils::percent_encoding::percent_encode;
+use crate::shared::utils::secure_token::constant_time_eq;
+use data_encoding::BASE32_NOPAD;
+use hmac::{Hmac, Mac};
+use rand::RngCore;
+use sha1::Sha1;
+
+/// 時間ステップ（秒）
+pub const TOTP_PERIOD_SECS: i64 = 30;
+/// コードの桁数
+pub const TOTP_DIGITS: u32 = 6;
+
+/// 160bitのシークレットを生成する（Base32、パディングなし）
+pub fn generate_secret() -> String {
+    let mut bytes = [0u8; 20];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    BASE32_NOPAD.encode(&bytes)
+}
+
+/// 認証アプリ登録用の`otpauth://` URI
+pub fn provisioning_uri(issuer: &str, account: &str, secret: &str) -> String {
+    format!(
+        "otpauth://totp/{}:{}?secret={}&issuer={}&algorithm=SHA1&digits={}&period={}",
+        percent_encode(issuer),
+        percent_encode(account),
+        secret,
+        percent_encode(issuer),
+        TOTP_DIGITS,
+        TOTP_PERIOD_SECS
+    )
+}
+
+/// UNIX時刻（秒）に対応する時間ステップ
+pub fn time_step(unix_secs: i64) -> i64 {
+    unix_secs.div_euclid(TOTP_PERIOD_SECS)
+}
+
+/// 指定ステップのコード（シークレットがBase32として不正な場合はNone）
+pub fn code_at(secret: &str, step: i64) -> Option<String> {
+    let key = BASE32_NOPAD
+        .decode(secret.trim_end_matches('=').as_bytes())
+        .ok()?;
+    let mut mac = Hmac::<Sha1>::new_from_slice(&key).ok()?;
+    mac.update(&step.to_be_bytes());
+    let digest = mac.finalize().into_bytes();
+    // 動的切り出し（RFC 4226 5.3）
+    let offset = (digest[digest.len() - 1] & 0x0f) as usize;
+    let binary = u32::from_be_bytes([
+        digest[offset] & 0x7f,
+        digest[offset + 1],
+        digest[offset + 2],
+        digest[offset + 3],
+    ]);
+    Some(format!(
+        "{:0width$}",
+        binary % 10u32.pow(TOTP_DIGITS),
+        width = TOTP_DIGITS as usize
+    ))
+}
+
+/// 前後`skew`ステップの範囲でコードを照合し、一致したステップを返す
+///
+/// 呼び出し側は返されたステップを記録し、同じステップ以前のコードの再利用を拒否すること
+pub fn verify_code(secret: &str, code: &str, unix_secs: i64, skew: i64) -> Option<i64> {
+    let code = code.trim();
+    if code.len() != TOTP_DIGITS as usize || !code.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let current = time_step(unix_secs);
+    (current - skew..=current + skew).find(|step| {
+        code_at(secret, *step).is_some_and(|expected| constant_time_eq(&expected, code))
+    })
+}
+
+/// TOTPコードの形式（数字6桁）かどうか
+pub fn looks_like_code(input: &str) -> bool {
+    let input = input.trim();
+    input.len() == TOTP_DIGITS as usize && input.bytes().all(|b| b.is_ascii_digit())
+}
@@ -11,9 +11,17 @@
 use axum::Router;
 use dotenvy::dotenv;
 use reqwest::StatusCode;
-use rusted_ca::infrastructure::config::app_config::DiscordConfig;
+use rusted_ca::domain::repository::audit_log_repository::AuditLogRepositoryInterface;
+use rusted_ca::domain::service::mailer::{EmailMessage, Mailer};
+use rusted_ca::domain::value_object::role::Role;
+use rusted_ca::infrastructure::config::app_config::{
+    AppConfig, BootstrapUserConfig, DiscordConfig, PasswordResetConfig,
+};
 use rusted_ca::infrastructure::di::container::DIContainer;
+use rusted_ca::infrastructure::mail::in_memory_mailer::InMemoryMailer;
 use rusted_ca::presentation::router::app_router::create_app_router;
+use rusted_ca::shared::middleware::auth_middleware::{IdTokenClaims, JwtClaims};
+use rusted_ca::shared::utils::{pkce, totp};
 use serde_json::json;
 use std::sync::Arc;
 use std::time::Duration;
@@ -26,9 +34,12 @@ async fn spawn_test_server(app: Router) -> TestAddr {
     let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
     let addr = listener.local_addr().unwrap();
     tokio::spawn(async move {
-        axum::serve(listener, app.into_make_service())
-            .await
-            .unwrap();
+        axum::serve(
+            listener,
+            app.into_make_service_with_connect_info::<TestAddr>(),
+        )
+        .await
+        .unwrap();
     });
     addr
 }
@@ -52,54 +63,180 @@ fn dummy_discord_config() -> Arc<DiscordConfig> {
     })
 }
 
+// リクエストログはリポジトリのlogs/ではなく一時ディレクトリに書き出す
+fn test_log_dir() -> Arc<str> {
+    Arc::from(
+        std::env::temp_dir()
+            .join("rusted-ca-test-logs")
+            .to_string_lossy()
+            .as_ref(),
+    )
+}
+
+// テスト用の初期ユーザー
+const TEST_EMAIL: &str = "auth_user@example.com";
+const TEST_PASSWORD: &str = "auth_password";
+
+// テスト用の管理者ユーザー
+const ADMIN_EMAIL: &str = "auth_admin@example.com";
+const ADMIN_PASSWORD: &str = "admin_password";
+
+// テスト用のスーパー管理者ユーザー
+const SUPERADMIN_EMAIL: &str = "auth_superadmin@example.com";
+const SUPERADMIN_PASSWORD: &str = "superadmin_password";
+
+// 初期ユーザー（一般ユーザー・管理者）を登録したアプリケーションを組み立てる
+async fn build_test_app() -> Router {
+    build_test_app_with_mailer().await.0
+}
+
+// 送信メールを確認できるアプリケーションを組み立てる
+async fn build_test_app_with_mailer() -> (Router, Arc<InMemoryMailer>) {
+    let (app, mailer, _) = build_test_app_with_container().await;
+    (app, mailer)
+}
+
+// リポジトリを直接確認できるよう、DIコンテナも返す
+async fn build_test_app_with_container() -> (Router, Arc<InMemoryMailer>, DIContainer) {
+    let mailer = Arc::new(InMemoryMailer::new());
+    let di = DIContainer::in_memory().with_mailer(mailer.clone());
+    let app = build_test_app_from(&di).await;
+    (app, mailer, di)
+}
+
+// 設定・メール送信を差し替えたコンテナからアプリケーションを組み立てる
+async fn build_test_app_from(di: &DIContainer) -> Router {
+    di.seed_bootstrap_user(&BootstrapUserConfig {
+        email: TEST_EMAIL.to_string(),
+        password: TEST_PASSWORD.to_string(),
+        name: "Auth User".to_string(),
+        role: Role::User,
+    })
+    .await
+    .unwrap();
+    di.seed_bootstrap_user(&BootstrapUserConfig {
+        email: ADMIN_EMAIL.to_string(),
+        password: ADMIN_PASSWORD.to_string(),
+        name: "Auth Admin".to_string(),
+        role: Role::Admin,
+    })
+    .await
+    .unwrap();
+    di.seed_bootstrap_user(&BootstrapUserConfig {
+        email: SUPERADMIN_EMAIL.to_string(),
+        password: SUPERADMIN_PASSWORD.to_string(),
+        name: "Auth Superadmin".to_string(),
+        role: Role::SuperAdmin,
+    })
+    .await
+    .unwrap();
+    let user_controller = di.build_user_controller().unwrap();
+    let auth_controller = di.build_auth_controller().unwrap();
+    let admin_controller = di.build_admin_controller().unwrap();
+    let api_key_controller = di.build_api_key_controller().unwrap();
+    let oauth_controller = di.build_oauth_controller().unwrap();
+    let oidc_controller = di.build_oidc_controller().unwrap();
+    let auth_services = di.build_auth_services().unwrap();
+    create_app_router(
+        user_controller,
+        auth_controller,
+        admin_controller,
+        api_key_controller,
+        oauth_controller,
+        oidc_controller,
+        auth_services,
+        dummy_discord_config(),
+        test_log_dir(),
+    )
+}
+
 #[tokio::test]
 async fn test_login_success() {
     init_env();
-    let di = DIContainer::new();
-    let user_controller = di.build_user_controller().unwrap();
-    let app = create_app_router(user_controller, dummy_discord_config());
+    let app = build_test_app().await;
     let addr = spawn_test_server(app).await;
     let client = reqwest::Client::new();
     let res = client
-        .post(&format!("http://{}/api/auth/login", addr))
-        .json(&json!({"username": "auth_user", "password": "auth_password"}))
+        .post(format!("http://{}/api/auth/login", addr))
+        .json(&json!({"email": TEST_EMAIL, "password": TEST_PASSWORD}))
         .send()
         .await
         .unwrap();
     assert_eq!(res.status(), StatusCode::OK);
     let body: serde_json::Value = res.json().await.unwrap();
     assert!(body["access_token"].is_string());
+    assert_eq!(body["user"]["email"], TEST_EMAIL);
+    assert_eq!(body["user"]["name"], "Auth User");
+    assert!(uuid::Uuid::parse_str(body["user"]["id"].as_str().unwrap()).is_ok());
 }
 
 #[tokio::test]
 async fn test_login_fail() {
     init_env();
-    let di = DIContainer::new();
-    let user_controller = di.build_user_controller().unwrap();
-    let app = create_app_router(user_controller, dummy_discord_config());
+    let app = build_test_app().await;
     let addr = spawn_test_server(app).await;
     let client = reqwest::Client::new();
     let res = client
-        .post(&format!("http://{}/api/auth/login", addr))
-        .json(&json!({"username": "wrong", "password": "wrong"}))
+        .post(format!("http://{}/api/auth/login", addr))
+        .json(&json!({"email": TEST_EMAIL, "password": "wrong_password"}))
         .send()
         .await
         .unwrap();
     assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
 }
 
+/// 未登録メールとパスワード誤りが区別できないことを確認
+#[tokio::test]
+async fn test_login_unknown_email_and_wrong_password_are_indistinguishable() {
+    init_env();
+    let app = build_test_app().await;
+    let addr = spawn_test_server(app).await;
+    let client = reqwest::Client::new();
+    let wrong_password = client
+        .post(format!("http://{}/api/auth/login", addr))
+        .json(&json!({"email": TEST_EMAIL, "password": "wrong_password"}))
+        .send()
+        .await
+        .unwrap();
+    let unknown_email = client
+        .post(format!("http://{}/api/auth/login", addr))
+        .json(&json!({"email": "nobody@example.com", "password": "wrong_password"}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(wrong_password.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(unknown_email.status(), StatusCode::UNAUTHORIZED);
+    let wrong_password_body: serde_json::Value = wrong_password.json().await.unwrap();
+    let unknown_email_body: serde_json::Value = unknown_email.json().await.unwrap();
+    assert_eq!(wrong_password_body, unknown_email_body);
+}
+
+/// 旧形式の`username`フィールドでもログインできることを確認
+#[tokio::test]
+async fn test_login_accepts_legacy_username_field() {
+    init_env();
+    let app = build_test_app().await;
+    let addr = spawn_test_server(app).await;
+    let client = reqwest::Client::new();
+    let res = client
+        .post(format!("http://{}/api/auth/login", addr))
+        .json(&json!({"username": TEST_EMAIL, "password": TEST_PASSWORD}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+}
+
 #[tokio::test]
 async fn test_create_user_with_auth() {
     init_env();
-    let di = DIContainer::new();
-    let user_controller = di.build_user_controller().unwrap();
-    let app = create_app_router(user_controller, dummy_discord_config());
+    let app = build_test_app().await;
     let addr = spawn_test_server(app).await;
     let client = reqwest::Client::new();
     // まずログインしてトークン取得
     let login_res = client
-        .post(&format!("http://{}/api/auth/login", addr))
-        .json(&json!({"username": "auth_user", "password": "auth_password"}))
+        .post(format!("http://{}/api/auth/login", addr))
+        .json(&json!({"email": TEST_EMAIL, "password": TEST_PASSWORD}))
         .send()
         .await
         .unwrap();
@@ -108,7 +245,7 @@ async fn test_create_user_with_auth() {
     let token = body["access_token"].as_str().unwrap();
     // 認証付きでユーザー作成
     let res = client
-        .post(&format!("http://{}/api/users", addr))
+        .post(format!("http://{}/api/users", addr))
         .bearer_auth(token)
         .json(&json!({
             "email": "newuser@example.com",
@@ -125,13 +262,11 @@ async fn test_create_user_with_auth() {
 #[tokio::test]
 async fn test_create_user_without_auth() {
     init_env();
-    let di = DIContainer::new();
-    let user_controller = di.build_user_controller().unwrap();
-    let app = create_app_router(user_controller, dummy_discord_config());
+    let app = build_test_app().await;
     let addr = spawn_test_server(app).await;
     let client = reqwest::Client::new();
     let res = client
-        .post(&format!("http://{}/api/users", addr))
+        .post(format!("http://{}/api/users", addr))
         .json(&json!({
             "email": "failuser@example.com",
             "name": "Fail User",
@@ -146,3 +281,2380 @@ async fn test_create_user_without_auth() {
         res.status()
     );
 }
+
+// ログインしてレスポンスボディを返す
+async fn login(client: &reqwest::Client, addr: TestAddr) -> serde_json::Value {
+    let res = client
+        .post(format!("http://{}/api/auth/login", addr))
+        .json(&json!({"email": TEST_EMAIL, "password": TEST_PASSWORD}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    res.json().await.unwrap()
+}
+
+// リフレッシュトークンでトークンを更新する
+async fn refresh(
+    client: &reqwest::Client,
+    addr: TestAddr,
+    refresh_token: &str,
+) -> reqwest::Response {
+    client
+        .post(format!("http://{}/api/auth/refresh", addr))
+        .json(&json!({"refresh_token": refresh_token}))
+        .send()
+        .await
+        .unwrap()
+}
+
+/// リフレッシュのたびにトークンがローテーションされることを確認
+#[tokio::test]
+async fn test_refresh_rotates_token_pair() {
+    init_env();
+    let app = build_test_app().await;
+    let addr = spawn_test_server(app).await;
+    let client = reqwest::Client::new();
+    let login_body = login(&client, addr).await;
+    let first_refresh = login_body["refresh_token"].as_str().unwrap();
+
+    let res = refresh(&client, addr, first_refresh).await;
+    assert_eq!(res.status(), StatusCode::OK);
+    let body: serde_json::Value = res.json().await.unwrap();
+    let second_refresh = body["refresh_token"].as_str().unwrap();
+    assert_ne!(second_refresh, first_refresh);
+    assert_eq!(body["user"]["email"], TEST_EMAIL);
+
+    // 新しいアクセストークンで保護APIを利用できる
+    let res = client
+        .post(format!("http://{}/api/users", addr))
+        .bearer_auth(body["access_token"].as_str().unwrap())
+        .json(&json!({
+            "email": "rotated@example.com",
+            "name": "Rotated User",
+            "password": "Password123!"
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::CREATED);
+
+    // ローテーション後のトークンも続けて使える
+    let res = refresh(&client, addr, second_refresh).await;
+    assert_eq!(res.status(), StatusCode::OK);
+}
+
+/// 使用済みリフレッシュトークンの再提示でファミリー全体が失効することを確認
+#[tokio::test]
+async fn test_refresh_reuse_revokes_whole_family() {
+    init_env();
+    let app = build_test_app().await;
+    let addr = spawn_test_server(app).await;
+    let client = reqwest::Client::new();
+    let login_body = login(&client, addr).await;
+    let stolen_refresh = login_body["refresh_token"].as_str().unwrap();
+
+    let res = refresh(&client, addr, stolen_refresh).await;
+    assert_eq!(res.status(), StatusCode::OK);
+    let body: serde_json::Value = res.json().await.unwrap();
+    let legitimate_refresh = body["refresh_token"].as_str().unwrap().to_string();
+
+    // 使用済みトークンの再提示は拒否される
+    let res = refresh(&client, addr, stolen_refresh).await;
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+    let error: serde_json::Value = res.json().await.unwrap();
+    assert_eq!(error["error"]["code"], "INVALID_TOKEN");
+
+    // 同じファミリーの最新トークンも失効している
+    let res = refresh(&client, addr, &legitimate_refresh).await;
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+
+    // 別ログインのセッションには影響しない
+    let other_session = login(&client, addr).await;
+    let res = refresh(
+        &client,
+        addr,
+        other_session["refresh_token"].as_str().unwrap(),
+    )
+    .await;
+    assert_eq!(res.status(), StatusCode::OK);
+}
+
+/// アクセストークンや不正な文字列はリフレッシュに使えないことを確認
+#[tokio::test]
+async fn test_refresh_rejects_access_token_and_garbage() {
+    init_env();
+    let app = build_test_app().await;
+    let addr = spawn_test_server(app).await;
+    let client = reqwest::Client::new();
+    let login_body = login(&client, addr).await;
+
+    let res = refresh(&client, addr, login_body["access_token"].as_str().unwrap()).await;
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+    let res = refresh(&client, addr, "not-a-jwt").await;
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+}
+
+// 認証付きでユーザー作成APIを叩き、ステータスを返す（トークン有効性の確認用）
+async fn create_user_status(
+    client: &reqwest::Client,
+    addr: TestAddr,
+    access_token: &str,
+) -> reqwest::Response {
+    client
+        .post(format!("http://{}/api/users", addr))
+        .bearer_auth(access_token)
+        .json(&json!({
+            "email": format!("{}@example.com", uuid::Uuid::new_v4()),
+            "name": "Session Check",
+            "password": "Password123!"
+        }))
+        .send()
+        .await
+        .unwrap()
+}
+
+/// ログアウト後はアクセストークン・リフレッシュトークンとも使えないことを確認
+#[tokio::test]
+async fn test_logout_revokes_access_and_refresh_tokens() {
+    init_env();
+    let app = build_test_app().await;
+    let addr = spawn_test_server(app).await;
+    let client = reqwest::Client::new();
+    let login_body = login(&client, addr).await;
+    let access_token = login_body["access_token"].as_str().unwrap();
+    let refresh_token = login_body["refresh_token"].as_str().unwrap();
+
+    let res = client
+        .post(format!("http://{}/api/auth/logout", addr))
+        .bearer_auth(access_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    let res = create_user_status(&client, addr, access_token).await;
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+    let body: serde_json::Value = res.json().await.unwrap();
+    assert_eq!(body["error"]["code"], "TOKEN_REVOKED");
+    let res = refresh(&client, addr, refresh_token).await;
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+}
+
+/// logout-allで他端末のセッションとセッションに紐づかないトークンも終了することを確認
+#[tokio::test]
+async fn test_logout_all_revokes_every_session() {
+    init_env();
+    let app = build_test_app().await;
+    let addr = spawn_test_server(app).await;
+    let client = reqwest::Client::new();
+    let session_a = login(&client, addr).await;
+    let session_b = login(&client, addr).await;
+    // セッションに紐づかないトークン（なりすまし・client_credentialsと同じくsidなし）
+    let sessionless_token = JwtClaims::new(
+        session_a["user"]["id"].as_str().unwrap().to_string(),
+        TEST_EMAIL.to_string(),
+        "Auth User".to_string(),
+        "user".to_string(),
+    )
+    .to_token()
+    .unwrap();
+    let res = create_user_status(&client, addr, &sessionless_token).await;
+    assert_eq!(res.status(), StatusCode::CREATED);
+
+    let res = client
+        .post(format!("http://{}/api/auth/logout-all", addr))
+        .bearer_auth(session_a["access_token"].as_str().unwrap())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    let body: serde_json::Value = res.json().await.unwrap();
+    assert_eq!(body["data"]["revoked_sessions"], 2);
+
+    for session in [&session_a, &session_b] {
+        let res =
+            create_user_status(&client, addr, session["access_token"].as_str().unwrap()).await;
+        assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+        let res = refresh(&client, addr, session["refresh_token"].as_str().unwrap()).await;
+        assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+    }
+    let res = create_user_status(&client, addr, &sessionless_token).await;
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+    let body: serde_json::Value = res.json().await.unwrap();
+    assert_eq!(body["error"]["code"], "TOKEN_REVOKED");
+
+    // 再ログイン後は新しいセッションで利用できる
+    let session_c = login(&client, addr).await;
+    let res = create_user_status(&client, addr, session_c["access_token"].as_str().unwrap()).await;
+    assert_eq!(res.status(), StatusCode::CREATED);
+}
+
+/// 管理者が対象ユーザーの全セッションを終了できることを確認
+#[tokio::test]
+async fn test_admin_can_revoke_all_sessions_of_user() {
+    use rusted_ca::shared::middleware::auth_middleware::JwtClaims;
+
+    init_env();
+    let app = build_test_app().await;
+    let addr = spawn_test_server(app).await;
+    let client = reqwest::Client::new();
+    let victim = login(&client, addr).await;
+    let victim_id = victim["user"]["id"].as_str().unwrap();
+    let admin_token = JwtClaims::new(
+        uuid::Uuid::new_v4().to_string(),
+        "admin@example.com".to_string(),
+        "Admin".to_string(),
+        "admin".to_string(),
+    )
+    .to_token()
+    .unwrap();
+
+    // 一般ユーザーは実行できない
+    let res = client
+        .delete(format!(
+            "http://{}/api/admin/users/{}/sessions",
+            addr, victim_id
+        ))
+        .bearer_auth(victim["access_token"].as_str().unwrap())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::FORBIDDEN);
+
+    let res = client
+        .delete(format!(
+            "http://{}/api/admin/users/{}/sessions",
+            addr, victim_id
+        ))
+        .bearer_auth(&admin_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    let body: serde_json::Value = res.json().await.unwrap();
+    assert_eq!(body["data"]["revoked_sessions"], 1);
+
+    let res = create_user_status(&client, addr, victim["access_token"].as_str().unwrap()).await;
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+    let res = refresh(&client, addr, victim["refresh_token"].as_str().unwrap()).await;
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+
+    // 存在しないユーザーは404
+    let res = client
+        .delete(format!(
+            "http://{}/api/admin/users/{}/sessions",
+            addr,
+            uuid::Uuid::new_v4()
+        ))
+        .bearer_auth(&admin_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+}
+
+/// 永続化したロールがログイン時のトークンに反映され、権限チェックに使われることを確認
+#[tokio::test]
+async fn test_persisted_role_is_used_for_permission_checks() {
+    use rusted_ca::shared::middleware::auth_middleware::JwtClaims;
+
+    init_env();
+    let app = build_test_app().await;
+    let addr = spawn_test_server(app).await;
+    let client = reqwest::Client::new();
+    let victim = login(&client, addr).await;
+    let admin = login_admin(&client, addr).await;
+    let admin_token = admin["access_token"].as_str().unwrap();
+    let claims = JwtClaims::from_token(admin_token).unwrap();
+    assert_eq!(claims.role(), Some(Role::Admin));
+
+    let res = client
+        .delete(format!(
+            "http://{}/api/admin/users/{}/sessions",
+            addr,
+            victim["user"]["id"].as_str().unwrap()
+        ))
+        .bearer_auth(admin_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    // リフレッシュ後もロールは維持される
+    let res = refresh(&client, addr, admin["refresh_token"].as_str().unwrap()).await;
+    assert_eq!(res.status(), StatusCode::OK);
+    let rotated: serde_json::Value = res.json().await.unwrap();
+    let claims = JwtClaims::from_token(rotated["access_token"].as_str().unwrap()).unwrap();
+    assert_eq!(claims.role(), Some(Role::Admin));
+}
+
+/// 権限不足は403・INSUFFICIENT_PERMISSIONSで拒否されることを確認
+#[tokio::test]
+async fn test_missing_permission_is_forbidden() {
+    init_env();
+    let app = build_test_app().await;
+    let addr = spawn_test_server(app).await;
+    let client = reqwest::Client::new();
+    let user = login(&client, addr).await;
+    let res = client
+        .delete(format!(
+            "http://{}/api/admin/users/{}/sessions",
+            addr,
+            user["user"]["id"].as_str().unwrap()
+        ))
+        .bearer_auth(user["access_token"].as_str().unwrap())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::FORBIDDEN);
+    let body: serde_json::Value = res.json().await.unwrap();
+    assert_eq!(body["error"]["code"], "INSUFFICIENT_PERMISSIONS");
+}
+
+/// リフレッシュトークンはアクセストークンとして使えないことを確認
+#[tokio::test]
+async fn test_refresh_token_is_rejected_as_access_token() {
+    init_env();
+    let app = build_test_app().await;
+    let addr = spawn_test_server(app).await;
+    let client = reqwest::Client::new();
+    let login_body = login(&client, addr).await;
+    let res =
+        create_user_status(&client, addr, login_body["refresh_token"].as_str().unwrap()).await;
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+}
+
+/// `token_type`導入前の形式のリフレッシュトークン（`role = "refresh"`）もアクセストークンとして使えないことを確認
+#[tokio::test]
+async fn test_legacy_refresh_token_is_rejected_as_access_token() {
+    use jsonwebtoken::{Header, encode};
+    use rusted_ca::shared::middleware::auth_middleware::JWT_CONFIG;
+
+    init_env();
+    let app = build_test_app().await;
+    let addr = spawn_test_server(app).await;
+    let client = reqwest::Client::new();
+    let login_body = login(&client, addr).await;
+    let now = chrono::Utc::now().timestamp();
+    let legacy_token = |role: &str| {
+        let mut header = Header::new(JWT_CONFIG.algorithm);
+        header.kid = Some(JWT_CONFIG.key_id.clone());
+        let claims = json!({
+            "sub": login_body["user"]["id"],
+            "email": TEST_EMAIL,
+            "name": role,
+            "role": role,
+            "iat": now,
+            "exp": now + 3600,
+            "jti": uuid::Uuid::new_v4().to_string(),
+        });
+        encode(&header, &claims, JWT_CONFIG.encoding_key()).unwrap()
+    };
+
+    let res = create_user_status(&client, addr, &legacy_token("refresh")).await;
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+    let body: serde_json::Value = res.json().await.unwrap();
+    assert_eq!(body["error"]["code"], "INVALID_TOKEN");
+    // 旧形式のアクセストークンはそのまま使える
+    let res = create_user_status(&client, addr, &legacy_token("user")).await;
+    assert_eq!(res.status(), StatusCode::CREATED);
+}
+
+// 管理者としてログインし、ログインレスポンスを返す
+async fn login_admin(client: &reqwest::Client, addr: TestAddr) -> serde_json::Value {
+    login_with_mfa_enrollment(client, addr, ADMIN_EMAIL, ADMIN_PASSWORD).await
+}
+
+// 二要素認証が必須のロールでログインする（初回ログイン時に登録を完了させる）
+async fn login_with_mfa_enrollment(
+    client: &reqwest::Client,
+    addr: TestAddr,
+    email: &str,
+    password: &str,
+) -> serde_json::Value {
+    let res = try_login(client, addr, email, password).await;
+    assert_eq!(res.status(), StatusCode::OK);
+    let challenge: serde_json::Value = res.json().await.unwrap();
+    assert_eq!(challenge["mfa_required"], true);
+    assert_eq!(challenge["enrollment_required"], true);
+    let mfa_token = challenge["mfa_token"].as_str().unwrap();
+
+    let secret = enroll_mfa(client, addr, mfa_token).await;
+    let res = post_mfa_code(
+        client,
+        addr,
+        "/api/auth/mfa/enroll/confirm",
+        mfa_token,
+        &totp_code(&secret, 0),
+    )
+    .await;
+    assert_eq!(res.status(), StatusCode::OK);
+    let body: serde_json::Value = res.json().await.unwrap();
+    body["session"].clone()
+}
+
+// 現在時刻から`offset`ステップずらしたTOTPコード
+fn totp_code(secret: &str, offset: i64) -> String {
+    let step = totp::time_step(chrono::Utc::now().timestamp()) + offset;
+    totp::code_at(secret, step).unwrap()
+}
+
+// 二要素認証の登録を開始し、シークレットを返す
+async fn enroll_mfa(client: &reqwest::Client, addr: TestAddr, bearer: &str) -> String {
+    let res = client
+        .post(format!("http://{}/api/auth/mfa/enroll", addr))
+        .bearer_auth(bearer)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    let body: serde_json::Value = res.json().await.unwrap();
+    assert!(
+        body["provisioning_uri"]
+            .as_str()
+            .unwrap()
+            .starts_with("otpauth://totp/")
+    );
+    body["secret"].as_str().unwrap().to_string()
+}
+
+// 二要素認証のコードを送信する
+async fn post_mfa_code(
+    client: &reqwest::Client,
+    addr: TestAddr,
+    path: &str,
+    bearer: &str,
+    code: &str,
+) -> reqwest::Response {
+    client
+        .post(format!("http://{}{}", addr, path))
+        .bearer_auth(bearer)
+        .json(&json!({"code": code}))
+        .send()
+        .await
+        .unwrap()
+}
+
+/// 一般ユーザーは自分のレコードのみ更新・削除でき、他人のレコードは403になることを確認
+#[tokio::test]
+async fn test_user_can_modify_only_own_record() {
+    init_env();
+    let app = build_test_app().await;
+    let addr = spawn_test_server(app).await;
+    let client = reqwest::Client::new();
+    let owner = login(&client, addr).await;
+    let owner_token = owner["access_token"].as_str().unwrap();
+    let owner_id = owner["user"]["id"].as_str().unwrap();
+    let res = create_user_status(&client, addr, owner_token).await;
+    assert_eq!(res.status(), StatusCode::CREATED);
+    let other: serde_json::Value = res.json().await.unwrap();
+    let other_id = other["data"]["id"].as_str().unwrap();
+
+    let res = client
+        .put(format!("http://{}/api/users/{}", addr, other_id))
+        .bearer_auth(owner_token)
+        .json(&json!({"name": "Hijacked"}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::FORBIDDEN);
+    let body: serde_json::Value = res.json().await.unwrap();
+    assert_eq!(body["error"]["code"], "INSUFFICIENT_PERMISSIONS");
+
+    let res = client
+        .delete(format!("http://{}/api/users/{}", addr, other_id))
+        .bearer_auth(owner_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::FORBIDDEN);
+
+    // 存在しないユーザーでも404ではなく403（存在有無を漏らさない）
+    let res = client
+        .delete(format!(
+            "http://{}/api/users/{}",
+            addr,
+            uuid::Uuid::new_v4()
+        ))
+        .bearer_auth(owner_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::FORBIDDEN);
+
+    let res = client
+        .put(format!("http://{}/api/users/{}", addr, owner_id))
+        .bearer_auth(owner_token)
+        .json(&json!({"name": "Renamed Owner"}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    let body: serde_json::Value = res.json().await.unwrap();
+    assert_eq!(body["data"]["name"], "Renamed Owner");
+}
+
+/// 管理者は他人のレコードを更新・削除できることを確認
+#[tokio::test]
+async fn test_admin_can_modify_other_users() {
+    init_env();
+    let app = build_test_app().await;
+    let addr = spawn_test_server(app).await;
+    let client = reqwest::Client::new();
+    let admin = login_admin(&client, addr).await;
+    let admin_token = admin["access_token"].as_str().unwrap();
+    let user = login(&client, addr).await;
+    let user_id = user["user"]["id"].as_str().unwrap();
+
+    let res = client
+        .put(format!("http://{}/api/users/{}", addr, user_id))
+        .bearer_auth(admin_token)
+        .json(&json!({"name": "Moderated"}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let res = client
+        .delete(format!("http://{}/api/users/{}", addr, user_id))
+        .bearer_auth(admin_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    let res = client
+        .delete(format!("http://{}/api/users/{}", addr, user_id))
+        .bearer_auth(admin_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+}
+
+// ログインを試行し、レスポンスを返す
+async fn try_login(
+    client: &reqwest::Client,
+    addr: TestAddr,
+    email: &str,
+    password: &str,
+) -> reqwest::Response {
+    client
+        .post(format!("http://{}/api/auth/login", addr))
+        .json(&json!({"email": email, "password": password}))
+        .send()
+        .await
+        .unwrap()
+}
+
+/// 連続失敗でロックされ、Retry-Afterが返ること（未登録メールでも同じ応答）を確認
+#[tokio::test]
+async fn test_repeated_failures_lock_account_without_revealing_existence() {
+    init_env();
+    let app = build_test_app().await;
+    let addr = spawn_test_server(app).await;
+    let client = reqwest::Client::new();
+
+    for email in [TEST_EMAIL, "nobody@example.com"] {
+        for _ in 0..5 {
+            let res = try_login(&client, addr, email, "wrong_password").await;
+            assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+        }
+    }
+    let mut bodies = Vec::new();
+    for email in [TEST_EMAIL, "nobody@example.com"] {
+        // ロック中は正しいパスワードでも拒否される
+        let res = try_login(&client, addr, email, TEST_PASSWORD).await;
+        assert_eq!(res.status(), StatusCode::TOO_MANY_REQUESTS);
+        let retry_after: u64 = res.headers()["retry-after"]
+            .to_str()
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!(retry_after > 0);
+        bodies.push(res.json::<serde_json::Value>().await.unwrap());
+    }
+    assert_eq!(bodies[0], bodies[1]);
+    assert_eq!(bodies[0]["error"]["code"], "TOO_MANY_ATTEMPTS");
+}
+
+/// 管理者がロックを解除するとログインできることを確認
+#[tokio::test]
+async fn test_admin_can_unlock_locked_account() {
+    init_env();
+    let app = build_test_app().await;
+    let addr = spawn_test_server(app).await;
+    let client = reqwest::Client::new();
+    let user_id = login(&client, addr).await["user"]["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    for _ in 0..5 {
+        try_login(&client, addr, TEST_EMAIL, "wrong_password").await;
+    }
+    let res = try_login(&client, addr, TEST_EMAIL, TEST_PASSWORD).await;
+    assert_eq!(res.status(), StatusCode::TOO_MANY_REQUESTS);
+
+    let admin = login_admin(&client, addr).await;
+    let res = client
+        .delete(format!(
+            "http://{}/api/admin/users/{}/lockout",
+            addr, user_id
+        ))
+        .bearer_auth(admin["access_token"].as_str().unwrap())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    let body: serde_json::Value = res.json().await.unwrap();
+    assert_eq!(body["data"]["unlocked"], true);
+
+    let res = try_login(&client, addr, TEST_EMAIL, TEST_PASSWORD).await;
+    assert_eq!(res.status(), StatusCode::OK);
+}
+
+// メール本文のリンクからトークンを取り出す
+fn token_from_mail(mailer: &InMemoryMailer, email: &str) -> String {
+    let message = mailer
+        .last_to(email)
+        .expect("mail with a token link should be sent");
+    message
+        .body
+        .split("token=")
+        .nth(1)
+        .and_then(|rest| rest.split_whitespace().next())
+        .unwrap()
+        .to_string()
+}
+
+async fn confirm_reset(
+    client: &reqwest::Client,
+    addr: TestAddr,
+    token: &str,
+    new_password: &str,
+) -> reqwest::Response {
+    client
+        .post(format!("http://{}/api/auth/password-reset/confirm", addr))
+        .json(&json!({"token": token, "new_password": new_password}))
+        .send()
+        .await
+        .unwrap()
+}
+
+/// パスワードリセットでパスワードが変わり、既存セッション（sidのないトークンを含む）が失効し、トークンは一度しか使えないことを確認
+#[tokio::test]
+async fn test_password_reset_flow() {
+    init_env();
+    let (app, mailer) = build_test_app_with_mailer().await;
+    let addr = spawn_test_server(app).await;
+    let client = reqwest::Client::new();
+    let session = login(&client, addr).await;
+    // セッションに紐づかないトークン（sidなし）
+    let sessionless_token = JwtClaims::new(
+        session["user"]["id"].as_str().unwrap().to_string(),
+        TEST_EMAIL.to_string(),
+        "Auth User".to_string(),
+        "user".to_string(),
+    )
+    .to_token()
+    .unwrap();
+
+    let res = client
+        .post(format!("http://{}/api/auth/password-reset/request", addr))
+        .json(&json!({"email": TEST_EMAIL}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::ACCEPTED);
+    let token = token_from_mail(&mailer, TEST_EMAIL);
+
+    // 弱いパスワードは拒否され、トークンは消費されない
+    let res = confirm_reset(&client, addr, &token, "short").await;
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    let new_password = "brand_new_password";
+    let res = confirm_reset(&client, addr, &token, new_password).await;
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+    let res = confirm_reset(&client, addr, &token, "another_password").await;
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+
+    // 既存セッションは失効
+    let res = create_user_status(&client, addr, session["access_token"].as_str().unwrap()).await;
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+    let res = refresh(&client, addr, session["refresh_token"].as_str().unwrap()).await;
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+    let res = create_user_status(&client, addr, &sessionless_token).await;
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+
+    let res = try_login(&client, addr, TEST_EMAIL, TEST_PASSWORD).await;
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+    let res = try_login(&client, addr, TEST_EMAIL, new_password).await;
+    assert_eq!(res.status(), StatusCode::OK);
+}
+
+// パスワードリセット設定を差し替えたアプリケーションを組み立てる
+async fn build_password_reset_test_app(
+    mailer: Arc<dyn Mailer>,
+    configure: impl FnOnce(&mut PasswordResetConfig),
+) -> Router {
+    let mut config = AppConfig::current().clone();
+    configure(&mut config.password_reset);
+    let di = DIContainer::in_memory()
+        .with_config(config)
+        .with_mailer(mailer);
+    build_test_app_from(&di).await
+}
+
+async fn request_reset(client: &reqwest::Client, addr: TestAddr, email: &str) -> reqwest::Response {
+    client
+        .post(format!("http://{}/api/auth/password-reset/request", addr))
+        .json(&json!({"email": email}))
+        .send()
+        .await
+        .unwrap()
+}
+
+/// 未登録メールアドレスでも同じ応答を返し、メールは送らないことを確認
+#[tokio::test]
+async fn test_password_reset_request_does_not_reveal_accounts() {
+    init_env();
+    let mailer = Arc::new(InMemoryMailer::new());
+    // 再発行を確認するため、同じメールアドレスへの最短間隔をなくす
+    let app = build_password_reset_test_app(mailer.clone(), |config| {
+        config.request_cooldown = Duration::ZERO;
+    })
+    .await;
+    let addr = spawn_test_server(app).await;
+    let client = reqwest::Client::new();
+    let res = client
+        .post(format!("http://{}/api/auth/password-reset/request", addr))
+        .json(&json!({"email": "nobody@example.com"}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::ACCEPTED);
+    assert!(mailer.sent().is_empty());
+
+    // 再発行すると古いトークンは使えない
+    for _ in 0..2 {
+        client
+            .post(format!("http://{}/api/auth/password-reset/request", addr))
+            .json(&json!({"email": TEST_EMAIL}))
+            .send()
+            .await
+            .unwrap();
+    }
+    let sent = mailer.sent();
+    assert_eq!(sent.len(), 2);
+    let first_token = sent[0]
+        .body
+        .split("token=")
+        .nth(1)
+        .unwrap()
+        .split_whitespace()
+        .next()
+        .unwrap();
+    let res = confirm_reset(&client, addr, first_token, "brand_new_password").await;
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+    let res = confirm_reset(
+        &client,
+        addr,
+        &token_from_mail(&mailer, TEST_EMAIL),
+        "brand_new_password",
+    )
+    .await;
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+}
+
+/// リセット要求はメールアドレス・接続元IPごとに制限され、制限中も同じ応答を返すことを確認
+#[tokio::test]
+async fn test_password_reset_request_is_rate_limited() {
+    init_env();
+    let mailer = Arc::new(InMemoryMailer::new());
+    let app = build_password_reset_test_app(mailer.clone(), |config| {
+        config.max_requests_per_ip = 4;
+    })
+    .await;
+    let addr = spawn_test_server(app).await;
+    let client = reqwest::Client::new();
+
+    // 最短間隔内の2回目は送らず、最初のトークンも無効にしない
+    let res = request_reset(&client, addr, TEST_EMAIL).await;
+    assert_eq!(res.status(), StatusCode::ACCEPTED);
+    let first_token = token_from_mail(&mailer, TEST_EMAIL);
+    let res = request_reset(&client, addr, TEST_EMAIL).await;
+    assert_eq!(res.status(), StatusCode::ACCEPTED);
+    assert_eq!(mailer.sent().len(), 1);
+
+    // 同じIPからの要求が上限に達すると、別のメールアドレスにも送らない
+    let res = request_reset(&client, addr, "nobody@example.com").await;
+    assert_eq!(res.status(), StatusCode::ACCEPTED);
+    let res = request_reset(&client, addr, ADMIN_EMAIL).await;
+    assert_eq!(res.status(), StatusCode::ACCEPTED);
+    assert_eq!(mailer.sent().len(), 2);
+    let res = request_reset(&client, addr, SUPERADMIN_EMAIL).await;
+    assert_eq!(res.status(), StatusCode::ACCEPTED);
+    assert!(mailer.last_to(SUPERADMIN_EMAIL).is_none());
+
+    let res = confirm_reset(&client, addr, &first_token, "brand_new_password").await;
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+}
+
+// 常に送信に失敗するMailer
+struct FailingMailer;
+
+#[async_trait::async_trait]
+impl Mailer for FailingMailer {
+    async fn send(
+        &self,
+        _message: &EmailMessage,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        Err("smtp unavailable".into())
+    }
+}
+
+/// メール送信に失敗しても、未登録のメールアドレスと同じ応答を返すことを確認
+#[tokio::test]
+async fn test_password_reset_request_hides_mail_failures() {
+    init_env();
+    let app = build_password_reset_test_app(Arc::new(FailingMailer), |_| {}).await;
+    let addr = spawn_test_server(app).await;
+    let client = reqwest::Client::new();
+
+    let res = request_reset(&client, addr, TEST_EMAIL).await;
+    assert_eq!(res.status(), StatusCode::ACCEPTED);
+    let existing: serde_json::Value = res.json().await.unwrap();
+    let res = request_reset(&client, addr, "nobody@example.com").await;
+    assert_eq!(res.status(), StatusCode::ACCEPTED);
+    let unknown: serde_json::Value = res.json().await.unwrap();
+    assert_eq!(existing["success"], unknown["success"]);
+    assert_eq!(existing["message"], unknown["message"]);
+}
+
+/// JWKSエンドポイントが公開されていることを確認
+#[tokio::test]
+async fn test_jwks_endpoint_is_published() {
+    init_env();
+    let app = build_test_app().await;
+    let addr = spawn_test_server(app).await;
+    let client = reqwest::Client::new();
+    let res = client
+        .get(format!("http://{}/.well-known/jwks.json", addr))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    let body: serde_json::Value = res.json().await.unwrap();
+    assert!(body["keys"].is_array());
+}
+
+async fn create_user_with_email(
+    client: &reqwest::Client,
+    addr: TestAddr,
+    access_token: &str,
+    email: &str,
+) -> reqwest::Response {
+    client
+        .post(format!("http://{}/api/users", addr))
+        .bearer_auth(access_token)
+        .json(&json!({
+            "email": email,
+            "name": "Verify Check",
+            "password": "Password123!"
+        }))
+        .send()
+        .await
+        .unwrap()
+}
+
+async fn resend_verification(
+    client: &reqwest::Client,
+    addr: TestAddr,
+    email: &str,
+) -> reqwest::Response {
+    client
+        .post(format!("http://{}/api/auth/verify-email/resend", addr))
+        .json(&json!({"email": email}))
+        .send()
+        .await
+        .unwrap()
+}
+
+/// 作成したユーザーに確認メールが届き、トークンは一度だけ使えることを確認
+#[tokio::test]
+async fn test_email_verification_flow() {
+    init_env();
+    let (app, mailer) = build_test_app_with_mailer().await;
+    let addr = spawn_test_server(app).await;
+    let client = reqwest::Client::new();
+    let session = login(&client, addr).await;
+    let access_token = session["access_token"].as_str().unwrap();
+
+    let email = format!("{}@example.com", uuid::Uuid::new_v4());
+    let res = create_user_with_email(&client, addr, access_token, &email).await;
+    assert_eq!(res.status(), StatusCode::CREATED);
+    let token = token_from_mail(&mailer, &email);
+
+    let verify_url = format!("http://{}/api/auth/verify-email?token={}", addr, token);
+    let res = client.get(&verify_url).send().await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    let res = client.get(&verify_url).send().await.unwrap();
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+
+    // 確認済みのユーザーには再送しない
+    let res = resend_verification(&client, addr, &email).await;
+    assert_eq!(res.status(), StatusCode::ACCEPTED);
+    let sent_to_user = mailer.sent().iter().filter(|m| m.to == email).count();
+    assert_eq!(sent_to_user, 1);
+}
+
+/// 再送は最短間隔内では送られず、アカウントの有無に関わらず同じ応答を返すことを確認
+#[tokio::test]
+async fn test_email_verification_resend_is_rate_limited() {
+    init_env();
+    let (app, mailer) = build_test_app_with_mailer().await;
+    let addr = spawn_test_server(app).await;
+    let client = reqwest::Client::new();
+    let session = login(&client, addr).await;
+    let access_token = session["access_token"].as_str().unwrap();
+
+    let email = format!("{}@example.com", uuid::Uuid::new_v4());
+    let res = create_user_with_email(&client, addr, access_token, &email).await;
+    assert_eq!(res.status(), StatusCode::CREATED);
+    let first_token = token_from_mail(&mailer, &email);
+
+    let res = resend_verification(&client, addr, &email).await;
+    assert_eq!(res.status(), StatusCode::ACCEPTED);
+    let res = resend_verification(&client, addr, "nobody@example.com").await;
+    assert_eq!(res.status(), StatusCode::ACCEPTED);
+    assert_eq!(mailer.sent().len(), 1);
+
+    // 抑止された再送は既存のトークンを無効にしない
+    let res = client
+        .get(format!(
+            "http://{}/api/auth/verify-email?token={}",
+            addr, first_token
+        ))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+}
+
+/// 管理者は初回ログインで二要素認証の登録を求められ、以降は2段階目のコードが必要なことを確認
+#[tokio::test]
+async fn test_admin_login_requires_mfa() {
+    init_env();
+    let app = build_test_app().await;
+    let addr = spawn_test_server(app).await;
+    let client = reqwest::Client::new();
+
+    let res = try_login(&client, addr, ADMIN_EMAIL, ADMIN_PASSWORD).await;
+    assert_eq!(res.status(), StatusCode::OK);
+    let challenge: serde_json::Value = res.json().await.unwrap();
+    assert!(challenge.get("access_token").is_none());
+    let mfa_token = challenge["mfa_token"].as_str().unwrap().to_string();
+
+    // ログイン途中のトークンでは通常のAPIを呼べない
+    let res = client
+        .post(format!("http://{}/api/users", addr))
+        .bearer_auth(&mfa_token)
+        .json(
+            &json!({"email": "pending@example.com", "password": "password123", "name": "Pending"}),
+        )
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+
+    let secret = enroll_mfa(&client, addr, &mfa_token).await;
+    let res = post_mfa_code(
+        &client,
+        addr,
+        "/api/auth/mfa/enroll/confirm",
+        &mfa_token,
+        "abcdef",
+    )
+    .await;
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+    let res = post_mfa_code(
+        &client,
+        addr,
+        "/api/auth/mfa/enroll/confirm",
+        &mfa_token,
+        &totp_code(&secret, 0),
+    )
+    .await;
+    assert_eq!(res.status(), StatusCode::OK);
+    let body: serde_json::Value = res.json().await.unwrap();
+    let recovery_codes: Vec<String> = body["recovery_codes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|code| code.as_str().unwrap().to_string())
+        .collect();
+    assert_eq!(recovery_codes.len(), 10);
+    let access_token = body["session"]["access_token"].as_str().unwrap();
+
+    // 使用済みのログイン途中トークンは再利用できない
+    let res = post_mfa_code(
+        &client,
+        addr,
+        "/api/auth/login/mfa",
+        &mfa_token,
+        &totp_code(&secret, 1),
+    )
+    .await;
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+
+    // 登録必須ロールは解除できない
+    let res = post_mfa_code(
+        &client,
+        addr,
+        "/api/auth/mfa/disable",
+        access_token,
+        &recovery_codes[0],
+    )
+    .await;
+    assert_eq!(res.status(), StatusCode::FORBIDDEN);
+    let body: serde_json::Value = res.json().await.unwrap();
+    assert_eq!(body["error"]["code"], "OPERATION_NOT_PERMITTED");
+
+    // 2回目以降のログインは登録済みのコードで完了する
+    let res = try_login(&client, addr, ADMIN_EMAIL, ADMIN_PASSWORD).await;
+    let challenge: serde_json::Value = res.json().await.unwrap();
+    assert_eq!(challenge["enrollment_required"], false);
+    let mfa_token = challenge["mfa_token"].as_str().unwrap();
+    let res = post_mfa_code(&client, addr, "/api/auth/login/mfa", mfa_token, "000000").await;
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+    let res = post_mfa_code(
+        &client,
+        addr,
+        "/api/auth/login/mfa",
+        mfa_token,
+        &recovery_codes[1].to_uppercase(),
+    )
+    .await;
+    assert_eq!(res.status(), StatusCode::OK);
+    let body: serde_json::Value = res.json().await.unwrap();
+    assert!(body["access_token"].is_string());
+
+    // リカバリーコードは1回限り
+    let res = try_login(&client, addr, ADMIN_EMAIL, ADMIN_PASSWORD).await;
+    let challenge: serde_json::Value = res.json().await.unwrap();
+    let mfa_token = challenge["mfa_token"].as_str().unwrap();
+    let res = post_mfa_code(
+        &client,
+        addr,
+        "/api/auth/login/mfa",
+        mfa_token,
+        &recovery_codes[1],
+    )
+    .await;
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+}
+
+/// 一般ユーザーは任意で二要素認証を有効化・解除できることを確認
+#[tokio::test]
+async fn test_user_can_enable_and_disable_mfa() {
+    init_env();
+    let app = build_test_app().await;
+    let addr = spawn_test_server(app).await;
+    let client = reqwest::Client::new();
+    let session = login(&client, addr).await;
+    let access_token = session["access_token"].as_str().unwrap();
+
+    let secret = enroll_mfa(&client, addr, access_token).await;
+    let res = post_mfa_code(
+        &client,
+        addr,
+        "/api/auth/mfa/enroll/confirm",
+        access_token,
+        &totp_code(&secret, 0),
+    )
+    .await;
+    assert_eq!(res.status(), StatusCode::OK);
+    let body: serde_json::Value = res.json().await.unwrap();
+    assert!(body.get("session").is_none());
+    let recovery_code = body["recovery_codes"][0].as_str().unwrap().to_string();
+
+    // 登録済みの状態で再登録はできない
+    let res = client
+        .post(format!("http://{}/api/auth/mfa/enroll", addr))
+        .bearer_auth(access_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::FORBIDDEN);
+
+    // 有効化後のログインは2段階目が必要（確認で使ったコードは再利用できない）
+    let res = try_login(&client, addr, TEST_EMAIL, TEST_PASSWORD).await;
+    let challenge: serde_json::Value = res.json().await.unwrap();
+    assert_eq!(challenge["mfa_required"], true);
+    let mfa_token = challenge["mfa_token"].as_str().unwrap();
+    let res = post_mfa_code(
+        &client,
+        addr,
+        "/api/auth/login/mfa",
+        mfa_token,
+        &totp_code(&secret, -1),
+    )
+    .await;
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+    let res = post_mfa_code(
+        &client,
+        addr,
+        "/api/auth/login/mfa",
+        mfa_token,
+        &totp_code(&secret, 1),
+    )
+    .await;
+    assert_eq!(res.status(), StatusCode::OK);
+    let session: serde_json::Value = res.json().await.unwrap();
+    let access_token = session["access_token"].as_str().unwrap();
+
+    let res = post_mfa_code(
+        &client,
+        addr,
+        "/api/auth/mfa/disable",
+        access_token,
+        &recovery_code,
+    )
+    .await;
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+
+    let res = try_login(&client, addr, TEST_EMAIL, TEST_PASSWORD).await;
+    assert_eq!(res.status(), StatusCode::OK);
+    let body: serde_json::Value = res.json().await.unwrap();
+    assert!(body["access_token"].is_string());
+}
+
+// APIキーを作成する
+async fn create_api_key(
+    client: &reqwest::Client,
+    addr: TestAddr,
+    bearer: &str,
+    body: serde_json::Value,
+) -> reqwest::Response {
+    client
+        .post(format!("http://{}/api/api-keys", addr))
+        .bearer_auth(bearer)
+        .json(&body)
+        .send()
+        .await
+        .unwrap()
+}
+
+/// APIキーのスコープ内でのみ`X-API-Key`ヘッダーで認証でき、削除後は使えないことを確認
+#[tokio::test]
+async fn test_api_key_authenticates_within_scopes() {
+    init_env();
+    let app = build_test_app().await;
+    let addr = spawn_test_server(app).await;
+    let client = reqwest::Client::new();
+    let admin = login_admin(&client, addr).await;
+    let admin_token = admin["access_token"].as_str().unwrap();
+    let user = login(&client, addr).await;
+    let user_id = user["user"]["id"].as_str().unwrap();
+
+    let res = create_api_key(
+        &client,
+        addr,
+        admin_token,
+        json!({"name": "batch", "scopes": ["users:write", "sessions:revoke"]}),
+    )
+    .await;
+    assert_eq!(res.status(), StatusCode::CREATED);
+    let created: serde_json::Value = res.json().await.unwrap();
+    let key = created["data"]["key"].as_str().unwrap().to_string();
+    let key_id = created["data"]["id"].as_str().unwrap().to_string();
+    assert!(key.starts_with("rca_"));
+    assert!(key.starts_with(created["data"]["prefix"].as_str().unwrap()));
+    assert!(created["data"]["expires_at"].is_string());
+
+    let res = client
+        .put(format!("http://{}/api/users/{}", addr, user_id))
+        .header("X-API-Key", &key)
+        .json(&json!({"name": "Updated By Batch"}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    let res = client
+        .delete(format!(
+            "http://{}/api/admin/users/{}/sessions",
+            addr, user_id
+        ))
+        .header("X-API-Key", &key)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    // スコープにない権限は、ロールが持っていても使えない
+    let res = create_api_key(
+        &client,
+        addr,
+        admin_token,
+        json!({"name": "read-only", "scopes": ["users:read"]}),
+    )
+    .await;
+    let read_only: serde_json::Value = res.json().await.unwrap();
+    let res = client
+        .put(format!("http://{}/api/users/{}", addr, user_id))
+        .header("X-API-Key", read_only["data"]["key"].as_str().unwrap())
+        .json(&json!({"name": "Not Allowed"}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::FORBIDDEN);
+
+    // APIキーでAPIキーは管理できない
+    let res = client
+        .get(format!("http://{}/api/api-keys", addr))
+        .header("X-API-Key", &key)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::FORBIDDEN);
+    let body: serde_json::Value = res.json().await.unwrap();
+    assert_eq!(body["error"]["code"], "OPERATION_NOT_PERMITTED");
+
+    let res = client
+        .get(format!("http://{}/api/api-keys/{}", addr, key_id))
+        .bearer_auth(admin_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    let body: serde_json::Value = res.json().await.unwrap();
+    assert!(body["data"]["last_used_at"].is_string());
+    assert!(body["data"].get("key").is_none());
+
+    let res = client
+        .delete(format!("http://{}/api/api-keys/{}", addr, key_id))
+        .bearer_auth(admin_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+    let res = client
+        .put(format!("http://{}/api/users/{}", addr, user_id))
+        .header("X-API-Key", &key)
+        .json(&json!({"name": "After Delete"}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+}
+
+/// APIキーの所有者とスコープの制限を確認（他人のキーの管理には`api_keys:manage`が必要）
+#[tokio::test]
+async fn test_api_key_management_is_limited_to_owner() {
+    init_env();
+    let app = build_test_app().await;
+    let addr = spawn_test_server(app).await;
+    let client = reqwest::Client::new();
+    let admin = login_admin(&client, addr).await;
+    let admin_token = admin["access_token"].as_str().unwrap();
+    let admin_id = admin["user"]["id"].as_str().unwrap();
+    let user = login(&client, addr).await;
+    let user_token = user["access_token"].as_str().unwrap();
+    let user_id = user["user"]["id"].as_str().unwrap();
+
+    // ロールにない権限はスコープに指定できない
+    let res = create_api_key(
+        &client,
+        addr,
+        user_token,
+        json!({"name": "too-wide", "scopes": ["users:write"]}),
+    )
+    .await;
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+    let res = create_api_key(
+        &client,
+        addr,
+        user_token,
+        json!({"name": "forever", "scopes": ["users:read"], "expires_in_days": 10000}),
+    )
+    .await;
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    // 管理者はサービスアカウント（他のユーザー）のキーを発行できる
+    let res = create_api_key(
+        &client,
+        addr,
+        admin_token,
+        json!({"owner_id": user_id, "name": "service", "scopes": ["users:read"]}),
+    )
+    .await;
+    assert_eq!(res.status(), StatusCode::CREATED);
+    let res = create_api_key(
+        &client,
+        addr,
+        admin_token,
+        json!({"name": "admin-own", "scopes": ["users:read"]}),
+    )
+    .await;
+    let admin_key: serde_json::Value = res.json().await.unwrap();
+    let admin_key_id = admin_key["data"]["id"].as_str().unwrap();
+
+    let res = client
+        .get(format!("http://{}/api/api-keys", addr))
+        .bearer_auth(user_token)
+        .send()
+        .await
+        .unwrap();
+    let body: serde_json::Value = res.json().await.unwrap();
+    let keys = body["data"].as_array().unwrap();
+    assert_eq!(keys.len(), 1);
+    assert_eq!(keys[0]["created_by"], admin_id);
+
+    let res = client
+        .get(format!(
+            "http://{}/api/api-keys?owner_id={}",
+            addr, admin_id
+        ))
+        .bearer_auth(user_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::FORBIDDEN);
+    let res = client
+        .patch(format!("http://{}/api/api-keys/{}", addr, admin_key_id))
+        .bearer_auth(user_token)
+        .json(&json!({"name": "stolen"}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+
+    let res = client
+        .patch(format!("http://{}/api/api-keys/{}", addr, admin_key_id))
+        .bearer_auth(admin_token)
+        .json(&json!({"name": "renamed", "scopes": ["users:read", "users:delete"]}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    let body: serde_json::Value = res.json().await.unwrap();
+    assert_eq!(body["data"]["name"], "renamed");
+    assert_eq!(
+        body["data"]["scopes"],
+        json!(["users:read", "users:delete"])
+    );
+
+    let res = client
+        .get(format!("http://{}/api/api-keys", addr))
+        .header("X-API-Key", "rca_not-a-real-key")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+}
+
+// OAuthクライアントを登録し、登録内容（`client_secret`を含む）を返す
+async fn register_oauth_client(
+    client: &reqwest::Client,
+    addr: TestAddr,
+    bearer: &str,
+    body: serde_json::Value,
+) -> serde_json::Value {
+    let res = client
+        .post(format!("http://{}/api/oauth/clients", addr))
+        .bearer_auth(bearer)
+        .json(&body)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::CREATED);
+    let body: serde_json::Value = res.json().await.unwrap();
+    body["data"].clone()
+}
+
+// `/oauth/*`にフォームを送信する（`basic`はクライアントIDとシークレット）
+async fn post_oauth_form(
+    client: &reqwest::Client,
+    addr: TestAddr,
+    path: &str,
+    basic: Option<(&str, &str)>,
+    form: &[(&str, &str)],
+) -> reqwest::Response {
+    let mut request = client.post(format!("http://{}{}", addr, path)).form(form);
+    if let Some((client_id, client_secret)) = basic {
+        request = request.basic_auth(client_id, Some(client_secret));
+    }
+    request.send().await.unwrap()
+}
+
+// リダイレクトURLのクエリパラメータ（テストで使う値はエンコード不要な文字のみ）
+fn query_param(location: &str, name: &str) -> Option<String> {
+    let (_, query) = location.split_once('?')?;
+    query.split('&').find_map(|pair| {
+        let (key, value) = pair.split_once('=')?;
+        (key == name).then(|| value.to_string())
+    })
+}
+
+const OAUTH_REDIRECT_URI: &str = "https://app.example.com/callback";
+
+// ログイン中のユーザーとして認可リクエストを送り、リダイレクト先のURLを返す
+async fn authorize_oauth(
+    addr: TestAddr,
+    bearer: &str,
+    client_id: &str,
+    redirect_uri: &str,
+    code_challenge: Option<&str>,
+) -> reqwest::Response {
+    let client = reqwest::Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .build()
+        .unwrap();
+    let mut query = vec![
+        ("response_type", "code"),
+        ("client_id", client_id),
+        ("redirect_uri", redirect_uri),
+        ("state", "xyz123"),
+    ];
+    if let Some(challenge) = code_challenge {
+        query.push(("code_challenge", challenge));
+        query.push(("code_challenge_method", "S256"));
+    }
+    client
+        .get(format!("http://{}/oauth/authorize", addr))
+        .bearer_auth(bearer)
+        .query(&query)
+        .send()
+        .await
+        .unwrap()
+}
+
+fn location(res: &reqwest::Response) -> String {
+    assert_eq!(res.status(), StatusCode::FOUND);
+    res.headers()["location"].to_str().unwrap().to_string()
+}
+
+/// client_credentialsグラントはサービスアカウントの権限内でのみ発行され、
+/// イントロスペクションと失効が機能することを確認
+#[tokio::test]
+async fn test_oauth_client_credentials_grant() {
+    init_env();
+    let app = build_test_app().await;
+    let addr = spawn_test_server(app).await;
+    let client = reqwest::Client::new();
+    let admin = login_admin(&client, addr).await;
+    let admin_token = admin["access_token"].as_str().unwrap();
+    let user = login(&client, addr).await;
+    let user_id = user["user"]["id"].as_str().unwrap();
+
+    // 一般ユーザーはクライアントを登録できない
+    let res = client
+        .get(format!("http://{}/api/oauth/clients", addr))
+        .bearer_auth(user["access_token"].as_str().unwrap())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::FORBIDDEN);
+
+    let registered = register_oauth_client(
+        &client,
+        addr,
+        admin_token,
+        json!({
+            "name": "batch",
+            "grant_types": ["client_credentials"],
+            "scopes": ["users:read", "users:write"],
+            "service_account_id": user_id,
+        }),
+    )
+    .await;
+    let client_id = registered["client_id"].as_str().unwrap();
+    let client_secret = registered["client_secret"].as_str().unwrap();
+    assert!(client_secret.starts_with("rcs_"));
+    assert_eq!(registered["confidential"], true);
+
+    let res = post_oauth_form(
+        &client,
+        addr,
+        "/oauth/token",
+        Some((client_id, "wrong-secret")),
+        &[("grant_type", "client_credentials")],
+    )
+    .await;
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+    let body: serde_json::Value = res.json().await.unwrap();
+    assert_eq!(body["error"], "invalid_client");
+
+    // サービスアカウントのロールにない権限は要求できない
+    let res = post_oauth_form(
+        &client,
+        addr,
+        "/oauth/token",
+        Some((client_id, client_secret)),
+        &[
+            ("grant_type", "client_credentials"),
+            ("scope", "users:write"),
+        ],
+    )
+    .await;
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+    let body: serde_json::Value = res.json().await.unwrap();
+    assert_eq!(body["error"], "invalid_scope");
+
+    let res = post_oauth_form(
+        &client,
+        addr,
+        "/oauth/token",
+        None,
+        &[
+            ("grant_type", "client_credentials"),
+            ("client_id", client_id),
+            ("client_secret", client_secret),
+        ],
+    )
+    .await;
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.headers()["cache-control"], "no-store");
+    let token: serde_json::Value = res.json().await.unwrap();
+    assert_eq!(token["token_type"], "Bearer");
+    assert_eq!(token["scope"], "users:read");
+    assert!(token.get("refresh_token").is_none());
+    let access_token = token["access_token"].as_str().unwrap();
+
+    // スコープにない操作は、本人のレコードでも拒否される
+    let res = client
+        .put(format!("http://{}/api/users/{}", addr, user_id))
+        .bearer_auth(access_token)
+        .json(&json!({"name": "Updated By Client"}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::FORBIDDEN);
+
+    let res = post_oauth_form(
+        &client,
+        addr,
+        "/oauth/introspect",
+        Some((client_id, client_secret)),
+        &[("token", access_token)],
+    )
+    .await;
+    let introspection: serde_json::Value = res.json().await.unwrap();
+    assert_eq!(introspection["active"], true);
+    assert_eq!(introspection["client_id"], client_id);
+    assert_eq!(introspection["sub"], user_id);
+    assert_eq!(introspection["scope"], "users:read");
+
+    let res = post_oauth_form(
+        &client,
+        addr,
+        "/oauth/revoke",
+        Some((client_id, client_secret)),
+        &[("token", access_token)],
+    )
+    .await;
+    assert_eq!(res.status(), StatusCode::OK);
+    let res = post_oauth_form(
+        &client,
+        addr,
+        "/oauth/introspect",
+        Some((client_id, client_secret)),
+        &[("token", access_token)],
+    )
+    .await;
+    let introspection: serde_json::Value = res.json().await.unwrap();
+    assert_eq!(introspection, json!({"active": false}));
+    let res = create_user_status(&client, addr, access_token).await;
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+
+    // 削除したクライアントは認証できない
+    let res = client
+        .delete(format!("http://{}/api/oauth/clients/{}", addr, client_id))
+        .bearer_auth(admin_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+    let res = post_oauth_form(
+        &client,
+        addr,
+        "/oauth/token",
+        Some((client_id, client_secret)),
+        &[("grant_type", "client_credentials")],
+    )
+    .await;
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+}
+
+/// OAuthクライアントのトークンでは、APIキー・二要素認証の管理と別の委任ができないことを確認
+#[tokio::test]
+async fn test_oauth_client_token_cannot_manage_credentials() {
+    init_env();
+    let app = build_test_app().await;
+    let addr = spawn_test_server(app).await;
+    let client = reqwest::Client::new();
+    let admin = login_admin(&client, addr).await;
+    let user = login(&client, addr).await;
+    let registered = register_oauth_client(
+        &client,
+        addr,
+        admin["access_token"].as_str().unwrap(),
+        json!({
+            "name": "profile-reader",
+            "grant_types": ["client_credentials"],
+            "scopes": ["users:read"],
+            "service_account_id": user["user"]["id"],
+        }),
+    )
+    .await;
+    let res = post_oauth_form(
+        &client,
+        addr,
+        "/oauth/token",
+        Some((
+            registered["client_id"].as_str().unwrap(),
+            registered["client_secret"].as_str().unwrap(),
+        )),
+        &[("grant_type", "client_credentials")],
+    )
+    .await;
+    assert_eq!(res.status(), StatusCode::OK);
+    let token: serde_json::Value = res.json().await.unwrap();
+    let access_token = token["access_token"].as_str().unwrap();
+
+    // 狭いスコープの委任から、所有者のロールの全権限を持つAPIキーは作れない
+    let res = create_api_key(
+        &client,
+        addr,
+        access_token,
+        json!({"name": "escalated", "scopes": ["users:read"]}),
+    )
+    .await;
+    assert_eq!(res.status(), StatusCode::FORBIDDEN);
+    let body: serde_json::Value = res.json().await.unwrap();
+    assert_eq!(body["error"]["code"], "OPERATION_NOT_PERMITTED");
+    let res = client
+        .get(format!("http://{}/api/api-keys", addr))
+        .bearer_auth(access_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::FORBIDDEN);
+
+    // 二要素認証の登録・解除・リカバリーコードの再発行もできない
+    let res = client
+        .post(format!("http://{}/api/auth/mfa/enroll", addr))
+        .bearer_auth(access_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::FORBIDDEN);
+    for path in [
+        "/api/auth/mfa/enroll/confirm",
+        "/api/auth/mfa/disable",
+        "/api/auth/mfa/recovery-codes",
+    ] {
+        let res = post_mfa_code(&client, addr, path, access_token, "000000").await;
+        assert_eq!(res.status(), StatusCode::FORBIDDEN, "{}", path);
+    }
+
+    // 本人のログインセッションでは登録できる
+    enroll_mfa(&client, addr, user["access_token"].as_str().unwrap()).await;
+}
+
+/// 公開クライアントの認可コード + PKCEフローと、コードの再利用・検証値の不一致・
+/// リフレッシュトークンのクライアント間流用が拒否されることを確認
+#[tokio::test]
+async fn test_oauth_authorization_code_with_pkce() {
+    init_env();
+    let app = build_test_app().await;
+    let addr = spawn_test_server(app).await;
+    let client = reqwest::Client::new();
+    let admin = login_admin(&client, addr).await;
+    let admin_token = admin["access_token"].as_str().unwrap();
+    let user = login(&client, addr).await;
+    let user_token = user["access_token"].as_str().unwrap();
+
+    let registered = register_oauth_client(
+        &client,
+        addr,
+        admin_token,
+        json!({
+            "name": "spa",
+            "confidential": false,
+            "redirect_uris": [OAUTH_REDIRECT_URI],
+            "grant_types": ["authorization_code", "refresh_token"],
+            "scopes": ["users:read", "users:write"],
+        }),
+    )
+    .await;
+    assert!(registered.get("client_secret").is_none());
+    let client_id = registered["client_id"].as_str().unwrap();
+    let verifier = "dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk";
+    let challenge = pkce::s256_challenge(verifier);
+
+    // 未登録のリダイレクトURIにはリダイレクトしない
+    let res = authorize_oauth(
+        addr,
+        user_token,
+        client_id,
+        "https://evil.example.com/callback",
+        Some(&challenge),
+    )
+    .await;
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    // PKCEなしの要求はエラーとしてリダイレクトする
+    let res = authorize_oauth(addr, user_token, client_id, OAUTH_REDIRECT_URI, None).await;
+    let redirected = location(&res);
+    assert!(redirected.starts_with(OAUTH_REDIRECT_URI));
+    assert_eq!(
+        query_param(&redirected, "error").as_deref(),
+        Some("invalid_request")
+    );
+    assert_eq!(query_param(&redirected, "state").as_deref(), Some("xyz123"));
+
+    // 検証値が一致しない場合、コードは消費されて再試行もできない
+    let res = authorize_oauth(
+        addr,
+        user_token,
+        client_id,
+        OAUTH_REDIRECT_URI,
+        Some(&challenge),
+    )
+    .await;
+    let code = query_param(&location(&res), "code").unwrap();
+    let exchange = |code: String, verifier: &'static str| {
+        let client = client.clone();
+        async move {
+            post_oauth_form(
+                &client,
+                addr,
+                "/oauth/token",
+                None,
+                &[
+                    ("grant_type", "authorization_code"),
+                    ("client_id", client_id),
+                    ("code", &code),
+                    ("redirect_uri", OAUTH_REDIRECT_URI),
+                    ("code_verifier", verifier),
+                ],
+            )
+            .await
+        }
+    };
+    let wrong_verifier = "wrong-verifier-wrong-verifier-wrong-verifier-0";
+    let res = exchange(code.clone(), wrong_verifier).await;
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+    let body: serde_json::Value = res.json().await.unwrap();
+    assert_eq!(body["error"], "invalid_grant");
+    let res = exchange(code, verifier).await;
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    // 一般ユーザーのロールにない権限は付与されない
+    let res = authorize_oauth(
+        addr,
+        user_token,
+        client_id,
+        OAUTH_REDIRECT_URI,
+        Some(&challenge),
+    )
+    .await;
+    let code = query_param(&location(&res), "code").unwrap();
+    let res = exchange(code.clone(), verifier).await;
+    assert_eq!(res.status(), StatusCode::OK);
+    let token: serde_json::Value = res.json().await.unwrap();
+    assert_eq!(token["scope"], "users:read");
+    let refresh_token = token["refresh_token"].as_str().unwrap().to_string();
+    let res = exchange(code, verifier).await;
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    // クライアントのトークンはクライアント経由でのみ更新できる
+    let res = client
+        .post(format!("http://{}/api/auth/refresh", addr))
+        .json(&json!({"refresh_token": refresh_token}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+    let res = post_oauth_form(
+        &client,
+        addr,
+        "/oauth/token",
+        None,
+        &[
+            ("grant_type", "refresh_token"),
+            ("client_id", client_id),
+            ("refresh_token", user["refresh_token"].as_str().unwrap()),
+        ],
+    )
+    .await;
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+    let res = post_oauth_form(
+        &client,
+        addr,
+        "/oauth/token",
+        None,
+        &[
+            ("grant_type", "refresh_token"),
+            ("client_id", client_id),
+            ("refresh_token", &refresh_token),
+        ],
+    )
+    .await;
+    assert_eq!(res.status(), StatusCode::OK);
+    let refreshed: serde_json::Value = res.json().await.unwrap();
+    assert_eq!(refreshed["scope"], "users:read");
+
+    // 公開クライアントはclient_credentialsを使えない
+    let res = post_oauth_form(
+        &client,
+        addr,
+        "/oauth/token",
+        None,
+        &[
+            ("grant_type", "client_credentials"),
+            ("client_id", client_id),
+        ],
+    )
+    .await;
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+    let body: serde_json::Value = res.json().await.unwrap();
+    assert_eq!(body["error"], "unauthorized_client");
+
+    // リフレッシュトークンの失効でセッションごと終了する
+    let new_refresh = refreshed["refresh_token"].as_str().unwrap();
+    let res = post_oauth_form(
+        &client,
+        addr,
+        "/oauth/revoke",
+        None,
+        &[("client_id", client_id), ("token", new_refresh)],
+    )
+    .await;
+    assert_eq!(res.status(), StatusCode::OK);
+    let res = create_user_status(&client, addr, refreshed["access_token"].as_str().unwrap()).await;
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+}
+
+/// `openid`スコープ付きの認可コードの交換でIDトークンが発行され、
+/// UserInfo・ディスカバリーがスコープに応じたクレームを返すことを確認
+#[tokio::test]
+async fn test_openid_connect_id_token_and_userinfo() {
+    init_env();
+    let app = build_test_app().await;
+    let addr = spawn_test_server(app).await;
+    let client = reqwest::Client::new();
+    let no_redirect = reqwest::Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .build()
+        .unwrap();
+    let admin = login_admin(&client, addr).await;
+    let admin_token = admin["access_token"].as_str().unwrap();
+
+    // ディスカバリー
+    let res = client
+        .get(format!("http://{}/.well-known/openid-configuration", addr))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    let discovery: serde_json::Value = res.json().await.unwrap();
+    let issuer = discovery["issuer"].as_str().unwrap().to_string();
+    assert_eq!(
+        discovery["userinfo_endpoint"],
+        format!("{}/userinfo", issuer)
+    );
+    assert_eq!(
+        discovery["jwks_uri"],
+        format!("{}/.well-known/jwks.json", issuer)
+    );
+    assert_eq!(
+        discovery["code_challenge_methods_supported"],
+        json!(["S256"])
+    );
+    let scopes = discovery["scopes_supported"].as_array().unwrap();
+    assert!(scopes.contains(&json!("openid")));
+    assert!(scopes.contains(&json!("users:read")));
+
+    // 生年月日を持つユーザー
+    let email = "oidc_user@example.com";
+    let res = client
+        .post(format!("http://{}/api/users", addr))
+        .bearer_auth(admin_token)
+        .json(&json!({
+            "email": email,
+            "name": "Oidc User",
+            "password": TEST_PASSWORD,
+            "birth_date": "1990-01-01",
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::CREATED);
+    let res = try_login(&client, addr, email, TEST_PASSWORD).await;
+    assert_eq!(res.status(), StatusCode::OK);
+    let session: serde_json::Value = res.json().await.unwrap();
+    let user_token = session["access_token"].as_str().unwrap();
+    let user_id = session["user"]["id"].as_str().unwrap();
+
+    let registered = register_oauth_client(
+        &client,
+        addr,
+        admin_token,
+        json!({
+            "name": "oidc-app",
+            "confidential": false,
+            "redirect_uris": [OAUTH_REDIRECT_URI],
+            "grant_types": ["authorization_code"],
+            "scopes": ["users:read"],
+        }),
+    )
+    .await;
+    let client_id = registered["client_id"].as_str().unwrap();
+    let verifier = "dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk";
+    let challenge = pkce::s256_challenge(verifier);
+    let authorize_and_exchange = |scope: &'static str| {
+        let client = client.clone();
+        let no_redirect = no_redirect.clone();
+        let challenge = challenge.clone();
+        async move {
+            let res = no_redirect
+                .get(format!("http://{}/oauth/authorize", addr))
+                .bearer_auth(user_token)
+                .query(&[
+                    ("response_type", "code"),
+                    ("client_id", client_id),
+                    ("redirect_uri", OAUTH_REDIRECT_URI),
+                    ("scope", scope),
+                    ("nonce", "n-0S6_WzA2Mj"),
+                    ("code_challenge", &challenge),
+                    ("code_challenge_method", "S256"),
+                ])
+                .send()
+                .await
+                .unwrap();
+            let code = query_param(&location(&res), "code").unwrap();
+            let res = post_oauth_form(
+                &client,
+                addr,
+                "/oauth/token",
+                None,
+                &[
+                    ("grant_type", "authorization_code"),
+                    ("client_id", client_id),
+                    ("code", &code),
+                    ("redirect_uri", OAUTH_REDIRECT_URI),
+                    ("code_verifier", verifier),
+                ],
+            )
+            .await;
+            assert_eq!(res.status(), StatusCode::OK);
+            res.json::<serde_json::Value>().await.unwrap()
+        }
+    };
+
+    // IDトークンは発行者・発行先・nonceとスコープに応じたクレームを持つ
+    let token = authorize_and_exchange("openid profile email users:read").await;
+    assert_eq!(token["scope"], "openid profile email users:read");
+    let id_token = token["id_token"].as_str().unwrap();
+    let claims = IdTokenClaims::from_token(id_token, &issuer, client_id).unwrap();
+    assert_eq!(claims.sub, user_id);
+    assert_eq!(claims.nonce.as_deref(), Some("n-0S6_WzA2Mj"));
+    assert_eq!(claims.email.as_deref(), Some(email));
+    assert_eq!(claims.email_verified, Some(false));
+    assert_eq!(claims.name.as_deref(), Some("Oidc User"));
+    assert_eq!(claims.birthdate.as_deref(), Some("1990-01-01"));
+    assert!(claims.phone_number.is_none());
+    assert!(IdTokenClaims::from_token(id_token, &issuer, "another-client").is_err());
+
+    // IDトークンはAPIの認証には使えない
+    let res = client
+        .get(format!("http://{}/userinfo", addr))
+        .bearer_auth(id_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+
+    let res = client
+        .get(format!("http://{}/userinfo", addr))
+        .bearer_auth(token["access_token"].as_str().unwrap())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    let userinfo: serde_json::Value = res.json().await.unwrap();
+    assert_eq!(userinfo["sub"], user_id);
+    assert_eq!(userinfo["email"], email);
+    assert_eq!(userinfo["name"], "Oidc User");
+    assert_eq!(userinfo["birthdate"], "1990-01-01");
+    assert!(userinfo.get("phone_number").is_none());
+
+    // emailスコープのみの場合はプロフィールを返さない
+    let token = authorize_and_exchange("openid email").await;
+    let res = client
+        .post(format!("http://{}/userinfo", addr))
+        .bearer_auth(token["access_token"].as_str().unwrap())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    let userinfo: serde_json::Value = res.json().await.unwrap();
+    assert_eq!(userinfo["email"], email);
+    assert!(userinfo.get("name").is_none());
+    assert!(userinfo.get("birthdate").is_none());
+
+    // openidなしのトークンにはIDトークンもUserInfoもない
+    let token = authorize_and_exchange("users:read").await;
+    assert!(token.get("id_token").is_none());
+    let res = client
+        .get(format!("http://{}/userinfo", addr))
+        .bearer_auth(token["access_token"].as_str().unwrap())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::FORBIDDEN);
+    assert!(
+        res.headers()["www-authenticate"]
+            .to_str()
+            .unwrap()
+            .contains("insufficient_scope")
+    );
+}
+
+/// スーパー管理者のみがなりすましトークンを発行でき、そのトークンでは
+/// 二要素認証・資格情報を変更できず、各リクエストが監査ログに残ることを確認
+#[tokio::test]
+async fn test_superadmin_impersonation() {
+    init_env();
+    let (app, _, di) = build_test_app_with_container().await;
+    let addr = spawn_test_server(app).await;
+    let client = reqwest::Client::new();
+    let superadmin =
+        login_with_mfa_enrollment(&client, addr, SUPERADMIN_EMAIL, SUPERADMIN_PASSWORD).await;
+    let superadmin_token = superadmin["access_token"].as_str().unwrap();
+    let superadmin_id = superadmin["user"]["id"].as_str().unwrap();
+    let admin = login_admin(&client, addr).await;
+    let user = login(&client, addr).await;
+    let user_id = user["user"]["id"].as_str().unwrap();
+    let impersonate = |bearer: String, target: String| {
+        let client = client.clone();
+        async move {
+            client
+                .post(format!(
+                    "http://{}/api/admin/users/{}/impersonate",
+                    addr, target
+                ))
+                .bearer_auth(bearer)
+                .send()
+                .await
+                .unwrap()
+        }
+    };
+
+    // スーパー管理者以外は発行できない
+    for bearer in [&admin, &user] {
+        let bearer = bearer["access_token"].as_str().unwrap().to_string();
+        let res = impersonate(bearer, user_id.to_string()).await;
+        assert_eq!(res.status(), StatusCode::FORBIDDEN);
+    }
+    let res = impersonate(superadmin_token.to_string(), superadmin_id.to_string()).await;
+    assert_eq!(res.status(), StatusCode::FORBIDDEN);
+    let res = impersonate(
+        superadmin_token.to_string(),
+        uuid::Uuid::new_v4().to_string(),
+    )
+    .await;
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+
+    let res = impersonate(superadmin_token.to_string(), user_id.to_string()).await;
+    assert_eq!(res.status(), StatusCode::CREATED);
+    let body: serde_json::Value = res.json().await.unwrap();
+    let data = &body["data"];
+    assert_eq!(data["user"]["id"], user_id);
+    assert_eq!(data["impersonator_id"], superadmin_id);
+    assert!(data.get("refresh_token").is_none());
+    let token = data["access_token"].as_str().unwrap();
+    let claims = JwtClaims::from_token(token).unwrap();
+    assert_eq!(claims.sub, user_id);
+    assert_eq!(claims.impersonator(), Some(superadmin_id));
+
+    // 対象ユーザーとして通常のAPIを利用できる
+    let res = create_user_status(&client, addr, token).await;
+    assert_eq!(res.status(), StatusCode::CREATED);
+
+    // 二要素認証・APIキーは変更できない
+    for (path, body) in [
+        ("/api/auth/mfa/enroll", json!({})),
+        ("/api/auth/mfa/disable", json!({"code": "000000"})),
+        ("/api/auth/mfa/recovery-codes", json!({"code": "000000"})),
+        (
+            "/api/api-keys",
+            json!({"name": "ci", "scopes": ["users:read"]}),
+        ),
+    ] {
+        let res = client
+            .post(format!("http://{}{}", addr, path))
+            .bearer_auth(token)
+            .json(&body)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), StatusCode::FORBIDDEN, "{}", path);
+        let body: serde_json::Value = res.json().await.unwrap();
+        assert_eq!(body["error"]["code"], "OPERATION_NOT_PERMITTED", "{}", path);
+    }
+
+    // なりすましトークンでさらになりすましはできない
+    let res = impersonate(token.to_string(), user_id.to_string()).await;
+    assert_eq!(res.status(), StatusCode::FORBIDDEN);
+
+    // 開始と各リクエストが両方の主体付きで監査ログに残る
+    let events = di
+        .create_audit_log_repository()
+        .unwrap()
+        .find_by_subject(&format!("user:{}", user_id), 50)
+        .await
+        .unwrap();
+    assert!(events.iter().any(|event| {
+        event.action == "impersonation.started" && event.actor.as_deref() == Some(superadmin_id)
+    }));
+    assert!(events.iter().any(|event| {
+        event.action == "impersonation.request"
+            && event.actor.as_deref() == Some(superadmin_id)
+            && event.detail.as_deref() == Some("POST /api/users")
+    }));
+}
+
+/// なりすましトークンは、対象ユーザーの全セッション終了とスーパー管理者のlogout-allで無効になることを確認
+#[tokio::test]
+async fn test_impersonation_tokens_are_revoked_with_sessions() {
+    init_env();
+    let app = build_test_app().await;
+    let addr = spawn_test_server(app).await;
+    let client = reqwest::Client::new();
+    let superadmin =
+        login_with_mfa_enrollment(&client, addr, SUPERADMIN_EMAIL, SUPERADMIN_PASSWORD).await;
+    let superadmin_token = superadmin["access_token"].as_str().unwrap();
+    let user_id = login(&client, addr).await["user"]["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    let admin_id = login_admin(&client, addr).await["user"]["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    let mut tokens = Vec::new();
+    for target in [&user_id, &admin_id] {
+        let res = client
+            .post(format!(
+                "http://{}/api/admin/users/{}/impersonate",
+                addr, target
+            ))
+            .bearer_auth(superadmin_token)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), StatusCode::CREATED);
+        let body: serde_json::Value = res.json().await.unwrap();
+        tokens.push(body["data"]["access_token"].as_str().unwrap().to_string());
+    }
+    let (user_token, admin_token) = (&tokens[0], &tokens[1]);
+
+    // 対象ユーザーの全セッションを終了すると、そのユーザーへのなりすましも終了する
+    let res = client
+        .delete(format!(
+            "http://{}/api/admin/users/{}/sessions",
+            addr, user_id
+        ))
+        .bearer_auth(superadmin_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    let res = create_user_status(&client, addr, user_token).await;
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+    let body: serde_json::Value = res.json().await.unwrap();
+    assert_eq!(body["error"]["code"], "TOKEN_REVOKED");
+    let res = create_user_status(&client, addr, admin_token).await;
+    assert_eq!(res.status(), StatusCode::CREATED);
+
+    // スーパー管理者がlogout-allすると、発行済みのなりすましトークンも使えない
+    let res = client
+        .post(format!("http://{}/api/auth/logout-all", addr))
+        .bearer_auth(superadmin_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    let res = create_user_status(&client, addr, admin_token).await;
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+}
+
+// `Set-Cookie`ヘッダーを名前ごとに取り出す（値, 属性を含むヘッダー全体）
+fn set_cookies(res: &reqwest::Response) -> std::collections::HashMap<String, (String, String)> {
+    res.headers()
+        .get_all(reqwest::header::SET_COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .filter_map(|header| {
+            let (name, rest) = header.split_once('=')?;
+            let value = rest.split(';').next().unwrap_or_default();
+            Some((name.to_string(), (value.to_string(), header.to_string())))
+        })
+        .collect()
+}
+
+// Cookieセッションで作成リクエストを送る
+async fn create_user_with_cookies(
+    client: &reqwest::Client,
+    addr: TestAddr,
+    cookie: &str,
+    csrf_token: Option<&str>,
+) -> reqwest::Response {
+    let mut request = client
+        .post(format!("http://{}/api/users", addr))
+        .header(reqwest::header::COOKIE, cookie)
+        .json(&json!({
+            "email": format!("{}@example.com", uuid::Uuid::new_v4()),
+            "name": "Cookie Session",
+            "password": "Password123!"
+        }));
+    if let Some(csrf_token) = csrf_token {
+        request = request.header("x-csrf-token", csrf_token);
+    }
+    request.send().await.unwrap()
+}
+
+/// Cookieセッションではトークンを本文に含めずHttpOnly Cookieで受け渡し、
+/// 状態を変更するリクエストにはCSRFトークンが必要なことを確認
+#[tokio::test]
+async fn test_cookie_session_with_csrf_protection() {
+    init_env();
+    let app = build_test_app().await;
+    let addr = spawn_test_server(app).await;
+    let client = reqwest::Client::new();
+
+    let res = client
+        .post(format!("http://{}/api/auth/login", addr))
+        .header("x-session-mode", "cookie")
+        .json(&json!({"email": TEST_EMAIL, "password": TEST_PASSWORD}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    let cookies = set_cookies(&res);
+    let body: serde_json::Value = res.json().await.unwrap();
+    assert!(body.get("access_token").is_none());
+    assert!(body.get("refresh_token").is_none());
+    assert_eq!(body["user"]["email"], TEST_EMAIL);
+    let (access_token, access_header) = &cookies["access_token"];
+    let (refresh_token, refresh_header) = &cookies["refresh_token"];
+    let (csrf_token, csrf_header) = &cookies["csrf_token"];
+    assert_eq!(body["csrf_token"], csrf_token.as_str());
+    for header in [access_header, refresh_header] {
+        assert!(header.contains("HttpOnly"), "{}", header);
+        assert!(header.contains("Secure"), "{}", header);
+        assert!(header.contains("SameSite=Strict"), "{}", header);
+    }
+    assert!(refresh_header.contains("Path=/api/auth"));
+    assert!(!csrf_header.contains("HttpOnly"));
+    let session_cookie = format!("access_token={}; csrf_token={}", access_token, csrf_token);
+
+    // CSRFトークンがない・一致しない場合は拒否
+    let res = create_user_with_cookies(&client, addr, &session_cookie, None).await;
+    assert_eq!(res.status(), StatusCode::FORBIDDEN);
+    let body: serde_json::Value = res.json().await.unwrap();
+    assert_eq!(body["error"]["code"], "CSRF_TOKEN_INVALID");
+    let res = create_user_with_cookies(&client, addr, &session_cookie, Some("forged")).await;
+    assert_eq!(res.status(), StatusCode::FORBIDDEN);
+    let res = create_user_with_cookies(&client, addr, &session_cookie, Some(csrf_token)).await;
+    assert_eq!(res.status(), StatusCode::CREATED);
+
+    // Bearerトークンでの利用は従来どおりCSRFトークン不要
+    let res = create_user_status(&client, addr, access_token).await;
+    assert_eq!(res.status(), StatusCode::CREATED);
+
+    // リフレッシュトークンのCookieでローテーションできる（CSRFトークン必須）
+    let refresh_cookie = format!("refresh_token={}; csrf_token={}", refresh_token, csrf_token);
+    let res = client
+        .post(format!("http://{}/api/auth/refresh", addr))
+        .header(reqwest::header::COOKIE, &refresh_cookie)
+        .json(&json!({}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::FORBIDDEN);
+    let res = client
+        .post(format!("http://{}/api/auth/refresh", addr))
+        .header(reqwest::header::COOKIE, &refresh_cookie)
+        .header("x-csrf-token", csrf_token)
+        .json(&json!({}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    let rotated = set_cookies(&res);
+    let body: serde_json::Value = res.json().await.unwrap();
+    assert!(body.get("access_token").is_none());
+    let (new_access_token, _) = &rotated["access_token"];
+    let (new_refresh_token, _) = &rotated["refresh_token"];
+    let (new_csrf_token, _) = &rotated["csrf_token"];
+    assert_ne!(new_refresh_token, refresh_token);
+    assert_ne!(new_csrf_token, csrf_token);
+
+    // ログアウトでCookieが削除され、アクセストークンも使えなくなる
+    let session_cookie = format!(
+        "access_token={}; csrf_token={}",
+        new_access_token, new_csrf_token
+    );
+    let res = client
+        .post(format!("http://{}/api/auth/logout", addr))
+        .header(reqwest::header::COOKIE, &session_cookie)
+        .header("x-csrf-token", new_csrf_token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+    let cleared = set_cookies(&res);
+    for name in ["access_token", "refresh_token", "csrf_token"] {
+        let (value, header) = &cleared[name];
+        assert!(value.is_empty(), "{}", name);
+        assert!(header.contains("Max-Age=0"), "{}", header);
+    }
+    let res = create_user_with_cookies(&client, addr, &session_cookie, Some(new_csrf_token)).await;
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+}
+
+/// ユーザー作成時のパスワードポリシー違反がすべて構造化されて返ることを確認
+#[tokio::test]
+async fn test_password_policy_violations_are_returned_together() {
+    init_env();
+    let app = build_test_app().await;
+    let addr = spawn_test_server(app).await;
+    let client = reqwest::Client::new();
+    let session = login(&client, addr).await;
+    let res = client
+        .post(format!("http://{}/api/users", addr))
+        .bearer_auth(session["access_token"].as_str().unwrap())
+        .json(&json!({
+            "email": "hanako.suzuki@example.com",
+            "name": "Hanako Suzuki",
+            "password": "suzuki"
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+    let body: serde_json::Value = res.json().await.unwrap();
+    assert_eq!(body["error"]["code"], "PASSWORD_POLICY_VIOLATION");
+    let codes: Vec<&str> = body["error"]["violations"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|violation| violation["code"].as_str().unwrap())
+        .collect();
+    assert_eq!(codes, ["too_short", "contains_email", "contains_name"]);
+}
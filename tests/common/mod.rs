@@ -0,0 +1,4 @@
+// tests/common/mod.rs
+// 複数のテストで使う共通の処理
+
+pub mod user_repository_conformance;
@@ -0,0 +1,497 @@
+// tests/common/user_repository_conformance.rs
+// ユーザーRepositoryの適合テスト（全ての実装が同じ振る舞いをすることを確認する）
+
+use chrono::{Duration, Utc};
+use rusted_ca::domain::entity::user::User;
+use rusted_ca::domain::repository::user_command_repository::UserCommandRepositoryInterface;
+use rusted_ca::domain::repository::user_query_repository::UserQueryRepositoryInterface;
+use rusted_ca::domain::value_object::pagination::{
+    PaginationParams, SortOrder, SortParams, TimeGranularity, TimePeriod, UserSearchFilters,
+};
+use rusted_ca::domain::value_object::{
+    birth_date::BirthDate, email::Email, password_hash::PasswordHash, phone::Phone, role::Role,
+    user_id::UserId, user_name::UserName,
+};
+
+const HASH: &str = "$argon2id$v=19$m=19456,t=2,p=1$c2FsdA$aGFzaA";
+const OTHER_HASH: &str = "$argon2id$v=19$m=19456,t=2,p=1$b3RoZXI$b3RoZXI";
+
+fn user(id: &str, email: &str, name: &str, phone: Option<&str>) -> User {
+    User::new(
+        UserId::new(id.to_string()),
+        Email::new(email.to_string()).unwrap(),
+        UserName::new(name.to_string()).unwrap(),
+        PasswordHash::new(HASH.to_string()).unwrap(),
+        phone.map(|p| Phone::new(p.to_string()).unwrap()),
+        None,
+    )
+    .unwrap()
+}
+
+fn email(value: &str) -> Email {
+    Email::new(value.to_string()).unwrap()
+}
+
+fn filters() -> UserSearchFilters {
+    UserSearchFilters {
+        email_domain: None,
+        name_contains: None,
+        created_after: None,
+        created_before: None,
+        has_phone: None,
+        age_range: None,
+    }
+}
+
+fn sort(field: &str, order: SortOrder) -> SortParams {
+    SortParams {
+        field: field.to_string(),
+        order,
+    }
+}
+
+fn names(users: &[User]) -> Vec<&str> {
+    users.iter().map(|u| u.name.0.as_str()).collect()
+}
+
+/// 空のCommand/Query Repositoryの組に対して適合テストを実行する
+///
+/// 2つのRepositoryは同じ保存先を使うこと。`backend`は失敗時の表示に使う
+pub async fn run<C, Q>(backend: &str, command: &C, query: &Q)
+where
+    C: UserCommandRepositoryInterface + ?Sized,
+    Q: UserQueryRepositoryInterface + ?Sized,
+{
+    assert_eq!(
+        query.count_total().await.unwrap(),
+        0,
+        "{}: not empty",
+        backend
+    );
+    save_and_find(backend, command, query).await;
+    update(backend, command, query).await;
+    save_batch(backend, command, query).await;
+    list_and_search(backend, query).await;
+    analytics(backend, command, query).await;
+    delete(backend, command, query).await;
+}
+
+// 保存・取得（全ての項目が保存されること、IDとメールアドレスの重複は保存しないこと）
+async fn save_and_find<C, Q>(backend: &str, command: &C, query: &Q)
+where
+    C: UserCommandRepositoryInterface + ?Sized,
+    Q: UserQueryRepositoryInterface + ?Sized,
+{
+    let verified_at = Utc::now() - Duration::minutes(5);
+    let mut alice = user("alice", "alice@example.com", "Alice", Some("09012345678"))
+        .with_role(Role::Admin)
+        .with_email_verified_at(Some(verified_at));
+    alice.birth_date = Some(BirthDate::new("1990-04-01".to_string()).unwrap());
+    command.save(&alice).await.unwrap();
+
+    let found = query.find_by_id(&alice.id).await.unwrap().unwrap();
+    assert_eq!(found.email, alice.email, "{}", backend);
+    assert_eq!(found.name, alice.name, "{}", backend);
+    assert_eq!(found.password_hash, alice.password_hash, "{}", backend);
+    assert_eq!(found.phone, alice.phone, "{}", backend);
+    assert_eq!(found.birth_date, alice.birth_date, "{}", backend);
+    assert_eq!(found.role, Role::Admin, "{}", backend);
+    assert_eq!(
+        found.email_verified_at.map(|t| t.timestamp_micros()),
+        Some(verified_at.timestamp_micros()),
+        "{}",
+        backend
+    );
+    let by_email = query.find_by_email(&alice.email).await.unwrap().unwrap();
+    assert_eq!(by_email.id, alice.id, "{}", backend);
+    assert!(query.exists_by_email(&alice.email).await.unwrap());
+    assert!(command.exists_by_email(&alice.email).await.unwrap());
+
+    let missing = UserId::new("missing".to_string());
+    assert!(query.find_by_id(&missing).await.unwrap().is_none());
+    assert!(
+        query
+            .find_by_email(&email("missing@example.com"))
+            .await
+            .unwrap()
+            .is_none()
+    );
+    assert!(
+        !command
+            .exists_by_email(&email("missing@example.com"))
+            .await
+            .unwrap()
+    );
+
+    // IDとメールアドレスは一意
+    let same_id = user("alice", "alice2@example.com", "Alice Two", None);
+    assert!(
+        command.save(&same_id).await.is_err(),
+        "{}: duplicate id",
+        backend
+    );
+    let same_email = user("alice-2", "alice@example.com", "Alice Two", None);
+    assert!(
+        command.save(&same_email).await.is_err(),
+        "{}: duplicate email",
+        backend
+    );
+    assert_eq!(query.count_total().await.unwrap(), 1, "{}", backend);
+}
+
+// 更新（メールアドレスを変えると確認済みの状態を取り消す、存在しないユーザーは何もしない）
+async fn update<C, Q>(backend: &str, command: &C, query: &Q)
+where
+    C: UserCommandRepositoryInterface + ?Sized,
+    Q: UserQueryRepositoryInterface + ?Sized,
+{
+    let alice_id = UserId::new("alice".to_string());
+    command
+        .save(&user("bob", "bob@example.com", "Bob", None))
+        .await
+        .unwrap();
+
+    let mut alice = query.find_by_id(&alice_id).await.unwrap().unwrap();
+    alice.name = UserName::new("Alice Renamed".to_string()).unwrap();
+    command.update(&alice).await.unwrap();
+    let found = query.find_by_id(&alice_id).await.unwrap().unwrap();
+    assert_eq!(found.name.0, "Alice Renamed", "{}", backend);
+    assert!(found.is_email_verified(), "{}: verification kept", backend);
+
+    // 他のユーザーのメールアドレスには変更できない
+    let mut taken = found.clone();
+    taken.email = email("bob@example.com");
+    assert!(
+        command.update(&taken).await.is_err(),
+        "{}: email taken",
+        backend
+    );
+    let found = query.find_by_id(&alice_id).await.unwrap().unwrap();
+    assert_eq!(found.email.0, "alice@example.com", "{}", backend);
+
+    let mut moved = found.clone();
+    moved.email = email("alice@example.org");
+    command.update(&moved).await.unwrap();
+    let found = query.find_by_id(&alice_id).await.unwrap().unwrap();
+    assert_eq!(found.email.0, "alice@example.org", "{}", backend);
+    assert!(
+        !found.is_email_verified(),
+        "{}: verification reset",
+        backend
+    );
+    assert!(
+        !query
+            .exists_by_email(&email("alice@example.com"))
+            .await
+            .unwrap()
+    );
+    assert!(
+        query
+            .exists_by_email(&email("alice@example.org"))
+            .await
+            .unwrap()
+    );
+
+    // 項目ごとの更新
+    command
+        .update_password_hash(
+            &alice_id,
+            &PasswordHash::new(OTHER_HASH.to_string()).unwrap(),
+        )
+        .await
+        .unwrap();
+    command
+        .update_role(&alice_id, Role::SuperAdmin)
+        .await
+        .unwrap();
+    let verified_at = Utc::now();
+    command
+        .mark_email_verified(&alice_id, verified_at)
+        .await
+        .unwrap();
+    let found = query.find_by_id(&alice_id).await.unwrap().unwrap();
+    assert_eq!(found.password_hash.0, OTHER_HASH, "{}", backend);
+    assert_eq!(found.role, Role::SuperAdmin, "{}", backend);
+    assert_eq!(
+        found.email_verified_at.map(|t| t.timestamp_micros()),
+        Some(verified_at.timestamp_micros()),
+        "{}",
+        backend
+    );
+
+    // 存在しないユーザーの更新はエラーにせず、作成もしない
+    let ghost = user("ghost", "ghost@example.com", "Ghost", None);
+    command.update(&ghost).await.unwrap();
+    command.update_role(&ghost.id, Role::Admin).await.unwrap();
+    command
+        .update_last_login(&ghost.id, Utc::now())
+        .await
+        .unwrap();
+    assert!(query.find_by_id(&ghost.id).await.unwrap().is_none());
+    assert_eq!(query.count_total().await.unwrap(), 2, "{}", backend);
+}
+
+// 一括保存（1件でも保存できなければ何も保存しない）
+async fn save_batch<C, Q>(backend: &str, command: &C, query: &Q)
+where
+    C: UserCommandRepositoryInterface + ?Sized,
+    Q: UserQueryRepositoryInterface + ?Sized,
+{
+    let conflicting = [
+        user("carol", "carol@example.net", "Carol", Some("08011112222")),
+        user("dave", "bob@example.com", "Dave", None),
+    ];
+    assert!(
+        command.save_batch(&conflicting).await.is_err(),
+        "{}",
+        backend
+    );
+    let duplicated = [
+        user("carol", "carol@example.net", "Carol", Some("08011112222")),
+        user("carol", "carol2@example.net", "Carol Two", None),
+    ];
+    assert!(
+        command.save_batch(&duplicated).await.is_err(),
+        "{}",
+        backend
+    );
+    assert!(
+        query
+            .find_by_id(&UserId::new("carol".to_string()))
+            .await
+            .unwrap()
+            .is_none()
+    );
+    assert_eq!(query.count_total().await.unwrap(), 2, "{}", backend);
+
+    let batch = [
+        user("carol", "carol@example.net", "Carol", Some("08011112222")),
+        user("dave", "dave@example.net", "Dave", None),
+        user("erin", "erin@example.com", "Erin", Some("07033334444")),
+    ];
+    command.save_batch(&batch).await.unwrap();
+    command.save_batch(&[]).await.unwrap();
+    assert_eq!(query.count_total().await.unwrap(), 5, "{}", backend);
+}
+
+// 一覧・検索（ページング、絞り込み、並び替え）
+async fn list_and_search<Q>(backend: &str, query: &Q)
+where
+    Q: UserQueryRepositoryInterface + ?Sized,
+{
+    let mut seen = Vec::new();
+    for page in 1..=3 {
+        let result = query
+            .find_all(PaginationParams { page, limit: 2 })
+            .await
+            .unwrap();
+        assert_eq!(result.pagination.total_count, 5, "{}", backend);
+        assert_eq!(result.pagination.total_pages, 3, "{}", backend);
+        assert_eq!(result.pagination.has_next, page < 3, "{}", backend);
+        assert_eq!(result.pagination.has_prev, page > 1, "{}", backend);
+        assert_eq!(
+            result.data.len(),
+            if page < 3 { 2 } else { 1 },
+            "{}",
+            backend
+        );
+        seen.extend(result.data.into_iter().map(|u| u.id.0));
+    }
+    seen.sort();
+    assert_eq!(
+        seen,
+        ["alice", "bob", "carol", "dave", "erin"],
+        "{}",
+        backend
+    );
+    let beyond = query
+        .find_all(PaginationParams { page: 4, limit: 2 })
+        .await
+        .unwrap();
+    assert!(beyond.data.is_empty(), "{}", backend);
+
+    let all = PaginationParams { page: 1, limit: 10 };
+    let by_name = query
+        .search_users(filters(), sort("name", SortOrder::Asc), all.clone())
+        .await
+        .unwrap();
+    assert_eq!(
+        names(&by_name.data),
+        ["Alice Renamed", "Bob", "Carol", "Dave", "Erin"],
+        "{}",
+        backend
+    );
+    let by_email = query
+        .search_users(filters(), sort("email", SortOrder::Desc), all.clone())
+        .await
+        .unwrap();
+    assert_eq!(
+        names(&by_email.data),
+        ["Erin", "Dave", "Carol", "Bob", "Alice Renamed"],
+        "{}",
+        backend
+    );
+
+    let mut domain = filters();
+    domain.email_domain = Some("example.net".to_string());
+    let result = query
+        .search_users(domain, sort("name", SortOrder::Asc), all.clone())
+        .await
+        .unwrap();
+    assert_eq!(names(&result.data), ["Carol", "Dave"], "{}", backend);
+
+    let mut name = filters();
+    name.name_contains = Some("a".to_string());
+    name.has_phone = Some(false);
+    let result = query
+        .search_users(name, sort("name", SortOrder::Asc), all.clone())
+        .await
+        .unwrap();
+    assert_eq!(names(&result.data), ["Dave"], "{}", backend);
+
+    let mut phone = filters();
+    phone.has_phone = Some(true);
+    let result = query
+        .search_users(
+            phone,
+            sort("name", SortOrder::Desc),
+            PaginationParams { page: 2, limit: 2 },
+        )
+        .await
+        .unwrap();
+    assert_eq!(result.pagination.total_count, 3, "{}", backend);
+    assert_eq!(result.pagination.total_pages, 2, "{}", backend);
+    assert_eq!(names(&result.data), ["Alice Renamed"], "{}", backend);
+
+    let yesterday = (Utc::now() - Duration::days(1)).to_rfc3339();
+    let mut after = filters();
+    after.created_after = Some(yesterday.clone());
+    let result = query
+        .search_users(after, sort("created_at", SortOrder::Desc), all.clone())
+        .await
+        .unwrap();
+    assert_eq!(result.pagination.total_count, 5, "{}", backend);
+    let mut before = filters();
+    before.created_before = Some(yesterday);
+    let result = query
+        .search_users(before, sort("created_at", SortOrder::Desc), all.clone())
+        .await
+        .unwrap();
+    assert_eq!(result.pagination.total_count, 0, "{}", backend);
+
+    // 使えない並び替えの項目は無視する
+    let result = query
+        .search_users(
+            filters(),
+            sort("name; DROP TABLE users", SortOrder::Asc),
+            all,
+        )
+        .await
+        .unwrap();
+    assert_eq!(result.pagination.total_count, 5, "{}", backend);
+}
+
+// 集計（期間内の登録数・ログインしたユーザー数・登録数の推移）
+async fn analytics<C, Q>(backend: &str, command: &C, query: &Q)
+where
+    C: UserCommandRepositoryInterface + ?Sized,
+    Q: UserQueryRepositoryInterface + ?Sized,
+{
+    let now = Utc::now();
+    command
+        .update_last_login(&UserId::new("bob".to_string()), now)
+        .await
+        .unwrap();
+    command
+        .update_last_login(&UserId::new("carol".to_string()), now - Duration::days(3))
+        .await
+        .unwrap();
+
+    let start = now - Duration::hours(1);
+    let end = now + Duration::hours(1);
+    assert_eq!(
+        query
+            .count_registrations_in_period(start, end)
+            .await
+            .unwrap(),
+        5,
+        "{}",
+        backend
+    );
+    assert_eq!(
+        query
+            .count_registrations_in_period(now - Duration::days(3), now - Duration::days(2))
+            .await
+            .unwrap(),
+        0,
+        "{}",
+        backend
+    );
+    assert_eq!(
+        query
+            .count_active_users_in_period(start, end)
+            .await
+            .unwrap(),
+        1,
+        "{}",
+        backend
+    );
+    assert_eq!(
+        query
+            .count_active_users_in_period(now - Duration::days(7), end)
+            .await
+            .unwrap(),
+        2,
+        "{}",
+        backend
+    );
+
+    let trend = query
+        .get_registration_trend(TimePeriod::Day, TimeGranularity::Day)
+        .await
+        .unwrap();
+    assert_eq!(trend.iter().map(|p| p.value).sum::<u64>(), 5, "{}", backend);
+    assert!(
+        trend
+            .iter()
+            .all(|p| p.timestamp.len() == "YYYY-MM-DD".len() && p.sample_count.is_none()),
+        "{}: {:?}",
+        backend,
+        trend
+    );
+}
+
+// 削除（存在しないユーザーの削除はエラーにしない、削除したメールアドレスは再び使える）
+async fn delete<C, Q>(backend: &str, command: &C, query: &Q)
+where
+    C: UserCommandRepositoryInterface + ?Sized,
+    Q: UserQueryRepositoryInterface + ?Sized,
+{
+    let alice_id = UserId::new("alice".to_string());
+    command.delete(&alice_id).await.unwrap();
+    command.delete(&alice_id).await.unwrap();
+    assert!(query.find_by_id(&alice_id).await.unwrap().is_none());
+    assert!(
+        !command
+            .exists_by_email(&email("alice@example.org"))
+            .await
+            .unwrap()
+    );
+    assert_eq!(query.count_total().await.unwrap(), 4, "{}", backend);
+
+    command
+        .save(&user(
+            "alice-again",
+            "alice@example.org",
+            "Alice Again",
+            None,
+        ))
+        .await
+        .unwrap();
+    let found = query
+        .find_by_email(&email("alice@example.org"))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(found.id.0, "alice-again", "{}", backend);
+}
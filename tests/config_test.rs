@@ -0,0 +1,278 @@
+// tests/config_test.rs
+// 設定（設定ファイル・プロファイル・環境変数・コマンドライン引数）のテスト
+
+use rusted_ca::infrastructure::config::app_config::{AppConfig, MailerBackend, SameSite};
+use rusted_ca::infrastructure::config::config_file::ConfigFile;
+use rusted_ca::infrastructure::config::config_source::{
+    CliArgs, ConfigErrors, ConfigOrigin, ConfigSource, Profile, REDACTED,
+};
+use rusted_ca::shared::error::infrastructure_error::InfrastructureError;
+use std::path::Path;
+use std::time::Duration;
+
+const CONFIG_TOML: &str = r#"
+# 共通の設定
+admin_listen = "127.0.0.1:9090"
+
+[server]
+listen = [
+    "127.0.0.1:8080",
+    "unix:/tmp/rusted-ca.sock",  # 複数指定
+]
+
+[mail]
+from = "noreply@example.com"
+
+[session_cookie]
+same_site = "lax"
+
+[profile.prod.mail]
+from = "security@example.com"
+
+[profile.prod]
+session_cookie.same-site = "strict"
+jwt.secret = 'prod-secret-from-file-0123456789abcdef'
+"#;
+
+fn file_source(profile: Profile, toml: &str) -> ConfigSource {
+    let mut source = ConfigSource::new(profile);
+    source.apply_file(toml).unwrap();
+    source
+}
+
+fn error_keys(errors: &ConfigErrors) -> Vec<String> {
+    errors
+        .0
+        .iter()
+        .map(|e| match e {
+            InfrastructureError::Configuration { key, .. } => key.clone(),
+            other => panic!("unexpected error: {}", other),
+        })
+        .collect()
+}
+
+/// テーブル・ドット区切りのキー・配列・コメントを環境変数と同じ名前に平坦化することを確認
+#[test]
+fn test_parse_config_file() {
+    let file = ConfigFile::parse(CONFIG_TOML).unwrap();
+    assert_eq!(
+        file.values["SERVER_LISTEN"],
+        "127.0.0.1:8080,unix:/tmp/rusted-ca.sock"
+    );
+    assert_eq!(file.values["ADMIN_LISTEN"], "127.0.0.1:9090");
+    assert_eq!(file.values["MAIL_FROM"], "noreply@example.com");
+    assert_eq!(file.values["SESSION_COOKIE_SAME_SITE"], "lax");
+    assert_eq!(file.profiles["prod"]["MAIL_FROM"], "security@example.com");
+    assert_eq!(file.profiles["prod"]["SESSION_COOKIE_SAME_SITE"], "strict");
+    assert_eq!(
+        file.profiles["prod"]["JWT_SECRET"],
+        "prod-secret-from-file-0123456789abcdef"
+    );
+
+    // 構文エラーは行番号付きで全て返す
+    let errors = ConfigFile::parse("a = 1\nb = \nc = \"open\n[d\na = 2\n").unwrap_err();
+    let lines: Vec<usize> = errors.iter().map(|e| e.line).collect();
+    assert_eq!(lines, vec![2, 3, 4, 5]);
+}
+
+/// 設定ファイル < プロファイル < 環境変数 < コマンドライン引数 の順に優先することを確認
+#[test]
+fn test_layers_are_applied_in_order() {
+    let toml = r#"
+mailer = "memory"
+mail_from = "file@example.com"
+session_cookie_same_site = "lax"
+smtp_tls = false
+password_min_length = 10
+
+[profile.test]
+mail_from = "profile@example.com"
+session_cookie_same_site = "none"
+smtp_tls = true
+"#;
+    let source = file_source(Profile::Test, toml)
+        .with_value("SESSION_COOKIE_SAME_SITE", "strict", ConfigOrigin::Env)
+        .with_value("SMTP_TLS", "false", ConfigOrigin::Env)
+        .with_value("SMTP_TLS", "true", ConfigOrigin::Cli);
+    let (config, report) = AppConfig::load(&source).unwrap();
+
+    assert_eq!(config.profile, Profile::Test);
+    assert_eq!(config.mailer.backend, MailerBackend::Memory);
+    assert_eq!(config.password_policy.min_length, 10);
+    assert_eq!(config.mailer.from, "profile@example.com");
+    assert_eq!(config.session_cookie.same_site, SameSite::Strict);
+    assert!(config.mailer.smtp_tls);
+    // 指定がない値は既定値
+    assert_eq!(config.session.gc_interval, Duration::from_secs(300));
+
+    let printed = report.to_string();
+    assert!(printed.contains("mail_from = \"profile@example.com\"  # profile"));
+    assert!(printed.contains("session_cookie_same_site = \"strict\"  # env"));
+    assert!(printed.contains("smtp_tls = \"true\"  # cli"));
+    assert!(printed.contains("password_min_length = \"10\"  # file"));
+    assert!(printed.contains("session_gc_interval_secs = \"300\"  # default"));
+
+    // 他のプロファイルの上書きは使わない
+    let (config, _) = AppConfig::load(&file_source(Profile::Dev, toml)).unwrap();
+    assert_eq!(config.mailer.from, "file@example.com");
+    assert_eq!(config.session_cookie.same_site, SameSite::Lax);
+}
+
+/// 不正な値は最初の1件で止めず、全てまとめて返すことを確認
+#[test]
+fn test_all_errors_are_reported_together() {
+    let source = ConfigSource::new(Profile::Dev)
+        .with_value("SERVER_PORT", "http", ConfigOrigin::Env)
+        .with_value("DISCORD_ENABLED", "maybe", ConfigOrigin::Env)
+        .with_value("SESSION_COOKIE_SAME_SITE", "sometimes", ConfigOrigin::Env)
+        .with_value("MFA_REQUIRED_ROLES", "admin,root", ConfigOrigin::Env)
+        .with_value("PASSWORD_MIN_LENGTH", "12", ConfigOrigin::Env)
+        .with_value("PASSWORD_MAX_LENGTH", "8", ConfigOrigin::Env)
+        .with_value(
+            "BOOTSTRAP_USER_EMAIL",
+            "admin@example.com",
+            ConfigOrigin::Env,
+        )
+        .with_value(
+            "TLS_CERT_PATH",
+            "/nonexistent/server.pem",
+            ConfigOrigin::Env,
+        )
+        .with_value(
+            "TLS_CLIENT_CERT_USERS",
+            "billing-service",
+            ConfigOrigin::Env,
+        )
+        .with_value("CORS_ALLOWED_ORIGINS", "*", ConfigOrigin::Env);
+    let errors = AppConfig::load(&source).unwrap_err();
+    let keys = error_keys(&errors);
+    for expected in [
+        "SERVER_PORT",
+        "DISCORD_ENABLED",
+        "SESSION_COOKIE_SAME_SITE",
+        "MFA_REQUIRED_ROLES",
+        "PASSWORD_MAX_LENGTH",
+        "BOOTSTRAP_USER_PASSWORD",
+        "TLS_KEY_PATH",
+        "TLS_CLIENT_CERT_USERS",
+        "CORS_ALLOWED_ORIGINS",
+    ] {
+        assert!(
+            keys.contains(&expected.to_string()),
+            "{} in {:?}",
+            expected,
+            keys
+        );
+    }
+    let message = errors.to_string();
+    assert!(message.starts_with(&format!("{} configuration error(s):", keys.len())));
+    assert!(message.contains("SERVER_PORT"));
+}
+
+/// 設定ファイル・コマンドライン引数の未知のキーはエラー、環境変数の未知のキーは無視することを確認
+#[test]
+fn test_unknown_keys_are_rejected_outside_env() {
+    let source = file_source(Profile::Dev, "[server]\nprot = 3000\n")
+        .with_value("PATH", "/usr/bin", ConfigOrigin::Env)
+        .with_value("SESION_GC_INTERVAL_SECS", "60", ConfigOrigin::Cli);
+    let keys = error_keys(&AppConfig::load(&source).unwrap_err());
+    assert_eq!(keys, vec!["SERVER_PROT", "SESION_GC_INTERVAL_SECS"]);
+}
+
+/// 以前の固定の認証情報（`AUTH_USER=auth_user`）が残っていても起動でき、初期ユーザーは作らないことを確認
+#[test]
+fn test_legacy_auth_user_is_ignored() {
+    let source = ConfigSource::new(Profile::Dev)
+        .with_value("AUTH_USER", "auth_user", ConfigOrigin::Env)
+        .with_value("AUTH_PASS", "auth_password", ConfigOrigin::Env);
+    let (config, _) = AppConfig::load(&source).unwrap();
+    assert!(config.bootstrap_user.is_none());
+
+    let source = ConfigSource::new(Profile::Dev)
+        .with_value("BOOTSTRAP_USER_EMAIL", "auth_user", ConfigOrigin::Env)
+        .with_value(
+            "BOOTSTRAP_USER_PASSWORD",
+            "auth_password",
+            ConfigOrigin::Env,
+        );
+    let keys = error_keys(&AppConfig::load(&source).unwrap_err());
+    assert_eq!(keys, vec!["BOOTSTRAP_USER_EMAIL"]);
+}
+
+/// `--print-config`の出力では秘密情報を伏せることを確認
+#[test]
+fn test_print_config_redacts_secrets() {
+    let source = file_source(Profile::Prod, CONFIG_TOML)
+        .with_value("SMTP_PASSWORD", "smtp-hunter2", ConfigOrigin::Env)
+        .with_value(
+            "DISCORD_WEBHOOK_URL",
+            "https://discord.example/hook/xyz",
+            ConfigOrigin::Env,
+        );
+    let (config, report) = AppConfig::load(&source).unwrap();
+    assert_eq!(
+        config.jwt.secret.as_deref(),
+        Some("prod-secret-from-file-0123456789abcdef")
+    );
+    assert_eq!(config.mailer.smtp_password.as_deref(), Some("smtp-hunter2"));
+    assert_eq!(config.mailer.from, "security@example.com");
+    assert_eq!(config.server.listen.len(), 2);
+
+    let printed = report.to_string();
+    assert!(printed.starts_with("# profile: prod\n"));
+    assert!(printed.contains(&format!("jwt_secret = \"{}\"  # profile", REDACTED)));
+    assert!(printed.contains(&format!("smtp_password = \"{}\"  # env", REDACTED)));
+    assert!(printed.contains(&format!("discord_webhook_url = \"{}\"  # env", REDACTED)));
+    assert!(printed.contains("# bootstrap_user_password = (unset)"));
+    for secret in ["prod-secret-from-file", "smtp-hunter2", "discord.example"] {
+        assert!(!printed.contains(secret), "{} leaked", secret);
+    }
+}
+
+/// prodプロファイルでは開発用の既定値（一時的なJWT署名鍵・Secureなし・メモリ送信）を拒否することを確認
+#[test]
+fn test_prod_profile_rejects_development_defaults() {
+    let source = ConfigSource::new(Profile::Prod)
+        .with_value("SESSION_COOKIE_SECURE", "false", ConfigOrigin::Env)
+        .with_value("MAILER", "memory", ConfigOrigin::Env);
+    let keys = error_keys(&AppConfig::load(&source).unwrap_err());
+    assert_eq!(keys, vec!["JWT_SECRET", "SESSION_COOKIE_SECURE", "MAILER"]);
+
+    // devでは同じ設定で起動できる
+    let source = ConfigSource::new(Profile::Dev)
+        .with_value("SESSION_COOKIE_SECURE", "false", ConfigOrigin::Env)
+        .with_value("MAILER", "memory", ConfigOrigin::Env);
+    assert!(AppConfig::load(&source).is_ok());
+}
+
+/// コマンドライン引数の解析（`--set`・`--<キー>`の上書き、不正な引数はまとめてエラー）を確認
+#[test]
+fn test_parse_cli_args() {
+    let args = [
+        "--config",
+        "conf/app.toml",
+        "--profile=prod",
+        "--print-config",
+        "--set",
+        "server.port=8080",
+        "--mail-from",
+        "ops@example.com",
+        "--smtp_tls=false",
+    ];
+    let cli = CliArgs::parse(args.iter().map(|a| a.to_string())).unwrap();
+    assert_eq!(cli.config_path.as_deref(), Some(Path::new("conf/app.toml")));
+    assert_eq!(cli.profile.as_deref(), Some("prod"));
+    assert!(cli.print_config);
+    assert_eq!(
+        cli.overrides,
+        vec![
+            ("SERVER_PORT".to_string(), "8080".to_string()),
+            ("MAIL_FROM".to_string(), "ops@example.com".to_string()),
+            ("SMTP_TLS".to_string(), "false".to_string()),
+        ]
+    );
+
+    let errors =
+        CliArgs::parse(["serve", "--set", "novalue", "--profile"].map(String::from)).unwrap_err();
+    assert_eq!(errors.0.len(), 3);
+}
@@ -0,0 +1,186 @@
+// tests/connection_pool_test.rs
+// SQLite接続プール（読み取り・書き込みの分離、取得待ちのタイムアウト、パニックからの復帰、メトリクス）のテスト
+
+use rusted_ca::infrastructure::config::app_config::{AppConfig, DatabaseConfig, DatabaseUrl};
+use rusted_ca::infrastructure::database::sqlite_connection::SqliteConnection;
+use rusted_ca::infrastructure::di::container::DIContainer;
+use rusted_ca::shared::error::infrastructure_error::InfrastructureError;
+use rusted_ca::shared::metrics::collector::PoolMetricsSource;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+fn temp_database_path() -> PathBuf {
+    std::env::temp_dir()
+        .join(format!("rusted-ca-pool-test-{}", uuid::Uuid::new_v4()))
+        .join("app.db")
+}
+
+fn file_config(path: &Path, readers: usize, acquire_timeout: Duration) -> DatabaseConfig {
+    DatabaseConfig {
+        url: DatabaseUrl::SqliteFile(path.to_path_buf()),
+        sqlite_url: DatabaseUrl::SqliteFile(path.to_path_buf()),
+        busy_timeout: Duration::from_secs(2),
+        auto_migrate: true,
+        readers,
+        acquire_timeout,
+    }
+}
+
+fn remove(path: &Path) {
+    let _ = std::fs::remove_dir_all(path.parent().unwrap());
+}
+
+async fn count_users(db: &SqliteConnection) -> i64 {
+    db.execute_query(|conn| conn.query_row("SELECT COUNT(*) FROM users", [], |row| row.get(0)))
+        .await
+        .unwrap()
+}
+
+async fn insert_user(db: &SqliteConnection, id: &str) {
+    let id = id.to_string();
+    db.execute_command(move |conn| {
+        conn.execute(
+            "INSERT INTO users (id, email, name, password, role) VALUES (?1, ?2, 'Pool', 'x', 'user')",
+            [&id, &format!("{}@example.com", id)],
+        )
+    })
+    .await
+    .unwrap();
+}
+
+/// 処理がパニックしてもエラーとして返し、同じ接続で処理を続けられることを確認
+#[tokio::test]
+async fn test_panic_is_reported_and_connection_is_reused() {
+    let db = SqliteConnection::new_in_memory().unwrap();
+    let error = db
+        .execute_command(|conn| -> rusqlite::Result<()> {
+            let tx = conn.transaction()?;
+            tx.execute(
+                "INSERT INTO users (id, email, name, password, role) VALUES ('p', 'p@example.com', 'P', 'x', 'user')",
+                [],
+            )?;
+            panic!("boom");
+        })
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        InfrastructureError::DatabaseTransaction { .. }
+    ));
+    assert!(error.to_string().contains("boom"));
+
+    // 途中のトランザクションは取り消され、インメモリのデータベースも失われない
+    assert_eq!(count_users(&db).await, 0);
+    insert_user(&db, "after-panic").await;
+    assert_eq!(count_users(&db).await, 1);
+}
+
+/// SQLのエラーはInfrastructureErrorとして返すことを確認
+#[tokio::test]
+async fn test_sql_error_is_infrastructure_error() {
+    let db = SqliteConnection::new_in_memory().unwrap();
+    let error = db
+        .execute_query(|conn| conn.query_row("SELECT * FROM no_such_table", [], |_| Ok(())))
+        .await
+        .unwrap_err();
+    assert!(matches!(error, InfrastructureError::DatabaseQuery { .. }));
+    assert!(error.to_string().contains("no_such_table"));
+}
+
+/// 書き込み用の接続の空きを待つ処理は設定した時間でタイムアウトすることを確認
+#[tokio::test]
+async fn test_acquire_timeout() {
+    let path = temp_database_path();
+    let db = SqliteConnection::open(&file_config(&path, 1, Duration::from_millis(100))).unwrap();
+
+    let busy = db.clone();
+    let holder = tokio::spawn(async move {
+        busy.execute_command(|_| {
+            std::thread::sleep(Duration::from_millis(500));
+            Ok(())
+        })
+        .await
+    });
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    let error = db.execute_command(|_| Ok(())).await.unwrap_err();
+    assert!(matches!(
+        error,
+        InfrastructureError::Timeout {
+            timeout_ms: 100,
+            ..
+        }
+    ));
+    // 読み取りは書き込みを待たない
+    assert_eq!(count_users(&db).await, 0);
+
+    holder.await.unwrap().unwrap();
+    let writer = &db.pool_metrics()[0];
+    assert_eq!(writer.name, "writer");
+    assert_eq!(writer.timeouts_total, 1);
+    assert_eq!(writer.acquired_total, 1);
+    assert_eq!(writer.in_use, 0);
+    remove(&path);
+}
+
+/// 読み取りは書き込み中のトランザクションを待たず、確定済みのデータを並行に読めることを確認
+#[tokio::test]
+async fn test_readers_do_not_wait_for_writer() {
+    let path = temp_database_path();
+    let db = SqliteConnection::open(&file_config(&path, 2, Duration::from_secs(2))).unwrap();
+    insert_user(&db, "committed").await;
+
+    let writer = db.clone();
+    let write = tokio::spawn(async move {
+        writer
+            .execute_command(|conn| {
+                let tx = conn.transaction()?;
+                tx.execute(
+                    "INSERT INTO users (id, email, name, password, role) VALUES ('pending', 'pending@example.com', 'P', 'x', 'user')",
+                    [],
+                )?;
+                std::thread::sleep(Duration::from_millis(500));
+                tx.commit()
+            })
+            .await
+    });
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    let started = Instant::now();
+    let (first, second) = tokio::join!(count_users(&db), count_users(&db));
+    assert!(started.elapsed() < Duration::from_millis(400));
+    assert_eq!((first, second), (1, 1));
+
+    write.await.unwrap().unwrap();
+    assert_eq!(count_users(&db).await, 2);
+
+    // 読み取り用の接続では書き込めない
+    let error = db
+        .execute_query(|conn| conn.execute("DELETE FROM users", []))
+        .await
+        .unwrap_err();
+    assert!(matches!(error, InfrastructureError::DatabaseQuery { .. }));
+    assert_eq!(count_users(&db).await, 2);
+    remove(&path);
+}
+
+/// 管理用リスナーのメトリクスに接続プールの利用状況を出力することを確認
+#[tokio::test]
+async fn test_pool_metrics_are_rendered() {
+    let path = temp_database_path();
+    let mut config = AppConfig::from_env();
+    config.database = file_config(&path, 3, Duration::from_secs(1));
+    let di = DIContainer::new().with_config(config);
+    let db = di.create_database_connection().unwrap();
+    let metrics = di.create_metrics_collector();
+    count_users(&db).await;
+
+    let rendered = metrics.render_prometheus();
+    assert!(rendered.contains("db_pool_connections{pool=\"writer\",state=\"idle\"} 1"));
+    assert!(rendered.contains("db_pool_connections{pool=\"reader\",state=\"idle\"} 3"));
+    assert!(rendered.contains("db_pool_connections{pool=\"reader\",state=\"in_use\"} 0"));
+    assert!(rendered.contains("db_pool_acquire_total{pool=\"reader\"} 1"));
+    assert!(rendered.contains("db_pool_acquire_timeouts_total{pool=\"writer\"} 0"));
+    assert!(rendered.contains("db_pool_waiting{pool=\"reader\"} 0"));
+    remove(&path);
+}
@@ -0,0 +1,123 @@
+// tests/jwt_keys_test.rs
+// 非対称鍵によるJWT署名・鍵ローテーション・JWKSのテスト
+
+use jsonwebtoken::decode_header;
+use rusted_ca::infrastructure::config::app_config::JwtKeyConfig;
+use rusted_ca::shared::middleware::auth_middleware::{JwtClaims, JwtConfig};
+
+const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/jwt");
+
+fn fixture(name: &str) -> String {
+    format!("{}/{}", FIXTURES, name)
+}
+
+fn key_config(algorithm: &str, kid: &str, key: &str) -> JwtKeyConfig {
+    JwtKeyConfig {
+        algorithm: algorithm.to_string(),
+        key_id: Some(kid.to_string()),
+        private_key_path: Some(fixture(&format!("{}_private.pem", key))),
+        public_key_path: Some(fixture(&format!("{}_public.pem", key))),
+        ..Default::default()
+    }
+}
+
+fn claims() -> JwtClaims {
+    JwtClaims::new(
+        uuid::Uuid::new_v4().to_string(),
+        "keys@example.com".to_string(),
+        "Keys".to_string(),
+        "user".to_string(),
+    )
+}
+
+#[test]
+fn test_sign_and_verify_with_each_asymmetric_algorithm() {
+    for (algorithm, key, kty) in [
+        ("RS256", "rsa", "RSA"),
+        ("ES256", "ec", "EC"),
+        ("EdDSA", "ed25519", "OKP"),
+    ] {
+        let config = JwtConfig::from_key_config(&key_config(algorithm, "key-1", key)).unwrap();
+        let token = claims().to_token_with(&config).unwrap();
+
+        let header = decode_header(&token).unwrap();
+        assert_eq!(header.kid.as_deref(), Some("key-1"));
+        assert_eq!(format!("{:?}", header.alg), algorithm);
+        let decoded = JwtClaims::from_token_with(&token, &config).unwrap();
+        assert_eq!(decoded.email, "keys@example.com");
+
+        let jwks = serde_json::to_value(config.jwks()).unwrap();
+        assert_eq!(jwks["keys"].as_array().unwrap().len(), 1);
+        assert_eq!(jwks["keys"][0]["kid"], "key-1");
+        assert_eq!(jwks["keys"][0]["kty"], kty);
+        assert_eq!(jwks["keys"][0]["use"], "sig");
+        assert_eq!(jwks["keys"][0]["alg"], algorithm);
+    }
+}
+
+#[test]
+fn test_old_key_tokens_remain_valid_during_rotation() {
+    let old_config =
+        JwtConfig::from_key_config(&key_config("RS256", "2025-01", "rsa_old")).unwrap();
+    let old_token = claims().to_token_with(&old_config).unwrap();
+
+    // 新しい鍵（別アルゴリズム）で署名しつつ、旧鍵の公開鍵でも検証する
+    let mut rotated = key_config("ES256", "2025-07", "ec");
+    rotated.verification_keys = vec![("2025-01".to_string(), fixture("rsa_old_public.pem"))];
+    let rotated_config = JwtConfig::from_key_config(&rotated).unwrap();
+    assert!(JwtClaims::from_token_with(&old_token, &rotated_config).is_ok());
+    let new_token = claims().to_token_with(&rotated_config).unwrap();
+    assert!(JwtClaims::from_token_with(&new_token, &rotated_config).is_ok());
+    let kids: Vec<String> = rotated_config
+        .jwks()
+        .keys
+        .iter()
+        .filter_map(|k| k.common.key_id.clone())
+        .collect();
+    assert_eq!(kids, vec!["2025-07".to_string(), "2025-01".to_string()]);
+
+    // 旧鍵を外すと旧トークンは検証できない
+    let retired_config = JwtConfig::from_key_config(&key_config("ES256", "2025-07", "ec")).unwrap();
+    assert!(JwtClaims::from_token_with(&old_token, &retired_config).is_err());
+}
+
+#[test]
+fn test_token_signed_by_unknown_key_is_rejected() {
+    let signer = JwtConfig::from_key_config(&key_config("RS256", "key-1", "rsa_old")).unwrap();
+    let verifier = JwtConfig::from_key_config(&key_config("RS256", "key-1", "rsa")).unwrap();
+    let token = claims().to_token_with(&signer).unwrap();
+    assert!(JwtClaims::from_token_with(&token, &verifier).is_err());
+
+    // 共通鍵の設定は公開鍵を公開しない
+    let hs_config = JwtConfig::new("secret".to_string());
+    assert!(hs_config.jwks().keys.is_empty());
+    let hs_token = claims().to_token_with(&hs_config).unwrap();
+    assert!(JwtClaims::from_token_with(&hs_token, &verifier).is_err());
+}
+
+#[test]
+fn test_invalid_key_configuration_is_reported() {
+    // アルゴリズムと鍵の種類が一致しない
+    assert!(JwtConfig::from_key_config(&key_config("ES256", "key-1", "rsa")).is_err());
+    // 必須項目の不足
+    let mut missing_kid = key_config("RS256", "key-1", "rsa");
+    missing_kid.key_id = None;
+    assert!(JwtConfig::from_key_config(&missing_kid).is_err());
+    // 未対応アルゴリズム
+    assert!(JwtConfig::from_key_config(&key_config("PS512", "key-1", "rsa")).is_err());
+    // kidの重複
+    let mut duplicated = key_config("RS256", "key-1", "rsa");
+    duplicated.verification_keys = vec![("key-1".to_string(), fixture("rsa_old_public.pem"))];
+    assert!(JwtConfig::from_key_config(&duplicated).is_err());
+}
+
+#[test]
+fn test_parse_verification_keys() {
+    assert_eq!(
+        JwtKeyConfig::parse_verification_keys("old=/keys/old.pem, /keys/older.pem"),
+        vec![
+            ("old".to_string(), "/keys/old.pem".to_string()),
+            ("older".to_string(), "/keys/older.pem".to_string()),
+        ]
+    );
+}
@@ -0,0 +1,141 @@
+// tests/login_throttle_test.rs
+// ログイン試行制限（ロックアウト・指数バックオフ・監査ログ）のテスト
+
+use chrono::{Duration, Utc};
+use rusted_ca::application::services::login_throttle_service::LoginThrottleService;
+use rusted_ca::domain::entity::login_attempt::{LoginAttempt, LoginAttemptScope};
+use rusted_ca::domain::repository::audit_log_repository::AuditLogRepositoryInterface;
+use rusted_ca::domain::repository::login_attempt_repository::LoginAttemptRepositoryInterface;
+use rusted_ca::domain::service::lockout_policy::LockoutPolicy;
+use rusted_ca::infrastructure::di::container::DIContainer;
+use rusted_ca::shared::error::application_error::ApplicationError;
+
+fn policy(max_failures: u32) -> LockoutPolicy {
+    LockoutPolicy {
+        max_failures,
+        base_lockout: Duration::seconds(30),
+        max_lockout: Duration::seconds(300),
+        failure_window: Duration::minutes(15),
+    }
+}
+
+/// 閾値到達でロックし、以降の失敗ごとにロック時間が倍になる（上限あり）ことを確認
+#[test]
+fn test_lockout_backs_off_exponentially() {
+    let policy = policy(3);
+    let start = Utc::now();
+    let mut attempt = LoginAttempt::new(LoginAttemptScope::Account, "a@example.com".into(), start);
+    assert!(!attempt.register_failure(start, &policy));
+    assert!(!attempt.register_failure(start, &policy));
+    assert!(attempt.register_failure(start, &policy));
+    assert_eq!(attempt.retry_after(start), Some(Duration::seconds(30)));
+
+    // ロック明けの失敗はロック時間が倍になる
+    let mut now = start + Duration::seconds(31);
+    assert!(!attempt.is_locked(now));
+    assert!(attempt.register_failure(now, &policy));
+    assert_eq!(attempt.retry_after(now), Some(Duration::seconds(60)));
+    for _ in 0..5 {
+        now = attempt.locked_until.unwrap() + Duration::seconds(1);
+        attempt.register_failure(now, &policy);
+    }
+    assert_eq!(attempt.retry_after(now), Some(Duration::seconds(300)));
+}
+
+/// 最後の失敗から一定時間経過すると失敗回数がリセットされることを確認
+#[test]
+fn test_failures_reset_after_window() {
+    let policy = policy(3);
+    let start = Utc::now();
+    let mut attempt = LoginAttempt::new(LoginAttemptScope::Ip, "127.0.0.1".into(), start);
+    attempt.register_failure(start, &policy);
+    attempt.register_failure(start, &policy);
+    let later = start + Duration::minutes(16);
+    assert!(!attempt.register_failure(later, &policy));
+    assert_eq!(attempt.failed_count, 1);
+}
+
+/// ロック・ロック解除が保存され、監査ログに記録されることを確認
+#[tokio::test]
+async fn test_throttle_service_locks_and_audits() {
+    let di = DIContainer::in_memory();
+    let attempts = di.create_login_attempt_repository().unwrap();
+    let audit_log = di.create_audit_log_repository().unwrap();
+    let service =
+        LoginThrottleService::new(attempts.clone(), audit_log.clone(), policy(2), policy(10));
+    let email = "Locked.User@Example.com";
+
+    service
+        .record_failure(email, Some("10.0.0.1"))
+        .await
+        .unwrap();
+    assert!(service.check(email, Some("10.0.0.1")).await.is_ok());
+    service
+        .record_failure(email, Some("10.0.0.1"))
+        .await
+        .unwrap();
+
+    // 大文字小文字違いでも同じアカウント、別IPからでもロック中
+    match service
+        .check("locked.user@example.com", Some("10.0.0.2"))
+        .await
+    {
+        Err(ApplicationError::TooManyAttempts { retry_after_secs }) => {
+            assert!((1..=30).contains(&retry_after_secs))
+        }
+        other => panic!("expected TooManyAttempts, got {:?}", other),
+    }
+    let stored = attempts
+        .find(LoginAttemptScope::Ip, "10.0.0.1")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(stored.failed_count, 2);
+
+    assert!(service.unlock_account(email, "admin-id").await.unwrap());
+    assert!(service.check(email, Some("10.0.0.2")).await.is_ok());
+    assert!(!service.unlock_account(email, "admin-id").await.unwrap());
+
+    let subject = LoginThrottleService::audit_subject(
+        LoginAttemptScope::Account,
+        &LoginThrottleService::account_key(email),
+    );
+    let events = audit_log.find_by_subject(&subject, 10).await.unwrap();
+    let actions: Vec<_> = events.iter().map(|e| e.action.as_str()).collect();
+    assert_eq!(actions, vec!["login.unlocked", "login.locked"]);
+    assert_eq!(events[0].actor.as_deref(), Some("admin-id"));
+    assert_eq!(events[1].actor, None);
+}
+
+/// 並行したログイン失敗が互いの加算を上書きせず、全て数えられることを確認
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_concurrent_failures_are_all_counted() {
+    let di = DIContainer::in_memory();
+    let attempts = di.create_login_attempt_repository().unwrap();
+    let service = std::sync::Arc::new(LoginThrottleService::new(
+        attempts.clone(),
+        di.create_audit_log_repository().unwrap(),
+        policy(5),
+        policy(100),
+    ));
+    let email = "parallel@example.com";
+
+    let tasks: Vec<_> = (0..20)
+        .map(|_| {
+            let service = service.clone();
+            tokio::spawn(async move { service.record_failure(email, Some("10.0.0.9")).await })
+        })
+        .collect();
+    for task in tasks {
+        task.await.unwrap().unwrap();
+    }
+
+    for (scope, key) in [
+        (LoginAttemptScope::Account, email),
+        (LoginAttemptScope::Ip, "10.0.0.9"),
+    ] {
+        let stored = attempts.find(scope, key).await.unwrap().unwrap();
+        assert_eq!(stored.failed_count, 20, "{}", scope.as_str());
+    }
+    assert!(service.check(email, None).await.is_err());
+}
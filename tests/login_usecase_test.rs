@@ -0,0 +1,178 @@
+// tests/login_usecase_test.rs
+// ログインユースケースのテスト（リポジトリ直接）
+
+use rusted_ca::application::dto::auth_dto::{LoginRequestDto, LoginResultDto};
+use rusted_ca::application::usecases::login_usecase::{LoginUseCase, LoginUsecaseInterface};
+use rusted_ca::domain::entity::user::User;
+use rusted_ca::domain::value_object::{
+    email::Email, password::Password, user_id::UserId, user_name::UserName,
+};
+use rusted_ca::infrastructure::config::app_config::PasswordHashConfig;
+use rusted_ca::infrastructure::di::container::DIContainer;
+use rusted_ca::shared::error::application_error::ApplicationError;
+use rusted_ca::shared::middleware::auth_middleware::JwtClaims;
+use rusted_ca::shared::utils::password_hasher::PasswordHasher;
+use std::sync::Arc;
+
+fn hasher(iterations: u32) -> Arc<PasswordHasher> {
+    Arc::new(
+        PasswordHasher::new(&PasswordHashConfig {
+            memory_kib: 1024,
+            iterations,
+            parallelism: 1,
+        })
+        .unwrap(),
+    )
+}
+
+#[tokio::test]
+async fn test_login_issues_tokens_for_stored_user_and_upgrades_hash() {
+    let di = DIContainer::in_memory();
+    let (command_repo, query_repo) = di.create_repositories().unwrap();
+
+    // 旧コスト（t=1）でハッシュ化されたユーザーを保存
+    let old_hash = hasher(1)
+        .hash(&Password::new("CorrectHorse42".to_string()).unwrap())
+        .await
+        .unwrap();
+    let user = User::new(
+        UserId::new(uuid::Uuid::new_v4().to_string()),
+        Email::new("login@example.com".to_string()).unwrap(),
+        UserName::new("Login User".to_string()).unwrap(),
+        old_hash.clone(),
+        None,
+        None,
+    )
+    .unwrap();
+    command_repo.save(&user).await.unwrap();
+
+    // 新コスト（t=2）のハッシャーでログイン
+    let usecase = LoginUseCase::new(
+        query_repo.clone(),
+        command_repo,
+        hasher(2),
+        di.create_session_token_service().unwrap(),
+        di.create_login_throttle_service().unwrap(),
+    );
+    let LoginResultDto::Authenticated(token_pair) = usecase
+        .execute(LoginRequestDto {
+            email: "login@example.com".to_string(),
+            password: "CorrectHorse42".to_string(),
+            client_ip: None,
+        })
+        .await
+        .unwrap()
+    else {
+        panic!("login without MFA should issue tokens");
+    };
+
+    let claims = JwtClaims::from_token(&token_pair.access_token).unwrap();
+    assert_eq!(claims.sub, user.id.0);
+    assert_eq!(claims.email, "login@example.com");
+    assert_eq!(claims.name, "Login User");
+    assert_eq!(token_pair.user.id, user.id.0);
+
+    // ハッシュが新しいコストで保存し直されている
+    let stored = query_repo.find_by_id(&user.id).await.unwrap().unwrap();
+    assert_ne!(stored.password_hash, old_hash);
+    assert!(stored.password_hash.value().contains("t=2"));
+}
+
+#[tokio::test]
+async fn test_login_rejects_unknown_email_and_wrong_password_with_same_error() {
+    let di = DIContainer::in_memory();
+    let (command_repo, query_repo) = di.create_repositories().unwrap();
+    let password_hash = hasher(1)
+        .hash(&Password::new("CorrectHorse42".to_string()).unwrap())
+        .await
+        .unwrap();
+    let user = User::new(
+        UserId::new(uuid::Uuid::new_v4().to_string()),
+        Email::new("known@example.com".to_string()).unwrap(),
+        UserName::new("Known User".to_string()).unwrap(),
+        password_hash,
+        None,
+        None,
+    )
+    .unwrap();
+    command_repo.save(&user).await.unwrap();
+    let usecase = LoginUseCase::new(
+        query_repo,
+        command_repo,
+        hasher(1),
+        di.create_session_token_service().unwrap(),
+        di.create_login_throttle_service().unwrap(),
+    );
+
+    for (email, password) in [
+        ("known@example.com", "WrongHorse42"),
+        ("unknown@example.com", "CorrectHorse42"),
+        ("not-an-email", "CorrectHorse42"),
+    ] {
+        let result = usecase
+            .execute(LoginRequestDto {
+                email: email.to_string(),
+                password: password.to_string(),
+                client_ip: None,
+            })
+            .await;
+        assert!(
+            matches!(result, Err(ApplicationError::InvalidCredentials)),
+            "{} should fail with InvalidCredentials",
+            email
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_login_requires_verified_email_when_enabled() {
+    let di = DIContainer::in_memory();
+    let (command_repo, query_repo) = di.create_repositories().unwrap();
+    let password_hash = hasher(1)
+        .hash(&Password::new("CorrectHorse42".to_string()).unwrap())
+        .await
+        .unwrap();
+    let user = User::new(
+        UserId::new(uuid::Uuid::new_v4().to_string()),
+        Email::new("unverified@example.com".to_string()).unwrap(),
+        UserName::new("Unverified User".to_string()).unwrap(),
+        password_hash,
+        None,
+        None,
+    )
+    .unwrap();
+    command_repo.save(&user).await.unwrap();
+    let usecase = LoginUseCase::new(
+        query_repo.clone(),
+        command_repo.clone(),
+        hasher(1),
+        di.create_session_token_service().unwrap(),
+        di.create_login_throttle_service().unwrap(),
+    )
+    .with_email_verification_required(true);
+    let request = || LoginRequestDto {
+        email: "unverified@example.com".to_string(),
+        password: "CorrectHorse42".to_string(),
+        client_ip: None,
+    };
+
+    // 未確認のうちは正しいパスワードでも拒否
+    let result = usecase.execute(request()).await;
+    assert!(matches!(result, Err(ApplicationError::EmailNotVerified)));
+    // パスワード不一致は従来どおりInvalidCredentials
+    let result = usecase
+        .execute(LoginRequestDto {
+            password: "WrongHorse42".to_string(),
+            ..request()
+        })
+        .await;
+    assert!(matches!(result, Err(ApplicationError::InvalidCredentials)));
+
+    command_repo
+        .mark_email_verified(&user.id, chrono::Utc::now())
+        .await
+        .unwrap();
+    let stored = query_repo.find_by_id(&user.id).await.unwrap().unwrap();
+    assert!(stored.is_email_verified());
+    assert!(usecase.execute(request()).await.is_ok());
+}
@@ -8,17 +8,12 @@ use axum::{
     http::{Request, StatusCode},
     routing::get,
 };
-use chrono::{DateTime, Timelike, Utc};
-use rand::Rng;
-use rusted_ca::shared::middleware::watch_middleware::{
-    watch_middleware, watch_middleware_with_base_path,
-};
+use chrono::{Timelike, Utc};
+use rusted_ca::shared::middleware::watch_middleware::watch_middleware_with_base_path;
 use serde_json::Value;
 use std::fs;
 use std::path::Path;
 use std::sync::Arc;
-use tokio::fs::OpenOptions;
-use tokio::io::AsyncWriteExt;
 use tower::util::ServiceExt;
 
 /// テスト用の簡単なエンドポイント
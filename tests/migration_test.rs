@@ -0,0 +1,277 @@
+// tests/migration_test.rs
+// スキーマのマイグレーション（バージョン管理・チェックサム・migrateサブコマンド）のテスト
+
+use rusqlite::{Connection, params};
+use rusted_ca::infrastructure::config::app_config::{DatabaseConfig, DatabaseUrl};
+use rusted_ca::infrastructure::config::config_source::{CliArgs, CliCommand, MigrateCommand};
+use rusted_ca::infrastructure::database::migration::{
+    MIGRATIONS, Migration, MigrationState, Migrator, run_migrate_command,
+};
+use rusted_ca::infrastructure::database::sqlite_connection::SqliteConnection;
+use rusted_ca::shared::error::infrastructure_error::InfrastructureError;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+const NOTES_V2: Migration = Migration {
+    version: 4,
+    name: "notes",
+    up: "CREATE TABLE notes (id TEXT PRIMARY KEY, body TEXT NOT NULL);",
+    down: "DROP TABLE notes;",
+};
+
+const NOTES_V3: Migration = Migration {
+    version: 5,
+    name: "notes_title",
+    up: "ALTER TABLE notes ADD COLUMN title TEXT;",
+    down: "ALTER TABLE notes DROP COLUMN title;",
+};
+
+fn temp_database_path() -> PathBuf {
+    std::env::temp_dir()
+        .join(format!("rusted-ca-migration-test-{}", uuid::Uuid::new_v4()))
+        .join("app.db")
+}
+
+fn file_config(path: &Path, auto_migrate: bool) -> DatabaseConfig {
+    DatabaseConfig {
+        url: DatabaseUrl::SqliteFile(path.to_path_buf()),
+        sqlite_url: DatabaseUrl::SqliteFile(path.to_path_buf()),
+        busy_timeout: Duration::from_secs(1),
+        auto_migrate,
+        readers: 2,
+        acquire_timeout: Duration::from_secs(1),
+    }
+}
+
+fn remove(path: &Path) {
+    let _ = std::fs::remove_dir_all(path.parent().unwrap());
+}
+
+fn table_exists(conn: &Connection, name: &str) -> bool {
+    conn.query_row(
+        "SELECT EXISTS (SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = ?1)",
+        params![name],
+        |row| row.get(0),
+    )
+    .unwrap()
+}
+
+fn notes_migrator() -> Migrator {
+    let mut migrations = MIGRATIONS.to_vec();
+    migrations.extend([NOTES_V2, NOTES_V3]);
+    Migrator::new(migrations).unwrap()
+}
+
+/// 新しいデータベースには全てのマイグレーションを適用し、2回目は何もしないことを確認
+#[test]
+fn test_migrate_up_records_versions() {
+    let mut conn = Connection::open_in_memory().unwrap();
+    let migrator = Migrator::embedded();
+    let applied = migrator.migrate_up(&mut conn, false).unwrap();
+    assert_eq!(applied.len(), MIGRATIONS.len());
+    assert!(table_exists(&conn, "users"));
+
+    let statuses = migrator.status(&conn).unwrap();
+    assert_eq!(statuses[0].version, 1);
+    assert!(matches!(statuses[0].state, MigrationState::Applied { .. }));
+    assert!(migrator.migrate_up(&mut conn, false).unwrap().is_empty());
+    assert!(migrator.ensure_up_to_date(&conn).is_ok());
+}
+
+/// dry-runでは対象を返すだけで適用・取り消しをしないことを確認
+#[test]
+fn test_dry_run_does_not_change_schema() {
+    let mut conn = Connection::open_in_memory().unwrap();
+    let migrator = notes_migrator();
+    let planned = migrator.migrate_up(&mut conn, true).unwrap();
+    assert_eq!(
+        planned.iter().map(|m| m.version).collect::<Vec<_>>(),
+        vec![1, 2, 3, 4, 5]
+    );
+    assert!(!table_exists(&conn, "users"));
+    assert!(!table_exists(&conn, "schema_migrations"));
+
+    migrator.migrate_up(&mut conn, false).unwrap();
+    let planned = migrator.migrate_down(&mut conn, 1, true).unwrap();
+    assert_eq!(
+        planned.iter().map(|m| m.version).collect::<Vec<_>>(),
+        vec![5, 4, 3, 2]
+    );
+    assert!(table_exists(&conn, "notes"));
+}
+
+/// 指定したバージョンまで新しい順に取り消し、再度適用できることを確認
+#[test]
+fn test_migrate_down_and_up_again() {
+    let mut conn = Connection::open_in_memory().unwrap();
+    let migrator = notes_migrator();
+    migrator.migrate_up(&mut conn, false).unwrap();
+
+    let rolled_back = migrator.migrate_down(&mut conn, 1, false).unwrap();
+    assert_eq!(
+        rolled_back.iter().map(|m| m.version).collect::<Vec<_>>(),
+        vec![5, 4, 3, 2]
+    );
+    assert!(!table_exists(&conn, "notes"));
+    assert!(table_exists(&conn, "users"));
+    let pending = migrator.pending(&conn).unwrap();
+    assert_eq!(
+        pending.iter().map(|m| m.version).collect::<Vec<_>>(),
+        vec![2, 3, 4, 5]
+    );
+
+    migrator.migrate_up(&mut conn, false).unwrap();
+    conn.execute(
+        "INSERT INTO notes (id, body, title) VALUES ('1', 'body', 'title')",
+        [],
+    )
+    .unwrap();
+
+    // バージョン0まで戻すと全てのテーブルがなくなる
+    migrator.migrate_down(&mut conn, 0, false).unwrap();
+    assert!(!table_exists(&conn, "users"));
+}
+
+/// データベースのスキーマの方が新しい場合は適用も起動もしないことを確認
+#[test]
+fn test_newer_database_is_refused() {
+    let path = temp_database_path();
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    {
+        let mut conn = SqliteConnection::connect_file(&path, Duration::from_secs(1)).unwrap();
+        notes_migrator().migrate_up(&mut conn, false).unwrap();
+    }
+
+    let mut conn = SqliteConnection::connect_file(&path, Duration::from_secs(1)).unwrap();
+    let error = Migrator::embedded()
+        .migrate_up(&mut conn, false)
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        InfrastructureError::SchemaMigration { version: 5, .. }
+    ));
+    assert!(error.to_string().contains("newer"));
+    assert!(SqliteConnection::open_file(&path, &file_config(&path, true)).is_err());
+    assert!(SqliteConnection::open_file(&path, &file_config(&path, false)).is_err());
+    remove(&path);
+}
+
+/// 適用済みのマイグレーションのSQLが書き換えられている場合はエラーにすることを確認
+#[test]
+fn test_modified_migration_is_detected() {
+    let mut conn = Connection::open_in_memory().unwrap();
+    notes_migrator().migrate_up(&mut conn, false).unwrap();
+
+    let mut modified = MIGRATIONS.to_vec();
+    modified.push(Migration {
+        up: "CREATE TABLE notes (id TEXT PRIMARY KEY, body TEXT);",
+        ..NOTES_V2
+    });
+    modified.push(NOTES_V3);
+    let migrator = Migrator::new(modified).unwrap();
+    let statuses = migrator.status(&conn).unwrap();
+    assert!(matches!(statuses[3].state, MigrationState::Modified { .. }));
+    let error = migrator.migrate_up(&mut conn, false).unwrap_err();
+    assert!(matches!(
+        error,
+        InfrastructureError::SchemaMigration { version: 4, .. }
+    ));
+
+    // バージョンの順序が不正な一覧は受け付けない
+    assert!(Migrator::new(vec![NOTES_V3, NOTES_V2]).is_err());
+}
+
+/// 自動適用しない設定では未適用のマイグレーションがあると起動しないことを確認
+#[test]
+fn test_pending_migrations_without_auto_migrate() {
+    let path = temp_database_path();
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    let error = SqliteConnection::open_file(&path, &file_config(&path, false))
+        .err()
+        .unwrap();
+    assert!(error.to_string().contains("migrate up"));
+
+    assert!(SqliteConnection::open_file(&path, &file_config(&path, true)).is_ok());
+    assert!(SqliteConnection::open_file(&path, &file_config(&path, false)).is_ok());
+    remove(&path);
+}
+
+/// `migrate`サブコマンドの解析と実行（status・dry-run・up・down）を確認
+#[tokio::test]
+async fn test_migrate_command() {
+    let parse = |args: &[&str]| CliArgs::parse(args.iter().map(|a| a.to_string()));
+    assert_eq!(
+        parse(&["migrate", "status"]).unwrap().command,
+        CliCommand::Migrate(MigrateCommand::Status)
+    );
+    assert_eq!(
+        parse(&["--dry-run", "migrate", "down", "0"])
+            .unwrap()
+            .command,
+        CliCommand::Migrate(MigrateCommand::Down {
+            target: 0,
+            dry_run: true
+        })
+    );
+    assert_eq!(parse(&[]).unwrap().command, CliCommand::Serve);
+    assert!(parse(&["migrate"]).is_err());
+    assert!(parse(&["migrate", "down", "latest"]).is_err());
+    assert!(parse(&["migrate", "status", "--dry-run"]).is_err());
+    assert!(parse(&["--dry-run"]).is_err());
+
+    let path = temp_database_path();
+    let config = file_config(&path, false);
+    let status = run_migrate_command(&config, MigrateCommand::Status)
+        .await
+        .unwrap();
+    assert!(status.contains("schema version: 0 (latest: 3)"));
+    assert!(status.contains("0001  initial_schema           pending"));
+    assert!(status.contains("0002  session_invalidations    pending"));
+    assert!(status.contains("0003  password_reset_requests  pending"));
+
+    let output = run_migrate_command(&config, MigrateCommand::Up { dry_run: true })
+        .await
+        .unwrap();
+    assert!(output.contains("would apply 0001 initial_schema"));
+    let output = run_migrate_command(&config, MigrateCommand::Up { dry_run: false })
+        .await
+        .unwrap();
+    assert!(output.contains("applied 0001 initial_schema"));
+    assert!(output.contains("applied 0002 session_invalidations"));
+    assert!(output.contains("applied 0003 password_reset_requests"));
+    let output = run_migrate_command(&config, MigrateCommand::Up { dry_run: false })
+        .await
+        .unwrap();
+    assert!(output.contains("nothing to do"));
+    let status = run_migrate_command(&config, MigrateCommand::Status)
+        .await
+        .unwrap();
+    assert!(status.contains("schema version: 3 (latest: 3)"));
+    assert!(status.contains("0001  initial_schema           applied"));
+
+    let output = run_migrate_command(
+        &config,
+        MigrateCommand::Down {
+            target: 0,
+            dry_run: false,
+        },
+    )
+    .await
+    .unwrap();
+    assert!(output.contains("rolled back 0003 password_reset_requests"));
+    assert!(output.contains("rolled back 0002 session_invalidations"));
+    assert!(output.contains("rolled back 0001 initial_schema"));
+    remove(&path);
+
+    // インメモリのデータベースは対象外
+    let memory = DatabaseConfig {
+        url: DatabaseUrl::SqliteMemory,
+        sqlite_url: DatabaseUrl::SqliteMemory,
+        ..config
+    };
+    assert!(
+        run_migrate_command(&memory, MigrateCommand::Status)
+            .await
+            .is_err()
+    );
+}
@@ -0,0 +1,71 @@
+// tests/password_hasher_test.rs
+// パスワードハッシュ化サービスのテスト
+
+use rusted_ca::domain::value_object::password::Password;
+use rusted_ca::infrastructure::config::app_config::PasswordHashConfig;
+use rusted_ca::shared::utils::password_hasher::{PasswordHasher, PasswordVerification};
+
+// テスト用の低コスト設定
+fn cheap_config(iterations: u32) -> PasswordHashConfig {
+    PasswordHashConfig {
+        memory_kib: 1024,
+        iterations,
+        parallelism: 1,
+    }
+}
+
+#[tokio::test]
+async fn test_hash_produces_argon2id_phc_string() {
+    let hasher = PasswordHasher::new(&cheap_config(1)).unwrap();
+    let password = Password::new("CorrectHorse42".to_string()).unwrap();
+    let hash = hasher.hash(&password).await.unwrap();
+
+    assert!(hash.value().starts_with("$argon2id$v=19$m=1024,t=1,p=1$"));
+    assert!(!hash.value().contains("CorrectHorse42"));
+
+    // ソルトが毎回異なること
+    let other = hasher.hash(&password).await.unwrap();
+    assert_ne!(hash, other);
+}
+
+#[tokio::test]
+async fn test_verify_match_and_mismatch() {
+    let hasher = PasswordHasher::new(&cheap_config(1)).unwrap();
+    let password = Password::new("CorrectHorse42".to_string()).unwrap();
+    let hash = hasher.hash(&password).await.unwrap();
+
+    assert_eq!(
+        hasher.verify("CorrectHorse42", &hash).await.unwrap(),
+        PasswordVerification::Match
+    );
+    assert_eq!(
+        hasher.verify("WrongHorse42", &hash).await.unwrap(),
+        PasswordVerification::Mismatch
+    );
+}
+
+#[tokio::test]
+async fn test_verify_rehashes_when_cost_changes() {
+    let old_hasher = PasswordHasher::new(&cheap_config(1)).unwrap();
+    let new_hasher = PasswordHasher::new(&cheap_config(2)).unwrap();
+    let password = Password::new("CorrectHorse42".to_string()).unwrap();
+    let old_hash = old_hasher.hash(&password).await.unwrap();
+
+    assert!(new_hasher.needs_rehash(&old_hash));
+    let upgraded = match new_hasher
+        .verify("CorrectHorse42", &old_hash)
+        .await
+        .unwrap()
+    {
+        PasswordVerification::MatchRehashed(hash) => hash,
+        other => panic!("expected rehash, got {:?}", other),
+    };
+    assert!(upgraded.value().contains("t=2"));
+    assert!(!new_hasher.needs_rehash(&upgraded));
+
+    // 不一致の場合は再ハッシュしない
+    assert_eq!(
+        new_hasher.verify("WrongHorse42", &old_hash).await.unwrap(),
+        PasswordVerification::Mismatch
+    );
+}
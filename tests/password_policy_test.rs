@@ -0,0 +1,132 @@
+// tests/password_policy_test.rs
+// パスワードポリシーのテスト
+
+use rusted_ca::domain::service::password_policy::{
+    PasswordContext, PasswordPolicy, PasswordViolation,
+};
+use rusted_ca::domain::value_object::password::Password;
+use rusted_ca::infrastructure::config::app_config::PasswordPolicyConfig;
+use rusted_ca::shared::error::domain_error::DomainError;
+
+fn strict_policy() -> PasswordPolicy {
+    let mut policy = PasswordPolicy::default();
+    policy.require_lowercase = true;
+    policy.require_uppercase = true;
+    policy.require_digit = true;
+    policy.require_symbol = true;
+    policy
+}
+
+/// 長さはバイト数ではなく文字数で数えることを確認
+#[test]
+fn test_length_counts_characters_not_bytes() {
+    let policy = PasswordPolicy::default();
+    let context = PasswordContext::default();
+    // 7文字（21バイト）は短すぎる
+    assert_eq!(
+        policy.check("あいうえおかき", &context),
+        vec![PasswordViolation::TooShort { min_length: 8 }]
+    );
+    assert!(policy.check("あいうえおかきく", &context).is_empty());
+    let too_long = "あ".repeat(129);
+    assert_eq!(
+        policy.check(&too_long, &context),
+        vec![PasswordViolation::TooLong { max_length: 128 }]
+    );
+}
+
+/// 違反はすべてまとめて返されることを確認
+#[test]
+fn test_all_violations_are_reported_at_once() {
+    let violations = strict_policy().check("abc", &PasswordContext::default());
+    assert_eq!(
+        violations,
+        vec![
+            PasswordViolation::TooShort { min_length: 8 },
+            PasswordViolation::MissingUppercase,
+            PasswordViolation::MissingDigit,
+            PasswordViolation::MissingSymbol,
+        ]
+    );
+    assert!(
+        strict_policy()
+            .check("Correct-Horse-42", &PasswordContext::default())
+            .is_empty()
+    );
+
+    let error = Password::with_policy(
+        "abc".to_string(),
+        &strict_policy(),
+        &PasswordContext::default(),
+    )
+    .unwrap_err();
+    assert_eq!(error, DomainError::PasswordPolicyViolation { violations });
+}
+
+/// メールアドレス・名前を含むパスワードを拒否することを確認
+#[test]
+fn test_personal_info_is_rejected() {
+    let policy = PasswordPolicy::default();
+    let context = PasswordContext {
+        email: Some("taro.yamada@example.com"),
+        name: Some("Taro Yamada"),
+    };
+    assert_eq!(
+        policy.check("MyTaro.Yamada!", &context),
+        vec![
+            PasswordViolation::ContainsEmail,
+            PasswordViolation::ContainsName,
+        ]
+    );
+    assert_eq!(
+        policy.check(
+            "secret-yamada-1",
+            &PasswordContext {
+                email: None,
+                ..context
+            }
+        ),
+        vec![PasswordViolation::ContainsName]
+    );
+    assert!(policy.check("unrelated-phrase", &context).is_empty());
+
+    let mut relaxed = PasswordPolicy::default();
+    relaxed.forbid_personal_info = false;
+    assert!(relaxed.check("MyTaro.Yamada!", &context).is_empty());
+}
+
+/// 漏洩済みパスワードの一覧ファイル（コメント行付き）を大文字・小文字を区別せず照合することを確認
+#[test]
+fn test_breached_password_list_file() {
+    let path = std::env::temp_dir().join(format!("breached-{}.txt", uuid::Uuid::new_v4()));
+    std::fs::write(&path, "# top passwords\npassword123\n\nqwertyuiop\n").unwrap();
+    let config = PasswordPolicyConfig {
+        breached_list_path: Some(path.to_string_lossy().to_string()),
+        ..PasswordPolicyConfig::default()
+    };
+    let policy = config.load().unwrap();
+    std::fs::remove_file(&path).unwrap();
+    let context = PasswordContext::default();
+    assert_eq!(
+        policy.check("Password123", &context),
+        vec![PasswordViolation::Breached]
+    );
+    assert!(policy.check("# top passwords", &context).is_empty());
+    assert!(policy.check("correct horse", &context).is_empty());
+}
+
+/// 矛盾した設定・読めないファイルは設定エラーになることを確認
+#[test]
+fn test_invalid_policy_config_is_rejected() {
+    let inverted = PasswordPolicyConfig {
+        min_length: 16,
+        max_length: 8,
+        ..PasswordPolicyConfig::default()
+    };
+    assert!(inverted.load().is_err());
+    let missing = PasswordPolicyConfig {
+        breached_list_path: Some("/nonexistent/breached.txt".to_string()),
+        ..PasswordPolicyConfig::default()
+    };
+    assert!(missing.load().is_err());
+}
@@ -0,0 +1,37 @@
+// tests/permission_policy_test.rs
+// ロール・権限ポリシーのテスト
+
+use rusted_ca::domain::service::permission_policy::PermissionPolicy;
+use rusted_ca::domain::value_object::{permission::Permission, role::Role};
+use rusted_ca::infrastructure::config::app_config::PermissionPolicyConfig;
+
+/// 既定ポリシーでは一般ユーザーはセッション失効を行えないことを確認
+#[test]
+fn test_default_policy_grants() {
+    let policy = PermissionPolicy::default();
+    assert!(policy.allows(Role::User, Permission::UsersRead));
+    assert!(!policy.allows(Role::User, Permission::SessionsRevoke));
+    assert!(policy.allows(Role::Admin, Permission::SessionsRevoke));
+    for permission in Permission::ALL {
+        assert!(policy.allows(Role::SuperAdmin, permission));
+    }
+}
+
+/// 設定ファイルで指定したロールだけが差し替えられることを確認
+#[test]
+fn test_policy_file_overrides_listed_roles() {
+    let policy =
+        PermissionPolicyConfig::parse(r#"{"user": ["users:read", "sessions:revoke"]}"#).unwrap();
+    assert!(policy.allows(Role::User, Permission::SessionsRevoke));
+    assert_eq!(
+        policy.permissions(Role::Admin),
+        PermissionPolicy::default().permissions(Role::Admin)
+    );
+}
+
+/// 未知のロール・権限は設定エラーになることを確認
+#[test]
+fn test_policy_file_rejects_unknown_names() {
+    assert!(PermissionPolicyConfig::parse(r#"{"root": ["users:read"]}"#).is_err());
+    assert!(PermissionPolicyConfig::parse(r#"{"user": ["users:everything"]}"#).is_err());
+}
@@ -0,0 +1,29 @@
+// tests/pkce_test.rs
+// PKCEユーティリティのテスト（RFC 7636 付録Bのテストベクタ）
+
+use rusted_ca::shared::utils::pkce;
+
+const RFC_VERIFIER: &str = "dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk";
+const RFC_CHALLENGE: &str = "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM";
+
+#[test]
+fn test_s256_challenge_matches_rfc7636_vector() {
+    assert_eq!(pkce::s256_challenge(RFC_VERIFIER), RFC_CHALLENGE);
+    assert!(pkce::is_valid_challenge(RFC_CHALLENGE));
+    assert!(pkce::verify_s256(RFC_VERIFIER, RFC_CHALLENGE));
+}
+
+#[test]
+fn test_verify_rejects_mismatch_and_malformed_verifiers() {
+    let other = "a".repeat(43);
+    assert!(!pkce::verify_s256(&other, RFC_CHALLENGE));
+    // 長さ・文字種が仕様外の検証値は、チャレンジと一致しても受け付けない
+    let short = "short";
+    assert!(!pkce::verify_s256(short, &pkce::s256_challenge(short)));
+    let invalid_chars = format!("{}+/", "a".repeat(43));
+    assert!(!pkce::verify_s256(
+        &invalid_chars,
+        &pkce::s256_challenge(&invalid_chars)
+    ));
+    assert!(!pkce::is_valid_verifier(&"a".repeat(129)));
+}
@@ -0,0 +1,305 @@
+// tests/postgres_repository_test.rs
+// PostgreSQLのユーザーRepository（DATABASE_URLによる選択・マイグレーション）のテスト
+// `--features postgres`でビルドし、TEST_POSTGRES_URLを指定した場合のみ実行する
+
+#![cfg(feature = "postgres")]
+
+mod common;
+
+use chrono::{Duration as ChronoDuration, Utc};
+use common::user_repository_conformance;
+use rusted_ca::domain::entity::user::User;
+use rusted_ca::domain::value_object::pagination::{
+    PaginationParams, SortOrder, SortParams, TimeGranularity, TimePeriod, UserSearchFilters,
+};
+use rusted_ca::domain::value_object::{
+    email::Email, password_hash::PasswordHash, phone::Phone, role::Role, user_id::UserId,
+    user_name::UserName,
+};
+use rusted_ca::infrastructure::config::app_config::{
+    AppConfig, BootstrapUserConfig, DatabaseConfig, DatabaseUrl,
+};
+use rusted_ca::infrastructure::config::config_source::MigrateCommand;
+use rusted_ca::infrastructure::database::postgres_connection::PostgresConnection;
+use rusted_ca::infrastructure::di::container::DIContainer;
+use rusted_ca::shared::error::infrastructure_error::InfrastructureError;
+use std::time::Duration;
+use tokio_postgres::NoTls;
+
+const HASH: &str = "$argon2id$v=19$m=19456,t=2,p=1$c2FsdA$aGFzaA";
+
+// テストごとに別のスキーマを作り、search_pathでそのスキーマだけを使う接続先を返す
+async fn isolated_url() -> Option<String> {
+    let Ok(base) = std::env::var("TEST_POSTGRES_URL") else {
+        // CIでは未指定のまま成功扱いにしない
+        assert!(
+            std::env::var("CI").is_err(),
+            "TEST_POSTGRES_URL is required on CI"
+        );
+        eprintln!("TEST_POSTGRES_URL is not set; skipping");
+        return None;
+    };
+    let (client, connection) = tokio_postgres::connect(&base, NoTls).await.unwrap();
+    tokio::spawn(connection);
+    let schema = format!("test_{}", uuid::Uuid::new_v4().simple());
+    client
+        .batch_execute(&format!("CREATE SCHEMA {}", schema))
+        .await
+        .unwrap();
+    let separator = if base.contains('?') { '&' } else { '?' };
+    Some(format!(
+        "{}{}options=-csearch_path%3D{}",
+        base, separator, schema
+    ))
+}
+
+fn database_config(url: &str, auto_migrate: bool) -> DatabaseConfig {
+    DatabaseConfig {
+        url: DatabaseUrl::Postgres(url.to_string()),
+        sqlite_url: DatabaseUrl::SqliteMemory,
+        busy_timeout: Duration::from_secs(2),
+        auto_migrate,
+        readers: 2,
+        acquire_timeout: Duration::from_secs(5),
+    }
+}
+
+fn postgres_container(url: &str) -> DIContainer {
+    let mut config = AppConfig::from_env();
+    config.database = database_config(url, true);
+    DIContainer::new().with_config(config)
+}
+
+fn user(id: &str, email: &str, phone: Option<&str>) -> User {
+    User::new(
+        UserId::new(id.to_string()),
+        Email::new(email.to_string()).unwrap(),
+        UserName::new(format!("User {}", id)).unwrap(),
+        PasswordHash::new(HASH.to_string()).unwrap(),
+        phone.map(|p| Phone::new(p.to_string()).unwrap()),
+        None,
+    )
+    .unwrap()
+}
+
+/// DATABASE_URLがPostgreSQLの場合、ユーザーはPostgreSQLに保存しSQLiteには保存しないことを確認
+#[tokio::test]
+async fn test_container_stores_users_in_postgres() {
+    let Some(url) = isolated_url().await else {
+        return;
+    };
+    let di = postgres_container(&url);
+    di.prepare_database().await.unwrap();
+    di.seed_bootstrap_user(&BootstrapUserConfig {
+        email: "pg-admin@example.com".to_string(),
+        password: "Postgres-Pass-123".to_string(),
+        name: "Postgres Admin".to_string(),
+        role: Role::Admin,
+    })
+    .await
+    .unwrap();
+
+    // 別のコンテナ（再起動）からも読める
+    let (_, query_repo) = postgres_container(&url).create_repositories().unwrap();
+    let found = query_repo
+        .find_by_email(&Email::new("pg-admin@example.com".to_string()).unwrap())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(found.role, Role::Admin);
+    assert!(found.is_email_verified());
+
+    let sqlite_users: i64 = di
+        .create_database_connection()
+        .unwrap()
+        .execute_query(|conn| conn.query_row("SELECT COUNT(*) FROM users", [], |row| row.get(0)))
+        .await
+        .unwrap();
+    assert_eq!(sqlite_users, 0);
+}
+
+/// SQLiteのRepositoryと同じ振る舞い（更新・一括保存・検索・集計・削除）を確認
+#[tokio::test]
+async fn test_postgres_user_repository() {
+    let Some(url) = isolated_url().await else {
+        return;
+    };
+    let (command_repo, query_repo) = postgres_container(&url).create_repositories().unwrap();
+    let verified_at = Utc::now();
+    let alice = user("alice", "alice@example.com", Some("09012345678"))
+        .with_email_verified_at(Some(verified_at));
+    command_repo.save(&alice).await.unwrap();
+    assert!(command_repo.save(&alice).await.is_err());
+
+    // メールアドレスを変えると確認済みの状態は取り消される
+    let found = query_repo.find_by_id(&alice.id).await.unwrap().unwrap();
+    assert_eq!(
+        found.email_verified_at.map(|t| t.timestamp_micros()),
+        Some(verified_at.timestamp_micros())
+    );
+    let mut renamed = found.clone();
+    renamed.name = UserName::new("Alice Renamed".to_string()).unwrap();
+    command_repo.update(&renamed).await.unwrap();
+    assert!(
+        query_repo
+            .find_by_id(&alice.id)
+            .await
+            .unwrap()
+            .unwrap()
+            .is_email_verified()
+    );
+    renamed.email = Email::new("alice@example.org".to_string()).unwrap();
+    command_repo.update(&renamed).await.unwrap();
+    let found = query_repo.find_by_id(&alice.id).await.unwrap().unwrap();
+    assert_eq!(found.name.0, "Alice Renamed");
+    assert!(!found.is_email_verified());
+
+    // 一括保存は途中で失敗すると全て取り消す
+    let batch = [
+        user("bob", "bob@example.com", None),
+        user("carol", "alice@example.org", None),
+    ];
+    assert!(command_repo.save_batch(&batch).await.is_err());
+    assert_eq!(query_repo.count_total().await.unwrap(), 1);
+    let batch = [
+        user("bob", "bob@example.com", None),
+        user("carol", "carol@example.net", Some("08011112222")),
+    ];
+    command_repo.save_batch(&batch).await.unwrap();
+    assert_eq!(query_repo.count_total().await.unwrap(), 3);
+
+    command_repo
+        .update_last_login(&batch[0].id, Utc::now())
+        .await
+        .unwrap();
+    let start = Utc::now() - ChronoDuration::hours(1);
+    let end = Utc::now() + ChronoDuration::hours(1);
+    assert_eq!(
+        query_repo
+            .count_registrations_in_period(start, end)
+            .await
+            .unwrap(),
+        3
+    );
+    assert_eq!(
+        query_repo
+            .count_active_users_in_period(start, end)
+            .await
+            .unwrap(),
+        1
+    );
+    let trend = query_repo
+        .get_registration_trend(TimePeriod::Day, TimeGranularity::Day)
+        .await
+        .unwrap();
+    assert_eq!(trend.len(), 1);
+    assert_eq!(trend[0].value, 3);
+    assert_eq!(
+        trend[0].timestamp,
+        Utc::now().format("%Y-%m-%d").to_string()
+    );
+
+    // 検索（並び替えの列は許可したもの以外を無視する）
+    let filters = UserSearchFilters {
+        email_domain: None,
+        name_contains: None,
+        created_after: Some(start.to_rfc3339()),
+        created_before: None,
+        has_phone: Some(true),
+        age_range: None,
+    };
+    let result = query_repo
+        .search_users(
+            filters,
+            SortParams {
+                field: "email; DROP TABLE users".to_string(),
+                order: SortOrder::Asc,
+            },
+            PaginationParams { page: 1, limit: 1 },
+        )
+        .await
+        .unwrap();
+    assert_eq!(result.pagination.total_count, 2);
+    assert_eq!(result.pagination.total_pages, 2);
+    assert!(result.pagination.has_next);
+    assert_eq!(result.data.len(), 1);
+
+    let page = query_repo
+        .find_all(PaginationParams { page: 2, limit: 2 })
+        .await
+        .unwrap();
+    assert_eq!(page.data.len(), 1);
+    assert!(page.pagination.has_prev);
+
+    command_repo.delete(&alice.id).await.unwrap();
+    assert!(query_repo.find_by_id(&alice.id).await.unwrap().is_none());
+    assert!(
+        !command_repo
+            .exists_by_email(&Email::new("alice@example.org".to_string()).unwrap())
+            .await
+            .unwrap()
+    );
+}
+
+/// マイグレーション（status・up・down）と、データベースの方が新しい場合に起動しないことを確認
+#[tokio::test]
+async fn test_postgres_migrations() {
+    let Some(url) = isolated_url().await else {
+        return;
+    };
+    let db = PostgresConnection::connect(&url, &database_config(&url, false)).unwrap();
+    let status = db
+        .run_migrate_command(MigrateCommand::Status)
+        .await
+        .unwrap();
+    assert!(status.contains("0001  users"));
+    assert!(status.contains("pending"));
+
+    // 自動適用しない場合は未適用があると使えない
+    assert!(matches!(
+        db.prepare().await.unwrap_err(),
+        InfrastructureError::SchemaMigration { version: 1, .. }
+    ));
+
+    let output = db
+        .run_migrate_command(MigrateCommand::Up { dry_run: false })
+        .await
+        .unwrap();
+    assert!(output.contains("applied 0001 users"));
+    let output = db
+        .run_migrate_command(MigrateCommand::Down {
+            target: 0,
+            dry_run: false,
+        })
+        .await
+        .unwrap();
+    assert!(output.contains("rolled back 0001 users"));
+
+    // 新しいバージョンのバイナリが適用したスキーマは拒否する
+    let db = PostgresConnection::connect(&url, &database_config(&url, true)).unwrap();
+    db.prepare().await.unwrap();
+    let client = db.client().await.unwrap();
+    client
+        .execute(
+            "INSERT INTO schema_migrations (version, name, checksum, applied_at)
+             VALUES (99, 'future', 'x', 'now')",
+            &[],
+        )
+        .await
+        .unwrap();
+    let fresh = PostgresConnection::connect(&url, &database_config(&url, true)).unwrap();
+    assert!(matches!(
+        fresh.prepare().await.unwrap_err(),
+        InfrastructureError::SchemaMigration { version: 99, .. }
+    ));
+}
+
+/// 適合テスト（SQLite・インメモリの実装と同じ振る舞いをすること）
+#[tokio::test]
+async fn test_postgres_repository_conformance() {
+    let Some(url) = isolated_url().await else {
+        return;
+    };
+    let (command_repo, query_repo) = postgres_container(&url).create_repositories().unwrap();
+    user_repository_conformance::run("postgres", command_repo.as_ref(), query_repo.as_ref()).await;
+}
@@ -0,0 +1,199 @@
+// tests/server_listener_test.rs
+// 待ち受け設定・リスナーのテスト
+
+use axum::Router;
+use axum::extract::ConnectInfo;
+use axum::routing::get;
+use reqwest::StatusCode;
+use rusted_ca::infrastructure::config::app_config::{ListenAddr, ServerConfig};
+use rusted_ca::infrastructure::web::listener::{Listener, serve};
+use rusted_ca::presentation::router::app_router::create_admin_listener_router;
+use rusted_ca::shared::metrics::collector::MetricsCollector;
+use rusted_ca::shared::middleware::metrics_middleware::metrics_middleware;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::sync::watch;
+
+fn config(listen: &[&str], admin_listen: &[&str]) -> ServerConfig {
+    ServerConfig {
+        listen: listen.iter().map(|v| v.to_string()).collect(),
+        admin_listen: admin_listen.iter().map(|v| v.to_string()).collect(),
+    }
+}
+
+// ポート0でバインドし、実際のアドレスを返す
+async fn spawn(router: Router, shutdown: watch::Receiver<bool>) -> SocketAddr {
+    let listener = Listener::bind(&ListenAddr::parse("127.0.0.1:0").unwrap())
+        .await
+        .unwrap();
+    let ListenAddr::Tcp(addr) = listener.local_addr().unwrap() else {
+        unreachable!()
+    };
+    tokio::spawn(serve(listener, router, shutdown));
+    addr
+}
+
+/// TCP・IPv6・Unixドメインソケットのアドレスを解析できることを確認
+#[test]
+fn test_parse_listen_addresses() {
+    assert_eq!(
+        ListenAddr::parse("0.0.0.0:8080").unwrap(),
+        ListenAddr::Tcp("0.0.0.0:8080".parse().unwrap())
+    );
+    assert_eq!(
+        ListenAddr::parse(" [::1]:3000 ").unwrap(),
+        ListenAddr::Tcp("[::1]:3000".parse().unwrap())
+    );
+    assert_eq!(
+        ListenAddr::parse("unix:/run/rusted-ca.sock").unwrap(),
+        ListenAddr::Unix("/run/rusted-ca.sock".into())
+    );
+    assert!(ListenAddr::parse("unix:").is_err());
+    assert!(ListenAddr::parse("127.0.0.1").is_err());
+    assert!(ListenAddr::parse("127.0.0.1:99999").is_err());
+}
+
+/// 複数のリスナーを指定でき、公開用と管理用が重なる設定は拒否されることを確認
+#[test]
+fn test_resolve_rejects_overlapping_listeners() {
+    let listeners = config(&["0.0.0.0:3000", "unix:/tmp/app.sock"], &["127.0.0.1:3001"])
+        .resolve()
+        .unwrap();
+    assert_eq!(listeners.public.len(), 2);
+    assert_eq!(listeners.admin.len(), 1);
+
+    assert!(
+        config(&["127.0.0.1:3000"], &["127.0.0.1:3000"])
+            .resolve()
+            .is_err()
+    );
+    // 全アドレスで待ち受ける公開用リスナーと同じポートは使えない
+    assert!(
+        config(&["0.0.0.0:3000"], &["127.0.0.1:3000"])
+            .resolve()
+            .is_err()
+    );
+    assert!(
+        config(&["unix:/tmp/a.sock"], &["unix:/tmp/a.sock"])
+            .resolve()
+            .is_err()
+    );
+    assert!(config(&[], &["127.0.0.1:3001"]).resolve().is_err());
+    assert!(
+        config(&["127.0.0.1:3000"], &["127.0.0.1:3001"])
+            .resolve()
+            .is_ok()
+    );
+}
+
+/// 公開用リスナーのリクエストが管理用リスナーのメトリクスに現れ、
+/// ヘルスチェックは公開用リスナーにないことを確認
+#[tokio::test]
+async fn test_admin_listener_serves_health_and_metrics() {
+    let metrics = Arc::new(MetricsCollector::new());
+    let (_shutdown_tx, shutdown_rx) = watch::channel(false);
+    let public_app =
+        Router::new()
+            .route(
+                "/api/whoami",
+                get(|ConnectInfo(remote): ConnectInfo<SocketAddr>| async move {
+                    remote.ip().to_string()
+                }),
+            )
+            .layer(axum::middleware::from_fn_with_state(
+                metrics.clone(),
+                metrics_middleware,
+            ));
+    let public = spawn(public_app, shutdown_rx.clone()).await;
+    let admin = spawn(create_admin_listener_router(metrics), shutdown_rx).await;
+    let client = reqwest::Client::new();
+
+    let res = client
+        .get(format!("http://{}/api/whoami", public))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.text().await.unwrap(), "127.0.0.1");
+    let res = client
+        .get(format!("http://{}/health", public))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+
+    let res = client
+        .get(format!("http://{}/health", admin))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    let body = client
+        .get(format!("http://{}/metrics", admin))
+        .send()
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    assert!(body.contains("http_requests_total 2"), "{}", body);
+    assert!(body.contains("http_responses_total{class=\"2xx\"} 1"));
+    assert!(body.contains("http_responses_total{class=\"4xx\"} 1"));
+}
+
+/// シャットダウンを通知すると受け付けを止めることを確認
+#[tokio::test]
+async fn test_listener_stops_on_shutdown() {
+    let (shutdown_tx, shutdown_rx) = watch::channel(false);
+    let listener = Listener::bind(&ListenAddr::parse("127.0.0.1:0").unwrap())
+        .await
+        .unwrap();
+    let ListenAddr::Tcp(addr) = listener.local_addr().unwrap() else {
+        unreachable!()
+    };
+    let server = tokio::spawn(serve(
+        listener,
+        Router::new().route("/", get(|| async { "OK" })),
+        shutdown_rx,
+    ));
+    let res = reqwest::get(format!("http://{}/", addr)).await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    shutdown_tx.send(true).unwrap();
+    tokio::time::timeout(std::time::Duration::from_secs(5), server)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(tokio::net::TcpStream::connect(addr).await.is_err());
+}
+
+/// Unixドメインソケットで待ち受け、終了時にソケットファイルを削除することを確認
+#[cfg(unix)]
+#[tokio::test]
+async fn test_unix_domain_socket_listener() {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let path = std::env::temp_dir().join(format!("rusted-ca-{}.sock", uuid::Uuid::new_v4()));
+    let addr = ListenAddr::Unix(path.clone());
+    // 前回の起動で残ったソケットファイルがあっても起動できる
+    drop(std::os::unix::net::UnixListener::bind(&path).unwrap());
+    let listener = Listener::bind(&addr).await.unwrap();
+    let (shutdown_tx, shutdown_rx) = watch::channel(false);
+    let server = tokio::spawn(serve(
+        listener,
+        Router::new().route("/health", get(|| async { "OK" })),
+        shutdown_rx,
+    ));
+
+    let mut stream = tokio::net::UnixStream::connect(&path).await.unwrap();
+    stream
+        .write_all(b"GET /health HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+        .await
+        .unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await.unwrap();
+    assert!(response.starts_with("HTTP/1.1 200 OK"), "{}", response);
+    assert!(response.ends_with("OK"));
+
+    shutdown_tx.send(true).unwrap();
+    server.await.unwrap();
+    assert!(!path.exists());
+}
@@ -0,0 +1,34 @@
+// tests/session_revocation_test.rs
+// セッション失効リストのテスト（リポジトリ直接）
+
+use chrono::{Duration, Utc};
+use rusted_ca::domain::repository::token_revocation_repository::TokenRevocationRepositoryInterface;
+use rusted_ca::domain::value_object::user_id::UserId;
+use rusted_ca::infrastructure::di::container::DIContainer;
+
+#[tokio::test]
+async fn test_purge_expired_removes_only_expired_revocations() {
+    let di = DIContainer::in_memory();
+    let repository = di.create_token_revocation_repository().unwrap();
+    let user_id = UserId::new(uuid::Uuid::new_v4().to_string());
+
+    repository
+        .revoke("expired-jti", &user_id, Utc::now() - Duration::minutes(5))
+        .await
+        .unwrap();
+    repository
+        .revoke("live-jti", &user_id, Utc::now() + Duration::minutes(5))
+        .await
+        .unwrap();
+    assert!(repository.is_revoked("expired-jti").await.unwrap());
+
+    let purged = di
+        .create_session_revocation_service()
+        .unwrap()
+        .purge_expired()
+        .await
+        .unwrap();
+    assert_eq!(purged, 1);
+    assert!(!repository.is_revoked("expired-jti").await.unwrap());
+    assert!(repository.is_revoked("live-jti").await.unwrap());
+}
@@ -0,0 +1,334 @@
+// tests/tls_listener_test.rs
+// TLS終端（ALPN・証明書の再読み込み・mTLS）のテスト
+
+use axum::Router;
+use axum::routing::get;
+use rusted_ca::domain::value_object::role::Role;
+use rusted_ca::infrastructure::config::app_config::{
+    AppConfig, BootstrapUserConfig, ClientAuthMode, DiscordConfig, ListenAddr, TlsConfig,
+};
+use rusted_ca::infrastructure::di::container::DIContainer;
+use rusted_ca::infrastructure::web::listener::{Listener, serve};
+use rusted_ca::infrastructure::web::tls::build_tls_acceptor;
+use rusted_ca::presentation::router::app_router::create_app_router;
+use rusted_ca::shared::middleware::client_cert_middleware::ClientCertAuth;
+use rustls::crypto::ring::default_provider;
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer, ServerName};
+use rustls::{ClientConfig, RootCertStore};
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio::sync::watch;
+use tokio_rustls::TlsConnector;
+use tokio_rustls::client::TlsStream;
+
+const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/tls");
+
+fn fixture(name: &str) -> PathBuf {
+    Path::new(FIXTURES).join(name)
+}
+
+fn tls_config(client_ca: Option<ClientAuthMode>) -> TlsConfig {
+    TlsConfig {
+        cert_path: fixture("server.pem"),
+        key_path: fixture("server.key"),
+        client_ca_path: client_ca.map(|_| fixture("ca.pem")),
+        client_auth: client_ca.unwrap_or(ClientAuthMode::Required),
+        reload_interval: Duration::from_secs(10),
+        client_cert_users: Vec::new(),
+    }
+}
+
+fn test_router() -> Router {
+    Router::new()
+        .route("/health", get(|| async { "OK" }))
+        .route(
+            "/whoami",
+            get(|ClientCertAuth(certificate): ClientCertAuth| async move {
+                format!("{}|{}", certificate.principal(), certificate.subject)
+            }),
+        )
+}
+
+// TLSリスナーを起動する（シャットダウン用の送信側も返す）
+async fn spawn_tls(config: &TlsConfig) -> (SocketAddr, watch::Sender<bool>) {
+    spawn_tls_router(config, test_router()).await
+}
+
+async fn spawn_tls_router(config: &TlsConfig, router: Router) -> (SocketAddr, watch::Sender<bool>) {
+    let (acceptor, _resolver) = build_tls_acceptor(config).unwrap();
+    let listener = Listener::bind(&ListenAddr::parse("127.0.0.1:0").unwrap())
+        .await
+        .unwrap()
+        .with_tls(acceptor);
+    let ListenAddr::Tcp(addr) = listener.local_addr().unwrap() else {
+        unreachable!()
+    };
+    let (shutdown_tx, shutdown_rx) = watch::channel(false);
+    tokio::spawn(serve(listener, router, shutdown_rx));
+    (addr, shutdown_tx)
+}
+
+async fn connect(
+    addr: SocketAddr,
+    alpn: &[&[u8]],
+    client_cert: bool,
+) -> std::io::Result<TlsStream<TcpStream>> {
+    let mut roots = RootCertStore::empty();
+    roots
+        .add(CertificateDer::from_pem_file(fixture("ca.pem")).unwrap())
+        .unwrap();
+    let builder = ClientConfig::builder_with_provider(Arc::new(default_provider()))
+        .with_safe_default_protocol_versions()
+        .unwrap()
+        .with_root_certificates(roots);
+    let mut config = if client_cert {
+        builder
+            .with_client_auth_cert(
+                vec![CertificateDer::from_pem_file(fixture("client.pem")).unwrap()],
+                PrivateKeyDer::from_pem_file(fixture("client.key")).unwrap(),
+            )
+            .unwrap()
+    } else {
+        builder.with_no_client_auth()
+    };
+    config.alpn_protocols = alpn.iter().map(|p| p.to_vec()).collect();
+    let tcp = TcpStream::connect(addr).await?;
+    TlsConnector::from(Arc::new(config))
+        .connect(ServerName::try_from("localhost").unwrap(), tcp)
+        .await
+}
+
+// HTTP/1.1でGETし、レスポンス全体を返す（読み込めない場合は空文字列）
+async fn get_http1(stream: &mut TlsStream<TcpStream>, path: &str) -> String {
+    request_http1(stream, "GET", path, None).await
+}
+
+// HTTP/1.1でリクエストし（本文はJSON）、レスポンス全体を返す
+async fn request_http1(
+    stream: &mut TlsStream<TcpStream>,
+    method: &str,
+    path: &str,
+    json: Option<&str>,
+) -> String {
+    let request = match json {
+        Some(body) => format!(
+            "{} {} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+            method,
+            path,
+            body.len(),
+            body
+        ),
+        None => format!(
+            "{} {} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n",
+            method, path
+        ),
+    };
+    if stream.write_all(request.as_bytes()).await.is_err() {
+        return String::new();
+    }
+    let mut response = Vec::new();
+    let _ = stream.read_to_end(&mut response).await;
+    String::from_utf8_lossy(&response).to_string()
+}
+
+fn leaf_certificate(stream: &TlsStream<TcpStream>) -> Vec<u8> {
+    stream.get_ref().1.peer_certificates().unwrap()[0].to_vec()
+}
+
+/// ALPNでh2を優先し、http/1.1のみのクライアントも処理できることを確認
+#[tokio::test]
+async fn test_alpn_negotiates_h2_and_http1() {
+    let (addr, _shutdown) = spawn_tls(&tls_config(None)).await;
+
+    let stream = connect(addr, &[b"h2", b"http/1.1"], false).await.unwrap();
+    assert_eq!(stream.get_ref().1.alpn_protocol(), Some(&b"h2"[..]));
+
+    let mut stream = connect(addr, &[b"http/1.1"], false).await.unwrap();
+    assert_eq!(stream.get_ref().1.alpn_protocol(), Some(&b"http/1.1"[..]));
+    let response = get_http1(&mut stream, "/health").await;
+    assert!(response.starts_with("HTTP/1.1 200 OK"), "{}", response);
+    assert!(response.ends_with("OK"));
+}
+
+/// 証明書ファイルを差し替えると新しい接続から新しい証明書を使い、
+/// 確立済みの接続はそのまま使えることを確認
+#[tokio::test]
+async fn test_certificate_reload_keeps_existing_connections() {
+    let dir = std::env::temp_dir().join(format!("rusted-ca-tls-{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let config = TlsConfig {
+        cert_path: dir.join("cert.pem"),
+        key_path: dir.join("key.pem"),
+        ..tls_config(None)
+    };
+    std::fs::copy(fixture("server.pem"), &config.cert_path).unwrap();
+    std::fs::copy(fixture("server.key"), &config.key_path).unwrap();
+
+    let (acceptor, resolver) = build_tls_acceptor(&config).unwrap();
+    let listener = Listener::bind(&ListenAddr::parse("127.0.0.1:0").unwrap())
+        .await
+        .unwrap()
+        .with_tls(acceptor);
+    let ListenAddr::Tcp(addr) = listener.local_addr().unwrap() else {
+        unreachable!()
+    };
+    let (_shutdown_tx, shutdown_rx) = watch::channel(false);
+    tokio::spawn(serve(listener, test_router(), shutdown_rx));
+
+    let original = CertificateDer::from_pem_file(fixture("server.pem")).unwrap();
+    let rotated = CertificateDer::from_pem_file(fixture("server_rotated.pem")).unwrap();
+    let mut existing = connect(addr, &[b"http/1.1"], false).await.unwrap();
+    assert_eq!(leaf_certificate(&existing), original.to_vec());
+    assert!(!resolver.reload_if_changed().unwrap());
+
+    // 書き込み途中（鍵が合わない状態）では今の証明書を使い続ける
+    std::fs::copy(fixture("server_rotated.pem"), &config.cert_path).unwrap();
+    let later = SystemTime::now() + Duration::from_secs(5);
+    std::fs::File::options()
+        .write(true)
+        .open(&config.cert_path)
+        .unwrap()
+        .set_modified(later)
+        .unwrap();
+    assert!(resolver.reload_if_changed().is_err());
+    let stream = connect(addr, &[b"http/1.1"], false).await.unwrap();
+    assert_eq!(leaf_certificate(&stream), original.to_vec());
+
+    std::fs::copy(fixture("server_rotated.key"), &config.key_path).unwrap();
+    assert!(resolver.reload_if_changed().unwrap());
+    let stream = connect(addr, &[b"http/1.1"], false).await.unwrap();
+    assert_eq!(leaf_certificate(&stream), rotated.to_vec());
+
+    let response = get_http1(&mut existing, "/health").await;
+    assert!(response.starts_with("HTTP/1.1 200 OK"), "{}", response);
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+/// mTLS必須モードでは証明書のない接続を拒否し、
+/// 検証済みの証明書のサブジェクトを認証済みの主体として使えることを確認
+#[tokio::test]
+async fn test_required_client_certificate_becomes_principal() {
+    let (addr, _shutdown) = spawn_tls(&tls_config(Some(ClientAuthMode::Required))).await;
+
+    let mut stream = connect(addr, &[b"http/1.1"], true).await.unwrap();
+    let response = get_http1(&mut stream, "/whoami").await;
+    assert!(response.starts_with("HTTP/1.1 200 OK"), "{}", response);
+    assert!(
+        response.ends_with("billing-service|CN=billing-service, O=Example"),
+        "{}",
+        response
+    );
+
+    // TLS 1.3ではクライアント証明書の拒否がハンドシェイク後に届くため、応答がないことで確認する
+    let response = match connect(addr, &[b"http/1.1"], false).await {
+        Ok(mut stream) => get_http1(&mut stream, "/health").await,
+        Err(_) => String::new(),
+    };
+    assert!(response.is_empty(), "{}", response);
+}
+
+/// mTLS任意モードでは証明書のない接続も受け付け、主体を要求するルートのみ拒否することを確認
+#[tokio::test]
+async fn test_optional_client_certificate() {
+    let (addr, _shutdown) = spawn_tls(&tls_config(Some(ClientAuthMode::Optional))).await;
+
+    let mut stream = connect(addr, &[b"http/1.1"], false).await.unwrap();
+    let response = get_http1(&mut stream, "/health").await;
+    assert!(response.starts_with("HTTP/1.1 200 OK"), "{}", response);
+    let mut stream = connect(addr, &[b"http/1.1"], false).await.unwrap();
+    let response = get_http1(&mut stream, "/whoami").await;
+    assert!(response.contains("MISSING_CREDENTIALS"), "{}", response);
+
+    let mut stream = connect(addr, &[b"http/1.1"], true).await.unwrap();
+    let response = get_http1(&mut stream, "/whoami").await;
+    assert!(response.ends_with("billing-service|CN=billing-service, O=Example"));
+}
+
+// アプリケーション全体のルーターを組み立てる（クライアント証明書の主体をユーザーに対応付ける）
+async fn app_router(tls: &TlsConfig) -> Router {
+    let mut config = AppConfig::from_env();
+    config.tls = Some(tls.clone());
+    let di = DIContainer::in_memory().with_config(config);
+    di.seed_bootstrap_user(&BootstrapUserConfig {
+        email: "billing@example.com".to_string(),
+        password: "Billing-Pass-123".to_string(),
+        name: "Billing Service".to_string(),
+        role: Role::Admin,
+    })
+    .await
+    .unwrap();
+    create_app_router(
+        di.build_user_controller().unwrap(),
+        di.build_auth_controller().unwrap(),
+        di.build_admin_controller().unwrap(),
+        di.build_api_key_controller().unwrap(),
+        di.build_oauth_controller().unwrap(),
+        di.build_oidc_controller().unwrap(),
+        di.build_auth_services().unwrap(),
+        Arc::new(DiscordConfig {
+            webhook_url: String::new(),
+            server_name: "test-server".to_string(),
+            enabled: false,
+            timeout: Duration::from_secs(1),
+        }),
+        Arc::from(
+            std::env::temp_dir()
+                .join("rusted-ca-test-logs")
+                .to_string_lossy()
+                .as_ref(),
+        ),
+    )
+}
+
+/// 対応表にある証明書の主体はそのユーザーとしてAPIを利用でき、
+/// ログインセッションが必要な操作と証明書なしの接続は拒否されることを確認
+#[tokio::test]
+async fn test_client_certificate_authenticates_app_routes() {
+    let config = TlsConfig {
+        client_cert_users: vec![(
+            "billing-service".to_string(),
+            "billing@example.com".to_string(),
+        )],
+        ..tls_config(Some(ClientAuthMode::Optional))
+    };
+    let (addr, _shutdown) = spawn_tls_router(&config, app_router(&config).await).await;
+    let body = r#"{"email":"created-by-cert@example.com","name":"Created By Cert","password":"Password123!"}"#;
+
+    let mut stream = connect(addr, &[b"http/1.1"], true).await.unwrap();
+    let response = request_http1(&mut stream, "POST", "/api/users", Some(body)).await;
+    assert!(response.starts_with("HTTP/1.1 201"), "{}", response);
+
+    let mut stream = connect(addr, &[b"http/1.1"], true).await.unwrap();
+    let response = get_http1(&mut stream, "/api/api-keys").await;
+    assert!(response.starts_with("HTTP/1.1 403"), "{}", response);
+    assert!(response.contains("OPERATION_NOT_PERMITTED"), "{}", response);
+
+    let mut stream = connect(addr, &[b"http/1.1"], false).await.unwrap();
+    let response = request_http1(&mut stream, "POST", "/api/users", Some(body)).await;
+    assert!(response.contains("MISSING_CREDENTIALS"), "{}", response);
+}
+
+/// 証明書と秘密鍵の組み合わせ違い・片方だけの設定は起動時に拒否されることを確認
+#[test]
+fn test_invalid_tls_config_is_rejected() {
+    let mismatched = TlsConfig {
+        key_path: fixture("server_rotated.key"),
+        ..tls_config(None)
+    };
+    assert!(build_tls_acceptor(&mismatched).is_err());
+    let missing_key = TlsConfig {
+        key_path: PathBuf::new(),
+        ..tls_config(None)
+    };
+    assert!(build_tls_acceptor(&missing_key).is_err());
+    let missing_ca = TlsConfig {
+        client_ca_path: Some(fixture("missing.pem")),
+        ..tls_config(None)
+    };
+    assert!(build_tls_acceptor(&missing_ca).is_err());
+}
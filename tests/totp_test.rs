@@ -0,0 +1,47 @@
+// tests/totp_test.rs
+// TOTPユーティリティのテスト（RFC 6238 付録Bのテストベクタ）
+
+use rusted_ca::shared::utils::totp;
+
+// RFC 6238のSHA1用シークレット "12345678901234567890" のBase32表現
+const RFC_SECRET: &str = "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ";
+
+#[test]
+fn test_code_matches_rfc6238_vectors() {
+    // 8桁の期待値の下位6桁
+    let vectors = [
+        (59, "287082"),
+        (1111111109, "081804"),
+        (1111111111, "050471"),
+        (1234567890, "005924"),
+        (2000000000, "279037"),
+    ];
+    for (unix, expected) in vectors {
+        let code = totp::code_at(RFC_SECRET, totp::time_step(unix)).unwrap();
+        assert_eq!(code, expected, "unix time {}", unix);
+    }
+}
+
+#[test]
+fn test_verify_code_allows_skew_and_returns_step() {
+    let step = totp::time_step(1234567890);
+    let previous = totp::code_at(RFC_SECRET, step - 1).unwrap();
+    assert_eq!(
+        totp::verify_code(RFC_SECRET, &previous, 1234567890, 1),
+        Some(step - 1)
+    );
+    assert_eq!(
+        totp::verify_code(RFC_SECRET, &previous, 1234567890, 0),
+        None
+    );
+    assert_eq!(totp::verify_code(RFC_SECRET, "12345", 1234567890, 1), None);
+}
+
+#[test]
+fn test_generated_secret_round_trips() {
+    let secret = totp::generate_secret();
+    assert_eq!(secret.len(), 32);
+    assert!(totp::code_at(&secret, 1).is_some());
+    let uri = totp::provisioning_uri("rusted ca", "user@example.com", &secret);
+    assert!(uri.starts_with("otpauth://totp/rusted%20ca:user%40example.com?secret="));
+}
@@ -3,18 +3,15 @@
 // 2025/7/8
 
 use rusted_ca::domain::entity::user::User;
-use rusted_ca::domain::repository::user_command_repository::UserCommandRepositoryInterface;
-use rusted_ca::domain::repository::user_query_repository::UserQueryRepositoryInterface;
 use rusted_ca::domain::value_object::{
     birth_date::BirthDate, email::Email, password::Password, phone::Phone, user_id::UserId,
     user_name::UserName,
 };
 use rusted_ca::infrastructure::di::container::DIContainer;
-use std::sync::Arc;
 
 #[tokio::test]
 async fn test_user_repository_crud() {
-    let di = DIContainer::new();
+    let di = DIContainer::in_memory();
     let (command_repo, query_repo) = di.create_repositories().unwrap();
 
     // ユーザー作成
@@ -22,13 +19,19 @@ async fn test_user_repository_crud() {
     let email = Email::new("integration@example.com".to_string()).unwrap();
     let name = UserName::new("Integration Test".to_string()).unwrap();
     let password = Password::new("password123".to_string()).unwrap();
+    let password_hash = di
+        .create_password_hasher()
+        .unwrap()
+        .hash(&password)
+        .await
+        .unwrap();
     let phone = Some(Phone::new("09012345678".to_string()).unwrap());
     let birth_date = Some(BirthDate::new("2000-01-01".to_string()).unwrap());
     let user = User::new(
         user_id.clone(),
         email.clone(),
         name,
-        password,
+        password_hash,
         phone,
         birth_date,
     )
@@ -43,6 +46,8 @@ async fn test_user_repository_crud() {
     let found = found.unwrap();
     assert_eq!(found.id.0, user_id.0);
     assert_eq!(found.email.0, email.0);
+    assert!(found.password_hash.0.starts_with("$argon2id$"));
+    assert_ne!(found.password_hash.0, "password123");
 
     // update
     let mut updated_user = found.clone();
@@ -0,0 +1,110 @@
+// tests/user_repository_conformance_test.rs
+// ユーザーRepositoryの各実装（SQLite・インメモリ）に適合テストを実行する
+
+mod common;
+
+use common::user_repository_conformance;
+use rusted_ca::domain::value_object::{email::Email, role::Role};
+use rusted_ca::infrastructure::config::app_config::{
+    AppConfig, BootstrapUserConfig, DatabaseConfig, DatabaseUrl,
+};
+use rusted_ca::infrastructure::database::sqlite_connection::SqliteConnection;
+use rusted_ca::infrastructure::di::container::DIContainer;
+use rusted_ca::infrastructure::repository::in_memory_user_store::InMemoryUserStore;
+use rusted_ca::infrastructure::repository::{
+    InMemoryUserCommandRepository, InMemoryUserQueryRepository, SqliteUserCommandRepository,
+    SqliteUserQueryRepository,
+};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// HashMap/BTreeMapのインメモリ実装
+#[tokio::test]
+async fn test_in_memory_repository_conformance() {
+    let store = InMemoryUserStore::new();
+    user_repository_conformance::run(
+        "in-memory",
+        &InMemoryUserCommandRepository::new(store.clone()),
+        &InMemoryUserQueryRepository::new(store),
+    )
+    .await;
+}
+
+/// SQLite（インメモリのデータベース、1接続）
+#[tokio::test]
+async fn test_sqlite_memory_repository_conformance() {
+    let db = SqliteConnection::new_in_memory().unwrap();
+    user_repository_conformance::run(
+        "sqlite::memory:",
+        &SqliteUserCommandRepository::new(db.clone()),
+        &SqliteUserQueryRepository::new(db),
+    )
+    .await;
+}
+
+/// SQLite（ファイルのデータベース、読み取りは別の接続）
+#[tokio::test]
+async fn test_sqlite_file_repository_conformance() {
+    let path = std::env::temp_dir()
+        .join(format!("rusted-ca-conformance-{}", uuid::Uuid::new_v4()))
+        .join("app.db");
+    let db = SqliteConnection::open(&DatabaseConfig {
+        url: DatabaseUrl::SqliteFile(path.clone()),
+        sqlite_url: DatabaseUrl::SqliteFile(path.clone()),
+        busy_timeout: Duration::from_secs(2),
+        auto_migrate: true,
+        readers: 2,
+        acquire_timeout: Duration::from_secs(2),
+    })
+    .unwrap();
+    user_repository_conformance::run(
+        "sqlite file",
+        &SqliteUserCommandRepository::new(db.clone()),
+        &SqliteUserQueryRepository::new(db),
+    )
+    .await;
+    let _ = std::fs::remove_dir_all(path.parent().unwrap());
+}
+
+/// DIコンテナに差し替えたインメモリ実装を、全てのユースケースが使うことを確認
+#[tokio::test]
+async fn test_container_uses_injected_repositories() {
+    let store = InMemoryUserStore::new();
+    let di = DIContainer::new()
+        .with_config(AppConfig::from_env())
+        .with_user_repositories((
+            Arc::new(InMemoryUserCommandRepository::new(store.clone())),
+            Arc::new(InMemoryUserQueryRepository::new(store.clone())),
+        ));
+    di.seed_bootstrap_user(&BootstrapUserConfig {
+        email: "memory-admin@example.com".to_string(),
+        password: "Memory-Pass-123".to_string(),
+        name: "Memory Admin".to_string(),
+        role: Role::Admin,
+    })
+    .await
+    .unwrap();
+
+    {
+        let users = store.read();
+        let stored = users.find_by_email("memory-admin@example.com").unwrap();
+        assert_eq!(stored.user.role, Role::Admin);
+        assert!(stored.user.is_email_verified());
+    }
+
+    let (_, query_repo) = di.create_repositories().unwrap();
+    assert!(
+        query_repo
+            .exists_by_email(&Email::new("memory-admin@example.com".to_string()).unwrap())
+            .await
+            .unwrap()
+    );
+    // SQLiteのusersには保存しない
+    let sqlite_users: i64 = di
+        .create_database_connection()
+        .unwrap()
+        .execute_query(|conn| conn.query_row("SELECT COUNT(*) FROM users", [], |row| row.get(0)))
+        .await
+        .unwrap();
+    assert_eq!(sqlite_users, 0);
+}
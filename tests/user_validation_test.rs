@@ -4,6 +4,7 @@ use rusted_ca::domain::entity::user::User;
 use rusted_ca::domain::value_object::birth_date::BirthDate;
 use rusted_ca::domain::value_object::email::Email;
 use rusted_ca::domain::value_object::password::Password;
+use rusted_ca::domain::value_object::password_hash::PasswordHash;
 use rusted_ca::domain::value_object::phone::Phone;
 use rusted_ca::domain::value_object::user_id::UserId;
 use rusted_ca::domain::value_object::user_name::UserName;
@@ -14,10 +15,13 @@ fn test_user_creation_success() {
     let id = UserId::new("user-1".to_string());
     let email = Email::new("user@example.com".to_string()).unwrap();
     let name = UserName::new("John Doe".to_string()).unwrap();
-    let password = Password::new("SecurePassword123!".to_string()).unwrap();
+    let password_hash = PasswordHash::new(
+        "$argon2id$v=19$m=19456,t=2,p=1$c2FsdHNhbHQ$aGFzaGhhc2hoYXNoaGFzaA".to_string(),
+    )
+    .unwrap();
     let phone = Some(Phone::new("+81-90-1234-5678".to_string()).unwrap());
     let birth_date = Some(BirthDate::new("1990-01-01".to_string()).unwrap());
-    let user = User::new(id, email, name, password, phone, birth_date);
+    let user = User::new(id, email, name, password_hash, phone, birth_date);
     assert!(user.is_ok());
 }
 
@@ -36,6 +40,15 @@ fn test_empty_user_name() {
 #[test]
 fn test_short_password() {
     let result = Password::new("short".to_string());
+    assert!(matches!(
+        result,
+        Err(DomainError::PasswordPolicyViolation { .. })
+    ));
+}
+
+#[test]
+fn test_password_hash_rejects_plaintext() {
+    let result = PasswordHash::new("SecurePassword123!".to_string());
     assert!(matches!(result, Err(DomainError::InvalidPassword { .. })));
 }
 